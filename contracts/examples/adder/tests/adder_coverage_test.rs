@@ -0,0 +1,49 @@
+use dharitri_sc_scenario::*;
+
+const ADDER_PATH_EXPR: &str = "file:output/adder.wasm";
+
+fn world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new();
+    blockchain.set_current_dir_from_workspace("contracts/examples/adder");
+
+    blockchain.register_contract(ADDER_PATH_EXPR, adder::ContractBuilder);
+    blockchain.start_coverage::<adder::AbiProvider>(ADDER_PATH_EXPR);
+    blockchain
+}
+
+#[test]
+fn adder_coverage_complete_test() {
+    let mut world = world();
+    world.run_file("scenarios/adder.scen.json");
+
+    let reports = world.coverage_reports();
+    assert_eq!(reports.len(), 1);
+    assert_eq!(reports[0].contract_name, "adder");
+    assert!(reports[0].is_complete(), "{}", reports[0].to_text());
+}
+
+#[test]
+fn adder_coverage_report_files_test() {
+    let mut world = world();
+    world.run_file("scenarios/interactor_trace.scen.json");
+
+    let report_dir = std::env::temp_dir().join(format!("adder-coverage-{}", std::process::id()));
+    world.write_coverage_reports(&report_dir);
+    let text = std::fs::read_to_string(report_dir.join("adder.coverage.txt")).unwrap();
+    let json = std::fs::read_to_string(report_dir.join("adder.coverage.json")).unwrap();
+    std::fs::remove_dir_all(&report_dir).unwrap();
+
+    assert_eq!(
+        text,
+        "adder: 2/3 ABI items exercised\n  not exercised: view getSum\n"
+    );
+    assert!(json.contains(r#""exercised": 2"#));
+    assert!(json.contains(
+        r#""missed": [
+    {
+      "kind": "view",
+      "name": "getSum"
+    }
+  ]"#
+    ));
+}
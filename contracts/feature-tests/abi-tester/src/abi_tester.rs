@@ -111,6 +111,19 @@ pub trait AbiTester {
     #[storage_mapper("sample_storage_mapper")]
     fn sample_storage_mapper(&self) -> SingleValueMapper<OnlyShowsUpAsNestedInSingleValueMapper>;
 
+    /// Storage that is not exposed as an endpoint still shows up in the storage layout.
+    #[storage_mapper("sample_storage_map")]
+    fn sample_storage_map(&self, address: &ManagedAddress) -> MapMapper<u32, AbiEnum>;
+
+    #[storage_get("sample_storage_get")]
+    fn sample_storage_get(&self, index: usize) -> BigUint;
+
+    #[storage_mapper("sample_storage_only_type")]
+    fn sample_storage_only_type(&self) -> SingleValueMapper<OnlyShowsUpInStorage>;
+
+    #[storage_mapper("sample_storage_without_type_abi")]
+    fn sample_storage_without_type_abi(&self) -> SingleValueMapper<StoredWithoutTypeAbi>;
+
     #[view]
     fn item_for_vec(&self) -> Vec<OnlyShowsUpAsNestedInVec> {
         Vec::new()
@@ -79,3 +79,15 @@ pub struct OnlyShowsUpAsNestedInSlice;
 /// Tests that the ABI generator also fetches types that only appear as fields.
 #[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, TypeAbi)]
 pub struct OnlyShowsUpAsNestedInOption;
+
+/// Tests that the ABI generator also fetches types that only appear in storage.
+#[derive(TopEncode, TopDecode, TypeAbi)]
+pub struct OnlyShowsUpInStorage {
+    pub value: u32,
+}
+
+/// Stored types are not required to implement `TypeAbi`, but then the storage layout cannot describe them.
+#[derive(TopEncode, TopDecode)]
+pub struct StoredWithoutTypeAbi {
+    pub value: u32,
+}
@@ -28,12 +28,18 @@ fn abi_tester_abi_generated_ok() {
 
     let main_contract_abi_json = abi_json::abi_to_json_dummy_environment(&main_contract.abi);
     let view_contract_abi_json = abi_json::abi_to_json_dummy_environment(&view_contract.abi);
+    let main_contract_storage_json = abi_json::serialize_storage_layout_to_json(
+        &abi_json::StorageLayoutJson::from(&main_contract.abi),
+    );
 
     // save generated ABI to disk for easier comparison in case something is off
     let mut file = File::create("abi_tester_generated_main.abi.json").unwrap();
     file.write_all(main_contract_abi_json.as_bytes()).unwrap();
     let mut file = File::create("abi_tester_generated_view.abi.json").unwrap();
     file.write_all(view_contract_abi_json.as_bytes()).unwrap();
+    let mut file = File::create("abi_tester_generated_main.storage.json").unwrap();
+    file.write_all(main_contract_storage_json.as_bytes())
+        .unwrap();
 
     // load expected from disk & check!
     assert_eq!(
@@ -44,6 +50,10 @@ fn abi_tester_abi_generated_ok() {
         view_contract_abi_json,
         fs::read_to_string("./abi_tester_expected_view.abi.json").unwrap()
     );
+    assert_eq!(
+        main_contract_storage_json,
+        fs::read_to_string("./abi_tester_expected_main.storage.json").unwrap()
+    );
 }
 
 #[test]
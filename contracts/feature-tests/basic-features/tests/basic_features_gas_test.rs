@@ -0,0 +1,87 @@
+use dharitri_sc_scenario::{dharitri_chain_vm::schedule::GasSchedule, scenario_model::*, *};
+
+const BASIC_FEATURES_PATH_EXPR: &str = "file:output/basic-features.wasm";
+const GAS_LIMIT: u64 = 10_000_000;
+
+fn world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new();
+    blockchain.set_current_dir_from_workspace("contracts/feature-tests/basic-features");
+
+    blockchain.register_contract(BASIC_FEATURES_PATH_EXPR, basic_features::ContractBuilder);
+    blockchain.set_gas_schedule(GasSchedule::default_schedule());
+    blockchain
+}
+
+fn deploy(world: &mut ScenarioWorld) {
+    let code = world.code_expression(BASIC_FEATURES_PATH_EXPR);
+    world
+        .set_state_step(
+            SetStateStep::new()
+                .put_account("address:owner", Account::new().nonce(1))
+                .new_address("address:owner", 1, "sc:basic-features"),
+        )
+        .sc_deploy(
+            ScDeployStep::new()
+                .from("address:owner")
+                .code(code)
+                .gas_limit(GAS_LIMIT)
+                .expect(TxExpect::ok().no_result()),
+        );
+}
+
+fn store_bytes_step(value: &str, gas_limit: u64) -> ScCallStep {
+    ScCallStep::new()
+        .from("address:owner")
+        .to("sc:basic-features")
+        .function("store_bytes")
+        .argument(value)
+        .gas_limit(gas_limit)
+}
+
+#[test]
+fn basic_features_gas_storage_write_and_release() {
+    let mut world = world();
+    deploy(&mut world);
+
+    let release_per_byte = GasSchedule::default_schedule()
+        .base_operation_cost
+        .release_per_byte;
+
+    world
+        .sc_call(
+            store_bytes_step("str:0123456789", GAS_LIMIT)
+                .expect(TxExpect::ok().no_result().gas(190_000u64).refund(0u64)),
+        )
+        // the shorter value costs less, no new bytes are added, and the released ones are refunded
+        .sc_call(
+            store_bytes_step("str:01", GAS_LIMIT).expect(
+                TxExpect::ok()
+                    .no_result()
+                    .gas(82_000u64)
+                    .refund(8 * release_per_byte),
+            ),
+        )
+        .check_state_step(CheckStateStep::new().put_account(
+            "sc:basic-features",
+            CheckAccount::new().check_storage("str:storage_bytes", "str:01"),
+        ));
+}
+
+#[test]
+fn basic_features_gas_out_of_gas() {
+    let mut world = world();
+    deploy(&mut world);
+
+    world
+        .sc_call(
+            store_bytes_step("str:0123456789", 1_000).expect(
+                TxExpect::err(5, "str:out of gas")
+                    .gas(1_000u64)
+                    .refund(0u64),
+            ),
+        )
+        .check_state_step(CheckStateStep::new().put_account(
+            "sc:basic-features",
+            CheckAccount::new().check_storage("str:storage_bytes", ""),
+        ));
+}
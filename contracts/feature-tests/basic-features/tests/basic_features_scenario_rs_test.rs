@@ -67,7 +67,6 @@ fn count_ones_rs() {
 }
 
 #[test]
-#[ignore]
 fn crypto_elliptic_curves_rs() {
     world().run("scenarios/crypto_elliptic_curves.scen.json");
 }
@@ -78,7 +77,6 @@ fn crypto_keccak_256_rs() {
 }
 
 #[test]
-#[ignore]
 fn crypto_ripemd_160_rs() {
     world().run("scenarios/crypto_ripemd160.scen.json");
 }
@@ -89,7 +87,6 @@ fn crypto_sha_256_rs() {
 }
 
 #[test]
-#[ignore]
 fn crypto_verify_bls_rs() {
     world().run("scenarios/crypto_verify_bls.scen.json");
 }
@@ -100,7 +97,6 @@ fn crypto_verify_ed_25519_rs() {
 }
 
 #[test]
-#[ignore]
 fn crypto_verify_secp_256_k_1_rs() {
     world().run("scenarios/crypto_verify_secp256k1.scen.json");
 }
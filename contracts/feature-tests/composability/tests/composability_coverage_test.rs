@@ -0,0 +1,203 @@
+use vault::Vault;
+use dharitri_sc_scenario::{debug_executor::CoverageItemKind, scenario_model::*, *};
+
+const USER: &str = "address:user";
+const FORWARDER: &str = "sc:forwarder";
+const VAULT: &str = "sc:vault";
+const FORWARDER_CODE: &str = "file:forwarder/output/forwarder.wasm";
+const VAULT_CODE: &str = "file:vault/output/vault.wasm";
+
+fn world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new();
+    blockchain.set_current_dir_from_workspace("contracts/feature-tests/composability");
+
+    blockchain.register_contract(FORWARDER_CODE, forwarder::ContractBuilder);
+    blockchain.register_contract(VAULT_CODE, vault::ContractBuilder);
+    blockchain.start_coverage::<forwarder::AbiProvider>(FORWARDER_CODE);
+    blockchain.start_coverage::<vault::AbiProvider>(VAULT_CODE);
+
+    let forwarder_code = blockchain.code_expression(FORWARDER_CODE);
+    let vault_code = blockchain.code_expression(VAULT_CODE);
+    blockchain.set_state_step(
+        SetStateStep::new()
+            .put_account(USER, Account::new().nonce("0").balance("1000"))
+            .put_account(FORWARDER, Account::new().code(forwarder_code))
+            .put_account(VAULT, Account::new().balance("1000").code(vault_code)),
+    );
+    blockchain
+}
+
+fn is_hit(world: &ScenarioWorld, contract_name: &str, kind: CoverageItemKind, name: &str) -> bool {
+    let reports = world.coverage_reports();
+    let report = reports
+        .iter()
+        .find(|report| report.contract_name == contract_name)
+        .unwrap();
+    report
+        .items
+        .iter()
+        .find(|item| item.kind == kind && item.name == name)
+        .unwrap()
+        .hit
+}
+
+#[test]
+fn coverage_endpoints_and_events_test() {
+    let mut world = world();
+    world.sc_call(
+        ScCallStep::new()
+            .from(USER)
+            .to(FORWARDER)
+            .moax_value("100")
+            .function("forward_sync_accept_funds")
+            .argument(VAULT)
+            .expect(TxExpect::ok().no_result()),
+    );
+
+    assert!(is_hit(
+        &world,
+        "forwarder",
+        CoverageItemKind::Endpoint,
+        "forward_sync_accept_funds"
+    ));
+    assert!(is_hit(
+        &world,
+        "vault",
+        CoverageItemKind::Endpoint,
+        "accept_funds_echo_payment"
+    ));
+    assert!(is_hit(
+        &world,
+        "vault",
+        CoverageItemKind::Event,
+        "accept_funds"
+    ));
+    assert!(!is_hit(
+        &world,
+        "vault",
+        CoverageItemKind::Endpoint,
+        "reject_funds"
+    ));
+    assert!(!is_hit(
+        &world,
+        "vault",
+        CoverageItemKind::Event,
+        "reject_funds"
+    ));
+    assert!(!is_hit(
+        &world,
+        "vault",
+        CoverageItemKind::View,
+        "call_counts"
+    ));
+
+    world.sc_query(
+        ScQueryStep::new()
+            .to(VAULT)
+            .function("call_counts")
+            .argument("str:accept_funds_echo_payment")
+            .expect(TxExpect::ok().result("1")),
+    );
+    assert!(is_hit(
+        &world,
+        "vault",
+        CoverageItemKind::View,
+        "call_counts"
+    ));
+}
+
+#[test]
+fn coverage_callback_test() {
+    let mut world = world();
+    assert!(!is_hit(
+        &world,
+        "forwarder",
+        CoverageItemKind::Callback,
+        "callBack"
+    ));
+
+    world.sc_call(
+        ScCallStep::new()
+            .from(USER)
+            .to(FORWARDER)
+            .function("forward_async_retrieve_funds")
+            .argument(VAULT)
+            .argument("str:MOAX")
+            .argument("0")
+            .argument("500")
+            .expect(TxExpect::ok().no_result()),
+    );
+
+    assert!(is_hit(
+        &world,
+        "vault",
+        CoverageItemKind::Endpoint,
+        "retrieve_funds"
+    ));
+    assert!(is_hit(
+        &world,
+        "vault",
+        CoverageItemKind::Event,
+        "retrieve_funds"
+    ));
+    assert!(is_hit(
+        &world,
+        "forwarder",
+        CoverageItemKind::Callback,
+        "callBack"
+    ));
+}
+
+#[test]
+fn coverage_failed_call_test() {
+    let mut world = world();
+    world.sc_call(
+        ScCallStep::new()
+            .from(USER)
+            .to(VAULT)
+            .moax_value("100")
+            .function("reject_funds")
+            .expect(TxExpect::user_error("str:reject_funds")),
+    );
+
+    // the endpoint was exercised, but its event got reverted
+    assert!(is_hit(
+        &world,
+        "vault",
+        CoverageItemKind::Endpoint,
+        "reject_funds"
+    ));
+    assert!(!is_hit(
+        &world,
+        "vault",
+        CoverageItemKind::Event,
+        "reject_funds"
+    ));
+}
+
+#[test]
+fn coverage_whitebox_call_test() {
+    let mut world = world();
+    let vault_whitebox = WhiteboxContract::new(VAULT, vault::contract_obj);
+    world.whitebox_call(
+        &vault_whitebox,
+        ScCallStep::new().from(USER).moax_value("100"),
+        |sc| {
+            sc.accept_funds();
+        },
+    );
+
+    // the endpoint method was called directly, only its event can be attributed
+    assert!(is_hit(
+        &world,
+        "vault",
+        CoverageItemKind::Event,
+        "accept_funds"
+    ));
+    assert!(!is_hit(
+        &world,
+        "vault",
+        CoverageItemKind::Endpoint,
+        "accept_funds"
+    ));
+}
@@ -0,0 +1,149 @@
+use dharitri_sc_scenario::{scenario_model::*, *};
+
+const USER: &str = "address:user#00";
+const FORWARDER: &str = "sc:forwarder#00";
+const VAULT: &str = "sc:vault#01";
+const FORWARDER_CODE: &str = "file:forwarder/output/forwarder.wasm";
+const VAULT_CODE: &str = "file:vault/output/vault.wasm";
+
+fn world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new();
+    blockchain.set_current_dir_from_workspace("contracts/feature-tests/composability");
+    blockchain.set_num_shards(3);
+
+    blockchain.register_contract(FORWARDER_CODE, forwarder::ContractBuilder);
+    blockchain.register_contract(VAULT_CODE, vault::ContractBuilder);
+
+    let forwarder_code = blockchain.code_expression(FORWARDER_CODE);
+    let vault_code = blockchain.code_expression(VAULT_CODE);
+    blockchain.set_state_step(
+        SetStateStep::new()
+            .put_account(USER, Account::new().nonce("0").balance("1000"))
+            .put_account(FORWARDER, Account::new().code(forwarder_code))
+            .put_account(VAULT, Account::new().balance("1000").code(vault_code)),
+    );
+    blockchain
+}
+
+fn next_block(world: &mut ScenarioWorld, block_nonce: u64) {
+    world.set_state_step(SetStateStep::new().block_nonce(block_nonce));
+}
+
+fn check_balance(world: &mut ScenarioWorld, address: &str, balance: &str) {
+    world.check_state_step(
+        CheckStateStep::new().put_account(address, CheckAccount::new().balance(balance)),
+    );
+}
+
+#[test]
+fn cross_shard_async_call_test() {
+    let mut world = world();
+
+    world.sc_call(
+        ScCallStep::new()
+            .from(USER)
+            .to(FORWARDER)
+            .moax_value("300")
+            .function("forward_async_accept_funds")
+            .argument(VAULT)
+            .expect(TxExpect::ok().no_result()),
+    );
+
+    // the funds are in flight
+    check_balance(&mut world, FORWARDER, "0");
+    check_balance(&mut world, VAULT, "1000");
+
+    next_block(&mut world, 1);
+    check_balance(&mut world, FORWARDER, "0");
+    check_balance(&mut world, VAULT, "1300");
+}
+
+#[test]
+fn cross_shard_callback_test() {
+    let mut world = world();
+
+    world.sc_call(
+        ScCallStep::new()
+            .from(USER)
+            .to(FORWARDER)
+            .function("forward_async_retrieve_funds")
+            .argument(VAULT)
+            .argument("str:MOAX")
+            .argument("0")
+            .argument("500")
+            .expect(TxExpect::ok().no_result()),
+    );
+    check_balance(&mut world, VAULT, "1000");
+
+    // the vault sends the funds, they only reach the forwarder in the next block, along with the callback
+    next_block(&mut world, 1);
+    check_balance(&mut world, VAULT, "500");
+    check_balance(&mut world, FORWARDER, "0");
+    world.sc_query(
+        ScQueryStep::new()
+            .to(FORWARDER)
+            .function("callback_data")
+            .expect(TxExpect::ok().no_result()),
+    );
+
+    next_block(&mut world, 2);
+    check_balance(&mut world, FORWARDER, "500");
+    world.sc_query(
+        ScQueryStep::new()
+            .to(FORWARDER)
+            .function("callback_data_at_index")
+            .argument("1")
+            .expect(
+                TxExpect::ok()
+                    .result("str:retrieve_funds_callback")
+                    .result("str:MOAX")
+                    .result("0")
+                    .result("500"),
+            ),
+    );
+}
+
+#[test]
+fn cross_shard_calls_wait_for_next_block_test() {
+    let mut world = world();
+
+    world.sc_call(
+        ScCallStep::new()
+            .from(USER)
+            .to(FORWARDER)
+            .function("forward_async_retrieve_funds")
+            .argument(VAULT)
+            .argument("str:MOAX")
+            .argument("0")
+            .argument("500"),
+    );
+
+    // the block nonce does not change, so nothing gets executed
+    world.set_state_step(SetStateStep::new().block_timestamp(100));
+    check_balance(&mut world, VAULT, "1000");
+
+    // advancing several blocks at once processes everything
+    next_block(&mut world, 5);
+    check_balance(&mut world, VAULT, "500");
+    check_balance(&mut world, FORWARDER, "500");
+}
+
+#[test]
+fn cross_shard_sync_call_test() {
+    let mut world = world();
+
+    world.sc_call(
+        ScCallStep::new()
+            .from(USER)
+            .to(FORWARDER)
+            .moax_value("300")
+            .function("forward_sync_accept_funds")
+            .argument(VAULT)
+            .expect(TxExpect::err(
+                10,
+                "str:sync execution request is not in the same shard",
+            )),
+    );
+    check_balance(&mut world, USER, "1000");
+    check_balance(&mut world, VAULT, "1000");
+}
@@ -0,0 +1,203 @@
+use dharitri_sc_scenario::{
+    dharitri_chain_vm::tx_mock::{TxCallKind, TxCallTrace},
+    scenario_model::*,
+    *,
+};
+
+const USER: &str = "address:user";
+const FORWARDER: &str = "sc:forwarder";
+const FORWARDER_RAW: &str = "sc:forwarder-raw";
+const VAULT: &str = "sc:vault";
+const FORWARDER_CODE: &str = "file:forwarder/output/forwarder.wasm";
+const FORWARDER_RAW_CODE: &str = "file:forwarder-raw/output/forwarder-raw.wasm";
+const VAULT_CODE: &str = "file:vault/output/vault.wasm";
+
+fn world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new();
+    blockchain.set_current_dir_from_workspace("contracts/feature-tests/composability");
+
+    blockchain.register_contract(FORWARDER_CODE, forwarder::ContractBuilder);
+    blockchain.register_contract(FORWARDER_RAW_CODE, forwarder_raw::ContractBuilder);
+    blockchain.register_contract(VAULT_CODE, vault::ContractBuilder);
+
+    let forwarder_code = blockchain.code_expression(FORWARDER_CODE);
+    let forwarder_raw_code = blockchain.code_expression(FORWARDER_RAW_CODE);
+    let vault_code = blockchain.code_expression(VAULT_CODE);
+    blockchain.set_state_step(
+        SetStateStep::new()
+            .put_account(USER, Account::new().nonce("0").balance("1000"))
+            .put_account(FORWARDER, Account::new().code(forwarder_code))
+            .put_account(FORWARDER_RAW, Account::new().code(forwarder_raw_code))
+            .put_account(VAULT, Account::new().balance("1000").code(vault_code)),
+    );
+    blockchain.start_execution_trace();
+    blockchain
+}
+
+fn single_trace(world: &ScenarioWorld) -> TxCallTrace {
+    let mut traces = world.execution_trace();
+    assert_eq!(traces.len(), 1);
+    traces.remove(0)
+}
+
+fn address(expr: &str) -> dharitri_chain_vm::types::VMAddress {
+    AddressValue::from(expr).to_vm_address()
+}
+
+#[test]
+fn execution_trace_sync_call_test() {
+    let mut world = world();
+    world.sc_call(
+        ScCallStep::new()
+            .from(USER)
+            .to(FORWARDER)
+            .moax_value("100")
+            .function("forward_sync_accept_funds")
+            .argument(VAULT)
+            .expect(TxExpect::ok().no_result()),
+    );
+
+    let trace = single_trace(&world);
+    assert_eq!(trace.call_kind, TxCallKind::Transaction);
+    assert_eq!(trace.endpoint.as_str(), "forward_sync_accept_funds");
+    assert_eq!(trace.arguments, vec![address(VAULT).to_vec()]);
+    assert_eq!(trace.moax_value, 100u32.into());
+    assert!(trace.is_success());
+    assert_eq!(trace.nested_calls.len(), 1);
+
+    let sync_call = &trace.nested_calls[0];
+    assert_eq!(sync_call.call_kind, TxCallKind::SyncCall);
+    assert_eq!(sync_call.from, address(FORWARDER));
+    assert_eq!(sync_call.to, address(VAULT));
+    assert_eq!(sync_call.endpoint.as_str(), "accept_funds_echo_payment");
+    assert_eq!(sync_call.moax_value, 100u32.into());
+    assert_eq!(sync_call.logs.len(), 1);
+    assert_eq!(sync_call.logs[0].topics[0], b"accept_funds".to_vec());
+    assert_eq!(sync_call.storage_writes.len(), 1);
+    assert_eq!(sync_call.storage_writes[0].address, address(VAULT));
+
+    // logs only belong to the call that produced them
+    assert_eq!(trace.logs.len(), 1);
+    assert_eq!(
+        trace.logs[0].topics[0],
+        b"accept_funds_sync_result".to_vec()
+    );
+}
+
+#[test]
+fn execution_trace_async_call_test() {
+    let mut world = world();
+    world.sc_call(
+        ScCallStep::new()
+            .from(USER)
+            .to(FORWARDER)
+            .function("forward_async_retrieve_funds")
+            .argument(VAULT)
+            .argument("str:MOAX")
+            .argument("0")
+            .argument("500")
+            .expect(TxExpect::ok().no_result()),
+    );
+
+    let trace = single_trace(&world);
+    let kinds: Vec<TxCallKind> = trace
+        .nested_calls
+        .iter()
+        .map(|call| call.call_kind)
+        .collect();
+    assert_eq!(kinds, vec![TxCallKind::AsyncCall, TxCallKind::Callback]);
+
+    let async_call = &trace.nested_calls[0];
+    assert_eq!(async_call.endpoint.as_str(), "retrieve_funds");
+    assert_eq!(async_call.nested_calls.len(), 1);
+    assert_eq!(
+        async_call.nested_calls[0].call_kind,
+        TxCallKind::TransferExecute
+    );
+    assert_eq!(async_call.nested_calls[0].moax_value, 500u32.into());
+
+    let callback = &trace.nested_calls[1];
+    assert_eq!(callback.to, address(FORWARDER));
+    assert_eq!(callback.endpoint.as_str(), "callBack");
+    assert!(!callback.storage_writes.is_empty());
+    assert!(callback
+        .storage_writes
+        .iter()
+        .all(|storage_write| storage_write.address == address(FORWARDER)));
+    assert!(trace.failed_call().is_none());
+
+    let tree = world.execution_trace_tree();
+    assert!(
+        tree.starts_with("transaction address:user -> sc:forwarder forward_async_retrieve_funds(")
+    );
+    assert!(tree.contains(
+        "├─ async sc:forwarder -> sc:vault retrieve_funds(0x4d4f4158 (str:MOAX), , 0x01f4 (500)) => ok\n"
+    ));
+    assert!(tree.contains("│  └─ transferExecute sc:vault -> sc:forwarder () moax: 500 => ok\n"));
+    assert!(tree.contains("└─ callback sc:vault -> sc:forwarder callBack(0x00 (0)) => ok\n"));
+}
+
+#[test]
+fn execution_trace_failed_call_test() {
+    let mut world = world();
+    world.sc_call(
+        ScCallStep::new()
+            .from(USER)
+            .to(FORWARDER_RAW)
+            .moax_value("100")
+            .function("forward_async_call")
+            .argument(VAULT)
+            .argument("str:reject_funds")
+            .expect(TxExpect::ok().no_result()),
+    );
+
+    let trace = single_trace(&world);
+    assert!(trace.is_success());
+
+    let failed_call = trace.failed_call().unwrap();
+    assert_eq!(failed_call.call_kind, TxCallKind::AsyncCall);
+    assert_eq!(failed_call.endpoint.as_str(), "reject_funds");
+    assert_eq!(failed_call.result_status, 4);
+    assert_eq!(failed_call.result_message, "reject_funds");
+
+    // the log is reverted, but still shows up in the trace
+    assert_eq!(failed_call.logs.len(), 1);
+    assert_eq!(failed_call.logs[0].topics[0], b"reject_funds".to_vec());
+
+    let tree = world.execution_trace_tree();
+    assert!(tree
+        .contains("async sc:forwarder-raw -> sc:vault reject_funds() moax: 100 => FAILED, status 4: reject_funds\n"));
+}
+
+#[test]
+fn execution_trace_json_test() {
+    let mut world = world();
+    world.sc_call(
+        ScCallStep::new()
+            .from(USER)
+            .to(FORWARDER)
+            .moax_value("100")
+            .function("forward_sync_accept_funds")
+            .argument(VAULT)
+            .expect(TxExpect::ok().no_result()),
+    );
+    world.sc_query(
+        ScQueryStep::new()
+            .to(VAULT)
+            .function("echo_arguments")
+            .argument("str:hello")
+            .expect(TxExpect::ok().result("str:hello")),
+    );
+
+    let trace_path =
+        std::env::temp_dir().join(format!("execution-trace-{}.json", std::process::id()));
+    world.write_execution_trace(&trace_path);
+    let json = std::fs::read_to_string(&trace_path).unwrap();
+    std::fs::remove_file(&trace_path).unwrap();
+
+    assert!(json.contains(r#""kind": "transaction""#));
+    assert!(json.contains(r#""kind": "sync""#));
+    assert!(json.contains(r#""kind": "query""#));
+    assert!(json.contains(r#""endpoint": "accept_funds_echo_payment""#));
+    assert!(json.contains(r#""moaxValue": "100""#));
+}
@@ -2,6 +2,7 @@ mod build_info_abi;
 mod contract_abi;
 mod endpoint_abi;
 mod event_abi;
+mod storage_abi;
 mod type_abi;
 mod type_abi_impl_basic;
 mod type_abi_impl_codec_multi;
@@ -12,6 +13,7 @@ pub use build_info_abi::*;
 pub use contract_abi::*;
 pub use endpoint_abi::*;
 pub use event_abi::*;
+pub use storage_abi::*;
 pub use type_abi::*;
 pub use type_description::*;
 pub use type_description_container::*;
@@ -10,6 +10,7 @@ pub struct ContractAbi {
     pub endpoints: Vec<EndpointAbi>,
     pub promise_callbacks: Vec<EndpointAbi>,
     pub events: Vec<EventAbi>,
+    pub storage: Vec<StorageAbi>,
     pub has_callback: bool,
     pub type_descriptions: TypeDescriptionContainerImpl,
 }
@@ -20,6 +21,7 @@ impl ContractAbi {
             .extend_from_slice(other.constructors.as_slice());
         self.endpoints.extend_from_slice(other.endpoints.as_slice());
         self.events.extend_from_slice(other.events.as_slice());
+        self.storage.extend_from_slice(other.storage.as_slice());
         self.promise_callbacks
             .extend_from_slice(other.promise_callbacks.as_slice());
         self.has_callback |= other.has_callback;
@@ -0,0 +1,95 @@
+use super::{TypeAbi, TypeDescriptionContainer, TypeName};
+use alloc::{string::ToString, vec::Vec};
+use core::marker::PhantomData;
+
+/// A storage key argument: a method argument that gets appended to the base storage key.
+#[derive(Clone, Debug)]
+pub struct StorageKeyArgAbi {
+    pub arg_name: &'static str,
+    pub type_name: TypeName,
+}
+
+/// Describes a storage entry, as declared by a `#[storage_mapper]`, `#[storage_get]` or `#[storage_set]` method.
+///
+/// Type names are the ABI ones for the stored types that implement `TypeAbi`, which also have their type descriptions
+/// added to the contract ABI. Stored types are not required to implement it, the others are named as written in the contract.
+#[derive(Clone, Debug)]
+pub struct StorageAbi {
+    pub docs: &'static [&'static str],
+    pub key: &'static str,
+    pub rust_method_name: &'static str,
+
+    /// The storage mapper type name, e.g. `SingleValueMapper`,
+    /// or `storage_get`/`storage_set` for methods that access the value directly.
+    pub mapper: &'static str,
+
+    pub key_args: Vec<StorageKeyArgAbi>,
+
+    /// The type arguments of the storage mapper, or the value type, when accessed directly.
+    pub value_types: Vec<TypeName>,
+
+    /// Key argument and value types that do not implement `TypeAbi`, so their structure is not known.
+    pub undescribed_types: Vec<&'static str>,
+}
+
+/// Lets the generated ABI code name and add the type descriptions of the stored types that implement `TypeAbi`,
+/// while still accepting the ones that do not.
+///
+/// Called as `(&StorageTypeDescriptions::<T>::new()).provide_type_descriptions(accumulator)`,
+/// or likewise `.storage_type_name(written_name)`, with both traits in scope: method resolution prefers `ProvideStorageTypeDescriptions` when `T: TypeAbi`,
+/// and falls back to `ProvideNoStorageTypeDescriptions` otherwise.
+pub struct StorageTypeDescriptions<T>(PhantomData<T>);
+
+impl<T> StorageTypeDescriptions<T> {
+    pub fn new() -> Self {
+        StorageTypeDescriptions(PhantomData)
+    }
+}
+
+impl<T> Default for StorageTypeDescriptions<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub trait ProvideStorageTypeDescriptions {
+    /// Returns `true`, since the type descriptions are known.
+    fn provide_type_descriptions<TDC: TypeDescriptionContainer>(
+        &self,
+        accumulator: &mut TDC,
+    ) -> bool;
+
+    /// The ABI type name, so that different spellings of the same type get the same name.
+    fn storage_type_name(&self, written_name: &str) -> TypeName;
+}
+
+impl<T: TypeAbi> ProvideStorageTypeDescriptions for StorageTypeDescriptions<T> {
+    fn provide_type_descriptions<TDC: TypeDescriptionContainer>(
+        &self,
+        accumulator: &mut TDC,
+    ) -> bool {
+        T::provide_type_descriptions(accumulator);
+        true
+    }
+
+    fn storage_type_name(&self, _written_name: &str) -> TypeName {
+        T::type_name()
+    }
+}
+
+pub trait ProvideNoStorageTypeDescriptions {
+    /// Returns `false`, since the type does not implement `TypeAbi`.
+    fn provide_type_descriptions<TDC: TypeDescriptionContainer>(
+        &self,
+        _accumulator: &mut TDC,
+    ) -> bool {
+        false
+    }
+
+    /// The type name as written in the contract, since there is no ABI name.
+    fn storage_type_name(&self, written_name: &str) -> TypeName {
+        written_name.to_string()
+    }
+}
+
+impl<T> ProvideNoStorageTypeDescriptions for &StorageTypeDescriptions<T> {}
@@ -1,7 +1,7 @@
 use super::util::*;
 use crate::model::{
-    AutoImpl, ContractTrait, EndpointMutabilityMetadata, EndpointTypeMetadata, Method, MethodImpl,
-    PublicRole,
+    AutoImpl, ContractTrait, EndpointMutabilityMetadata, EndpointTypeMetadata, Method,
+    MethodArgument, MethodImpl, PublicRole,
 };
 
 fn generate_endpoint_snippet(
@@ -169,6 +169,136 @@ fn generate_event_snippets(contract: &ContractTrait) -> Vec<proc_macro2::TokenSt
         .collect()
 }
 
+/// Adds the type descriptions of a stored type to the contract ABI, if it implements `TypeAbi`,
+/// otherwise records it as undescribed.
+fn generate_storage_type_description_snippet(ty: &syn::Type) -> proc_macro2::TokenStream {
+    let mut ty = ty.clone();
+    clear_all_type_lifetimes(&mut ty);
+    let type_name = storage_type_name(&ty);
+    quote! {
+        let described = {
+            use dharitri_sc::abi::{ProvideNoStorageTypeDescriptions, ProvideStorageTypeDescriptions};
+            (&dharitri_sc::abi::StorageTypeDescriptions::<#ty>::new())
+                .provide_type_descriptions(&mut contract_abi.type_descriptions)
+        };
+        if !described {
+            storage_abi.undescribed_types.push(#type_name);
+        }
+    }
+}
+
+/// The ABI name of a stored type, if it implements `TypeAbi`, otherwise the name as written in the contract.
+fn generate_storage_type_name_snippet(ty: &syn::Type) -> proc_macro2::TokenStream {
+    let mut ty = ty.clone();
+    clear_all_type_lifetimes(&mut ty);
+    let written_name = storage_type_name(&ty);
+    quote! {
+        {
+            use dharitri_sc::abi::{ProvideNoStorageTypeDescriptions, ProvideStorageTypeDescriptions};
+            (&dharitri_sc::abi::StorageTypeDescriptions::<#ty>::new())
+                .storage_type_name(#written_name)
+        }
+    }
+}
+
+fn generate_storage_snippet(
+    m: &Method,
+    identifier: &str,
+    mapper: String,
+    key_args: &[MethodArgument],
+    value_types: &[syn::Type],
+) -> proc_macro2::TokenStream {
+    let storage_docs = &m.docs;
+    let rust_method_name = m.name.to_string();
+    let key_arg_snippets: Vec<proc_macro2::TokenStream> = key_args
+        .iter()
+        .map(|arg| {
+            let arg_name = &arg.pat;
+            let arg_name_str = quote! { #arg_name }.to_string();
+            let type_name = generate_storage_type_name_snippet(&arg.ty);
+            quote! {
+                dharitri_sc::abi::StorageKeyArgAbi {
+                    arg_name: #arg_name_str,
+                    type_name: #type_name,
+                }
+            }
+        })
+        .collect();
+    let value_type_names: Vec<proc_macro2::TokenStream> = value_types
+        .iter()
+        .map(generate_storage_type_name_snippet)
+        .collect();
+    let type_description_snippets: Vec<proc_macro2::TokenStream> = key_args
+        .iter()
+        .map(|arg| &arg.ty)
+        .chain(value_types.iter())
+        .map(generate_storage_type_description_snippet)
+        .collect();
+    // some mappers, e.g. `UserMapper`, have no types to describe
+    let storage_abi_mut = if type_description_snippets.is_empty() {
+        quote! {}
+    } else {
+        quote! { mut }
+    };
+
+    quote! {
+        let #storage_abi_mut storage_abi = dharitri_sc::abi::StorageAbi {
+            docs: &[ #(#storage_docs),* ],
+            key: #identifier,
+            rust_method_name: #rust_method_name,
+            mapper: #mapper,
+            key_args: dharitri_sc::types::heap::Vec::from([ #(#key_arg_snippets),* ]),
+            value_types: dharitri_sc::types::heap::Vec::from([ #(#value_type_names),* ]),
+            undescribed_types: dharitri_sc::types::heap::Vec::new(),
+        };
+        #(#type_description_snippets)*
+        contract_abi.storage.push(storage_abi);
+    }
+}
+
+fn generate_storage_snippets(contract: &ContractTrait) -> Vec<proc_macro2::TokenStream> {
+    contract
+        .methods
+        .iter()
+        .filter_map(|m| match (&m.implementation, &m.return_type) {
+            (
+                MethodImpl::Generated(AutoImpl::StorageMapper { identifier }),
+                syn::ReturnType::Type(_, ty),
+            ) => {
+                let (mapper, value_types) = storage_mapper_name_and_type_args(ty);
+                Some(generate_storage_snippet(
+                    m,
+                    identifier,
+                    mapper,
+                    &m.method_args,
+                    &value_types,
+                ))
+            },
+            (
+                MethodImpl::Generated(AutoImpl::StorageGetter { identifier }),
+                syn::ReturnType::Type(_, ty),
+            ) => Some(generate_storage_snippet(
+                m,
+                identifier,
+                "storage_get".to_string(),
+                &m.method_args,
+                &[ty.as_ref().clone()],
+            )),
+            (MethodImpl::Generated(AutoImpl::StorageSetter { identifier }), _) => {
+                let (value_arg, key_args) = m.method_args.split_last()?;
+                Some(generate_storage_snippet(
+                    m,
+                    identifier,
+                    "storage_set".to_string(),
+                    key_args,
+                    &[value_arg.ty.clone()],
+                ))
+            },
+            _ => None,
+        })
+        .collect()
+}
+
 fn has_callback(contract: &ContractTrait) -> bool {
     contract.methods.iter().any(|m| {
         matches!(
@@ -199,6 +329,7 @@ fn generate_abi_method_body(
     let contract_name = &contract.trait_name.to_string();
     let endpoint_snippets = generate_endpoint_snippets(contract);
     let event_snippets = generate_event_snippets(contract);
+    let storage_snippets = generate_storage_snippets(contract);
     let has_callbacks = has_callback(contract);
     let supertrait_snippets: Vec<proc_macro2::TokenStream> = if is_contract_main {
         generate_supertrait_snippets(contract)
@@ -222,11 +353,13 @@ fn generate_abi_method_body(
             endpoints: dharitri_sc::types::heap::Vec::new(),
             promise_callbacks: dharitri_sc::types::heap::Vec::new(),
             events: dharitri_sc::types::heap::Vec::new(),
+            storage: dharitri_sc::types::heap::Vec::new(),
             has_callback: #has_callbacks,
             type_descriptions: <dharitri_sc::abi::TypeDescriptionContainerImpl as dharitri_sc::abi::TypeDescriptionContainer>::new(),
         };
         #(#endpoint_snippets)*
         #(#event_snippets)*
+        #(#storage_snippets)*
         #(#supertrait_snippets)*
         contract_abi
     }
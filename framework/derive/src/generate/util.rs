@@ -81,3 +81,98 @@ pub fn clear_all_type_lifetimes(ty: &mut syn::Type) {
         _ => {},
     }
 }
+
+/// The type name as written in the contract, for the storage ABI of the types that do not implement `TypeAbi`.
+///
+/// References and module paths are dropped, and so are the `Self::Api` type arguments added by preprocessing,
+/// so that the names match the ones in the ABI type descriptions.
+pub fn storage_type_name(ty: &syn::Type) -> String {
+    let mut ty = ty.clone();
+    clear_all_type_lifetimes(&mut ty);
+    simplify_storage_type(&mut ty);
+    let ty = match ty {
+        syn::Type::Reference(r) => *r.elem,
+        ty => ty,
+    };
+    quote! { #ty }.to_string().replace(' ', "")
+}
+
+fn is_self_api(ty: &syn::Type) -> bool {
+    if let syn::Type::Path(type_path) = ty {
+        let segments: Vec<String> = type_path
+            .path
+            .segments
+            .iter()
+            .map(|segment| segment.ident.to_string())
+            .collect();
+        return type_path.qself.is_none() && segments == ["Self", "Api"];
+    }
+    false
+}
+
+fn simplify_storage_type(ty: &mut syn::Type) {
+    match ty {
+        syn::Type::Reference(r) => simplify_storage_type(&mut r.elem),
+        syn::Type::Array(a) => simplify_storage_type(&mut a.elem),
+        syn::Type::Slice(s) => simplify_storage_type(&mut s.elem),
+        syn::Type::Paren(p) => simplify_storage_type(&mut p.elem),
+        syn::Type::Group(g) => simplify_storage_type(&mut g.elem),
+        syn::Type::Tuple(t) => t.elems.iter_mut().for_each(simplify_storage_type),
+        syn::Type::Path(type_path) => {
+            for path_segm in type_path.path.segments.iter_mut() {
+                if let syn::PathArguments::AngleBracketed(angle_bracketed) =
+                    &mut path_segm.arguments
+                {
+                    angle_bracketed.args = angle_bracketed
+                        .args
+                        .iter()
+                        .filter(|gen_arg| {
+                            !matches!(gen_arg, syn::GenericArgument::Type(gen_ty) if is_self_api(gen_ty))
+                        })
+                        .cloned()
+                        .map(|mut gen_arg| {
+                            if let syn::GenericArgument::Type(gen_ty) = &mut gen_arg {
+                                simplify_storage_type(gen_ty);
+                            }
+                            gen_arg
+                        })
+                        .collect();
+                    if angle_bracketed.args.is_empty() {
+                        path_segm.arguments = syn::PathArguments::None;
+                    }
+                }
+            }
+            if type_path.qself.is_none() {
+                if let Some(last_segment) = type_path.path.segments.pop() {
+                    type_path.path.segments.clear();
+                    type_path.path.segments.push(last_segment.into_value());
+                    type_path.path.leading_colon = None;
+                }
+            }
+        },
+        _ => {},
+    }
+}
+
+/// Splits a storage mapper type into the mapper name and its type arguments, without the `Self::Api` one.
+pub fn storage_mapper_name_and_type_args(ty: &syn::Type) -> (String, Vec<syn::Type>) {
+    if let syn::Type::Path(type_path) = ty {
+        if let Some(last_segment) = type_path.path.segments.last() {
+            let type_args = match &last_segment.arguments {
+                syn::PathArguments::AngleBracketed(angle_bracketed) => angle_bracketed
+                    .args
+                    .iter()
+                    .filter_map(|gen_arg| match gen_arg {
+                        syn::GenericArgument::Type(gen_ty) if !is_self_api(gen_ty) => {
+                            Some(gen_ty.clone())
+                        },
+                        _ => None,
+                    })
+                    .collect(),
+                _ => Vec::new(),
+            };
+            return (last_segment.ident.to_string(), type_args);
+        }
+    }
+    (storage_type_name(ty), Vec::new())
+}
@@ -2,13 +2,15 @@ mod build_info_abi_json;
 mod contract_abi_json;
 mod endpoint_abi_json;
 mod event_abi_json;
+mod storage_abi_json;
 mod type_abi_json;
 
-pub use build_info_abi_json::{BuildInfoAbiJson, RustcAbiJson};
+pub use build_info_abi_json::{BuildInfoAbiJson, FrameworkBuildAbiJson, RustcAbiJson};
 pub use contract_abi_json::*;
 pub use endpoint_abi_json::*;
 pub use event_abi_json::*;
 use dharitri_sc::{abi::ContractAbi, contract_base::ContractAbiProvider};
+pub use storage_abi_json::*;
 pub use type_abi_json::*;
 
 /// Function provided for convenience.
@@ -0,0 +1,119 @@
+use super::TypeDescriptionJson;
+use dharitri_sc::abi::*;
+use serde::{Deserialize, Serialize};
+use std::{collections::BTreeMap, fs, path::Path};
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct StorageKeyArgAbiJson {
+    #[serde(rename = "name")]
+    pub arg_name: String,
+    #[serde(rename = "type")]
+    pub type_name: String,
+}
+
+impl From<&StorageKeyArgAbi> for StorageKeyArgAbiJson {
+    fn from(abi: &StorageKeyArgAbi) -> Self {
+        StorageKeyArgAbiJson {
+            arg_name: abi.arg_name.to_string(),
+            type_name: abi.type_name.to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct StorageAbiJson {
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub docs: Vec<String>,
+    pub key: String,
+    pub rust_method_name: String,
+    pub mapper: String,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub key_args: Vec<StorageKeyArgAbiJson>,
+    pub value_types: Vec<String>,
+    /// Types that do not implement `TypeAbi`, so changes to their structure cannot be checked.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub undescribed_types: Vec<String>,
+}
+
+impl From<&StorageAbi> for StorageAbiJson {
+    fn from(abi: &StorageAbi) -> Self {
+        StorageAbiJson {
+            docs: abi.docs.iter().map(|d| d.to_string()).collect(),
+            key: abi.key.to_string(),
+            rust_method_name: abi.rust_method_name.to_string(),
+            mapper: abi.mapper.to_string(),
+            key_args: abi
+                .key_args
+                .iter()
+                .map(StorageKeyArgAbiJson::from)
+                .collect(),
+            value_types: abi.value_types.iter().map(|t| t.to_string()).collect(),
+            undescribed_types: abi
+                .undescribed_types
+                .iter()
+                .map(|t| t.to_string())
+                .collect(),
+        }
+    }
+}
+
+/// The storage layout of a contract, saved alongside the ABI, as `<contract>.storage.json`.
+///
+/// Also contains the descriptions of the contract types, so that upgrades can be checked
+/// for changes in the stored structs and enums.
+#[derive(Serialize, Deserialize)]
+pub struct StorageLayoutJson {
+    pub name: String,
+    pub storage: Vec<StorageAbiJson>,
+    pub types: BTreeMap<String, TypeDescriptionJson>,
+}
+
+impl From<&ContractAbi> for StorageLayoutJson {
+    fn from(abi: &ContractAbi) -> Self {
+        let mut storage: Vec<StorageAbiJson> =
+            abi.storage.iter().map(StorageAbiJson::from).collect();
+        storage.sort_by(|a, b| a.key.cmp(&b.key));
+        StorageLayoutJson {
+            name: abi.name.to_string(),
+            storage,
+            types: abi
+                .type_descriptions
+                .0
+                .iter()
+                .filter(|(_, type_description)| type_description.contents.is_specified())
+                .map(|(type_name, type_description)| {
+                    (
+                        type_name.clone(),
+                        TypeDescriptionJson::from(type_description),
+                    )
+                })
+                .collect(),
+        }
+    }
+}
+
+impl StorageLayoutJson {
+    pub fn load(path: impl AsRef<Path>) -> Self {
+        let json = fs::read_to_string(path.as_ref()).unwrap_or_else(|err| {
+            panic!(
+                "failed to read storage layout {}: {err}",
+                path.as_ref().display()
+            )
+        });
+        serde_json::from_str(&json).expect("invalid storage layout JSON")
+    }
+}
+
+pub fn serialize_storage_layout_to_json(storage_layout_json: &StorageLayoutJson) -> String {
+    let buf = Vec::new();
+    let formatter = serde_json::ser::PrettyFormatter::with_indent(b"    ");
+    let mut ser = serde_json::Serializer::with_formatter(buf, formatter);
+    storage_layout_json.serialize(&mut ser).unwrap();
+    let mut serialized = String::from_utf8(ser.into_inner()).unwrap();
+    serialized.push('\n');
+    serialized
+}
@@ -6,12 +6,15 @@ pub struct TypeDescriptionJson {
     #[serde(rename = "type")]
     pub content_type: String,
 
+    #[serde(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub docs: Vec<String>,
 
+    #[serde(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub variants: Vec<EnumVariantDescriptionJson>,
 
+    #[serde(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub fields: Vec<StructFieldDescriptionJson>,
 }
@@ -61,6 +64,7 @@ impl From<&TypeDescription> for TypeDescriptionJson {
 
 #[derive(Serialize, Deserialize)]
 pub struct StructFieldDescriptionJson {
+    #[serde(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub docs: Vec<String>,
     pub name: String,
@@ -80,11 +84,14 @@ impl From<&StructFieldDescription> for StructFieldDescriptionJson {
 
 #[derive(Serialize, Deserialize)]
 pub struct EnumVariantDescriptionJson {
+    #[serde(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub docs: Vec<String>,
     pub name: String,
+    #[serde(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub discriminant: Option<usize>,
+    #[serde(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub fields: Vec<StructFieldDescriptionJson>,
 }
@@ -0,0 +1,470 @@
+use blake2::{digest::consts::U32, Blake2b};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::{
+    collections::BTreeMap,
+    env, fs,
+    io::ErrorKind,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+use crate::{
+    abi_json::FrameworkBuildAbiJson,
+    cmd::contract::output_contract::OutputContractSettings,
+    tools::{cargo_metadata, git_ls_files},
+};
+
+/// Directories that only contain build outputs, never hashed as sources, even if committed.
+const BUILD_OUTPUT_DIR_NAMES: &[&str] = &["target", "output"];
+
+const RUST_TOOLCHAIN_FILE_NAMES: &[&str] = &["rust-toolchain.toml", "rust-toolchain"];
+
+const RUST_CHANNEL_NAMES: &[&str] = &["stable", "beta", "nightly"];
+
+/// Everything needed to reproduce a contract build, and the hash of the resulting code.
+///
+/// Produced by `sc-meta build --reproducible`, checked by `sc-meta verify`.
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BuildManifestJson {
+    pub contract_name: String,
+
+    /// Name of the .wasm file, in the output directory.
+    pub wasm_file: String,
+
+    /// The rustup toolchain the contract was built with.
+    pub toolchain: String,
+
+    /// The full rustc version of the toolchain.
+    pub rustc: String,
+
+    pub framework: FrameworkBuildAbiJson,
+
+    /// SHA-256 of all the files tracked by git in the contract directory, except build outputs.
+    pub source_hashes: BTreeMap<String, String>,
+
+    /// Directory of the wasm crate, relative to the contract directory.
+    pub wasm_crate: String,
+
+    /// The local crates outside the contract directory that the wasm crate depends on,
+    /// e.g. the framework crates, by package name.
+    pub path_dependencies: BTreeMap<String, PathDependencyJson>,
+
+    /// SHA-256 of the Cargo.lock of the wasm crate.
+    pub cargo_lock_hash: Option<String>,
+
+    /// Settings of the output contract, from the multicontract config.
+    pub settings: BuildSettingsJson,
+
+    /// Compiler flags, other than the path remappings, which are machine specific.
+    pub rustflags: String,
+
+    /// The wasm-opt version used to optimize the code, if any.
+    pub wasm_opt: Option<String>,
+
+    /// BLAKE2b-256 of the contract code, the same hash the protocol stores for the contract account.
+    pub code_hash: String,
+
+    pub code_size: usize,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct PathDependencyJson {
+    /// The crate directory, as it appears in the compiled code, e.g. `/workspace/framework/base`.
+    pub path: String,
+
+    /// SHA-256 over the source hashes of the crate directory, computed as for the contract.
+    pub sources_hash: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct BuildSettingsJson {
+    pub external_view: bool,
+    pub panic_message: bool,
+    pub check_ei: Option<String>,
+    pub allocator: String,
+    pub stack_size: usize,
+    pub features: Vec<String>,
+    pub kill_legacy_callback: bool,
+}
+
+impl From<&OutputContractSettings> for BuildSettingsJson {
+    fn from(settings: &OutputContractSettings) -> Self {
+        BuildSettingsJson {
+            external_view: settings.external_view,
+            panic_message: settings.panic_message,
+            check_ei: settings.check_ei.map(|ei| ei.name().to_string()),
+            allocator: settings.allocator.name().to_string(),
+            stack_size: settings.stack_size,
+            features: settings.features.clone(),
+            kill_legacy_callback: settings.kill_legacy_callback,
+        }
+    }
+}
+
+impl BuildManifestJson {
+    pub fn load(path: impl AsRef<Path>) -> Self {
+        let json = fs::read_to_string(path.as_ref()).unwrap_or_else(|err| {
+            panic!(
+                "failed to read build manifest {}: {err}",
+                path.as_ref().display()
+            )
+        });
+        serde_json::from_str(&json).expect("invalid build manifest JSON")
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) {
+        let mut json = serde_json::to_string_pretty(self).unwrap();
+        json.push('\n');
+        fs::write(path, json).expect("failed to write build manifest");
+    }
+}
+
+/// Hex-encoded hash of the contract code, as stored on-chain.
+pub fn code_hash_hex(code: &[u8]) -> String {
+    hex::encode(Blake2b::<U32>::digest(code))
+}
+
+pub fn sha256_hex(data: &[u8]) -> String {
+    hex::encode(Sha256::digest(data))
+}
+
+/// Hashes all the files tracked by git in the contract directory, except build outputs.
+///
+/// Untracked files are left out, so that local leftovers do not end up in the manifest.
+/// The keys are the paths relative to the contract directory, always `/`-separated.
+pub fn hash_contract_sources(contract_root: &Path) -> BTreeMap<String, String> {
+    let mut source_hashes = BTreeMap::new();
+    for relative_path in git_ls_files(contract_root) {
+        if is_build_output(&relative_path) {
+            continue;
+        }
+
+        let path = contract_root.join(&relative_path);
+        let contents = match fs::read(&path) {
+            Ok(contents) => contents,
+            // deleted, but not committed yet
+            Err(err) if err.kind() == ErrorKind::NotFound => continue,
+            Err(err) => panic!("failed to read {}: {err}", path.display()),
+        };
+        source_hashes.insert(relative_path, sha256_hex(&contents));
+    }
+    source_hashes
+}
+
+fn is_build_output(relative_path: &str) -> bool {
+    let mut dir_names = relative_path.split('/').rev().skip(1);
+    dir_names.any(|dir_name| BUILD_OUTPUT_DIR_NAMES.contains(&dir_name))
+}
+
+/// A single hash over all the source hashes of a directory, to keep the manifest short.
+fn combined_sources_hash(dir_path: &Path) -> String {
+    let mut hasher = Sha256::new();
+    for (relative_path, hash) in hash_contract_sources(dir_path) {
+        hasher.update(relative_path.as_bytes());
+        hasher.update(b"\0");
+        hasher.update(hash.as_bytes());
+        hasher.update(b"\n");
+    }
+    hex::encode(hasher.finalize())
+}
+
+/// The machine specific directories of a build, and the local crates the wasm crate depends on.
+pub struct BuildPaths {
+    /// Each directory with the path that replaces it in the compiled code.
+    /// Ordered from the most general to the most specific, since rustc applies the last matching remap.
+    remaps: Vec<(PathBuf, String)>,
+
+    /// Name and directory of the local crates outside the contract directory.
+    path_dependencies: Vec<(String, PathBuf)>,
+}
+
+impl BuildPaths {
+    /// The workspace root of the contract crate becomes `/workspace` and the contract directory `/contract`.
+    /// Path dependencies outside the workspace become `/dependencies/<name>`, and the cargo home `/cargo`.
+    pub fn resolve(contract_root: &Path, wasm_crate_path: &Path) -> Self {
+        let contract_root = contract_root
+            .canonicalize()
+            .expect("failed to resolve the contract directory");
+        let workspace_root = cargo_metadata(&contract_root, true).workspace_root;
+
+        let mut path_dependencies: Vec<(String, PathBuf)> = cargo_metadata(wasm_crate_path, false)
+            .packages
+            .into_iter()
+            .filter(|package| package.is_local() && !package.dir_path().starts_with(&contract_root))
+            .map(|package| (package.name.clone(), package.dir_path().to_path_buf()))
+            .collect();
+        path_dependencies.sort();
+
+        let mut remaps = Vec::new();
+        if let Some(cargo_home) = cargo_home() {
+            remaps.push((cargo_home, "/cargo".to_string()));
+        }
+        for (name, dir_path) in &path_dependencies {
+            if !dir_path.starts_with(&workspace_root) {
+                remaps.push((dir_path.clone(), format!("/dependencies/{name}")));
+            }
+        }
+        remaps.push((workspace_root, "/workspace".to_string()));
+        remaps.push((contract_root, "/contract".to_string()));
+
+        BuildPaths {
+            remaps,
+            path_dependencies,
+        }
+    }
+
+    pub fn remap_path_prefix_flags(&self) -> Vec<String> {
+        self.remaps
+            .iter()
+            .map(|(dir_path, remapped)| {
+                format!("--remap-path-prefix={}={remapped}", dir_path.display())
+            })
+            .collect()
+    }
+
+    /// The path as it appears in the compiled code.
+    pub fn remap(&self, path: &Path) -> String {
+        self.remaps
+            .iter()
+            .rev()
+            .find_map(|(dir_path, remapped)| {
+                let relative_path = path.strip_prefix(dir_path).ok()?;
+                Some(
+                    relative_path
+                        .iter()
+                        .fold(remapped.clone(), |mut result, component| {
+                            result.push('/');
+                            result.push_str(&component.to_string_lossy());
+                            result
+                        }),
+                )
+            })
+            .unwrap_or_else(|| path.display().to_string())
+    }
+
+    pub fn hash_path_dependencies(&self) -> BTreeMap<String, PathDependencyJson> {
+        self.path_dependencies
+            .iter()
+            .map(|(name, dir_path)| {
+                let path_dependency = PathDependencyJson {
+                    path: self.remap(dir_path),
+                    sources_hash: combined_sources_hash(dir_path),
+                };
+                (name.clone(), path_dependency)
+            })
+            .collect()
+    }
+}
+
+fn cargo_home() -> Option<PathBuf> {
+    env::var("CARGO_HOME").ok().map(PathBuf::from).or_else(|| {
+        env::var("HOME")
+            .ok()
+            .map(|home| Path::new(&home).join(".cargo"))
+    })
+}
+
+/// The toolchain set in a `rust-toolchain(.toml)` file, in the given directory or one of its ancestors.
+///
+/// The `RUSTUP_TOOLCHAIN` environment variable is deliberately ignored:
+/// rustup sets it for every `cargo run`, to the toolchain it resolved, so it does not say anything was pinned.
+pub fn toolchain_file_channel(dir_path: &Path) -> Option<String> {
+    let mut current_dir = Some(dir_path.canonicalize().ok()?);
+    while let Some(dir) = current_dir {
+        for file_name in RUST_TOOLCHAIN_FILE_NAMES {
+            let toolchain_file_path = dir.join(file_name);
+            if let Ok(contents) = fs::read_to_string(&toolchain_file_path) {
+                return Some(parse_toolchain_file(&contents));
+            }
+        }
+        current_dir = dir.parent().map(PathBuf::from);
+    }
+
+    None
+}
+
+/// Only toolchains with an exact version or a date always resolve to the same compiler,
+/// e.g. `1.74.0`, `nightly-2023-12-11`, optionally followed by the host, but not `stable` or `1.74`.
+pub fn is_versioned_toolchain(toolchain: &str) -> bool {
+    let mut parts = toolchain.split('-');
+    let channel = parts.next().unwrap_or_default();
+    if RUST_CHANNEL_NAMES.contains(&channel) {
+        let date: Vec<&str> = parts.take(3).collect();
+        return date.len() == 3
+            && date
+                .iter()
+                .zip([4, 2, 2])
+                .all(|(part, len)| part.len() == len && is_all_digits(part));
+    }
+
+    let version: Vec<&str> = channel.split('.').collect();
+    version.len() == 3 && version.iter().all(|part| is_all_digits(part))
+}
+
+fn is_all_digits(s: &str) -> bool {
+    !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit())
+}
+
+/// The short rustc version of the given toolchain, e.g. `rustc 1.76.0-nightly (21cce21d8 2023-12-11)`.
+///
+/// `None` if the toolchain is not installed.
+pub fn toolchain_rustc_version(toolchain: &str) -> Option<String> {
+    let mut rustc_command = Command::new("rustc");
+    rustc_command.env("RUSTUP_TOOLCHAIN", toolchain);
+    rustc_version::VersionMeta::for_command(rustc_command)
+        .ok()
+        .map(|version_meta| version_meta.short_version_string)
+}
+
+/// Accepts both the TOML format and the legacy format, which only contains the toolchain name.
+fn parse_toolchain_file(contents: &str) -> String {
+    if let Ok(value) = contents.parse::<toml::Value>() {
+        if let Some(channel) = value
+            .get("toolchain")
+            .and_then(|toolchain| toolchain.get("channel"))
+            .and_then(|channel| channel.as_str())
+        {
+            return channel.to_string();
+        }
+    }
+    contents.trim().to_string()
+}
+
+/// Compares the sources on disk with the ones in the manifest,
+/// returning the keys of all the entries that were changed, added or removed.
+pub fn changed_sources<V: PartialEq>(
+    expected: &BTreeMap<String, V>,
+    actual: &BTreeMap<String, V>,
+) -> Vec<String> {
+    let mut changed: Vec<String> = expected
+        .iter()
+        .filter(|(path, hash)| actual.get(*path) != Some(hash))
+        .map(|(path, _)| path.clone())
+        .collect();
+    changed.extend(
+        actual
+            .keys()
+            .filter(|path| !expected.contains_key(*path))
+            .cloned(),
+    );
+    changed.sort();
+    changed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_toolchain_file() {
+        assert_eq!(
+            parse_toolchain_file("[toolchain]\nchannel = \"nightly-2023-12-11\"\n"),
+            "nightly-2023-12-11"
+        );
+        assert_eq!(parse_toolchain_file("1.74.0\n"), "1.74.0");
+    }
+
+    #[test]
+    fn test_is_versioned_toolchain() {
+        assert!(is_versioned_toolchain("1.74.0"));
+        assert!(is_versioned_toolchain("1.74.0-x86_64-unknown-linux-gnu"));
+        assert!(is_versioned_toolchain("nightly-2023-12-11"));
+        assert!(is_versioned_toolchain(
+            "nightly-2023-12-11-x86_64-unknown-linux-gnu"
+        ));
+        assert!(!is_versioned_toolchain("stable"));
+        assert!(!is_versioned_toolchain("stable-x86_64-unknown-linux-gnu"));
+        assert!(!is_versioned_toolchain("nightly"));
+        assert!(!is_versioned_toolchain("beta-x86_64-apple-darwin"));
+        assert!(!is_versioned_toolchain("1.74"));
+        assert!(!is_versioned_toolchain("my-local-build"));
+    }
+
+    #[test]
+    fn test_remap() {
+        let build_paths = BuildPaths {
+            remaps: vec![
+                (PathBuf::from("/home/user/.cargo"), "/cargo".to_string()),
+                (
+                    PathBuf::from("/home/user/framework"),
+                    "/dependencies/dharitri-sc".to_string(),
+                ),
+                (PathBuf::from("/home/user/repo"), "/workspace".to_string()),
+                (
+                    PathBuf::from("/home/user/repo/contracts/adder"),
+                    "/contract".to_string(),
+                ),
+            ],
+            path_dependencies: Vec::new(),
+        };
+        assert_eq!(
+            build_paths.remap(Path::new("/home/user/repo/contracts/adder/src/adder.rs")),
+            "/contract/src/adder.rs"
+        );
+        assert_eq!(
+            build_paths.remap(Path::new("/home/user/repo/framework/base")),
+            "/workspace/framework/base"
+        );
+        assert_eq!(
+            build_paths.remap(Path::new("/home/user/framework/src/lib.rs")),
+            "/dependencies/dharitri-sc/src/lib.rs"
+        );
+        assert_eq!(build_paths.remap(Path::new("/tmp/other")), "/tmp/other");
+        assert_eq!(
+            build_paths.remap_path_prefix_flags().last().unwrap(),
+            "--remap-path-prefix=/home/user/repo/contracts/adder=/contract"
+        );
+    }
+
+    #[test]
+    fn test_code_hash_hex() {
+        assert_eq!(
+            code_hash_hex(b""),
+            "0e5751c026e543b2e8ab2eb06099daa1d1e5df47778f7787faab45cdf12fe3a8"
+        );
+        assert_eq!(
+            code_hash_hex(b"abc"),
+            "bddd813c634239723171ef3fee98579b94964e3bb1cb3e427262c8c068d52319"
+        );
+    }
+
+    #[test]
+    fn test_is_build_output() {
+        assert!(is_build_output("output/adder.wasm"));
+        assert!(is_build_output("wasm/target/release/adder.wasm"));
+        assert!(!is_build_output("src/output.rs"));
+        assert!(!is_build_output("Cargo.toml"));
+    }
+
+    #[test]
+    fn test_hash_contract_sources() {
+        let source_hashes = hash_contract_sources(Path::new("../../contracts/examples/adder"));
+        assert!(source_hashes.contains_key("src/adder.rs"));
+        assert!(source_hashes.contains_key("wasm/src/lib.rs"));
+        assert!(source_hashes
+            .keys()
+            .all(|path| !is_build_output(path) && !path.starts_with("../")));
+    }
+
+    #[test]
+    fn test_changed_sources() {
+        let expected: BTreeMap<String, String> = [("a.rs", "1"), ("b.rs", "2"), ("c.rs", "3")]
+            .into_iter()
+            .map(|(path, hash)| (path.to_string(), hash.to_string()))
+            .collect();
+        let mut actual = expected.clone();
+        assert!(changed_sources(&expected, &actual).is_empty());
+
+        actual.insert("b.rs".to_string(), "changed".to_string());
+        actual.remove("c.rs");
+        actual.insert("d.rs".to_string(), "4".to_string());
+        assert_eq!(
+            changed_sources(&expected, &actual),
+            vec!["b.rs".to_string(), "c.rs".to_string(), "d.rs".to_string()]
+        );
+    }
+}
@@ -47,6 +47,11 @@ pub struct BuildArgs {
     )]
     pub extract_imports: bool,
 
+    /// Fails the build if the contract uses VM hooks that are not available in the configured EI version, or deprecated ones.
+    /// Otherwise these are only reported as warnings.
+    #[arg(long = "ei-strict", verbatim_doc_comment)]
+    pub ei_strict: bool,
+
     /// For the wasm crate, allows specifying the target directory where the Rust compiler will build the intermediary files.
     /// Sharing the same target directory can speed up building multiple contract crates at once.
     /// Has alias `target-dir` for backwards compatibility.
@@ -69,6 +74,17 @@ pub struct BuildArgs {
     #[arg(long = "twiggy-dominators", verbatim_doc_comment)]
     pub twiggy_dominators: bool,
 
+    /// Builds with a pinned toolchain, a locked Cargo.lock and machine independent paths,
+    /// then writes a build manifest to the output directory, which `sc-meta verify` can check.
+    /// The toolchain is pinned with a rust-toolchain.toml file, or with the --toolchain argument.
+    #[arg(long = "reproducible", verbatim_doc_comment)]
+    pub reproducible: bool,
+
+    /// The toolchain of a reproducible build, overriding the rust-toolchain.toml file.
+    /// Needs an exact version or date, e.g. 1.74.0 or nightly-2023-12-11.
+    #[arg(long = "toolchain", verbatim_doc_comment)]
+    pub toolchain: Option<String>,
+
     /// Backwards compatibility with mxpy, delete when github actions are fixed.
     #[deprecated]
     #[arg(long = "target", verbatim_doc_comment)]
@@ -98,11 +114,14 @@ impl Default for BuildArgs {
             emit_mir: false,
             emit_llvm_ir: false,
             extract_imports: true,
+            ei_strict: false,
             target_dir_wasm: None,
             twiggy_top: false,
             twiggy_paths: false,
             twiggy_monos: false,
             twiggy_dominators: false,
+            reproducible: false,
+            toolchain: None,
             target: None,
             release: false,
             out_dir: None,
@@ -148,6 +167,9 @@ impl CliArgsToRaw for BuildArgs {
         if !self.extract_imports {
             raw.push("--no-imports".to_string());
         }
+        if self.ei_strict {
+            raw.push("--ei-strict".to_string());
+        }
         if let Some(target_dir_wasm) = &self.target_dir_wasm {
             // not using --target-dir-wasm, for backward compatibility
             raw.push("--target-dir".to_string());
@@ -165,6 +187,13 @@ impl CliArgsToRaw for BuildArgs {
         if self.twiggy_dominators {
             raw.push("--twiggy-dominators".to_string());
         }
+        if self.reproducible {
+            raw.push("--reproducible".to_string());
+        }
+        if let Some(toolchain) = &self.toolchain {
+            raw.push("--toolchain".to_string());
+            raw.push(toolchain.clone());
+        }
         raw
     }
 }
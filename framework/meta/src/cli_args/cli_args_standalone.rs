@@ -61,6 +61,28 @@ pub enum StandaloneCliAction {
         about = "Generates Rust integration tests based on scenarios provided in the scenarios folder of each contract."
     )]
     TestGen(TestGenArgs),
+
+    #[command(
+        name = "ei-check",
+        about = "Checks the VM hooks used by a contract .wasm against an EI version, and reports the endpoints reaching the offending ones."
+    )]
+    EICheck(EICheckArgs),
+
+    #[command(
+        about = "Attributes the code size of a contract .wasm to its endpoints, and compares it against a baseline."
+    )]
+    Size(SizeArgs),
+
+    #[command(
+        about = "Rebuilds a contract reproducibly and checks that the code matches a build manifest, and optionally a deployed contract."
+    )]
+    Verify(VerifyArgs),
+
+    #[command(
+        name = "check-upgrade",
+        about = "Compares two storage layouts and flags the changes that would break the storage of an upgraded contract."
+    )]
+    CheckUpgrade(CheckUpgradeArgs),
 }
 
 #[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
@@ -229,3 +251,74 @@ pub struct TestGenArgs {
     #[arg(long, verbatim_doc_comment)]
     pub create: bool,
 }
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct EICheckArgs {
+    /// The contract .wasm file to check.
+    #[arg(long, verbatim_doc_comment)]
+    pub path: String,
+
+    /// The EI version to check against.
+    #[arg(long, default_value = "1.2", verbatim_doc_comment)]
+    pub ei: String,
+
+    /// Exit with an error if any VM hook is not available in the EI version, or is deprecated.
+    #[arg(long, verbatim_doc_comment)]
+    pub strict: bool,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct SizeArgs {
+    /// The contract .wasm file to analyze.
+    #[arg(long, verbatim_doc_comment)]
+    pub path: String,
+
+    /// A previously saved size report, to compare against.
+    /// The command fails if any size grew more than the threshold.
+    #[arg(long, verbatim_doc_comment)]
+    pub baseline: Option<String>,
+
+    /// The maximum allowed growth compared to the baseline, in percent.
+    #[arg(long, default_value = "0", verbatim_doc_comment)]
+    pub threshold: u32,
+
+    /// Saves the size report as JSON, e.g. to serve as a baseline.
+    #[arg(long, verbatim_doc_comment)]
+    pub output: Option<String>,
+
+    /// How many of the largest functions to list.
+    #[arg(long, default_value = "10", verbatim_doc_comment)]
+    pub top: usize,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct VerifyArgs {
+    /// The build manifest, as produced by `build --reproducible`.
+    #[arg(long, verbatim_doc_comment)]
+    pub manifest: String,
+
+    /// The contract crate directory.
+    /// Will be current directory if not specified.
+    #[arg(long, verbatim_doc_comment)]
+    pub path: Option<String>,
+
+    /// A .wasm file, e.g. the deployed code, that must match the rebuilt code.
+    #[arg(long, verbatim_doc_comment)]
+    pub wasm: Option<String>,
+
+    /// The code hash of a deployed contract, that must match the rebuilt code.
+    /// Accepts both hex and base64, as returned by the gateway.
+    #[arg(long = "code-hash", verbatim_doc_comment)]
+    pub code_hash: Option<String>,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct CheckUpgradeArgs {
+    /// The storage layout of the deployed contract, i.e. its `<contract>.storage.json`.
+    #[arg(verbatim_doc_comment)]
+    pub old: String,
+
+    /// The storage layout of the new contract code.
+    #[arg(verbatim_doc_comment)]
+    pub new: String,
+}
@@ -3,7 +3,9 @@ use std::{
     io::Write,
 };
 
-use crate::abi_json::{serialize_abi_to_json, ContractAbiJson};
+use crate::abi_json::{
+    serialize_abi_to_json, serialize_storage_layout_to_json, ContractAbiJson, StorageLayoutJson,
+};
 
 use super::{meta_config::MetaConfig, output_contract::OutputContract};
 
@@ -19,6 +21,18 @@ fn write_contract_abi(output_contract: &OutputContract, git_version: &str, outpu
     write!(abi_file, "{abi_string}").unwrap();
 }
 
+fn write_contract_storage_layout(output_contract: &OutputContract, output_path: &str) {
+    let storage_layout_json = StorageLayoutJson::from(&output_contract.abi);
+    let storage_layout_string = serialize_storage_layout_to_json(&storage_layout_json);
+
+    let storage_layout_file_path = format!(
+        "{output_path}/{}",
+        output_contract.storage_layout_output_name()
+    );
+    let mut storage_layout_file = File::create(storage_layout_file_path).unwrap();
+    write!(storage_layout_file, "{storage_layout_string}").unwrap();
+}
+
 impl MetaConfig {
     pub fn write_abi(&self) {
         create_dir_all(&self.output_dir).unwrap();
@@ -29,6 +43,7 @@ impl MetaConfig {
                 git_version.as_str(),
                 self.output_dir.as_str(),
             );
+            write_contract_storage_layout(output_contract, self.output_dir.as_str());
         }
     }
 
@@ -172,6 +172,7 @@ fn build_contract_abi(builder: OutputContractBuilder, original_abi: &ContractAbi
         endpoints,
         promise_callbacks,
         events: original_abi.events.clone(),
+        storage: original_abi.storage.clone(),
         has_callback,
         type_descriptions: original_abi.type_descriptions.clone(),
     }
@@ -92,6 +92,10 @@ impl OutputContract {
         format!("{}.abi.json", &self.contract_name)
     }
 
+    pub fn storage_layout_output_name(&self) -> String {
+        format!("{}.storage.json", &self.contract_name)
+    }
+
     fn output_name_base(&self, build_args: &BuildArgs) -> String {
         if let Some(wasm_name_override) = &build_args.wasm_name_override {
             wasm_name_override.clone()
@@ -114,6 +118,10 @@ impl OutputContract {
         format!("{}.mxsc.json", self.output_name_base(build_args))
     }
 
+    pub fn build_manifest_output_name(&self, build_args: &BuildArgs) -> String {
+        format!("{}.build-manifest.json", self.output_name_base(build_args))
+    }
+
     pub fn imports_json_output_name(&self, build_args: &BuildArgs) -> String {
         format!("{}.imports.json", self.output_name_base(build_args))
     }
@@ -35,6 +35,16 @@ impl ContractAllocator {
         })
     }
 
+    /// The name of the option in the multicontract config.
+    pub fn name(&self) -> &'static str {
+        match self {
+            ContractAllocator::AllocationForbidden => "fail",
+            ContractAllocator::LeakingAllocator => "leaking",
+            ContractAllocator::StaticAllocator64K => "static64k",
+            ContractAllocator::WeeAlloc => "wee_alloc",
+        }
+    }
+
     pub fn to_allocator_macro_selector(&self) -> &'static str {
         match self {
             ContractAllocator::AllocationForbidden => "",
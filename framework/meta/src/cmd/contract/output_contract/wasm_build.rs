@@ -1,15 +1,23 @@
-use std::{ffi::OsStr, fs, process::Command};
+use std::{ffi::OsStr, fs, path::Path, process::Command};
 
 use super::OutputContract;
 use crate::{
-    abi_json::ContractAbiJson,
+    abi_json::{ContractAbiJson, FrameworkBuildAbiJson},
+    build_manifest_json::{
+        code_hash_hex, hash_contract_sources, is_versioned_toolchain, sha256_hex,
+        toolchain_file_channel, toolchain_rustc_version, BuildManifestJson, BuildPaths,
+        BuildSettingsJson,
+    },
     cli_args::BuildArgs,
-    ei::EIVersion,
+    ei::{self, EIVersion},
     mxsc_file_json::{save_mxsc_file_json, MxscFileJson},
     print_util::*,
     tools,
 };
 
+/// The meta crate is always called from its own directory, inside the contract crate.
+const CONTRACT_ROOT_PATH: &str = "..";
+
 impl OutputContract {
     pub fn build_contract(&self, build_args: &BuildArgs, output_path: &str) {
         let mut command = self.compose_build_command(build_args);
@@ -32,13 +40,19 @@ impl OutputContract {
         command
             .args(["build", "--target=wasm32-unknown-unknown", "--release"])
             .current_dir(self.wasm_crate_path());
-        if build_args.locked {
+        if build_args.locked || build_args.reproducible {
             command.arg("--locked");
         }
         if let Some(target_dir_wasm) = &build_args.target_dir_wasm {
             command.args(["--target-dir", target_dir_wasm]);
         }
-        let rustflags = self.compose_rustflags(build_args);
+        let mut rustflags = self.compose_rustflags(build_args);
+        if build_args.reproducible {
+            command.env("RUSTUP_TOOLCHAIN", reproducible_toolchain(build_args));
+            for remap_flag in self.build_paths().remap_path_prefix_flags() {
+                rustflags.push_flag(&remap_flag);
+            }
+        }
         if !rustflags.is_empty() {
             command.env("RUSTFLAGS", rustflags);
         }
@@ -74,6 +88,7 @@ impl OutputContract {
         self.extract_imports(build_args, output_path);
         self.run_twiggy(build_args, output_path);
         self.pack_mxsc_file(build_args, output_path);
+        self.write_build_manifest(build_args, output_path);
     }
 
     fn copy_contracts_to_output(&self, build_args: &BuildArgs, output_path: &str) {
@@ -101,6 +116,57 @@ impl OutputContract {
         save_mxsc_file_json(&mxsc_file_json, output_mxsc_path);
     }
 
+    fn write_build_manifest(&self, build_args: &BuildArgs, output_path: &str) {
+        if !build_args.reproducible {
+            return;
+        }
+
+        let output_wasm_path = format!("{output_path}/{}", self.wasm_output_name(build_args));
+        let code = fs::read(output_wasm_path).expect("failed to open compiled contract");
+        let toolchain = reproducible_toolchain(build_args);
+        let rustc =
+            toolchain_rustc_version(&toolchain).expect("failed to retrieve the rustc version");
+        let cargo_lock_path = format!("{}/Cargo.lock", self.wasm_crate_path());
+
+        let build_manifest = BuildManifestJson {
+            contract_name: self.contract_name.clone(),
+            wasm_file: self.wasm_output_name(build_args),
+            toolchain,
+            rustc,
+            framework: FrameworkBuildAbiJson::from(&self.abi.build_info.framework),
+            source_hashes: hash_contract_sources(Path::new(CONTRACT_ROOT_PATH)),
+            wasm_crate: self.wasm_crate_dir_name(),
+            path_dependencies: self.build_paths().hash_path_dependencies(),
+            cargo_lock_hash: fs::read(cargo_lock_path)
+                .ok()
+                .map(|cargo_lock| sha256_hex(&cargo_lock)),
+            settings: BuildSettingsJson::from(&self.settings),
+            rustflags: self.compose_rustflags(build_args).0,
+            wasm_opt: if build_args.wasm_opt {
+                tools::wasm_opt_version()
+            } else {
+                None
+            },
+            code_hash: code_hash_hex(&code),
+            code_size: code.len(),
+        };
+
+        let output_manifest_path = format!(
+            "{output_path}/{}",
+            self.build_manifest_output_name(build_args)
+        );
+        print_write_build_manifest(&output_manifest_path, &build_manifest.code_hash);
+        build_manifest.save(output_manifest_path);
+    }
+
+    /// Machine specific paths are replaced in the compiled code, e.g. in panic messages.
+    fn build_paths(&self) -> BuildPaths {
+        BuildPaths::resolve(
+            Path::new(CONTRACT_ROOT_PATH),
+            Path::new(&self.wasm_crate_path()),
+        )
+    }
+
     fn run_wasm_opt(&self, build_args: &BuildArgs, output_path: &str) {
         if !build_args.wasm_opt {
             return;
@@ -138,7 +204,11 @@ impl OutputContract {
         let import_names = tools::extract_wasm_imports(&output_wasm_path)
             .expect("error occured while extracting imports from .wasm ");
         write_imports_output(output_imports_json_path.as_str(), import_names.as_slice());
-        validate_ei(&import_names, &self.settings.check_ei);
+        validate_ei(
+            &output_wasm_path,
+            &self.settings.check_ei,
+            build_args.ei_strict,
+        );
     }
 }
 
@@ -147,19 +217,25 @@ fn write_imports_output(dest_path: &str, import_names: &[String]) {
     fs::write(dest_path, json).expect("failed to write imports json file");
 }
 
-fn validate_ei(import_names: &[String], check_ei: &Option<EIVersion>) {
+fn validate_ei(output_wasm_path: &str, check_ei: &Option<EIVersion>, ei_strict: bool) {
     if let Some(ei) = check_ei {
         print_check_ei(ei.name());
-        let mut num_errors = 0;
-        for import_name in import_names {
-            if !ei.contains_vm_hook(import_name) {
-                print_invalid_vm_hook(import_name, ei.name());
-                num_errors += 1;
-            }
-        }
-        if num_errors == 0 {
+        let wasm_data = fs::read(output_wasm_path).expect("failed to read contract .wasm");
+        let violations = ei::check_ei_compliance(&wasm_data, *ei)
+            .expect("error occured while analyzing the .wasm call graph");
+        if violations.is_empty() {
             print_check_ei_ok();
+            return;
         }
+
+        print_ei_violations(&violations, ei.name());
+
+        assert!(
+            !ei_strict,
+            "EI check failed for {output_wasm_path}: {} VM hook(s) not compliant with EI version {}",
+            violations.len(),
+            ei.name()
+        );
     } else {
         print_ignore_ei_check();
     }
@@ -206,6 +282,22 @@ impl OutputContract {
     }
 }
 
+/// Taken either from the `--toolchain` argument, or from a `rust-toolchain.toml` file.
+fn reproducible_toolchain(build_args: &BuildArgs) -> String {
+    let toolchain = build_args
+        .toolchain
+        .clone()
+        .or_else(|| toolchain_file_channel(Path::new(CONTRACT_ROOT_PATH)))
+        .expect(
+            "reproducible builds need a pinned toolchain, either in a rust-toolchain.toml file, or with the --toolchain argument",
+        );
+    assert!(
+        is_versioned_toolchain(&toolchain),
+        "reproducible builds need a toolchain with an exact version or date, e.g. 1.74.0 or nightly-2023-12-11, not {toolchain}"
+    );
+    toolchain
+}
+
 /// For convenience, for building rustflags.
 #[derive(Default)]
 struct Rustflags(String);
@@ -1,19 +1,27 @@
 mod all;
+pub mod check_upgrade;
+mod ei_check;
 mod info;
 mod local_deps;
 pub mod scen_test_gen;
+pub mod size;
 pub(crate) mod upgrade;
+mod verify;
 
 use crate::{
     cli_args::{StandaloneCliAction, StandaloneCliArgs},
     template::{create_contract, print_template_names},
 };
 use all::call_all_meta;
+use check_upgrade::check_upgrade_tool;
 use clap::Parser;
+use ei_check::ei_check;
 use info::call_info;
 use local_deps::local_deps;
 use scen_test_gen::test_gen_tool;
+use size::size_tool;
 use upgrade::upgrade_sc;
+use verify::verify_contract;
 
 /// Entry point in the program when calling it as a standalone tool.
 pub async fn cli_main_standalone() {
@@ -36,6 +44,18 @@ pub async fn cli_main_standalone() {
         Some(StandaloneCliAction::TestGen(args)) => {
             test_gen_tool(args);
         },
+        Some(StandaloneCliAction::EICheck(args)) => {
+            ei_check(args);
+        },
+        Some(StandaloneCliAction::Size(args)) => {
+            size_tool(args);
+        },
+        Some(StandaloneCliAction::Verify(args)) => {
+            verify_contract(args);
+        },
+        Some(StandaloneCliAction::CheckUpgrade(args)) => {
+            check_upgrade_tool(args);
+        },
         None => {},
     }
 }
@@ -0,0 +1,317 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    process,
+};
+
+use colored::Colorize;
+
+use crate::{
+    abi_json::{
+        StorageAbiJson, StorageLayoutJson, StructFieldDescriptionJson, TypeDescriptionJson,
+    },
+    cli_args::CheckUpgradeArgs,
+};
+
+/// Plain storage accessors read and write the value the same way a `SingleValueMapper` does.
+const SINGLE_VALUE_MAPPER_EQUIVALENTS: &[&str] = &["storage_get", "storage_set"];
+const SINGLE_VALUE_MAPPER: &str = "SingleValueMapper";
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum StorageUpgradeSeverity {
+    /// The new contract code would misread the existing storage, or lose access to it.
+    Breaking,
+
+    /// Worth a second look, but the existing storage can still be read.
+    Warning,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct StorageUpgradeIssue {
+    pub key: String,
+    pub severity: StorageUpgradeSeverity,
+    pub message: String,
+}
+
+/// Compares the storage layouts of the deployed contract and of its upgrade.
+///
+/// Flags removed storage keys, mapper kind changes, key argument changes
+/// and value type changes, including changes to the stored structs and enums.
+/// Stored types that do not implement `TypeAbi` cannot be checked, they only get a warning.
+pub fn check_storage_upgrade(
+    old: &StorageLayoutJson,
+    new: &StorageLayoutJson,
+) -> Vec<StorageUpgradeIssue> {
+    let new_storage = storage_by_key(&new.storage);
+    let mut issues = Vec::new();
+    for (key, old_entry) in storage_by_key(&old.storage) {
+        let mut checker = StorageUpgradeChecker {
+            key,
+            old_types: &old.types,
+            new_types: &new.types,
+            compared_types: BTreeSet::new(),
+            issues: &mut issues,
+        };
+        match new_storage.get(key) {
+            Some(new_entry) => checker.compare_entries(old_entry, new_entry),
+            None => checker.breaking(format!(
+                "storage key removed (was {}), the existing data becomes unreachable",
+                old_entry.rust_method_name
+            )),
+        }
+    }
+    issues
+}
+
+/// Several methods can access the same key, e.g. a getter and a setter.
+/// Storage mappers are preferred, since they describe the storage most accurately.
+fn storage_by_key(storage: &[StorageAbiJson]) -> BTreeMap<&str, &StorageAbiJson> {
+    let mut result = BTreeMap::<&str, &StorageAbiJson>::new();
+    for entry in storage {
+        let replace = match result.get(entry.key.as_str()) {
+            Some(existing) => {
+                SINGLE_VALUE_MAPPER_EQUIVALENTS.contains(&existing.mapper.as_str())
+                    && !SINGLE_VALUE_MAPPER_EQUIVALENTS.contains(&entry.mapper.as_str())
+            },
+            None => true,
+        };
+        if replace {
+            result.insert(entry.key.as_str(), entry);
+        }
+    }
+    result
+}
+
+fn normalized_mapper(mapper: &str) -> &str {
+    if SINGLE_VALUE_MAPPER_EQUIVALENTS.contains(&mapper) {
+        SINGLE_VALUE_MAPPER
+    } else {
+        mapper
+    }
+}
+
+struct StorageUpgradeChecker<'a> {
+    key: &'a str,
+    old_types: &'a BTreeMap<String, TypeDescriptionJson>,
+    new_types: &'a BTreeMap<String, TypeDescriptionJson>,
+    compared_types: BTreeSet<String>,
+    issues: &'a mut Vec<StorageUpgradeIssue>,
+}
+
+impl<'a> StorageUpgradeChecker<'a> {
+    fn breaking(&mut self, message: String) {
+        self.issues.push(StorageUpgradeIssue {
+            key: self.key.to_string(),
+            severity: StorageUpgradeSeverity::Breaking,
+            message,
+        });
+    }
+
+    fn warning(&mut self, message: String) {
+        self.issues.push(StorageUpgradeIssue {
+            key: self.key.to_string(),
+            severity: StorageUpgradeSeverity::Warning,
+            message,
+        });
+    }
+
+    fn compare_entries(&mut self, old: &StorageAbiJson, new: &StorageAbiJson) {
+        if normalized_mapper(&old.mapper) != normalized_mapper(&new.mapper) {
+            self.breaking(format!(
+                "mapper changed from {} to {}",
+                old.mapper, new.mapper
+            ));
+            return;
+        }
+
+        let undescribed_types: BTreeSet<&String> = old
+            .undescribed_types
+            .iter()
+            .chain(new.undescribed_types.iter())
+            .collect();
+        for type_name in undescribed_types {
+            self.warning(format!(
+                "cannot check type {type_name}, it does not implement TypeAbi"
+            ));
+        }
+
+        let old_key_types: Vec<&str> = old
+            .key_args
+            .iter()
+            .map(|arg| arg.type_name.as_str())
+            .collect();
+        let new_key_types: Vec<&str> = new
+            .key_args
+            .iter()
+            .map(|arg| arg.type_name.as_str())
+            .collect();
+        if old_key_types != new_key_types {
+            self.breaking(format!(
+                "key arguments changed from ({}) to ({})",
+                old_key_types.join(", "),
+                new_key_types.join(", ")
+            ));
+        }
+
+        if old.value_types.len() != new.value_types.len() {
+            self.breaking(format!(
+                "value types changed from <{}> to <{}>",
+                old.value_types.join(", "),
+                new.value_types.join(", ")
+            ));
+            return;
+        }
+        for (old_type, new_type) in old.value_types.iter().zip(new.value_types.iter()) {
+            self.compare_type_names(old_type, new_type, "value type");
+        }
+    }
+
+    fn compare_type_names(&mut self, old_type: &str, new_type: &str, context: &str) {
+        if old_type != new_type {
+            self.breaking(format!("{context} changed from {old_type} to {new_type}"));
+            return;
+        }
+
+        // same name, but the types it is made of might have changed, e.g. the fields of a struct
+        for type_name in type_name_identifiers(old_type) {
+            self.compare_type_descriptions(type_name);
+        }
+    }
+
+    fn compare_type_descriptions(&mut self, type_name: &str) {
+        let (old, new) = match (self.old_types.get(type_name), self.new_types.get(type_name)) {
+            (Some(old), Some(new)) => (old, new),
+            _ => return,
+        };
+        if !self.compared_types.insert(type_name.to_string()) {
+            return;
+        }
+
+        if old.content_type != new.content_type {
+            self.breaking(format!(
+                "type {type_name} changed from {} to {}",
+                old.content_type, new.content_type
+            ));
+            return;
+        }
+
+        match old.content_type.as_str() {
+            "struct" => self.compare_fields(type_name, &old.fields, &new.fields),
+            "enum" => {
+                for (index, old_variant) in old.variants.iter().enumerate() {
+                    let new_variant = match new.variants.get(index) {
+                        Some(new_variant) => new_variant,
+                        None => {
+                            self.breaking(format!(
+                                "variant {type_name}::{} was removed",
+                                old_variant.name
+                            ));
+                            continue;
+                        },
+                    };
+                    if old_variant.discriminant != new_variant.discriminant {
+                        self.breaking(format!(
+                            "variant {type_name}::{} changed its discriminant",
+                            old_variant.name
+                        ));
+                        continue;
+                    }
+                    if old_variant.name != new_variant.name {
+                        self.warning(format!(
+                            "variant {type_name}::{} renamed to {}",
+                            old_variant.name, new_variant.name
+                        ));
+                    }
+                    let variant_path = format!("{type_name}::{}", old_variant.name);
+                    self.compare_fields(&variant_path, &old_variant.fields, &new_variant.fields);
+                }
+            },
+            "explicit-enum" => {
+                // explicit enums are encoded by variant name
+                for old_variant in &old.variants {
+                    if !new
+                        .variants
+                        .iter()
+                        .any(|new_variant| new_variant.name == old_variant.name)
+                    {
+                        self.breaking(format!(
+                            "variant {type_name}::{} was removed",
+                            old_variant.name
+                        ));
+                    }
+                }
+            },
+            _ => {},
+        }
+    }
+
+    fn compare_fields(
+        &mut self,
+        path: &str,
+        old_fields: &[StructFieldDescriptionJson],
+        new_fields: &[StructFieldDescriptionJson],
+    ) {
+        if old_fields.len() != new_fields.len() {
+            self.breaking(format!(
+                "{path} changed from {} to {} fields",
+                old_fields.len(),
+                new_fields.len()
+            ));
+            return;
+        }
+
+        for (old_field, new_field) in old_fields.iter().zip(new_fields.iter()) {
+            if old_field.name != new_field.name {
+                self.warning(format!(
+                    "field {path}.{} renamed to {}",
+                    old_field.name, new_field.name
+                ));
+            }
+            self.compare_type_names(
+                &old_field.field_type,
+                &new_field.field_type,
+                &format!("type of field {path}.{}", old_field.name),
+            );
+        }
+    }
+}
+
+/// All identifiers in a type name, e.g. `List<Option<MyStruct>>` yields `List`, `Option` and `MyStruct`.
+fn type_name_identifiers(type_name: &str) -> impl Iterator<Item = &str> {
+    type_name
+        .split(|c: char| !c.is_alphanumeric() && c != '_')
+        .filter(|identifier| !identifier.is_empty())
+}
+
+pub fn check_upgrade_tool(args: &CheckUpgradeArgs) {
+    let old = StorageLayoutJson::load(&args.old);
+    let new = StorageLayoutJson::load(&args.new);
+    let issues = check_storage_upgrade(&old, &new);
+
+    let mut num_breaking = 0;
+    for issue in &issues {
+        match issue.severity {
+            StorageUpgradeSeverity::Breaking => {
+                num_breaking += 1;
+                println!(
+                    "{}",
+                    format!("ERROR! Storage key '{}': {}", issue.key, issue.message).red()
+                );
+            },
+            StorageUpgradeSeverity::Warning => {
+                println!(
+                    "{}",
+                    format!("WARNING! Storage key '{}': {}", issue.key, issue.message).yellow()
+                );
+            },
+        }
+    }
+
+    if num_breaking > 0 {
+        println!(
+            "{}",
+            format!("Upgrade would break {num_breaking} storage entries.").red()
+        );
+        process::exit(1);
+    }
+    println!("{}", "Storage layout is upgrade compatible.".green());
+}
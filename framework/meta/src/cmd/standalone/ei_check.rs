@@ -0,0 +1,27 @@
+use std::{fs, process};
+
+use crate::{
+    cli_args::EICheckArgs,
+    ei::{check_ei_compliance, EIVersion},
+    print_util::{print_check_ei, print_check_ei_ok, print_ei_violations},
+};
+
+pub fn ei_check(args: &EICheckArgs) {
+    let ei =
+        EIVersion::from_name(&args.ei).unwrap_or_else(|| panic!("invalid EI version: {}", args.ei));
+    let wasm_data =
+        fs::read(&args.path).unwrap_or_else(|err| panic!("failed to read {}: {err}", args.path));
+    let violations = check_ei_compliance(&wasm_data, ei)
+        .unwrap_or_else(|err| panic!("failed to analyze {}: {err}", args.path));
+
+    print_check_ei(ei.name());
+    if violations.is_empty() {
+        print_check_ei_ok();
+        return;
+    }
+
+    print_ei_violations(&violations, ei.name());
+    if args.strict {
+        process::exit(1);
+    }
+}
@@ -0,0 +1,249 @@
+use std::{collections::BTreeMap, fs, path::Path, process};
+
+use anyhow::Result;
+use colored::Colorize;
+use serde::{Deserialize, Serialize};
+
+use crate::{cli_args::SizeArgs, tools::WasmCallGraph};
+
+/// Size breakdown of a contract .wasm file, attributed to its endpoints.
+///
+/// Saved as JSON, it also serves as the baseline for later size checks.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct SizeReport {
+    /// Size of the whole .wasm file.
+    pub total_size: usize,
+
+    /// Sum of all function bodies.
+    pub code_size: usize,
+
+    /// Sum of all data segments.
+    pub data_size: usize,
+
+    pub endpoints: Vec<EndpointSize>,
+
+    /// The largest functions, in descending order of size.
+    pub largest_functions: Vec<FunctionSize>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct EndpointSize {
+    pub name: String,
+
+    /// Size of all the code reachable from the endpoint.
+    pub reachable_size: usize,
+
+    /// Size of the code reachable only from this endpoint.
+    /// This is how much the contract would shrink if the endpoint were removed.
+    pub exclusive_size: usize,
+
+    /// Size of the code also reachable from other endpoints.
+    pub shared_size: usize,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct FunctionSize {
+    pub name: String,
+    pub size: usize,
+}
+
+/// A size that grew more than allowed, compared to the baseline.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SizeGrowth {
+    pub item: String,
+    pub baseline_size: usize,
+    pub size: usize,
+}
+
+impl SizeReport {
+    pub fn from_wasm(wasm_data: &[u8], num_largest_functions: usize) -> Result<Self> {
+        let call_graph = WasmCallGraph::parse(wasm_data)?;
+        let function_sizes = call_graph.function_sizes();
+
+        let reachable_sets: Vec<_> = call_graph
+            .exports
+            .iter()
+            .map(|(name, function_index)| (name, call_graph.reachable_from(*function_index)))
+            .collect();
+
+        let mut num_reaching_endpoints = BTreeMap::<u32, usize>::new();
+        for (_, reachable) in &reachable_sets {
+            for function_index in reachable {
+                *num_reaching_endpoints.entry(*function_index).or_default() += 1;
+            }
+        }
+
+        let endpoints = reachable_sets
+            .iter()
+            .map(|(name, reachable)| {
+                let mut reachable_size = 0;
+                let mut exclusive_size = 0;
+                for function_index in reachable {
+                    // imports have no size
+                    let size = function_sizes.get(function_index).copied().unwrap_or(0);
+                    reachable_size += size;
+                    if num_reaching_endpoints[function_index] == 1 {
+                        exclusive_size += size;
+                    }
+                }
+                EndpointSize {
+                    name: name.to_string(),
+                    reachable_size,
+                    exclusive_size,
+                    shared_size: reachable_size - exclusive_size,
+                }
+            })
+            .collect();
+
+        let mut largest_functions: Vec<_> = function_sizes
+            .iter()
+            .map(|(function_index, size)| FunctionSize {
+                name: call_graph.function_name(*function_index),
+                size: *size,
+            })
+            .collect();
+        largest_functions.sort_by(|a, b| b.size.cmp(&a.size));
+        largest_functions.truncate(num_largest_functions);
+
+        Ok(SizeReport {
+            total_size: wasm_data.len(),
+            code_size: function_sizes.values().sum(),
+            data_size: call_graph.data_size,
+            endpoints,
+            largest_functions,
+        })
+    }
+
+    pub fn load<P: AsRef<Path>>(path: P) -> Self {
+        let json = fs::read_to_string(path.as_ref()).unwrap_or_else(|err| {
+            panic!(
+                "failed to read size baseline {}: {err}",
+                path.as_ref().display()
+            )
+        });
+        serde_json::from_str(&json).expect("invalid size baseline JSON")
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, path: P) {
+        let json = serde_json::to_string_pretty(self).unwrap();
+        fs::write(path, json).expect("failed to write size report");
+    }
+
+    /// Lists the sizes that grew by more than `threshold_percent` compared to the baseline:
+    /// the total, code and data sizes, as well as the reachable size of each endpoint.
+    ///
+    /// Endpoints missing from the baseline are not checked.
+    pub fn growth_over(&self, baseline: &SizeReport, threshold_percent: u32) -> Vec<SizeGrowth> {
+        let mut compared = vec![
+            ("total".to_string(), baseline.total_size, self.total_size),
+            ("code".to_string(), baseline.code_size, self.code_size),
+            ("data".to_string(), baseline.data_size, self.data_size),
+        ];
+        for endpoint in &self.endpoints {
+            if let Some(baseline_endpoint) = baseline
+                .endpoints
+                .iter()
+                .find(|baseline_endpoint| baseline_endpoint.name == endpoint.name)
+            {
+                compared.push((
+                    format!("endpoint {}", endpoint.name),
+                    baseline_endpoint.reachable_size,
+                    endpoint.reachable_size,
+                ));
+            }
+        }
+
+        compared
+            .into_iter()
+            .filter(|(_, baseline_size, size)| {
+                *size as u128 * 100 > *baseline_size as u128 * (100 + threshold_percent as u128)
+            })
+            .map(|(item, baseline_size, size)| SizeGrowth {
+                item,
+                baseline_size,
+                size,
+            })
+            .collect()
+    }
+}
+
+pub fn size_tool(args: &SizeArgs) {
+    let wasm_data =
+        fs::read(&args.path).unwrap_or_else(|err| panic!("failed to read {}: {err}", args.path));
+    let report = SizeReport::from_wasm(&wasm_data, args.top)
+        .unwrap_or_else(|err| panic!("failed to analyze {}: {err}", args.path));
+    print_size_report(&args.path, &report);
+
+    if let Some(output) = &args.output {
+        report.save(output);
+        println!("{}", format!("Size report saved to {output}").green());
+    }
+
+    if let Some(baseline_path) = &args.baseline {
+        let baseline = SizeReport::load(baseline_path);
+        let growth = report.growth_over(&baseline, args.threshold);
+        if growth.is_empty() {
+            println!(
+                "{}",
+                format!(
+                    "No size growth above {}% compared to {baseline_path}",
+                    args.threshold
+                )
+                .green()
+            );
+            return;
+        }
+
+        for item_growth in &growth {
+            println!(
+                "{}",
+                format!(
+                    "ERROR! Size of {} grew from {} to {} bytes, more than the allowed {}%",
+                    item_growth.item, item_growth.baseline_size, item_growth.size, args.threshold
+                )
+                .red()
+            );
+        }
+        process::exit(1);
+    }
+}
+
+fn print_size_report(path: &str, report: &SizeReport) {
+    println!(
+        "{}",
+        format!(
+            "{path}: {} bytes (code: {} bytes, data: {} bytes)",
+            report.total_size, report.code_size, report.data_size
+        )
+        .green()
+    );
+
+    let name_width = report
+        .endpoints
+        .iter()
+        .map(|endpoint| endpoint.name.len())
+        .chain(std::iter::once("endpoint".len()))
+        .max()
+        .unwrap_or_default();
+    println!(
+        "\n{}",
+        format!(
+            "{:name_width$}  {:>10}  {:>10}  {:>10}",
+            "endpoint", "reachable", "exclusive", "shared"
+        )
+        .truecolor(128, 128, 128)
+    );
+    for endpoint in &report.endpoints {
+        println!(
+            "{:name_width$}  {:>10}  {:>10}  {:>10}",
+            endpoint.name, endpoint.reachable_size, endpoint.exclusive_size, endpoint.shared_size
+        );
+    }
+
+    println!("\n{}", "largest functions".truecolor(128, 128, 128));
+    for function in &report.largest_functions {
+        println!("{:>10}  {}", function.size, function.name);
+    }
+}
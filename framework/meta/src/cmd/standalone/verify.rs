@@ -0,0 +1,181 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process::{self, Command},
+};
+
+use colored::Colorize;
+
+use crate::{
+    build_manifest_json::{
+        changed_sources, code_hash_hex, hash_contract_sources, toolchain_rustc_version,
+        BuildManifestJson, BuildPaths,
+    },
+    cli_args::VerifyArgs,
+    print_util::print_all_command,
+    tools,
+};
+
+pub fn verify_contract(args: &VerifyArgs) {
+    let contract_path = PathBuf::from(args.path.as_deref().unwrap_or("./"));
+    // loaded before rebuilding, since the rebuild overwrites the manifests in the output directory
+    let manifest = BuildManifestJson::load(&args.manifest);
+
+    let changed = changed_sources(
+        &manifest.source_hashes,
+        &hash_contract_sources(&contract_path),
+    );
+    if !changed.is_empty() {
+        for path in &changed {
+            print_error(&format!("Source file {path} does not match the manifest"));
+        }
+        process::exit(1);
+    }
+    print_ok("Sources match the manifest");
+
+    let build_paths =
+        BuildPaths::resolve(&contract_path, &contract_path.join(&manifest.wasm_crate));
+    let changed = changed_sources(
+        &manifest.path_dependencies,
+        &build_paths.hash_path_dependencies(),
+    );
+    if !changed.is_empty() {
+        for name in &changed {
+            print_error(&format!(
+                "Path dependency {name} does not match the manifest, either its sources or its location"
+            ));
+        }
+        process::exit(1);
+    }
+    print_ok("Path dependencies match the manifest");
+
+    // reported, but the rebuild is still attempted, its code might match nonetheless
+    let mut num_mismatches = check_build_environment(&manifest);
+
+    rebuild(&contract_path, &manifest);
+    let rebuilt_code = fs::read(contract_path.join("output").join(&manifest.wasm_file))
+        .expect("failed to open rebuilt contract");
+    let rebuilt_code_hash = code_hash_hex(&rebuilt_code);
+
+    let mut check_code_hash = |description: &str, expected_code_hash: &str| {
+        if expected_code_hash == rebuilt_code_hash {
+            print_ok(&format!("Rebuilt code matches {description}"));
+        } else {
+            print_error(&format!(
+                "Rebuilt code hash {rebuilt_code_hash} does not match {description}: {expected_code_hash}"
+            ));
+            num_mismatches += 1;
+        }
+    };
+
+    check_code_hash("the manifest", &manifest.code_hash);
+    if let Some(wasm_path) = &args.wasm {
+        let code =
+            fs::read(wasm_path).unwrap_or_else(|err| panic!("failed to read {wasm_path}: {err}"));
+        check_code_hash(wasm_path, &code_hash_hex(&code));
+    }
+    if let Some(code_hash) = &args.code_hash {
+        check_code_hash("the on-chain code hash", &parse_code_hash(code_hash));
+    }
+
+    if num_mismatches > 0 {
+        process::exit(1);
+    }
+}
+
+/// Compares the rustc and wasm-opt versions available for the rebuild with the ones in the manifest,
+/// returning the number of mismatches.
+fn check_build_environment(manifest: &BuildManifestJson) -> usize {
+    let mut num_mismatches = 0;
+
+    match toolchain_rustc_version(&manifest.toolchain) {
+        Some(rustc) if rustc == manifest.rustc => {
+            print_ok(&format!("Toolchain matches the manifest: {rustc}"));
+        },
+        Some(rustc) => {
+            print_error(&format!(
+                "Toolchain {} is {rustc}, but the manifest was built with {}",
+                manifest.toolchain, manifest.rustc
+            ));
+            num_mismatches += 1;
+        },
+        None => {
+            print_error(&format!(
+                "Toolchain {} is not installed, the manifest was built with {}",
+                manifest.toolchain, manifest.rustc
+            ));
+            num_mismatches += 1;
+        },
+    }
+
+    if let Some(expected_wasm_opt) = &manifest.wasm_opt {
+        match tools::wasm_opt_version() {
+            Some(wasm_opt) if &wasm_opt == expected_wasm_opt => {
+                print_ok(&format!("wasm-opt matches the manifest: {wasm_opt}"));
+            },
+            Some(wasm_opt) => {
+                print_error(&format!(
+                    "wasm-opt is {wasm_opt}, but the manifest was built with {expected_wasm_opt}"
+                ));
+                num_mismatches += 1;
+            },
+            None => {
+                print_error(&format!(
+                    "wasm-opt is not installed, the manifest was built with {expected_wasm_opt}"
+                ));
+                num_mismatches += 1;
+            },
+        }
+    }
+
+    num_mismatches
+}
+
+/// Builds the contract again, with the same toolchain and wasm-opt setting as the original build.
+fn rebuild(contract_path: &Path, manifest: &BuildManifestJson) {
+    let meta_path = contract_path.join("meta");
+    assert!(
+        meta_path.exists(),
+        "Contract meta crate not found at {}",
+        meta_path.as_path().display()
+    );
+
+    let mut cargo_run_args = vec![
+        "run".to_string(),
+        "build".to_string(),
+        "--reproducible".to_string(),
+        "--toolchain".to_string(),
+        manifest.toolchain.clone(),
+    ];
+    if manifest.wasm_opt.is_none() {
+        cargo_run_args.push("--no-wasm-opt".to_string());
+    }
+    print_all_command(meta_path.as_path(), &cargo_run_args);
+
+    let exit_status = Command::new("cargo")
+        .current_dir(&meta_path)
+        .args(&cargo_run_args)
+        .spawn()
+        .expect("failed to spawn cargo run process in meta crate")
+        .wait()
+        .expect("cargo run process in meta crate was not running");
+    assert!(exit_status.success(), "contract rebuild failed");
+}
+
+/// The gateway returns code hashes in base64, but hex is also accepted.
+fn parse_code_hash(code_hash: &str) -> String {
+    if code_hash.len() == 64 && hex::decode(code_hash).is_ok() {
+        return code_hash.to_lowercase();
+    }
+    let bytes = base64::decode(code_hash)
+        .unwrap_or_else(|_| panic!("invalid code hash, expected hex or base64: {code_hash}"));
+    hex::encode(bytes)
+}
+
+fn print_ok(message: &str) {
+    println!("{}", message.green());
+}
+
+fn print_error(message: &str) {
+    println!("{}", format!("ERROR! {message}").red());
+}
@@ -2,10 +2,14 @@ mod ei_1_0;
 mod ei_1_1;
 mod ei_1_2;
 mod ei_1_3;
+mod ei_check;
+mod ei_deprecated;
 mod ei_version;
 
 pub use ei_1_0::EI_1_0_NAMES;
 pub use ei_1_1::EI_1_1_NAMES;
 pub use ei_1_2::EI_1_2_NAMES;
 pub use ei_1_3::EI_1_3_NAMES;
+pub use ei_check::{check_ei_compliance, EIViolation, EIViolationKind};
+pub use ei_deprecated::EI_DEPRECATED_NAMES;
 pub use ei_version::EIVersion;
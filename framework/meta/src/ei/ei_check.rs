@@ -0,0 +1,62 @@
+use anyhow::Result;
+
+use super::{EIVersion, EI_DEPRECATED_NAMES};
+use crate::tools::WasmCallGraph;
+
+/// Why a VM hook imported by a contract does not pass the EI check.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum EIViolationKind {
+    /// The VM hook is not part of the targeted EI version.
+    NotAvailable,
+
+    /// The VM hook is available, but deprecated.
+    Deprecated,
+}
+
+/// A VM hook that does not pass the EI check, together with the code that uses it.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct EIViolation {
+    pub vm_hook_name: String,
+    pub kind: EIViolationKind,
+
+    /// The functions calling the VM hook directly.
+    ///
+    /// They only have meaningful names if the contract was built with `--wasm-symbols`.
+    pub callers: Vec<String>,
+
+    /// The endpoints reaching the VM hook through the call graph.
+    pub endpoints: Vec<String>,
+}
+
+/// Checks all the VM hooks imported by a contract against an EI version.
+///
+/// For each VM hook that is either missing from the EI version or deprecated,
+/// it walks the call graph of the contract to find the endpoints that end up calling it.
+pub fn check_ei_compliance(wasm_data: &[u8], ei: EIVersion) -> Result<Vec<EIViolation>> {
+    let call_graph = WasmCallGraph::parse(wasm_data)?;
+
+    let mut violations = Vec::new();
+    for (import_index, import_name) in call_graph.import_names.iter().enumerate() {
+        let kind = if !ei.contains_vm_hook(import_name) {
+            EIViolationKind::NotAvailable
+        } else if EI_DEPRECATED_NAMES.contains(&import_name.as_str()) {
+            EIViolationKind::Deprecated
+        } else {
+            continue;
+        };
+
+        let import_index = import_index as u32;
+        violations.push(EIViolation {
+            vm_hook_name: import_name.clone(),
+            kind,
+            callers: call_graph
+                .direct_callers(import_index)
+                .into_iter()
+                .map(|caller| call_graph.function_name(caller))
+                .collect(),
+            endpoints: call_graph.exports_reaching(import_index),
+        });
+    }
+
+    Ok(violations)
+}
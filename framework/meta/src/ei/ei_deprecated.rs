@@ -0,0 +1,9 @@
+/// VM hooks that are still available, but are kept only for older contracts.
+///
+/// The framework no longer calls them, they all have managed or small int replacements.
+pub const EI_DEPRECATED_NAMES: &[&str] = &[
+    "int64getArgument",
+    "int64finish",
+    "int64storageStore",
+    "int64storageLoad",
+];
@@ -1,4 +1,5 @@
 pub mod abi_json;
+mod build_manifest_json;
 mod cargo_toml_contents;
 pub mod cli_args;
 pub mod cmd;
@@ -2,6 +2,8 @@ use std::{path::Path, process::Command};
 
 use colored::Colorize;
 
+use crate::ei::{EIViolation, EIViolationKind};
+
 pub fn print_all_count(num_contract_crates: usize) {
     println!(
         "\n{}",
@@ -79,6 +81,14 @@ pub fn print_pack_mxsc_file(output_mxsc_path: &str) {
     println!("{}", format!("Packing {output_mxsc_path} ...").green(),);
 }
 
+pub fn print_write_build_manifest(output_manifest_path: &str, code_hash: &str) {
+    println!(
+        "{}",
+        format!("Writing build manifest {output_manifest_path}, code hash: {code_hash} ...")
+            .green(),
+    );
+}
+
 pub fn print_contract_size(size: usize) {
     println!("{}", format!("Contract size: {size} bytes.").blue(),);
 }
@@ -106,6 +116,49 @@ pub fn print_invalid_vm_hook(import_name: &str, ei_version: &str) {
     );
 }
 
+pub fn print_deprecated_vm_hook(import_name: &str) {
+    print!(
+        "\n{}",
+        format!("WARNING! Import '{import_name}' is deprecated!").yellow(),
+    );
+}
+
+pub fn print_ei_violations(violations: &[EIViolation], ei_version: &str) {
+    for violation in violations {
+        match violation.kind {
+            EIViolationKind::NotAvailable => {
+                print_invalid_vm_hook(&violation.vm_hook_name, ei_version)
+            },
+            EIViolationKind::Deprecated => print_deprecated_vm_hook(&violation.vm_hook_name),
+        }
+        print!(
+            "\n{}",
+            format!(
+                "    reached from endpoints: {}",
+                list_or_none(&violation.endpoints)
+            )
+            .yellow(),
+        );
+        print!(
+            "\n{}",
+            format!(
+                "    called directly by: {}",
+                list_or_none(&violation.callers)
+            )
+            .truecolor(128, 128, 128),
+        );
+    }
+    println!();
+}
+
+fn list_or_none(names: &[String]) -> String {
+    if names.is_empty() {
+        "<none>".to_string()
+    } else {
+        names.join(", ")
+    }
+}
+
 pub fn print_check_ei_ok() {
     println!("{}", " OK".green(),);
 }
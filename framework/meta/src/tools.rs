@@ -1,12 +1,18 @@
+mod cargo_metadata;
 mod git_describe;
+mod git_ls_files;
 pub mod twiggy;
+mod wasm_call_graph;
 mod wasm_imports;
 mod wasm_opt;
 mod wasm_to_wat;
 
+pub use cargo_metadata::cargo_metadata;
 pub use git_describe::git_describe;
+pub use git_ls_files::git_ls_files;
+pub use wasm_call_graph::WasmCallGraph;
 pub use wasm_imports::extract_wasm_imports;
-pub use wasm_opt::run_wasm_opt;
+pub use wasm_opt::{run_wasm_opt, wasm_opt_version};
 pub use wasm_to_wat::wasm_to_wat;
 
 use crate::cli_args::BuildArgs;
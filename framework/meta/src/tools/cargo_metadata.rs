@@ -0,0 +1,63 @@
+use std::{
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+use serde::Deserialize;
+
+/// The parts of the `cargo metadata` output needed to make builds machine independent.
+#[derive(Deserialize)]
+pub struct CargoMetadata {
+    pub workspace_root: PathBuf,
+    pub packages: Vec<CargoMetadataPackage>,
+}
+
+#[derive(Deserialize)]
+pub struct CargoMetadataPackage {
+    pub name: String,
+
+    /// `None` for the workspace members and the path dependencies.
+    pub source: Option<String>,
+
+    pub manifest_path: PathBuf,
+}
+
+impl CargoMetadataPackage {
+    pub fn is_local(&self) -> bool {
+        self.source.is_none()
+    }
+
+    pub fn dir_path(&self) -> &Path {
+        self.manifest_path
+            .parent()
+            .expect("package manifest path has no parent directory")
+    }
+}
+
+/// Runs `cargo metadata` for the crate in the given directory.
+///
+/// With `no_deps`, only the workspace members are listed, which does not require resolving the dependencies.
+pub fn cargo_metadata(crate_path: &Path, no_deps: bool) -> CargoMetadata {
+    let mut command = Command::new("cargo");
+    command
+        .args(["metadata", "--format-version=1", "--locked"])
+        .current_dir(crate_path);
+    if no_deps {
+        command.arg("--no-deps");
+    }
+
+    let output = command.output().unwrap_or_else(|err| {
+        panic!(
+            "failed to run cargo metadata in {}: {err}",
+            crate_path.display()
+        )
+    });
+    assert!(
+        output.status.success(),
+        "cargo metadata failed in {}: {}",
+        crate_path.display(),
+        String::from_utf8_lossy(&output.stderr).trim()
+    );
+
+    serde_json::from_slice(&output.stdout).expect("invalid cargo metadata output")
+}
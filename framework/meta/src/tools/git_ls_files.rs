@@ -0,0 +1,28 @@
+use std::{path::Path, process::Command};
+
+/// The files tracked by git in the given directory, with paths relative to it, always `/`-separated.
+pub fn git_ls_files(dir_path: &Path) -> Vec<String> {
+    let output = Command::new("git")
+        .args(["ls-files", "-z"])
+        .current_dir(dir_path)
+        .output()
+        .unwrap_or_else(|err| {
+            panic!(
+                "failed to run git ls-files in {}: {err}",
+                dir_path.display()
+            )
+        });
+    assert!(
+        output.status.success(),
+        "git ls-files failed in {}: {}",
+        dir_path.display(),
+        String::from_utf8_lossy(&output.stderr).trim()
+    );
+
+    String::from_utf8(output.stdout)
+        .expect("git ls-files returned a path that is not valid UTF-8")
+        .split('\0')
+        .filter(|path| !path.is_empty())
+        .map(str::to_string)
+        .collect()
+}
@@ -0,0 +1,201 @@
+use anyhow::Result;
+use std::collections::{BTreeMap, BTreeSet};
+use wasmparser::{
+    ElementItems, ExternalKind, Name, NameSectionReader, Operator, Parser, Payload, TypeRef,
+};
+
+/// The static call graph of a contract WASM binary.
+///
+/// Functions are identified by their index in the WASM function index space,
+/// so imports come first, followed by the functions defined in the module.
+///
+/// Indirect calls cannot be resolved statically, so every function performing one
+/// is considered to call all the functions placed in the function table.
+#[derive(Default)]
+pub struct WasmCallGraph {
+    /// Function imports (VM hooks), in index order.
+    pub import_names: Vec<String>,
+
+    /// Exported functions, i.e. endpoints, by name.
+    pub exports: BTreeMap<String, u32>,
+
+    /// Function names, as found in the `name` custom section, if present.
+    function_names: BTreeMap<u32, String>,
+
+    /// Direct and indirect callees, for each function defined in the module.
+    calls: BTreeMap<u32, BTreeSet<u32>>,
+
+    /// Body size in bytes, for each function defined in the module.
+    function_sizes: BTreeMap<u32, usize>,
+
+    /// Total size of the data segments, in bytes.
+    pub data_size: usize,
+}
+
+impl WasmCallGraph {
+    /// Parses the WebAssembly code and builds the call graph.
+    pub fn parse(wasm_data: &[u8]) -> Result<Self> {
+        let mut call_graph = WasmCallGraph::default();
+        let mut table_functions = BTreeSet::new();
+        let mut indirect_callers = Vec::new();
+        let mut next_function_index = 0u32;
+
+        let parser = Parser::new(0);
+        for payload in parser.parse_all(wasm_data) {
+            match payload? {
+                Payload::ImportSection(import_section) => {
+                    for import in import_section {
+                        let import = import?;
+                        if let TypeRef::Func(_) = import.ty {
+                            call_graph.import_names.push(import.name.to_string());
+                        }
+                    }
+                    next_function_index = call_graph.import_names.len() as u32;
+                },
+                Payload::ExportSection(export_section) => {
+                    for export in export_section {
+                        let export = export?;
+                        if export.kind == ExternalKind::Func {
+                            call_graph
+                                .exports
+                                .insert(export.name.to_string(), export.index);
+                        }
+                    }
+                },
+                Payload::ElementSection(element_section) => {
+                    for element in element_section {
+                        collect_element_functions(element?.items, &mut table_functions)?;
+                    }
+                },
+                Payload::DataSection(data_section) => {
+                    for data in data_section {
+                        call_graph.data_size += data?.data.len();
+                    }
+                },
+                Payload::CodeSectionEntry(body) => {
+                    let function_index = next_function_index;
+                    next_function_index += 1;
+                    call_graph
+                        .function_sizes
+                        .insert(function_index, body.range().len());
+
+                    let mut callees = BTreeSet::new();
+                    for operator in body.get_operators_reader()? {
+                        match operator? {
+                            Operator::Call { function_index }
+                            | Operator::ReturnCall { function_index }
+                            | Operator::RefFunc { function_index } => {
+                                callees.insert(function_index);
+                            },
+                            Operator::CallIndirect { .. } | Operator::ReturnCallIndirect { .. } => {
+                                indirect_callers.push(function_index);
+                            },
+                            _ => {},
+                        }
+                    }
+                    call_graph.calls.insert(function_index, callees);
+                },
+                Payload::CustomSection(reader) if reader.name() == "name" => {
+                    call_graph.parse_name_section(reader.data(), reader.data_offset());
+                },
+                _ => {},
+            }
+        }
+
+        for caller in indirect_callers {
+            if let Some(callees) = call_graph.calls.get_mut(&caller) {
+                callees.extend(table_functions.iter().copied());
+            }
+        }
+
+        Ok(call_graph)
+    }
+
+    /// The name section is optional and only informative, so a malformed one is simply ignored.
+    fn parse_name_section(&mut self, data: &[u8], offset: usize) {
+        for name in NameSectionReader::new(data, offset).flatten() {
+            if let Name::Function(name_map) = name {
+                for naming in name_map.into_iter().flatten() {
+                    self.function_names
+                        .insert(naming.index, naming.name.to_string());
+                }
+            }
+        }
+    }
+
+    /// The symbol name of a function, if the binary was built with symbols,
+    /// otherwise a generic name based on its index.
+    pub fn function_name(&self, function_index: u32) -> String {
+        if let Some(name) = self.function_names.get(&function_index) {
+            return name.clone();
+        }
+        if let Some(import_name) = self.import_names.get(function_index as usize) {
+            return import_name.clone();
+        }
+        format!("func[{function_index}]")
+    }
+
+    /// All functions defined in the module, with their body size in bytes.
+    pub fn function_sizes(&self) -> &BTreeMap<u32, usize> {
+        &self.function_sizes
+    }
+
+    /// All functions defined in the module that call the given function directly.
+    pub fn direct_callers(&self, function_index: u32) -> Vec<u32> {
+        self.calls
+            .iter()
+            .filter(|(_, callees)| callees.contains(&function_index))
+            .map(|(caller, _)| *caller)
+            .collect()
+    }
+
+    /// All exported functions that reach the given function through the call graph.
+    pub fn exports_reaching(&self, function_index: u32) -> Vec<String> {
+        self.exports
+            .iter()
+            .filter(|(_, export_index)| {
+                self.reachable_from(**export_index)
+                    .contains(&function_index)
+            })
+            .map(|(export_name, _)| export_name.clone())
+            .collect()
+    }
+
+    /// All functions reachable from the given function, including itself.
+    pub fn reachable_from(&self, function_index: u32) -> BTreeSet<u32> {
+        let mut visited = BTreeSet::new();
+        let mut stack = vec![function_index];
+        while let Some(function_index) = stack.pop() {
+            if !visited.insert(function_index) {
+                continue;
+            }
+            if let Some(callees) = self.calls.get(&function_index) {
+                stack.extend(callees.iter().copied());
+            }
+        }
+        visited
+    }
+}
+
+fn collect_element_functions(
+    items: ElementItems<'_>,
+    table_functions: &mut BTreeSet<u32>,
+) -> Result<()> {
+    match items {
+        ElementItems::Functions(functions) => {
+            for function_index in functions {
+                table_functions.insert(function_index?);
+            }
+        },
+        ElementItems::Expressions(_, expressions) => {
+            for expression in expressions {
+                for operator in expression?.get_operators_reader() {
+                    if let Operator::RefFunc { function_index } = operator? {
+                        table_functions.insert(function_index);
+                    }
+                }
+            }
+        },
+    }
+    Ok(())
+}
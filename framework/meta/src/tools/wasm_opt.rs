@@ -9,6 +9,14 @@ pub fn is_wasm_opt_installed() -> bool {
         .is_ok()
 }
 
+pub fn wasm_opt_version() -> Option<String> {
+    let output = Command::new(WASM_OPT_NAME)
+        .args(["--version"])
+        .output()
+        .ok()?;
+    Some(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
 pub fn run_wasm_opt(output_wasm_path: &str) {
     let exit_status = Command::new(WASM_OPT_NAME)
         .args([output_wasm_path, "-Oz", "--output", output_wasm_path])
@@ -0,0 +1,229 @@
+use dharitri_sc_meta::{
+    abi_json::StorageLayoutJson,
+    cmd::standalone::check_upgrade::{
+        check_storage_upgrade, StorageUpgradeIssue, StorageUpgradeSeverity,
+    },
+};
+
+const OLD_LAYOUT: &str = r#"{
+    "name": "Sample",
+    "storage": [
+        {
+            "key": "balance",
+            "rustMethodName": "balance",
+            "mapper": "SingleValueMapper",
+            "keyArgs": [
+                {
+                    "name": "address",
+                    "type": "ManagedAddress"
+                }
+            ],
+            "valueTypes": [
+                "BigUint"
+            ]
+        },
+        {
+            "key": "balance",
+            "rustMethodName": "get_balance",
+            "mapper": "storage_get",
+            "keyArgs": [
+                {
+                    "name": "address",
+                    "type": "ManagedAddress"
+                }
+            ],
+            "valueTypes": [
+                "BigUint"
+            ]
+        },
+        {
+            "key": "config",
+            "rustMethodName": "config",
+            "mapper": "SingleValueMapper",
+            "valueTypes": [
+                "Config"
+            ]
+        },
+        {
+            "key": "users",
+            "rustMethodName": "users",
+            "mapper": "UnorderedSetMapper",
+            "valueTypes": [
+                "ManagedAddress"
+            ]
+        }
+    ],
+    "types": {
+        "Config": {
+            "type": "struct",
+            "fields": [
+                {
+                    "name": "status",
+                    "type": "Status"
+                },
+                {
+                    "name": "fee",
+                    "type": "u64"
+                }
+            ]
+        },
+        "Status": {
+            "type": "enum",
+            "variants": [
+                {
+                    "name": "Inactive",
+                    "discriminant": 0
+                },
+                {
+                    "name": "Active",
+                    "discriminant": 1
+                }
+            ]
+        }
+    }
+}"#;
+
+fn old_layout() -> StorageLayoutJson {
+    serde_json::from_str(OLD_LAYOUT).unwrap()
+}
+
+fn issue(key: &str, severity: StorageUpgradeSeverity, message: &str) -> StorageUpgradeIssue {
+    StorageUpgradeIssue {
+        key: key.to_string(),
+        severity,
+        message: message.to_string(),
+    }
+}
+
+#[test]
+fn test_check_upgrade_same_layout() {
+    assert!(check_storage_upgrade(&old_layout(), &old_layout()).is_empty());
+}
+
+#[test]
+fn test_check_upgrade_compatible_changes() {
+    let mut new_layout = old_layout();
+    // storage_get and SingleValueMapper access the storage the same way
+    new_layout.storage[0].mapper = "storage_get".to_string();
+    // new storage and new enum variants are fine
+    new_layout.storage.push(
+        serde_json::from_str(
+            r#"{
+                "key": "admins",
+                "rustMethodName": "admins",
+                "mapper": "UnorderedSetMapper",
+                "valueTypes": ["ManagedAddress"]
+            }"#,
+        )
+        .unwrap(),
+    );
+    let status = new_layout.types.get_mut("Status").unwrap();
+    status
+        .variants
+        .push(serde_json::from_str(r#"{ "name": "Paused", "discriminant": 2 }"#).unwrap());
+
+    assert!(check_storage_upgrade(&old_layout(), &new_layout).is_empty());
+}
+
+#[test]
+fn test_check_upgrade_removed_key() {
+    let mut new_layout = old_layout();
+    new_layout.storage.retain(|storage| storage.key != "users");
+
+    assert_eq!(
+        check_storage_upgrade(&old_layout(), &new_layout),
+        vec![issue(
+            "users",
+            StorageUpgradeSeverity::Breaking,
+            "storage key removed (was users), the existing data becomes unreachable"
+        )]
+    );
+}
+
+#[test]
+fn test_check_upgrade_mapper_and_type_changes() {
+    let mut new_layout = old_layout();
+    new_layout.storage[0].key_args[0].type_name = "TokenIdentifier".to_string();
+    new_layout.storage[1].key_args[0].type_name = "TokenIdentifier".to_string();
+    new_layout.storage[3].mapper = "SetMapper".to_string();
+
+    assert_eq!(
+        check_storage_upgrade(&old_layout(), &new_layout),
+        vec![
+            issue(
+                "balance",
+                StorageUpgradeSeverity::Breaking,
+                "key arguments changed from (ManagedAddress) to (TokenIdentifier)"
+            ),
+            issue(
+                "users",
+                StorageUpgradeSeverity::Breaking,
+                "mapper changed from UnorderedSetMapper to SetMapper"
+            ),
+        ]
+    );
+
+    let mut new_layout = old_layout();
+    new_layout.storage[0].value_types[0] = "u64".to_string();
+    assert_eq!(
+        check_storage_upgrade(&old_layout(), &new_layout),
+        vec![issue(
+            "balance",
+            StorageUpgradeSeverity::Breaking,
+            "value type changed from BigUint to u64"
+        )]
+    );
+}
+
+#[test]
+fn test_check_upgrade_stored_type_changes() {
+    let mut new_layout = old_layout();
+    let config = new_layout.types.get_mut("Config").unwrap();
+    config.fields[1].name = "fee_percent".to_string();
+    let status = new_layout.types.get_mut("Status").unwrap();
+    status.variants.remove(1);
+
+    assert_eq!(
+        check_storage_upgrade(&old_layout(), &new_layout),
+        vec![
+            issue(
+                "config",
+                StorageUpgradeSeverity::Breaking,
+                "variant Status::Active was removed"
+            ),
+            issue(
+                "config",
+                StorageUpgradeSeverity::Warning,
+                "field Config.fee renamed to fee_percent"
+            ),
+        ]
+    );
+
+    let mut new_layout = old_layout();
+    let config = new_layout.types.get_mut("Config").unwrap();
+    config.fields[1].field_type = "u32".to_string();
+    assert_eq!(
+        check_storage_upgrade(&old_layout(), &new_layout),
+        vec![issue(
+            "config",
+            StorageUpgradeSeverity::Breaking,
+            "type of field Config.fee changed from u64 to u32"
+        )]
+    );
+}
+
+#[test]
+fn test_check_upgrade_undescribed_types() {
+    let mut layout = old_layout();
+    layout.storage[2].undescribed_types = vec!["Config".to_string()];
+    layout.types.remove("Config");
+
+    assert_eq!(
+        check_storage_upgrade(&layout, &layout),
+        vec![issue(
+            "config",
+            StorageUpgradeSeverity::Warning,
+            "cannot check type Config, it does not implement TypeAbi"
+        )]
+    );
+}
@@ -0,0 +1,130 @@
+use dharitri_sc_meta::ei::{check_ei_compliance, EIVersion, EIViolation, EIViolationKind};
+
+fn leb128(mut value: usize, out: &mut Vec<u8>) {
+    loop {
+        let byte = (value & 0x7f) as u8;
+        value >>= 7;
+        if value == 0 {
+            out.push(byte);
+            return;
+        }
+        out.push(byte | 0x80);
+    }
+}
+
+fn name(name: &str, out: &mut Vec<u8>) {
+    leb128(name.len(), out);
+    out.extend_from_slice(name.as_bytes());
+}
+
+fn section(id: u8, count: usize, items: &[u8], out: &mut Vec<u8>) {
+    let mut content = Vec::new();
+    leb128(count, &mut content);
+    content.extend_from_slice(items);
+    out.push(id);
+    leb128(content.len(), out);
+    out.extend(content);
+}
+
+fn function_body(code: &[u8], out: &mut Vec<u8>) {
+    // no locals
+    let mut body = vec![0x00];
+    body.extend_from_slice(code);
+    body.push(0x0b);
+    leb128(body.len(), out);
+    out.extend(body);
+}
+
+/// A minimal contract, with the following call graph:
+///
+/// - `plain` (3) -> `bigIntAdd` (0)
+/// - `promise` (4) -> `helper` (6) -> `managedCreateAsyncCall` (1)
+/// - `legacy` (7) -> call_indirect -> table function (5) -> `int64finish` (2)
+fn contract_wasm() -> Vec<u8> {
+    let mut wasm = b"\0asm\x01\0\0\0".to_vec();
+
+    // a single type: [] -> []
+    section(1, 1, &[0x60, 0x00, 0x00], &mut wasm);
+
+    let mut imports = Vec::new();
+    for import_name in ["bigIntAdd", "managedCreateAsyncCall", "int64finish"] {
+        name("env", &mut imports);
+        name(import_name, &mut imports);
+        imports.extend_from_slice(&[0x00, 0x00]);
+    }
+    section(2, 3, &imports, &mut wasm);
+
+    section(3, 5, &[0x00; 5], &mut wasm);
+
+    // a funcref table, with one element
+    section(4, 1, &[0x70, 0x00, 0x01], &mut wasm);
+
+    let mut exports = Vec::new();
+    for (export_name, function_index) in [("plain", 3u8), ("promise", 4), ("legacy", 7)] {
+        name(export_name, &mut exports);
+        exports.extend_from_slice(&[0x00, function_index]);
+    }
+    section(7, 3, &exports, &mut wasm);
+
+    // table[0] = function 5
+    section(9, 1, &[0x00, 0x41, 0x00, 0x0b, 0x01, 0x05], &mut wasm);
+
+    let mut code = Vec::new();
+    function_body(&[0x10, 0x00], &mut code);
+    function_body(&[0x10, 0x06], &mut code);
+    function_body(&[0x10, 0x02], &mut code);
+    function_body(&[0x10, 0x01], &mut code);
+    function_body(&[0x41, 0x00, 0x11, 0x00, 0x00], &mut code);
+    section(10, 5, &code, &mut wasm);
+
+    // name section, only naming the helper function
+    let mut function_names = Vec::new();
+    leb128(1, &mut function_names);
+    leb128(6, &mut function_names);
+    name("helper", &mut function_names);
+    let mut names = Vec::new();
+    name("name", &mut names);
+    names.push(0x01);
+    leb128(function_names.len(), &mut names);
+    names.extend(function_names);
+    wasm.push(0x00);
+    leb128(names.len(), &mut wasm);
+    wasm.extend(names);
+
+    wasm
+}
+
+#[test]
+fn test_ei_check_1_2() {
+    let violations = check_ei_compliance(&contract_wasm(), EIVersion::V1_2).unwrap();
+    assert_eq!(
+        violations,
+        vec![
+            EIViolation {
+                vm_hook_name: "managedCreateAsyncCall".to_string(),
+                kind: EIViolationKind::NotAvailable,
+                callers: vec!["helper".to_string()],
+                endpoints: vec!["promise".to_string()],
+            },
+            EIViolation {
+                vm_hook_name: "int64finish".to_string(),
+                kind: EIViolationKind::Deprecated,
+                callers: vec!["func[5]".to_string()],
+                endpoints: vec!["legacy".to_string()],
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_ei_check_1_3() {
+    let violations = check_ei_compliance(&contract_wasm(), EIVersion::V1_3).unwrap();
+    assert_eq!(violations.len(), 1);
+    assert_eq!(violations[0].vm_hook_name, "int64finish");
+    assert_eq!(violations[0].kind, EIViolationKind::Deprecated);
+}
+
+#[test]
+fn test_ei_check_invalid_wasm() {
+    assert!(check_ei_compliance(b"not wasm", EIVersion::V1_2).is_err());
+}
@@ -0,0 +1,68 @@
+use dharitri_sc_meta::cmd::standalone::size::{SizeGrowth, SizeReport};
+
+const ADDER_WASM_PATH: &str = "../../contracts/examples/multisig/test-contracts/adder.wasm";
+
+fn adder_size_report() -> SizeReport {
+    let wasm_data = std::fs::read(ADDER_WASM_PATH).unwrap();
+    SizeReport::from_wasm(&wasm_data, 5).unwrap()
+}
+
+#[test]
+fn test_size_report_adder() {
+    let report = adder_size_report();
+    assert!(report.code_size + report.data_size < report.total_size);
+    assert!(report.largest_functions.len() <= 5);
+    assert!(report
+        .largest_functions
+        .windows(2)
+        .all(|pair| pair[0].size >= pair[1].size));
+
+    let endpoint_names: Vec<&str> = report
+        .endpoints
+        .iter()
+        .map(|endpoint| endpoint.name.as_str())
+        .collect();
+    assert!(endpoint_names.contains(&"add"));
+    assert!(endpoint_names.contains(&"getSum"));
+
+    for endpoint in &report.endpoints {
+        assert!(endpoint.reachable_size > 0);
+        assert!(endpoint.reachable_size <= report.code_size);
+        assert_eq!(
+            endpoint.reachable_size,
+            endpoint.exclusive_size + endpoint.shared_size
+        );
+    }
+}
+
+#[test]
+fn test_size_growth() {
+    let report = adder_size_report();
+    assert!(report.growth_over(&report, 0).is_empty());
+
+    let mut baseline = report.clone();
+    baseline.total_size -= 10;
+    baseline.endpoints[0].reachable_size -= 1;
+    assert_eq!(
+        report.growth_over(&baseline, 0),
+        vec![
+            SizeGrowth {
+                item: "total".to_string(),
+                baseline_size: report.total_size - 10,
+                size: report.total_size,
+            },
+            SizeGrowth {
+                item: format!("endpoint {}", report.endpoints[0].name),
+                baseline_size: report.endpoints[0].reachable_size - 1,
+                size: report.endpoints[0].reachable_size,
+            },
+        ]
+    );
+
+    // a large enough threshold allows the growth
+    assert!(report.growth_over(&baseline, 100).is_empty());
+
+    // new endpoints are not compared
+    baseline.endpoints.clear();
+    assert_eq!(report.growth_over(&baseline, 0).len(), 1);
+}
@@ -0,0 +1,13 @@
+mod mock_chain;
+mod mock_gateway;
+mod mock_gateway_routes;
+mod mock_tx_output;
+
+pub use mock_chain::{MOCK_CHAIN_ID, MOCK_SHARD_ID};
+pub use mock_gateway::{MockGateway, MockGatewayHandle};
+
+pub(crate) use mock_chain::MockChain;
+pub(crate) use mock_gateway_routes::handle_request;
+
+pub use dharitri_sc_scenario;
+pub use dharitri_sdk;
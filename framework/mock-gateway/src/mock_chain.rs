@@ -0,0 +1,564 @@
+use dharitri_sc_scenario::{
+    dharitri_chain_vm::{
+        tx_execution::{execute_current_tx_context_input, is_system_sc_address},
+        tx_mock::TxResult,
+        types::VMAddress,
+    },
+    dharitri_sc::types::{Address as ScAddress, CodeMetadata, H256},
+    num_bigint::BigUint,
+    scenario_model::{
+        AddressValue, BigUintValue, BytesValue, ScCallStep, ScDeployStep, ScQueryStep, U64Value,
+    },
+    ScenarioWorld,
+};
+use dharitri_sdk::data::{
+    account::Account,
+    address::Address,
+    hyperblock::{HyperBlock, ShardBlocks},
+    network_config::NetworkConfig,
+    network_status::NetworkStatus,
+    transaction::{Transaction, TransactionOnNetwork, TxCostResponseData},
+    vm::{VMOutputApi, VmValueRequest},
+};
+use sha2::{Digest, Sha256};
+use std::{
+    collections::HashMap,
+    panic::{catch_unwind, AssertUnwindSafe},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use crate::mock_tx_output::{pending_tx_on_network, tx_on_network, vm_output, MockTxExecution};
+
+pub const MOCK_CHAIN_ID: &str = "mock";
+pub const MOCK_SHARD_ID: u32 = 0;
+
+/// The state behind the mock gateway: the scenario world, plus the transactions and hyperblocks
+/// that the gateway has to remember, since the blockchain mock has no notion of them.
+pub(crate) struct MockChain {
+    world: ScenarioWorld,
+    network_config: NetworkConfig,
+    hyperblocks: Vec<HyperBlock>,
+    transactions: HashMap<String, TransactionOnNetwork>,
+    /// Accepted transactions, waiting for the next round, in the order they were received.
+    mempool: Vec<MempoolTx>,
+    forced_rejections: Vec<ForcedRejection>,
+}
+
+struct MempoolTx {
+    tx_hash: String,
+    tx: Transaction,
+}
+
+/// Rejects the next transaction with the given sender and nonce, regardless of its validity.
+struct ForcedRejection {
+    sender: Address,
+    nonce: u64,
+    reason: String,
+}
+
+impl MockChain {
+    pub fn new(world: ScenarioWorld, network_config: NetworkConfig) -> Self {
+        let mut chain = MockChain {
+            world,
+            network_config,
+            hyperblocks: Vec::new(),
+            transactions: HashMap::new(),
+            mempool: Vec::new(),
+            forced_rejections: Vec::new(),
+        };
+        chain.produce_hyperblock(0); // genesis
+        chain
+    }
+
+    pub fn world(&mut self) -> &mut ScenarioWorld {
+        &mut self.world
+    }
+
+    pub fn network_config(&self) -> &NetworkConfig {
+        &self.network_config
+    }
+
+    pub fn network_status(&self) -> NetworkStatus {
+        let nonce = self.latest_hyperblock().nonce;
+        NetworkStatus {
+            current_round: nonce,
+            epoch_number: 0,
+            nonce,
+            nonce_at_epoch_start: 0,
+            nonces_passed_in_current_epoch: nonce,
+            round_at_epoch_start: 0,
+            rounds_passed_in_current_epoch: nonce,
+            rounds_per_epoch: u32::MAX,
+        }
+    }
+
+    pub fn latest_hyperblock(&self) -> &HyperBlock {
+        self.hyperblocks.last().unwrap()
+    }
+
+    pub fn hyperblock_by_nonce(&self, nonce: u64) -> Option<&HyperBlock> {
+        self.hyperblocks.get(nonce as usize)
+    }
+
+    pub fn hyperblock_by_hash(&self, hash: &str) -> Option<&HyperBlock> {
+        self.hyperblocks
+            .iter()
+            .find(|hyperblock| hyperblock.hash == hash)
+    }
+
+    /// Adds a new hyperblock on top of the chain.
+    pub fn produce_hyperblock(&mut self, num_txs: u64) -> &HyperBlock {
+        let nonce = self.hyperblocks.len() as u64;
+        let prev_block_hash = self
+            .hyperblocks
+            .last()
+            .map(|hyperblock| hyperblock.hash.clone())
+            .unwrap_or_default();
+        let hash = hex_sha256(format!("hyperblock{nonce}").as_bytes());
+        self.hyperblocks.push(HyperBlock {
+            nonce,
+            round: nonce,
+            hash: hash.clone(),
+            prev_block_hash,
+            epoch: 0,
+            num_txs,
+            shard_blocks: vec![ShardBlocks {
+                hash,
+                nonce,
+                shard: MOCK_SHARD_ID,
+            }],
+            timestamp: unix_timestamp(),
+            accumulated_fees: "0".to_string(),
+            developer_fees: "0".to_string(),
+            accumulated_fees_in_epoch: "0".to_string(),
+            developer_fees_in_epoch: "0".to_string(),
+        });
+        self.hyperblocks.last().unwrap()
+    }
+
+    pub fn account(&mut self, address: &Address) -> Account {
+        let vm_address = VMAddress::from(address.to_bytes());
+        let state = &self.world.get_mut_vm_runner().blockchain_mock.state;
+        match state.accounts.get(&vm_address) {
+            Some(account) => Account {
+                address: address.clone(),
+                nonce: account.nonce,
+                balance: account.moax_balance.to_string(),
+                username: String::from_utf8_lossy(&account.username).to_string(),
+                code: account
+                    .contract_path
+                    .as_ref()
+                    .map(hex::encode)
+                    .unwrap_or_default(),
+                code_hash: None,
+                root_hash: None,
+                code_metadata: None,
+                developer_reward: Some(account.developer_rewards.to_string()),
+                owner_address: account
+                    .contract_owner
+                    .as_ref()
+                    .map(|owner| Address::from_bytes(*owner.as_array()).to_string()),
+            },
+            None => Account {
+                address: address.clone(),
+                nonce: 0,
+                balance: "0".to_string(),
+                username: String::new(),
+                code: String::new(),
+                code_hash: None,
+                root_hash: None,
+                code_metadata: None,
+                developer_reward: None,
+                owner_address: None,
+            },
+        }
+    }
+
+    pub fn transaction(&self, tx_hash: &str) -> Option<&TransactionOnNetwork> {
+        self.transactions.get(tx_hash)
+    }
+
+    /// Adds the valid transactions to the mempool, to be executed in the next round.
+    ///
+    /// The result is aligned with the input: the hashes of the accepted transactions,
+    /// or the reason why they were rejected.
+    pub fn send_transactions(&mut self, txs: &[Transaction]) -> Vec<Result<String, String>> {
+        txs.iter()
+            .map(|tx| {
+                self.take_forced_rejection(tx)?;
+                self.validate_tx(tx)?;
+
+                let tx_hash = tx_hash(tx);
+                self.transactions
+                    .insert(tx_hash.clone(), pending_tx_on_network(tx, &tx_hash));
+                self.mempool.push(MempoolTx {
+                    tx_hash: tx_hash.clone(),
+                    tx: tx.clone(),
+                });
+                Ok(tx_hash)
+            })
+            .collect()
+    }
+
+    /// The next transaction received from the sender with this nonce gets rejected with the given reason.
+    pub fn force_rejection(&mut self, sender: Address, nonce: u64, reason: String) {
+        self.forced_rejections.push(ForcedRejection {
+            sender,
+            nonce,
+            reason,
+        });
+    }
+
+    fn take_forced_rejection(&mut self, tx: &Transaction) -> Result<(), String> {
+        let position = self.forced_rejections.iter().position(|rejection| {
+            rejection.sender.to_bytes() == tx.sender.to_bytes() && rejection.nonce == tx.nonce
+        });
+        match position {
+            Some(position) => Err(self.forced_rejections.remove(position).reason),
+            None => Ok(()),
+        }
+    }
+
+    /// Executes the mempool transactions whose nonces follow the sender nonces, all of them in a new hyperblock.
+    ///
+    /// Transactions with nonce gaps wait in the mempool until the missing nonces arrive.
+    pub fn produce_round(&mut self) {
+        let mut executed = Vec::new();
+        while let Some(position) = self.next_executable_tx() {
+            let MempoolTx { tx_hash, tx } = self.mempool.remove(position);
+            let execution = self.execute_tx(&tx, &tx_hash);
+            executed.push(tx_on_network(&tx, &tx_hash, &execution));
+        }
+
+        let hyperblock = self.produce_hyperblock(executed.len() as u64).clone();
+        for mut tx in executed {
+            tx.round = hyperblock.round;
+            tx.block_nonce = hyperblock.nonce;
+            tx.block_hash = hyperblock.hash.clone();
+            tx.notarized_at_source_in_meta_nonce = hyperblock.nonce;
+            tx.notarized_at_source_in_meta_hash = hyperblock.hash.clone();
+            tx.notarized_at_destination_in_meta_nonce = hyperblock.nonce;
+            tx.notarized_at_destination_in_meta_hash = hyperblock.hash.clone();
+            tx.timestamp = hyperblock.timestamp;
+            tx.hyperblock_nonce = hyperblock.nonce;
+            tx.hyperblock_hash = hyperblock.hash.clone();
+            self.transactions.insert(tx.hash.clone().unwrap(), tx);
+        }
+    }
+
+    /// Simulates the transaction, then reverts the state.
+    pub fn transaction_cost(&mut self, tx: &Transaction) -> TxCostResponseData {
+        // the cost of moving the data is charged per byte of the data itself, not of its base64 encoding
+        let data_len = match decode_tx_data(tx) {
+            Ok(data) => data.len() as u64,
+            Err(err) => {
+                return TxCostResponseData {
+                    tx_gas_units: 0,
+                    return_message: err,
+                }
+            },
+        };
+
+        let snapshot = (*self.world.get_mut_vm_runner().blockchain_mock.state).clone();
+
+        let execution = self.execute_tx(tx, &tx_hash(tx));
+
+        let vm_runner = self.world.get_mut_vm_runner();
+        *vm_runner.blockchain_mock.state = snapshot;
+
+        if execution.tx_result.result_status != 0 {
+            return TxCostResponseData {
+                tx_gas_units: 0,
+                return_message: execution.tx_result.result_message,
+            };
+        }
+
+        // without a gas schedule, only the cost of moving the data can be estimated
+        TxCostResponseData {
+            tx_gas_units: self.network_config.min_gas_limit
+                + self.network_config.gas_per_data_byte * data_len
+                + execution.tx_result.gas_used,
+            return_message: String::new(),
+        }
+    }
+
+    pub fn vm_query(&mut self, request: &VmValueRequest) -> Result<VMOutputApi, String> {
+        let mut step = ScQueryStep::new();
+        step.tx.to = sc_address_value(&request.sc_address);
+        step.tx.function = request.func_name.clone();
+        step.tx.arguments = decode_hex_args(request.args.iter().map(String::as_str))?
+            .into_iter()
+            .map(BytesValue::from)
+            .collect();
+
+        let vm_runner = self.world.get_mut_vm_runner();
+        let tx_result = catch_tx_panic(|| {
+            vm_runner.perform_sc_query_lambda(&step, execute_current_tx_context_input)
+        });
+        Ok(vm_output(&tx_result))
+    }
+
+    fn next_executable_tx(&mut self) -> Option<usize> {
+        let state = &self.world.get_mut_vm_runner().blockchain_mock.state;
+        self.mempool.iter().position(|mempool_tx| {
+            let sender = VMAddress::from(mempool_tx.tx.sender.to_bytes());
+            let sender_nonce = state
+                .accounts
+                .get(&sender)
+                .map(|account| account.nonce)
+                .unwrap_or_default();
+            mempool_tx.tx.nonce == sender_nonce
+        })
+    }
+
+    fn validate_tx(&mut self, tx: &Transaction) -> Result<(), String> {
+        if tx.chain_id != self.network_config.chain_id {
+            return Err("invalid chain ID".to_string());
+        }
+        if tx.signature.is_none() {
+            return Err("missing signature".to_string());
+        }
+
+        let sender = self.account(&tx.sender);
+        if tx.nonce < sender.nonce {
+            return Err("lower nonce in transaction".to_string());
+        }
+        if self.mempool.iter().any(|mempool_tx| {
+            mempool_tx.tx.sender.to_bytes() == tx.sender.to_bytes()
+                && mempool_tx.tx.nonce == tx.nonce
+        }) {
+            return Err("transaction with the same nonce already in the mempool".to_string());
+        }
+
+        let balance = sender.balance.parse::<BigUint>().unwrap();
+        let value = parse_value(&tx.value)?;
+        let max_fee = BigUint::from(tx.gas_limit) * BigUint::from(tx.gas_price);
+        if balance < value + max_fee {
+            return Err("insufficient funds".to_string());
+        }
+
+        Ok(())
+    }
+
+    fn execute_tx(&mut self, tx: &Transaction, tx_hash: &str) -> MockTxExecution {
+        let sender = VMAddress::from(tx.sender.to_bytes());
+        let receiver = VMAddress::from(tx.receiver.to_bytes());
+        let is_deploy = receiver == VMAddress::zero();
+
+        let vm_runner = self.world.get_mut_vm_runner();
+        let parsed = decode_tx_data(tx).and_then(|data| {
+            let value = parse_value(&tx.value)?;
+            if is_deploy {
+                Ok(TxStep::Deploy(deploy_step(tx, tx_hash, value, &data)?))
+            } else if !vm_runner
+                .blockchain_mock
+                .state
+                .accounts
+                .contains_key(&receiver)
+            {
+                // the VM panics mid-execution on a missing account, which would leave the state unusable
+                Err("receiver account not found".to_string())
+            } else {
+                Ok(TxStep::Call(call_step(tx, tx_hash, value, &data)?))
+            }
+        });
+
+        match parsed {
+            Ok(TxStep::Deploy(step)) => {
+                let state = &mut vm_runner.blockchain_mock.state;
+                if state.get_new_address(sender.clone(), tx.nonce).is_none() {
+                    state.put_new_address(
+                        sender.clone(),
+                        tx.nonce,
+                        new_sc_address(&sender, tx.nonce),
+                    );
+                }
+
+                let mut new_address = None;
+                let tx_result = catch_tx_panic(|| {
+                    let (address, tx_result) =
+                        vm_runner.perform_sc_deploy_lambda(&step, execute_current_tx_context_input);
+                    new_address = Some(VMAddress::from(*address.as_array()));
+                    tx_result
+                });
+                MockTxExecution {
+                    tx_result,
+                    new_address,
+                    produces_scr: true,
+                }
+            },
+            Ok(TxStep::Call(step)) => {
+                let tx_result = catch_tx_panic(|| {
+                    vm_runner.perform_sc_call_lambda(&step, execute_current_tx_context_input)
+                });
+                let state = &vm_runner.blockchain_mock.state;
+                let produces_scr = is_system_sc_address(&receiver)
+                    || state
+                        .accounts
+                        .get(&receiver)
+                        .map(|account| account.contract_path.is_some())
+                        .unwrap_or_default();
+                MockTxExecution {
+                    tx_result,
+                    new_address: None,
+                    produces_scr,
+                }
+            },
+            Err(err) => {
+                // the nonce is consumed even if the transaction cannot be executed
+                vm_runner
+                    .blockchain_mock
+                    .state
+                    .increase_account_nonce(&sender);
+                MockTxExecution {
+                    tx_result: TxResult::from_panic_string(&err),
+                    new_address: None,
+                    produces_scr: false,
+                }
+            },
+        }
+    }
+}
+
+enum TxStep {
+    Deploy(ScDeployStep),
+    Call(ScCallStep),
+}
+
+fn deploy_step(
+    tx: &Transaction,
+    tx_hash: &str,
+    value: BigUint,
+    data: &str,
+) -> Result<ScDeployStep, String> {
+    let mut parts = data.split('@');
+    let code = hex::decode(parts.next().unwrap_or_default())
+        .map_err(|_| "invalid contract code".to_string())?;
+    let _vm_type = parts.next();
+    let code_metadata_bytes = hex::decode(parts.next().unwrap_or_default())
+        .map_err(|_| "invalid code metadata".to_string())?;
+    if code_metadata_bytes.len() != 2 {
+        return Err("invalid code metadata".to_string());
+    }
+    let code_metadata = u16::from_be_bytes([code_metadata_bytes[0], code_metadata_bytes[1]]);
+
+    let mut step = ScDeployStep::new();
+    step.explicit_tx_hash = Some(tx_hash_h256(tx_hash));
+    step.tx.from = sc_address_value(&tx.sender);
+    step.tx.moax_value = BigUintValue::from(value);
+    step.tx.code_metadata = CodeMetadata::from(code_metadata);
+    step.tx.contract_code = BytesValue::from(code);
+    step.tx.arguments = decode_hex_args(parts)?
+        .into_iter()
+        .map(BytesValue::from)
+        .collect();
+    step.tx.gas_limit = U64Value::from(tx.gas_limit);
+    step.tx.gas_price = U64Value::from(tx.gas_price);
+    Ok(step)
+}
+
+fn call_step(
+    tx: &Transaction,
+    tx_hash: &str,
+    value: BigUint,
+    data: &str,
+) -> Result<ScCallStep, String> {
+    let mut parts = data.split('@');
+    let function = parts.next().unwrap_or_default().to_string();
+
+    let mut step = ScCallStep::new();
+    step.explicit_tx_hash = Some(tx_hash_h256(tx_hash));
+    step.tx.from = sc_address_value(&tx.sender);
+    step.tx.to = sc_address_value(&tx.receiver);
+    step.tx.moax_value = BigUintValue::from(value);
+    step.tx.function = function;
+    step.tx.arguments = decode_hex_args(parts)?
+        .into_iter()
+        .map(BytesValue::from)
+        .collect();
+    step.tx.gas_limit = U64Value::from(tx.gas_limit);
+    step.tx.gas_price = U64Value::from(tx.gas_price);
+    Ok(step)
+}
+
+/// Failures of the debugger itself (e.g. unknown contract code) are reported as failed transactions.
+fn catch_tx_panic<F>(f: F) -> TxResult
+where
+    F: FnOnce() -> TxResult,
+{
+    catch_unwind(AssertUnwindSafe(f)).unwrap_or_else(|panic_payload| {
+        let message = if let Some(message) = panic_payload.downcast_ref::<String>() {
+            message.clone()
+        } else if let Some(message) = panic_payload.downcast_ref::<&str>() {
+            message.to_string()
+        } else {
+            "execution failed".to_string()
+        };
+        TxResult::from_panic_string(&message)
+    })
+}
+
+fn decode_tx_data(tx: &Transaction) -> Result<String, String> {
+    match &tx.data {
+        Some(data) => {
+            let bytes = base64::decode(data).map_err(|_| "invalid data field".to_string())?;
+            String::from_utf8(bytes).map_err(|_| "invalid data field".to_string())
+        },
+        None => Ok(String::new()),
+    }
+}
+
+fn decode_hex_args<'a, I>(args: I) -> Result<Vec<Vec<u8>>, String>
+where
+    I: Iterator<Item = &'a str>,
+{
+    args.map(|arg| hex::decode(arg).map_err(|_| format!("invalid argument: {arg}")))
+        .collect()
+}
+
+fn parse_value(value: &str) -> Result<BigUint, String> {
+    if value.is_empty() {
+        return Ok(BigUint::default());
+    }
+    value
+        .parse::<BigUint>()
+        .map_err(|_| format!("invalid value: {value}"))
+}
+
+fn sc_address_value(address: &Address) -> AddressValue {
+    AddressValue::from(&ScAddress::from(address.to_bytes()))
+}
+
+/// Explicit new addresses set in the world take precedence, so these only need to look like contract addresses.
+fn new_sc_address(creator: &VMAddress, creator_nonce: u64) -> VMAddress {
+    let mut hash_input = creator.to_vec();
+    hash_input.extend_from_slice(&creator_nonce.to_be_bytes());
+    let hash = Sha256::digest(&hash_input);
+
+    let mut address = [0u8; 32];
+    address[8] = 5; // wasm VM type
+    address[10..30].copy_from_slice(&hash[10..30]);
+    address[30..].copy_from_slice(&creator.as_bytes()[30..]);
+    VMAddress::from(address)
+}
+
+fn tx_hash(tx: &Transaction) -> String {
+    hex_sha256(&serde_json::to_vec(tx).unwrap())
+}
+
+fn tx_hash_h256(tx_hash: &str) -> H256 {
+    let mut bytes = [0u8; 32];
+    bytes.copy_from_slice(&hex::decode(tx_hash).unwrap());
+    H256::from(bytes)
+}
+
+fn hex_sha256(data: &[u8]) -> String {
+    hex::encode(Sha256::digest(data))
+}
+
+fn unix_timestamp() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
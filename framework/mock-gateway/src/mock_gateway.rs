@@ -0,0 +1,142 @@
+use dharitri_sc_scenario::{dharitri_sc::types::Address as ScAddress, ScenarioWorld};
+use dharitri_sdk::data::{address::Address as SdkAddress, network_config::NetworkConfig};
+use hyper::{
+    service::{make_service_fn, service_fn},
+    Server,
+};
+use std::{
+    convert::Infallible,
+    net::SocketAddr,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+use tokio::task::JoinHandle;
+
+use crate::{handle_request, MockChain, MOCK_CHAIN_ID};
+
+const DEFAULT_ROUND_DURATION: Duration = Duration::from_millis(100);
+
+/// A local gateway, serving the state of a scenario world over HTTP.
+///
+/// A new hyperblock is produced every round, with the transactions received since the previous one,
+/// executed by the debugger. Transactions with nonce gaps wait in the mempool, same as on a real node.
+pub struct MockGateway {
+    world: ScenarioWorld,
+    network_config: NetworkConfig,
+}
+
+impl MockGateway {
+    pub fn new(world: ScenarioWorld) -> Self {
+        MockGateway {
+            world,
+            network_config: default_network_config(),
+        }
+    }
+
+    pub fn chain_id(mut self, chain_id: &str) -> Self {
+        self.network_config.chain_id = chain_id.to_string();
+        self
+    }
+
+    pub fn round_duration(mut self, round_duration: Duration) -> Self {
+        assert!(!round_duration.is_zero(), "round duration cannot be zero");
+        self.network_config.round_duration = round_duration.as_millis() as i64;
+        self
+    }
+
+    /// Starts serving on a random local port. The server stops when the handle is dropped.
+    pub async fn start(self) -> MockGatewayHandle {
+        let round_duration = Duration::from_millis(self.network_config.round_duration as u64);
+        let chain = Arc::new(Mutex::new(MockChain::new(self.world, self.network_config)));
+
+        let service_chain = chain.clone();
+        let make_service = make_service_fn(move |_| {
+            let chain = service_chain.clone();
+            async move {
+                Ok::<_, Infallible>(service_fn(move |request| {
+                    handle_request(chain.clone(), request)
+                }))
+            }
+        });
+        let server = Server::bind(&SocketAddr::from(([127, 0, 0, 1], 0))).serve(make_service);
+        let address = server.local_addr();
+        let server_task = tokio::spawn(async move {
+            server.await.expect("mock gateway server failed");
+        });
+
+        let round_chain = chain.clone();
+        let round_task = tokio::spawn(async move {
+            let mut interval = tokio::time::interval(round_duration);
+            interval.tick().await; // the first tick completes immediately
+            loop {
+                interval.tick().await;
+                round_chain.lock().unwrap().produce_round();
+            }
+        });
+
+        MockGatewayHandle {
+            address,
+            chain,
+            tasks: vec![server_task, round_task],
+        }
+    }
+}
+
+/// A running mock gateway.
+pub struct MockGatewayHandle {
+    address: SocketAddr,
+    chain: Arc<Mutex<MockChain>>,
+    tasks: Vec<JoinHandle<()>>,
+}
+
+impl MockGatewayHandle {
+    /// The proxy URL, to be passed to the interactor.
+    pub fn url(&self) -> String {
+        format!("http://{}", self.address)
+    }
+
+    /// Direct access to the world behind the gateway, e.g. to set up state or to check it.
+    pub fn with_world<F, R>(&self, f: F) -> R
+    where
+        F: FnOnce(&mut ScenarioWorld) -> R,
+    {
+        f(self.chain.lock().unwrap().world())
+    }
+
+    /// The next transaction received from the sender with this nonce gets rejected with the given reason,
+    /// e.g. to check how clients recover from rejections.
+    pub fn reject_next_tx(&self, sender: &ScAddress, nonce: u64, reason: &str) {
+        self.chain.lock().unwrap().force_rejection(
+            SdkAddress::from_bytes(*sender.as_array()),
+            nonce,
+            reason.to_string(),
+        );
+    }
+}
+
+impl Drop for MockGatewayHandle {
+    fn drop(&mut self) {
+        for task in &self.tasks {
+            task.abort();
+        }
+    }
+}
+
+fn default_network_config() -> NetworkConfig {
+    NetworkConfig {
+        chain_id: MOCK_CHAIN_ID.to_string(),
+        denomination: 18,
+        gas_per_data_byte: 1_500,
+        latest_tag_software_version: "mock".to_string(),
+        meta_consensus_group_size: 1,
+        min_gas_limit: 50_000,
+        min_gas_price: 1_000_000_000,
+        min_transaction_version: 1,
+        num_metachain_nodes: 1,
+        num_nodes_in_shard: 1,
+        num_shards_without_meta: 1,
+        round_duration: DEFAULT_ROUND_DURATION.as_millis() as i64,
+        shard_consensus_group_size: 1,
+        start_time: 0,
+    }
+}
@@ -0,0 +1,175 @@
+use dharitri_sdk::data::{
+    account::AccountData,
+    address::Address,
+    hyperblock::{HyperBlock, HyperBlockData},
+    network_config::NetworkConfigData,
+    network_status::NetworkStatusData,
+    transaction::{
+        SendTransactionData, SendTransactionsResponseData, Transaction, TransactionInfoData,
+        TransactionStatusData,
+    },
+    vm::{VmValueRequest, VmValuesResponseData},
+};
+use hyper::{body, Body, Method, Request, Response, StatusCode};
+use serde_json::{json, Value};
+use std::{
+    collections::HashMap,
+    convert::Infallible,
+    sync::{Arc, Mutex},
+};
+
+use crate::MockChain;
+
+const CODE_SUCCESSFUL: &str = "successful";
+const CODE_BAD_REQUEST: &str = "bad_request";
+const CODE_NOT_FOUND: &str = "not_found";
+
+type RouteResult = Result<Value, (StatusCode, String)>;
+
+/// Handles one gateway request, replying in the same envelope as the real gateway.
+pub(crate) async fn handle_request(
+    chain: Arc<Mutex<MockChain>>,
+    request: Request<Body>,
+) -> Result<Response<Body>, Infallible> {
+    let method = request.method().clone();
+    let path = request.uri().path().trim_matches('/').to_string();
+    let body = body::to_bytes(request.into_body())
+        .await
+        .map(|bytes| bytes.to_vec())
+        .unwrap_or_default();
+
+    let result = route(&chain, &method, &path, &body);
+    let (status, envelope) = match result {
+        Ok(data) => (
+            StatusCode::OK,
+            json!({ "data": data, "error": "", "code": CODE_SUCCESSFUL }),
+        ),
+        Err((status, error)) => {
+            let code = if status == StatusCode::NOT_FOUND {
+                CODE_NOT_FOUND
+            } else {
+                CODE_BAD_REQUEST
+            };
+            (
+                status,
+                json!({ "data": null, "error": error, "code": code }),
+            )
+        },
+    };
+
+    Ok(Response::builder()
+        .status(status)
+        .header("Content-Type", "application/json")
+        .body(Body::from(envelope.to_string()))
+        .unwrap())
+}
+
+fn route(chain: &Mutex<MockChain>, method: &Method, path: &str, body: &[u8]) -> RouteResult {
+    let segments: Vec<&str> = path.split('/').collect();
+    let mut chain = chain.lock().unwrap();
+
+    match (method, segments.as_slice()) {
+        (&Method::GET, ["network", "config"]) => to_json(NetworkConfigData {
+            config: chain.network_config().clone(),
+        }),
+        (&Method::GET, ["network", "status"]) | (&Method::GET, ["network", "status", _]) => {
+            to_json(NetworkStatusData {
+                status: chain.network_status(),
+            })
+        },
+        (&Method::GET, ["address", bech32]) => {
+            let address = Address::from_bech32_string(bech32)
+                .map_err(|_| bad_request(format!("invalid address: {bech32}")))?;
+            to_json(AccountData {
+                account: chain.account(&address),
+            })
+        },
+        (&Method::POST, ["transaction", "send"]) => {
+            let tx: Transaction = from_json(body)?;
+            let tx_hash = chain
+                .send_transactions(&[tx])
+                .remove(0)
+                .map_err(bad_request)?;
+            to_json(SendTransactionData { tx_hash })
+        },
+        (&Method::POST, ["transaction", "send-multiple"]) => {
+            let txs: Vec<Transaction> = from_json(body)?;
+            let txs_hashes: HashMap<i32, String> = chain
+                .send_transactions(&txs)
+                .into_iter()
+                .enumerate()
+                .filter_map(|(index, result)| Some((index as i32, result.ok()?)))
+                .collect();
+            to_json(SendTransactionsResponseData {
+                num_of_sent_txs: txs_hashes.len() as i32,
+                txs_hashes,
+            })
+        },
+        (&Method::POST, ["transaction", "cost"]) => {
+            let tx: Transaction = from_json(body)?;
+            to_json(chain.transaction_cost(&tx))
+        },
+        (&Method::GET, ["transaction", tx_hash, "status"]) => {
+            let tx = chain
+                .transaction(tx_hash)
+                .ok_or_else(|| tx_not_found(tx_hash))?;
+            to_json(TransactionStatusData {
+                status: tx.status.clone(),
+            })
+        },
+        (&Method::GET, ["transaction", tx_hash]) => {
+            // results are always included, so the `withResults` parameter makes no difference
+            let tx = chain
+                .transaction(tx_hash)
+                .ok_or_else(|| tx_not_found(tx_hash))?;
+            to_json(TransactionInfoData {
+                transaction: tx.clone(),
+            })
+        },
+        (&Method::POST, ["vm-values", "query"]) => {
+            let request: VmValueRequest = from_json(body)?;
+            let data = chain.vm_query(&request).map_err(bad_request)?;
+            to_json(VmValuesResponseData { data })
+        },
+        (&Method::GET, ["hyperblock", "by-nonce", nonce]) => {
+            let nonce: u64 = nonce
+                .parse()
+                .map_err(|_| bad_request(format!("invalid nonce: {nonce}")))?;
+            hyperblock_to_json(chain.hyperblock_by_nonce(nonce))
+        },
+        (&Method::GET, ["hyperblock", "by-hash", hash]) => {
+            hyperblock_to_json(chain.hyperblock_by_hash(hash))
+        },
+        _ => Err((
+            StatusCode::NOT_FOUND,
+            format!("unknown route: {method} /{path}"),
+        )),
+    }
+}
+
+fn hyperblock_to_json(hyperblock: Option<&HyperBlock>) -> RouteResult {
+    let hyperblock =
+        hyperblock.ok_or_else(|| (StatusCode::NOT_FOUND, "hyperblock not found".to_string()))?;
+    to_json(HyperBlockData {
+        hyperblock: hyperblock.clone(),
+    })
+}
+
+fn tx_not_found(tx_hash: &str) -> (StatusCode, String) {
+    (
+        StatusCode::NOT_FOUND,
+        format!("transaction not found: {tx_hash}"),
+    )
+}
+
+fn bad_request(error: String) -> (StatusCode, String) {
+    (StatusCode::BAD_REQUEST, error)
+}
+
+fn from_json<T: serde::de::DeserializeOwned>(body: &[u8]) -> Result<T, (StatusCode, String)> {
+    serde_json::from_slice(body).map_err(|err| bad_request(format!("invalid request body: {err}")))
+}
+
+fn to_json<T: serde::Serialize>(data: T) -> RouteResult {
+    Ok(serde_json::to_value(data).unwrap())
+}
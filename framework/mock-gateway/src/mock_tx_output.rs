@@ -0,0 +1,215 @@
+use dharitri_sc_scenario::dharitri_chain_vm::{tx_mock::TxResult, types::VMAddress};
+use dharitri_sdk::data::{
+    address::Address,
+    transaction::{ApiLogs, ApiSmartContractResult, Events, Transaction, TransactionOnNetwork},
+    vm::{CallType, LogEntryApi, VMOutputApi},
+};
+use std::collections::HashMap;
+
+use crate::mock_chain::MOCK_SHARD_ID;
+
+const LOG_IDENTIFIER_SC_DEPLOY: &str = "SCDeploy";
+const LOG_IDENTIFIER_SIGNAL_ERROR: &str = "signalError";
+const LOG_IDENTIFIER_COMPLETED_TX: &str = "completedTxEvent";
+
+const TX_STATUS_PENDING: &str = "pending";
+const TX_STATUS_SUCCESS: &str = "success";
+const TX_STATUS_FAIL: &str = "fail";
+
+/// What the debugger did with a transaction.
+pub(crate) struct MockTxExecution {
+    pub tx_result: TxResult,
+    /// Only set for successful deploys.
+    pub new_address: Option<VMAddress>,
+    /// Smart contract calls and deploys return their results in a smart contract result.
+    pub produces_scr: bool,
+}
+
+/// Converts the execution to the format of the `transaction/{hash}?withResults=true` endpoint.
+///
+/// The block-related fields are filled in by the chain, once the transaction is included in a hyperblock.
+pub(crate) fn tx_on_network(
+    tx: &Transaction,
+    tx_hash: &str,
+    execution: &MockTxExecution,
+) -> TransactionOnNetwork {
+    let tx_result = &execution.tx_result;
+    let is_success = tx_result.result_status == 0;
+
+    let mut events: Vec<Events> = tx_result
+        .result_logs
+        .iter()
+        .map(|log| Events {
+            address: vm_address_to_sdk(&log.address),
+            identifier: log.endpoint.as_str().to_string(),
+            topics: Some(log.topics.iter().map(base64::encode).collect()),
+            data: Some(base64::encode(&log.data)),
+        })
+        .collect();
+
+    let mut smart_contract_results = Vec::new();
+    if is_success {
+        if let Some(new_address) = &execution.new_address {
+            events.push(Events {
+                address: vm_address_to_sdk(new_address),
+                identifier: LOG_IDENTIFIER_SC_DEPLOY.to_string(),
+                topics: Some(vec![
+                    base64::encode(new_address.as_bytes()),
+                    base64::encode(tx.sender.to_bytes()),
+                ]),
+                data: None,
+            });
+        }
+
+        if execution.produces_scr {
+            let data = tx_result
+                .result_values
+                .iter()
+                .fold("@6f6b".to_string(), |data, value| {
+                    format!("{data}@{}", hex::encode(value))
+                });
+            smart_contract_results.push(ApiSmartContractResult {
+                hash: format!("{tx_hash}00"),
+                nonce: tx.nonce + 1,
+                value: 0,
+                receiver: tx.sender.clone(),
+                sender: execution
+                    .new_address
+                    .as_ref()
+                    .map(vm_address_to_sdk)
+                    .unwrap_or_else(|| tx.receiver.clone()),
+                data,
+                prev_tx_hash: tx_hash.to_string(),
+                original_tx_hash: tx_hash.to_string(),
+                gas_limit: 0,
+                gas_price: tx.gas_price,
+                call_type: CallType::DirectCall,
+                relayer_address: None,
+                relayed_value: None,
+                code: None,
+                code_metadata: None,
+                return_message: None,
+                original_sender: None,
+            });
+            events.push(Events {
+                address: tx.sender.clone(),
+                identifier: LOG_IDENTIFIER_COMPLETED_TX.to_string(),
+                topics: Some(vec![base64::encode(hex::decode(tx_hash).unwrap())]),
+                data: None,
+            });
+        }
+    } else {
+        events.push(Events {
+            address: tx.sender.clone(),
+            identifier: LOG_IDENTIFIER_SIGNAL_ERROR.to_string(),
+            topics: Some(vec![
+                base64::encode(tx.sender.to_bytes()),
+                base64::encode(&tx_result.result_message),
+            ]),
+            data: None,
+        });
+    }
+
+    TransactionOnNetwork {
+        kind: "normal".to_string(),
+        hash: Some(tx_hash.to_string()),
+        nonce: tx.nonce,
+        round: 0,
+        epoch: 0,
+        value: tx.value.clone(),
+        receiver: tx.receiver.clone(),
+        sender: tx.sender.clone(),
+        gas_price: tx.gas_price,
+        gas_limit: tx.gas_limit,
+        signature: tx.signature.clone().unwrap_or_default(),
+        source_shard: MOCK_SHARD_ID,
+        destination_shard: MOCK_SHARD_ID,
+        block_nonce: 0,
+        block_hash: String::new(),
+        notarized_at_source_in_meta_nonce: 0,
+        notarized_at_source_in_meta_hash: String::new(),
+        notarized_at_destination_in_meta_nonce: 0,
+        notarized_at_destination_in_meta_hash: String::new(),
+        miniblock_type: "TxBlock".to_string(),
+        miniblock_hash: String::new(),
+        timestamp: 0,
+        data: tx.data.clone(),
+        status: if is_success {
+            TX_STATUS_SUCCESS
+        } else {
+            TX_STATUS_FAIL
+        }
+        .to_string(),
+        hyperblock_nonce: 0,
+        hyperblock_hash: String::new(),
+        smart_contract_results: Some(smart_contract_results),
+        logs: Some(ApiLogs {
+            address: tx.receiver.clone(),
+            events,
+        }),
+    }
+}
+
+/// A transaction that was accepted, but not yet executed.
+pub(crate) fn pending_tx_on_network(tx: &Transaction, tx_hash: &str) -> TransactionOnNetwork {
+    let mut tx_on_network = tx_on_network(
+        tx,
+        tx_hash,
+        &MockTxExecution {
+            tx_result: TxResult::empty(),
+            new_address: None,
+            produces_scr: false,
+        },
+    );
+    tx_on_network.status = TX_STATUS_PENDING.to_string();
+    tx_on_network.smart_contract_results = None;
+    tx_on_network.logs = None;
+    tx_on_network
+}
+
+/// Converts a query result to the format of the `vm-values/query` endpoint.
+pub(crate) fn vm_output(tx_result: &TxResult) -> VMOutputApi {
+    VMOutputApi {
+        return_data: tx_result.result_values.iter().map(base64::encode).collect(),
+        return_code: return_code_name(tx_result.result_status).to_string(),
+        return_message: tx_result.result_message.clone(),
+        gas_remaining: 0,
+        gas_refund: tx_result.gas_refund,
+        output_accounts: HashMap::new(),
+        deleted_accounts: None,
+        touched_accounts: None,
+        logs: Some(
+            tx_result
+                .result_logs
+                .iter()
+                .map(|log| LogEntryApi {
+                    identifier: log.endpoint.as_str().to_string(),
+                    address: vm_address_to_sdk(&log.address),
+                    topics: log.topics.iter().map(base64::encode).collect(),
+                    data: base64::encode(&log.data),
+                })
+                .collect(),
+        ),
+    }
+}
+
+/// The names of the VM return codes, as the gateway reports them.
+fn return_code_name(result_status: u64) -> &'static str {
+    match result_status {
+        0 => "ok",
+        1 => "function not found",
+        2 => "wrong signature",
+        3 => "contract not found",
+        4 => "user error",
+        5 => "out of gas",
+        6 => "account collision",
+        7 => "out of funds",
+        8 => "call stack overflow",
+        9 => "contract invalid",
+        _ => "execution failed",
+    }
+}
+
+fn vm_address_to_sdk(address: &VMAddress) -> Address {
+    Address::from_bytes(*address.as_array())
+}
@@ -0,0 +1,87 @@
+use dharitri_sc_mock_gateway::MockGateway;
+use dharitri_sc_scenario::{
+    dharitri_sc::types::Address, scenario_model::*, test_wallets, ScenarioWorld,
+};
+use dharitri_sc_snippets::{Interactor, TxCompletionConfig};
+use std::time::Duration;
+
+const ADDER_PATH_EXPR: &str = "file:output/adder.wasm";
+
+fn world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new();
+    blockchain.set_current_dir_from_workspace("contracts/examples/adder");
+
+    blockchain.register_contract(ADDER_PATH_EXPR, adder::ContractBuilder);
+    blockchain
+}
+
+#[tokio::test]
+async fn mock_gateway_adder() {
+    let mut world = world();
+    let adder_code = world.code_expression(ADDER_PATH_EXPR);
+
+    let owner_wallet = test_wallets::alice();
+    let owner_address = Address::from(owner_wallet.address().to_bytes());
+    world.set_state_step(SetStateStep::new().put_account(
+        &owner_address,
+        Account::new().nonce(0).balance("1,000,000,000,000,000,000"),
+    ));
+
+    let gateway = MockGateway::new(world)
+        .round_duration(Duration::from_millis(50))
+        .start()
+        .await;
+
+    let mut interactor = Interactor::new(&gateway.url()).await.with_tx_completion(
+        TxCompletionConfig::new()
+            .polling_interval(Duration::from_millis(10))
+            .confirmations(1),
+    );
+    let owner = interactor.register_wallet(owner_wallet);
+
+    let mut deploy_step = ScDeployStep::new()
+        .from(&owner)
+        .code(adder_code)
+        .argument("5")
+        .expect(TxExpect::ok().no_result());
+    interactor.sc_deploy(&mut deploy_step).await;
+    let adder_address = deploy_step
+        .response()
+        .new_deployed_address
+        .clone()
+        .expect("deploy failed");
+
+    let mut call_step = ScCallStep::new()
+        .from(&owner)
+        .to(&adder_address)
+        .function("add")
+        .argument("3")
+        .expect(TxExpect::ok().no_result());
+    interactor.sc_call(&mut call_step).await;
+    assert!(call_step.response().is_success());
+
+    let mut query_step = ScQueryStep::new()
+        .to(&adder_address)
+        .function("getSum")
+        .expect(TxExpect::ok().result("8"));
+    interactor.sc_query(&mut query_step).await;
+
+    let mut failing_call_step = ScCallStep::new()
+        .from(&owner)
+        .to(&adder_address)
+        .function("unknownEndpoint")
+        .expect(TxExpect::user_error("str:invalid function (not found)"));
+    interactor.sc_call(&mut failing_call_step).await;
+    assert!(!failing_call_step.response().is_success());
+
+    gateway.with_world(|world| {
+        world.check_state_step(
+            CheckStateStep::new()
+                .put_account(&owner_address, CheckAccount::new().nonce("3"))
+                .put_account(
+                    &adder_address,
+                    CheckAccount::new().check_storage("str:sum", "8"),
+                ),
+        );
+    });
+}
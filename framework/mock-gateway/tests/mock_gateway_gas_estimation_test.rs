@@ -0,0 +1,249 @@
+use dharitri_sc_mock_gateway::{MockGateway, MockGatewayHandle};
+use dharitri_sc_scenario::{
+    dharitri_sc::types::Address, scenario_model::*, test_wallets, ScenarioWorld,
+};
+use dharitri_sc_snippets::{GasEstimationConfig, Interactor, StepBuffer, TxCompletionConfig};
+use dharitri_sdk::data::transaction::{Transaction, GUARDED_TX_EXTRA_GAS_LIMIT};
+use futures::FutureExt;
+use std::{panic::AssertUnwindSafe, time::Duration};
+
+const ADDER_PATH_EXPR: &str = "file:output/adder.wasm";
+const OWNER_BALANCE: &str = "1,000,000,000,000,000,000";
+
+struct GasEstimationTestState {
+    gateway: MockGatewayHandle,
+    interactor: Interactor,
+    owner_address: Address,
+    adder_address: Address,
+}
+
+/// Deploys the adder with nonce 0, with auto gas doubling the simulated gas.
+async fn deploy_adder() -> GasEstimationTestState {
+    let mut world = ScenarioWorld::new();
+    world.set_current_dir_from_workspace("contracts/examples/adder");
+    world.register_contract(ADDER_PATH_EXPR, adder::ContractBuilder);
+    let adder_code = world.code_expression(ADDER_PATH_EXPR);
+
+    let owner_wallet = test_wallets::alice();
+    let owner_address = Address::from(owner_wallet.address().to_bytes());
+    world.set_state_step(SetStateStep::new().put_account(
+        &owner_address,
+        Account::new().nonce(0).balance(OWNER_BALANCE),
+    ));
+
+    let gateway = MockGateway::new(world)
+        .round_duration(Duration::from_millis(50))
+        .start()
+        .await;
+
+    let mut interactor = Interactor::new(&gateway.url())
+        .await
+        .with_tx_completion(
+            TxCompletionConfig::new()
+                .polling_interval(Duration::from_millis(10))
+                .timeout(Duration::from_secs(10)),
+        )
+        .with_gas_estimation(GasEstimationConfig::new().gas_multiplier(2.0));
+    interactor.register_wallet(owner_wallet);
+
+    let mut deploy_step = ScDeployStep::new()
+        .from(&owner_address)
+        .code(adder_code)
+        .argument("5")
+        .expect(TxExpect::ok().no_result());
+    interactor.sc_deploy(&mut deploy_step).await;
+    let adder_address = deploy_step
+        .response()
+        .new_deployed_address
+        .clone()
+        .expect("deploy failed");
+
+    GasEstimationTestState {
+        gateway,
+        interactor,
+        owner_address,
+        adder_address,
+    }
+}
+
+impl GasEstimationTestState {
+    fn add_tx(&self) -> Transaction {
+        Transaction {
+            nonce: 0,
+            value: "0".to_string(),
+            receiver: test_address(&self.adder_address),
+            sender: test_address(&self.owner_address),
+            gas_price: self.interactor.network_config.min_gas_price,
+            gas_limit: 0,
+            data: Some(base64::encode("add@01")),
+            signature: None,
+            chain_id: self.interactor.network_config.chain_id.clone(),
+            version: 1,
+            options: 0,
+            guardian: None,
+            guardian_signature: None,
+            relayer: None,
+            relayer_signature: None,
+        }
+    }
+
+    fn check_state(&self, owner_nonce: &str, sum: &str) {
+        self.gateway.with_world(|world| {
+            world.check_state_step(
+                CheckStateStep::new()
+                    .put_account(&self.owner_address, CheckAccount::new().nonce(owner_nonce))
+                    .put_account(
+                        &self.adder_address,
+                        CheckAccount::new().check_storage("str:sum", sum),
+                    ),
+            );
+        });
+    }
+}
+
+fn test_address(address: &Address) -> dharitri_sdk::data::address::Address {
+    dharitri_sdk::data::address::Address::from_bytes(*address.as_array())
+}
+
+#[tokio::test]
+async fn mock_gateway_transaction_cost() {
+    let state = deploy_adder().await;
+    let network_config = &state.interactor.network_config;
+
+    let cost = state
+        .interactor
+        .proxy
+        .request_transaction_cost(&state.add_tx())
+        .await
+        .unwrap();
+    assert!(cost.return_message.is_empty());
+    // "add@01" is 6 bytes long, its base64 encoding is 8
+    assert_eq!(
+        cost.tx_gas_units,
+        network_config.min_gas_limit + 6 * network_config.gas_per_data_byte
+    );
+
+    let invalid_data_tx = Transaction {
+        data: Some("not base64!".to_string()),
+        ..state.add_tx()
+    };
+    let cost = state
+        .interactor
+        .proxy
+        .request_transaction_cost(&invalid_data_tx)
+        .await
+        .unwrap();
+    assert_eq!(cost.return_message, "invalid data field");
+}
+
+#[tokio::test]
+async fn mock_gateway_gas_estimation_guarded_tx_signed_again() {
+    let mut state = deploy_adder().await;
+    let owner_address = state.owner_address.clone();
+    state
+        .interactor
+        .register_guardian_wallet(&owner_address, test_wallets::bob());
+
+    let simulated_gas = state
+        .interactor
+        .proxy
+        .request_transaction_cost(&state.add_tx())
+        .await
+        .unwrap()
+        .tx_gas_units;
+
+    // another client used nonce 1, so the transaction gets rejected and signed again with nonce 2
+    state.gateway.with_world(|world| {
+        world.set_state_step(SetStateStep::new().put_account(
+            &owner_address,
+            Account::new().nonce(2).balance(OWNER_BALANCE),
+        ));
+    });
+    let add_tx = state.add_tx();
+    let results = state.interactor.send_transactions(vec![add_tx]).await;
+
+    // the extra gas of the guardian is not lost when signing again
+    assert_eq!(results[0].nonce, 2);
+    assert_eq!(
+        results[0].gas_limit,
+        2 * simulated_gas + GUARDED_TX_EXTRA_GAS_LIMIT
+    );
+    state.check_state("3", "6");
+}
+
+#[tokio::test]
+async fn mock_gateway_gas_estimation_failure_reserves_no_nonce() {
+    let mut state = deploy_adder().await;
+
+    let mut failing_call_step = ScCallStep::new()
+        .from(&state.owner_address)
+        .to(&state.adder_address)
+        .function("unknownEndpoint");
+    let result = AssertUnwindSafe(state.interactor.sc_call(&mut failing_call_step))
+        .catch_unwind()
+        .await;
+    let panic_message = *result.unwrap_err().downcast::<String>().unwrap();
+    assert!(panic_message.contains("transaction simulation failed"));
+
+    let add_tx = state.add_tx();
+    let failing_tx = Transaction {
+        data: Some(base64::encode("unknownEndpoint")),
+        ..state.add_tx()
+    };
+    let result = AssertUnwindSafe(
+        state
+            .interactor
+            .send_transactions(vec![add_tx.clone(), failing_tx]),
+    )
+    .catch_unwind()
+    .await;
+    assert!(result.is_err());
+
+    // nothing was broadcast and no nonce gap was left
+    let results = state.interactor.send_transactions(vec![add_tx]).await;
+    assert_eq!(results[0].nonce, 1);
+    state.check_state("2", "6");
+}
+
+#[tokio::test]
+async fn mock_gateway_gas_estimation_dependent_batch() {
+    let mut state = deploy_adder().await;
+    let owner_address = state.owner_address.clone();
+    let second_adder_address = AddressValue::from("sc:second-adder").to_address();
+    let adder_code = state.gateway.with_world(|world| {
+        world.set_state_step(SetStateStep::new().new_address(
+            &owner_address,
+            1,
+            &second_adder_address,
+        ));
+        world.code_expression(ADDER_PATH_EXPR)
+    });
+
+    let mut deploy_step = ScDeployStep::new()
+        .from(&owner_address)
+        .code(adder_code)
+        .argument("5")
+        .expect(TxExpect::ok().no_result());
+    // the contract is only deployed in the same batch, so this one fails in simulation
+    let mut call_step = ScCallStep::new()
+        .from(&owner_address)
+        .to(&second_adder_address)
+        .function("add")
+        .argument("1")
+        .gas_limit("7,000,000")
+        .expect(TxExpect::ok().no_result());
+    state
+        .interactor
+        .multi_sc_exec(StepBuffer {
+            refs: vec![&mut deploy_step, &mut call_step],
+        })
+        .await;
+
+    // the call kept its given gas limit and was broadcast instead of panicking
+    state.gateway.with_world(|world| {
+        world.check_state_step(CheckStateStep::new().put_account(
+            &second_adder_address,
+            CheckAccount::new().check_storage("str:sum", "6"),
+        ));
+    });
+}
@@ -0,0 +1,157 @@
+use dharitri_sc_mock_gateway::{MockGateway, MockGatewayHandle};
+use dharitri_sc_scenario::{
+    dharitri_sc::types::Address, scenario_model::*, test_wallets, ScenarioWorld,
+};
+use dharitri_sc_snippets::{Interactor, StepBuffer, TxCompletionConfig};
+use std::time::Duration;
+
+const ADDER_PATH_EXPR: &str = "file:output/adder.wasm";
+const OWNER_BALANCE: &str = "1,000,000,000,000,000,000";
+
+struct NonceTestState {
+    gateway: MockGatewayHandle,
+    interactor: Interactor,
+    owner_address: Address,
+    adder_address: Address,
+}
+
+/// Deploys the adder with nonce 0.
+async fn deploy_adder() -> NonceTestState {
+    let mut world = ScenarioWorld::new();
+    world.set_current_dir_from_workspace("contracts/examples/adder");
+    world.register_contract(ADDER_PATH_EXPR, adder::ContractBuilder);
+    let adder_code = world.code_expression(ADDER_PATH_EXPR);
+
+    let owner_wallet = test_wallets::alice();
+    let owner_address = Address::from(owner_wallet.address().to_bytes());
+    world.set_state_step(SetStateStep::new().put_account(
+        &owner_address,
+        Account::new().nonce(0).balance(OWNER_BALANCE),
+    ));
+
+    // long enough rounds, so that the batches are still in the mempool when the rejections are handled
+    let gateway = MockGateway::new(world)
+        .round_duration(Duration::from_millis(200))
+        .start()
+        .await;
+
+    let mut interactor = Interactor::new(&gateway.url()).await.with_tx_completion(
+        TxCompletionConfig::new()
+            .polling_interval(Duration::from_millis(10))
+            .timeout(Duration::from_secs(10)),
+    );
+    interactor.register_wallet(owner_wallet);
+
+    let mut deploy_step = ScDeployStep::new()
+        .from(&owner_address)
+        .code(adder_code)
+        .argument("5")
+        .expect(TxExpect::ok().no_result());
+    interactor.sc_deploy(&mut deploy_step).await;
+    let adder_address = deploy_step
+        .response()
+        .new_deployed_address
+        .clone()
+        .expect("deploy failed");
+
+    NonceTestState {
+        gateway,
+        interactor,
+        owner_address,
+        adder_address,
+    }
+}
+
+impl NonceTestState {
+    async fn add_batch(&mut self, num_calls: usize) {
+        let mut call_steps: Vec<ScCallStep> = (0..num_calls)
+            .map(|_| {
+                ScCallStep::new()
+                    .from(&self.owner_address)
+                    .to(&self.adder_address)
+                    .function("add")
+                    .argument("1")
+                    .expect(TxExpect::ok().no_result())
+            })
+            .collect();
+        self.interactor
+            .multi_sc_exec(StepBuffer::from_sc_call_vec(&mut call_steps))
+            .await;
+        for call_step in &call_steps {
+            assert!(call_step.response().is_success());
+        }
+    }
+
+    fn check_state(&self, owner_nonce: &str, sum: &str) {
+        self.gateway.with_world(|world| {
+            world.check_state_step(
+                CheckStateStep::new()
+                    .put_account(&self.owner_address, CheckAccount::new().nonce(owner_nonce))
+                    .put_account(
+                        &self.adder_address,
+                        CheckAccount::new().check_storage("str:sum", sum),
+                    ),
+            );
+        });
+    }
+}
+
+#[tokio::test]
+async fn mock_gateway_batch_rejected_in_the_middle() {
+    let mut state = deploy_adder().await;
+
+    // rejected both in the batch and when sent again on its own,
+    // while the transactions around it are waiting in the mempool
+    for _ in 0..2 {
+        state
+            .gateway
+            .reject_next_tx(&state.owner_address, 3, "higher nonce in transaction");
+    }
+    state.add_batch(5).await;
+
+    // no nonce was used twice and no nonce gap was left
+    state.check_state("6", "10");
+}
+
+#[tokio::test]
+async fn mock_gateway_batch_nonce_used_elsewhere() {
+    let mut state = deploy_adder().await;
+
+    // another client used nonces 1 and 2, unknown to the interactor
+    let owner_address = state.owner_address.clone();
+    state.gateway.with_world(|world| {
+        world.set_state_step(SetStateStep::new().put_account(
+            &owner_address,
+            Account::new().nonce(3).balance(OWNER_BALANCE),
+        ));
+    });
+
+    // nonces 1 and 2 are rejected, they get signed again with nonces 4 and 5, after the one in flight
+    state.add_batch(3).await;
+
+    state.check_state("6", "8");
+}
+
+#[tokio::test]
+async fn mock_gateway_abandoned_nonce_gap() {
+    let mut state = deploy_adder().await;
+
+    // nonce 1 was reserved, but its transaction was never sent
+    state
+        .interactor
+        .sender_map
+        .get_mut(&state.owner_address)
+        .unwrap()
+        .current_nonce = Some(2);
+    // rejected both in the batch and when sent again on its own
+    for _ in 0..2 {
+        state
+            .gateway
+            .reject_next_tx(&state.owner_address, 2, "nonce too high");
+    }
+
+    // nothing is in flight before nonce 2, so the call gets signed again with nonce 1
+    state.add_batch(1).await;
+
+    state.check_state("2", "6");
+}
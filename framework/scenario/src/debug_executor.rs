@@ -1,5 +1,6 @@
 mod catch_tx_panic;
 mod contract_container;
+mod contract_coverage;
 mod contract_map;
 mod static_var_stack;
 mod tx_static_vars;
@@ -8,6 +9,9 @@ pub use catch_tx_panic::catch_tx_panic;
 pub use contract_container::{
     contract_instance_wrapped_execution, ContractContainer, ContractContainerRef,
 };
+pub use contract_coverage::{
+    ContractCoverage, ContractCoverageReport, CoverageItem, CoverageItemKind,
+};
 pub use contract_map::{ContractMap, ContractMapRef};
 pub use static_var_stack::{StaticVarData, StaticVarStack};
 pub use tx_static_vars::TxStaticVars;
@@ -1,17 +1,20 @@
 use dharitri_chain_vm::tx_mock::{TxContextRef, TxFunctionName, TxPanic};
 use dharitri_chain_vm_executor::{BreakpointValue, ExecutorError, Instance, MemLength, MemPtr};
-use dharitri_sc::contract_base::CallableContract;
-use std::sync::Arc;
+use dharitri_sc::{abi::ContractAbi, contract_base::CallableContract};
+use std::sync::{Arc, Mutex};
 
-use super::{catch_tx_panic, StaticVarStack};
+use super::{catch_tx_panic, ContractCoverage, ContractCoverageReport, StaticVarStack};
 
 /// Contains a reference to a contract implementation.
 ///
 /// It can optionally also contain an allowed endpoint whitelist, to simulate multi-contract.
+///
+/// Once coverage is started, it also records which of the ABI items get exercised.
 pub struct ContractContainer {
     callable: Box<dyn CallableContract>,
     function_whitelist: Option<Vec<String>>,
     pub panic_message: bool,
+    coverage: Mutex<Option<ContractCoverage>>,
 }
 
 impl ContractContainer {
@@ -24,6 +27,7 @@ impl ContractContainer {
             callable,
             function_whitelist,
             panic_message,
+            coverage: Mutex::new(None),
         }
     }
 
@@ -44,6 +48,41 @@ impl ContractContainer {
             false
         }
     }
+
+    pub fn start_coverage(&self, abi: ContractAbi) {
+        *self.coverage.lock().unwrap() = Some(ContractCoverage::new(abi));
+    }
+
+    pub fn coverage_report(&self) -> Option<ContractCoverageReport> {
+        self.coverage
+            .lock()
+            .unwrap()
+            .as_ref()
+            .map(ContractCoverage::report)
+    }
+
+    fn record_call_coverage(&self, function_name: &TxFunctionName) {
+        if let Some(coverage) = self.coverage.lock().unwrap().as_mut() {
+            if self.validate_function_name(function_name) {
+                coverage.record_function_call(function_name.as_str());
+            }
+        }
+    }
+
+    /// Only the events of successful calls can be found, failed calls lose their logs.
+    pub(crate) fn record_events_coverage(&self) {
+        if let Some(coverage) = self.coverage.lock().unwrap().as_mut() {
+            let tx_context = TxContextRef::new_from_static();
+            let address = &tx_context.input_ref().to;
+            for log in &tx_context.result_lock().result_logs {
+                if &log.address == address {
+                    if let Some(identifier) = log.topics.first() {
+                        coverage.record_event(identifier);
+                    }
+                }
+            }
+        }
+    }
 }
 
 /// Prepares the StaticVarStack and catches panics.
@@ -75,6 +114,7 @@ impl ContractContainerRef {
 impl Instance for ContractContainerRef {
     fn call(&self, func_name: &str) -> Result<(), String> {
         let tx_func_name = TxFunctionName::from(func_name);
+        self.0.record_call_coverage(&tx_func_name);
 
         contract_instance_wrapped_execution(self.0.panic_message, || {
             let call_successful = self.0.call(&tx_func_name);
@@ -84,6 +124,7 @@ impl Instance for ContractContainerRef {
                 Err(TxPanic::new(1, "invalid function (not found)"))
             }
         });
+        self.0.record_events_coverage();
 
         Ok(())
     }
@@ -0,0 +1,172 @@
+use dharitri_sc::abi::{ContractAbi, EndpointMutabilityAbi};
+use serde_json::{json, Value};
+use std::{collections::BTreeSet, fmt::Write};
+
+/// The name under which the legacy async callback gets called.
+const CALLBACK_FUNCTION_NAME: &str = "callBack";
+
+/// Keeps track of which items of a contract ABI got exercised by the executed transactions.
+#[derive(Debug)]
+pub struct ContractCoverage {
+    abi: ContractAbi,
+    hit_functions: BTreeSet<String>,
+    hit_events: BTreeSet<Vec<u8>>,
+}
+
+impl ContractCoverage {
+    pub fn new(abi: ContractAbi) -> Self {
+        ContractCoverage {
+            abi,
+            hit_functions: BTreeSet::new(),
+            hit_events: BTreeSet::new(),
+        }
+    }
+
+    /// Functions are counted as soon as they are called, even if they fail afterwards.
+    pub fn record_function_call(&mut self, function_name: &str) {
+        self.hit_functions.insert(function_name.to_string());
+    }
+
+    /// Events are identified by their first topic.
+    pub fn record_event(&mut self, identifier: &[u8]) {
+        self.hit_events.insert(identifier.to_vec());
+    }
+
+    pub fn report(&self) -> ContractCoverageReport {
+        let mut items = Vec::new();
+        for constructor in &self.abi.constructors {
+            items.push(self.function_item(CoverageItemKind::Constructor, constructor.name));
+        }
+        for endpoint in &self.abi.endpoints {
+            let kind = match endpoint.mutability {
+                EndpointMutabilityAbi::Mutable => CoverageItemKind::Endpoint,
+                EndpointMutabilityAbi::Readonly | EndpointMutabilityAbi::Pure => {
+                    CoverageItemKind::View
+                },
+            };
+            items.push(self.function_item(kind, endpoint.name));
+        }
+        for promise_callback in &self.abi.promise_callbacks {
+            items
+                .push(self.function_item(CoverageItemKind::PromiseCallback, promise_callback.name));
+        }
+        if self.abi.has_callback {
+            items.push(self.function_item(CoverageItemKind::Callback, CALLBACK_FUNCTION_NAME));
+        }
+        for event in &self.abi.events {
+            items.push(CoverageItem {
+                kind: CoverageItemKind::Event,
+                name: event.identifier.to_string(),
+                hit: self.hit_events.contains(event.identifier.as_bytes()),
+            });
+        }
+
+        ContractCoverageReport {
+            contract_name: self.abi.get_crate_name().to_string(),
+            items,
+        }
+    }
+
+    fn function_item(&self, kind: CoverageItemKind, name: &str) -> CoverageItem {
+        CoverageItem {
+            kind,
+            name: name.to_string(),
+            hit: self.hit_functions.contains(name),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CoverageItemKind {
+    Constructor,
+    Endpoint,
+    View,
+    PromiseCallback,
+    Callback,
+    Event,
+}
+
+impl CoverageItemKind {
+    pub fn name(&self) -> &'static str {
+        match self {
+            CoverageItemKind::Constructor => "constructor",
+            CoverageItemKind::Endpoint => "endpoint",
+            CoverageItemKind::View => "view",
+            CoverageItemKind::PromiseCallback => "promiseCallback",
+            CoverageItemKind::Callback => "callback",
+            CoverageItemKind::Event => "event",
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CoverageItem {
+    pub kind: CoverageItemKind,
+    pub name: String,
+    pub hit: bool,
+}
+
+/// Lists all the ABI items of a contract, and whether or not they were exercised.
+///
+/// Endpoints only exercised from whitebox closures show up as not exercised,
+/// only the events emitted there are counted.
+#[derive(Clone, Debug)]
+pub struct ContractCoverageReport {
+    pub contract_name: String,
+    pub items: Vec<CoverageItem>,
+}
+
+impl ContractCoverageReport {
+    pub fn missed_items(&self) -> impl Iterator<Item = &CoverageItem> {
+        self.items.iter().filter(|item| !item.hit)
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.items.iter().all(|item| item.hit)
+    }
+
+    pub fn to_text(&self) -> String {
+        let num_hit = self.items.iter().filter(|item| item.hit).count();
+        let mut out = format!(
+            "{}: {}/{} ABI items exercised\n",
+            self.contract_name,
+            num_hit,
+            self.items.len()
+        );
+        for item in self.missed_items() {
+            writeln!(out, "  not exercised: {} {}", item.kind.name(), item.name).unwrap();
+        }
+        out
+    }
+
+    pub fn to_json(&self) -> String {
+        let items: Vec<Value> = self
+            .items
+            .iter()
+            .map(|item| {
+                json!({
+                    "kind": item.kind.name(),
+                    "name": item.name,
+                    "hit": item.hit,
+                })
+            })
+            .collect();
+        let missed: Vec<Value> = self
+            .missed_items()
+            .map(|item| {
+                json!({
+                    "kind": item.kind.name(),
+                    "name": item.name,
+                })
+            })
+            .collect();
+        let report = json!({
+            "contract": self.contract_name,
+            "total": self.items.len(),
+            "exercised": self.items.len() - missed.len(),
+            "items": items,
+            "missed": missed,
+        });
+        serde_json::to_string_pretty(&report).unwrap()
+    }
+}
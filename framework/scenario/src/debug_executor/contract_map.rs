@@ -3,6 +3,7 @@ use super::*;
 use dharitri_chain_vm_executor::{
     CompilationOptions, Executor, ExecutorError, Instance, OpcodeCost,
 };
+use dharitri_sc::abi::ContractAbi;
 use std::{
     collections::HashMap,
     fmt,
@@ -49,6 +50,21 @@ impl ContractMap {
     pub fn contains_contract(&self, contract_bytes: &[u8]) -> bool {
         self.contract_objs.contains_key(contract_bytes)
     }
+
+    pub fn start_coverage(&self, contract_bytes: &[u8], abi: ContractAbi) {
+        self.get_contract(contract_bytes).0.start_coverage(abi);
+    }
+
+    /// Reports for all contracts with coverage started, sorted by contract name.
+    pub fn coverage_reports(&self) -> Vec<ContractCoverageReport> {
+        let mut reports: Vec<ContractCoverageReport> = self
+            .contract_objs
+            .values()
+            .filter_map(|contract_container| contract_container.0.coverage_report())
+            .collect();
+        reports.sort_by(|a, b| a.contract_name.cmp(&b.contract_name));
+        reports
+    }
 }
 
 fn unknown_contract_panic(contract_identifier: &[u8]) -> ! {
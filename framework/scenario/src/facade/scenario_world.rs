@@ -1,19 +1,31 @@
 use dharitri_chain_scenario_format::interpret_trait::InterpretableFrom;
-use dharitri_chain_vm::world_mock::BlockchainState;
+use dharitri_chain_vm::{
+    schedule::GasSchedule,
+    tx_mock::{TxCallTrace, TxTracer},
+    world_mock::{AccountFetcher, BlockchainState, ShardCoordinator},
+};
 
 use crate::{
     api::DebugApi,
-    debug_executor::ContractContainer,
+    debug_executor::{ContractContainer, ContractCoverageReport},
     dharitri_sc::{
         api,
         contract_base::{CallableContractBuilder, ContractAbiProvider},
     },
-    scenario::{run_trace::ScenarioTrace, run_vm::ScenarioVMRunner},
+    scenario::{
+        run_trace::ScenarioTrace,
+        run_vm::{
+            execution_trace_json, execution_trace_tree, ForkAccountFetcher, ScenarioVMRunner,
+        },
+    },
     scenario_format::{interpret_trait::InterpreterContext, value_interpreter::interpret_string},
     scenario_model::BytesValue,
     vm_go_tool::run_vm_go_tool,
 };
-use std::path::{Path, PathBuf};
+use std::{
+    path::{Path, PathBuf},
+    sync::Arc,
+};
 
 use super::debugger_backend::DebuggerBackend;
 
@@ -65,11 +77,16 @@ impl ScenarioWorld {
     /// Runs a scenario file (`.scen.json`) with the configured backend.
     ///
     /// Will crash and produce an output if the test failed for any reason.
-    pub fn run<P: AsRef<Path>>(self, relative_path: P) {
+    pub fn run<P: AsRef<Path>>(mut self, relative_path: P) {
+        self.run_file(relative_path);
+    }
+
+    /// Same as `run`, but keeps the world, so that coverage and other data can be retrieved afterwards.
+    pub fn run_file<P: AsRef<Path>>(&mut self, relative_path: P) {
         let mut absolute_path = self.current_dir.clone();
         absolute_path.push(relative_path);
-        match self.backend {
-            Backend::Debugger(mut debugger) => {
+        match &mut self.backend {
+            Backend::Debugger(debugger) => {
                 debugger.run_scenario_file(&absolute_path);
             },
             Backend::VmGoBackend => {
@@ -106,11 +123,104 @@ impl ScenarioWorld {
             .state
     }
 
+    /// Direct access to the debugger, for tools that drive the blockchain mock themselves, like the mock gateway.
+    ///
+    /// Transactions executed this way do not show up in the scenario trace.
+    pub fn get_mut_vm_runner(&mut self) -> &mut ScenarioVMRunner {
+        &mut self.get_mut_debugger_backend().vm_runner
+    }
+
     pub fn start_trace(&mut self) -> &mut Self {
         self.get_mut_debugger_backend().trace = Some(ScenarioTrace::default());
         self
     }
 
+    /// Enables gas metering in the debugger, with the given gas schedule.
+    ///
+    /// Once enabled, the `gas` and `refund` fields of the tx expectations are also checked.
+    pub fn set_gas_schedule(&mut self, gas_schedule: GasSchedule) -> &mut Self {
+        self.get_mut_debugger_backend()
+            .vm_runner
+            .blockchain_mock
+            .vm
+            .set_gas_schedule(Some(gas_schedule));
+        self
+    }
+
+    /// Enables sharded execution in the debugger: accounts are assigned to shards by address,
+    /// the same way the node does it.
+    ///
+    /// Async calls, promises and transfers to another shard are not executed right away,
+    /// but only when the block nonce advances, via a set state step.
+    /// Their callbacks then need one more block.
+    pub fn set_num_shards(&mut self, num_shards: u32) -> &mut Self {
+        self.get_mut_debugger_backend()
+            .vm_runner
+            .blockchain_mock
+            .vm
+            .set_shard_coordinator(Some(ShardCoordinator::new(num_shards)));
+        self
+    }
+
+    /// Starts recording the call tree of every transaction executed in the debugger,
+    /// with arguments, transfers, logs, storage writes and errors.
+    pub fn start_execution_trace(&mut self) -> &mut Self {
+        self.get_mut_debugger_backend()
+            .vm_runner
+            .blockchain_mock
+            .vm
+            .set_tracer(Some(TxTracer::default()));
+        self
+    }
+
+    /// The call trees of all transactions executed since the execution trace was started.
+    pub fn execution_trace(&self) -> Vec<TxCallTrace> {
+        self.get_debugger_backend()
+            .vm_runner
+            .blockchain_mock
+            .vm
+            .execution_trace()
+    }
+
+    /// The execution trace, pretty-printed as a tree.
+    pub fn execution_trace_tree(&self) -> String {
+        execution_trace_tree(&self.execution_trace())
+    }
+
+    /// Exports the execution trace to a JSON file.
+    pub fn write_execution_trace<P: AsRef<Path>>(&self, file_path: P) {
+        std::fs::write(file_path, execution_trace_json(&self.execution_trace()))
+            .expect("could not write execution trace file");
+    }
+
+    /// Fork mode: accounts missing from the local state are retrieved through the gateway
+    /// the first time they are accessed, and then cached on disk, in the given directory.
+    ///
+    /// Transactions run in the debugger, on top of the forked state.
+    /// Contract code coming from the network still needs to be registered with `register_contract`.
+    pub fn fork_from_gateway<P: AsRef<Path>>(
+        &mut self,
+        gateway_uri: &str,
+        cache_dir: P,
+    ) -> &mut Self {
+        let cache_dir = self.current_dir.join(cache_dir);
+        self.set_account_fetcher(ForkAccountFetcher::new(gateway_uri, cache_dir))
+    }
+
+    /// Fork mode, offline: accounts are only loaded from the cache created by `fork_from_gateway`.
+    pub fn fork_from_cache<P: AsRef<Path>>(&mut self, cache_dir: P) -> &mut Self {
+        let cache_dir = self.current_dir.join(cache_dir);
+        self.set_account_fetcher(ForkAccountFetcher::offline(cache_dir))
+    }
+
+    fn set_account_fetcher<F: AccountFetcher + 'static>(
+        &mut self,
+        account_fetcher: F,
+    ) -> &mut Self {
+        self.get_mut_state().account_fetcher = Some(Arc::new(account_fetcher));
+        self
+    }
+
     /// Tells the tests where the crate lies relative to the workspace.
     /// This ensures that the paths are set correctly, including in debug mode.
     pub fn set_current_dir_from_workspace(&mut self, relative_path: &str) -> &mut Self {
@@ -161,6 +271,49 @@ impl ScenarioWorld {
         )
     }
 
+    /// Starts recording which endpoints, views, callbacks and events of a registered contract get exercised.
+    ///
+    /// Calls made before coverage was started are not counted.
+    ///
+    /// Whitebox calls, queries and deploys only count the events they emit: their closures call
+    /// the contract methods directly, so there is no endpoint to attribute them to.
+    pub fn start_coverage<Abi: ContractAbiProvider>(&mut self, expression: &str) -> &mut Self {
+        let contract_bytes = interpret_string(expression, &self.interpreter_context());
+        self.get_mut_debugger_backend()
+            .vm_runner
+            .contract_map_ref
+            .lock()
+            .start_coverage(&contract_bytes, Abi::abi());
+        self
+    }
+
+    /// One report for each contract with coverage started, sorted by contract name.
+    pub fn coverage_reports(&self) -> Vec<ContractCoverageReport> {
+        self.get_debugger_backend()
+            .vm_runner
+            .contract_map_ref
+            .lock()
+            .coverage_reports()
+    }
+
+    /// Writes a text and a JSON coverage report for each contract, in the given directory.
+    pub fn write_coverage_reports<P: AsRef<Path>>(&self, dir_path: P) {
+        let dir_path = dir_path.as_ref();
+        std::fs::create_dir_all(dir_path).expect("could not create coverage report directory");
+        for report in self.coverage_reports() {
+            std::fs::write(
+                dir_path.join(format!("{}.coverage.txt", report.contract_name)),
+                report.to_text(),
+            )
+            .expect("could not write coverage report file");
+            std::fs::write(
+                dir_path.join(format!("{}.coverage.json", report.contract_name)),
+                report.to_json(),
+            )
+            .expect("could not write coverage report file");
+        }
+    }
+
     #[deprecated(
         since = "0.37.0",
         note = "Got renamed to `register_contract`, but not completely removed, in order to ease test migration. Please replace with `register_contract`."
@@ -1,8 +1,8 @@
-use dharitri_chain_vm::tx_mock::{TxFunctionName, TxResult};
+use dharitri_chain_vm::tx_mock::{TxContextRef, TxFunctionName, TxResult};
 use dharitri_sc::contract_base::{CallableContract, ContractBase};
 
 use crate::{
-    debug_executor::contract_instance_wrapped_execution,
+    debug_executor::{contract_instance_wrapped_execution, ContractMapRef},
     scenario_model::{ScCallStep, ScDeployStep, ScQueryStep},
     DebugApi, ScenarioWorld,
 };
@@ -38,12 +38,14 @@ impl ScenarioWorld {
         let sc_query_step = ScQueryStep::new().to(&whitebox_contract.address_expr);
         let contract_obj = (whitebox_contract.contract_obj_builder)();
         let debugger_backend = self.get_mut_debugger_backend();
+        let contract_map_ref = debugger_backend.vm_runner.contract_map_ref.clone();
         let tx_result = debugger_backend
             .vm_runner
             .perform_sc_query_lambda_and_check(&sc_query_step, || {
                 catch_whitebox_panic(|| {
                     f(contract_obj);
                 });
+                record_whitebox_events_coverage(&contract_map_ref);
             });
         check_result(tx_result);
 
@@ -87,6 +89,7 @@ impl ScenarioWorld {
 
         let contract_obj = (whitebox_contract.contract_obj_builder)();
         let debugger_backend = self.get_mut_debugger_backend();
+        let contract_map_ref = debugger_backend.vm_runner.contract_map_ref.clone();
         let tx_result =
             debugger_backend
                 .vm_runner
@@ -94,6 +97,7 @@ impl ScenarioWorld {
                     catch_whitebox_panic(|| {
                         f(contract_obj);
                     });
+                    record_whitebox_events_coverage(&contract_map_ref);
                 });
         check_result(tx_result);
         self
@@ -128,12 +132,14 @@ impl ScenarioWorld {
     {
         let contract_obj = (whitebox_contract.contract_obj_builder)();
         let debugger_backend = self.get_mut_debugger_backend();
+        let contract_map_ref = debugger_backend.vm_runner.contract_map_ref.clone();
         let (_, tx_result) = debugger_backend
             .vm_runner
             .perform_sc_deploy_lambda_and_check(&sc_deploy_step, || {
                 catch_whitebox_panic(|| {
                     f(contract_obj);
                 });
+                record_whitebox_events_coverage(&contract_map_ref);
             });
         check_result(tx_result);
         self
@@ -149,3 +155,23 @@ where
         Ok(())
     });
 }
+
+/// The whitebox closures call the contract methods directly, not through an endpoint,
+/// so only the events they emit can be recorded.
+fn record_whitebox_events_coverage(contract_map_ref: &ContractMapRef) {
+    let tx_context = TxContextRef::new_from_static();
+    let contract_code = tx_context
+        .tx_cache
+        .with_account(&tx_context.input_ref().to, |account| {
+            account.contract_path.clone()
+        });
+    let contract_map = contract_map_ref.lock();
+    if let Some(contract_code) = contract_code {
+        if contract_map.contains_contract(&contract_code) {
+            contract_map
+                .get_contract(&contract_code)
+                .0
+                .record_events_coverage();
+        }
+    }
+}
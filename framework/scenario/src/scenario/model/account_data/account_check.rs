@@ -2,7 +2,7 @@ use dharitri_sc::codec::{top_encode_to_vec_u8_or_panic, TopEncode};
 
 use crate::{
     scenario::model::{
-        BigUintValue, BytesKey, BytesValue, CheckDct, CheckDctInstances, CheckDctMap,
+        BigUintValue, BytesKey, BytesValue, CheckDct, CheckDctInstance, CheckDctMap,
         CheckDctMapContents, CheckStorage, CheckStorageDetails, CheckValue, U64Value,
     },
     scenario_format::{
@@ -73,30 +73,13 @@ impl CheckAccount {
         let token_id = BytesKey::from(token_id_expr);
         let balance = BigUintValue::from(balance_expr);
 
-        match &mut self.dct {
-            CheckDctMap::Unspecified | CheckDctMap::Star => {
-                let mut new_dct_map = BTreeMap::new();
-                let _ = new_dct_map.insert(token_id, CheckDct::Short(balance));
-
-                let new_check_dct_map = CheckDctMapContents {
-                    contents: new_dct_map,
-                    other_dcts_allowed: true,
-                };
-
-                self.dct = CheckDctMap::Equal(new_check_dct_map);
-            },
-            CheckDctMap::Equal(check_dct_map) => {
-                if check_dct_map.contents.contains_key(&token_id) {
-                    let prev_entry = check_dct_map.contents.get_mut(&token_id).unwrap();
-                    match prev_entry {
-                        CheckDct::Short(prev_balance_check) => *prev_balance_check = balance,
-                        CheckDct::Full(prev_dct_check) => match prev_dct_check.instances {
-                            CheckDctInstances::Star => todo!(),
-                            CheckDctInstances::Equal(_) => todo!(),
-                        },
-                    }
-                }
-            },
+        let check_dct = self.get_check_dct_or_insert(token_id, CheckDct::Short(balance.clone()));
+        if let CheckDct::Short(prev_balance_check) = check_dct {
+            *prev_balance_check = balance;
+        } else {
+            check_dct
+                .get_or_insert_instance_check(U64Value::from(0u64))
+                .balance = CheckValue::Equal(balance);
         }
 
         self
@@ -116,38 +99,92 @@ impl CheckAccount {
         T: TopEncode,
     {
         let token_id = BytesKey::from(token_id_expr);
+        let attributes = attributes_expr
+            .map(|attributes_expr| top_encode_to_vec_u8_or_panic(&attributes_expr))
+            .unwrap_or_default();
 
-        if let CheckDctMap::Unspecified = &self.dct {
-            let mut check_dct = CheckDct::Full(CheckDctData::default());
-
-            if let Some(attributes_expr) = attributes_expr {
-                check_dct.add_balance_and_attributes_check(
-                    nonce_expr,
-                    balance_expr,
-                    top_encode_to_vec_u8_or_panic(&attributes_expr),
-                );
-            } else {
-                check_dct.add_balance_and_attributes_check(
-                    nonce_expr,
-                    balance_expr,
-                    Vec::<u8>::new(),
-                );
-            }
-
-            let mut new_dct_map = BTreeMap::new();
-            let _ = new_dct_map.insert(token_id, check_dct);
-
-            let new_check_dct_map = CheckDctMapContents {
-                contents: new_dct_map,
-                other_dcts_allowed: true,
-            };
+        self.get_check_dct_or_insert(token_id, CheckDct::Full(CheckDctData::default()))
+            .add_balance_and_attributes_check(nonce_expr, balance_expr, attributes);
 
-            self.dct = CheckDctMap::Equal(new_check_dct_map);
-        }
+        self
+    }
+
+    /// Checks all the properties of a token instance.
+    ///
+    /// The optional properties are not checked when missing. The attributes are top-encoded,
+    /// as in `dct_nft_balance_and_attributes`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn dct_nft_all_properties<K, N, V, A, R, C, H, U>(
+        mut self,
+        token_id_expr: K,
+        nonce_expr: N,
+        balance_expr: V,
+        opt_attributes_expr: Option<A>,
+        royalties_expr: R,
+        creator_expr: Option<C>,
+        hash_expr: Option<H>,
+        uris_expr: Option<Vec<U>>,
+    ) -> Self
+    where
+        BytesKey: From<K>,
+        U64Value: From<N>,
+        BigUintValue: From<V>,
+        A: TopEncode,
+        U64Value: From<R>,
+        BytesValue: From<C>,
+        BytesValue: From<H>,
+        BytesValue: From<U>,
+    {
+        let token_id = BytesKey::from(token_id_expr);
+        let attributes = opt_attributes_expr.map(|attributes_expr| {
+            BytesValue::from(top_encode_to_vec_u8_or_panic(&attributes_expr))
+        });
+        let uri = uris_expr
+            .map(|uris_expr| {
+                CheckValue::Equal(
+                    uris_expr
+                        .into_iter()
+                        .map(|uri| CheckValue::Equal(BytesValue::from(uri)))
+                        .collect(),
+                )
+            })
+            .unwrap_or_default();
+        let instance_check = CheckDctInstance {
+            nonce: U64Value::from(nonce_expr),
+            balance: CheckValue::Equal(BigUintValue::from(balance_expr)),
+            creator: check_value_if_present(creator_expr),
+            royalties: CheckValue::Equal(U64Value::from(royalties_expr)),
+            hash: check_value_if_present(hash_expr),
+            uri,
+            attributes: check_value_if_present(attributes),
+        };
+
+        self.get_check_dct_or_insert(token_id, CheckDct::Full(CheckDctData::default()))
+            .add_instance_check(instance_check);
 
         self
     }
 
+    /// The check for the given token, added if missing. Tokens that are not mentioned are not checked.
+    fn get_check_dct_or_insert(
+        &mut self,
+        token_id: BytesKey,
+        new_check: CheckDct,
+    ) -> &mut CheckDct {
+        if !matches!(self.dct, CheckDctMap::Equal(_)) {
+            self.dct = CheckDctMap::Equal(CheckDctMapContents {
+                contents: BTreeMap::new(),
+                other_dcts_allowed: true,
+            });
+        }
+
+        if let CheckDctMap::Equal(check_dct_map) = &mut self.dct {
+            check_dct_map.contents.entry(token_id).or_insert(new_check)
+        } else {
+            unreachable!()
+        }
+    }
+
     pub fn check_storage(mut self, key: &str, value: &str) -> Self {
         let mut details = match self.storage {
             CheckStorage::Star => CheckStorageDetails::default(),
@@ -165,6 +202,15 @@ impl CheckAccount {
     }
 }
 
+fn check_value_if_present<T>(opt_expr: Option<T>) -> CheckValue<BytesValue>
+where
+    BytesValue: From<T>,
+{
+    opt_expr
+        .map(|expr| CheckValue::Equal(BytesValue::from(expr)))
+        .unwrap_or_default()
+}
+
 impl InterpretableFrom<Box<CheckAccountRaw>> for CheckAccount {
     fn interpret_from(from: Box<CheckAccountRaw>, context: &InterpreterContext) -> Self {
         CheckAccount {
@@ -62,6 +62,44 @@ impl CheckDct {
         }
     }
 
+    /// The check for the token instance with the given nonce, added if missing.
+    ///
+    /// If previously all instances were accepted (`"instances": "*"`), only the checked instances are accepted afterwards.
+    pub fn get_or_insert_instance_check(&mut self, nonce: U64Value) -> &mut CheckDctInstance {
+        self.convert_to_full();
+
+        let dct_check = match self {
+            CheckDct::Full(dct_check) => dct_check,
+            CheckDct::Short(_) => unreachable!(),
+        };
+        if dct_check.instances.is_star() {
+            dct_check.instances = CheckDctInstances::Equal(Vec::new());
+        }
+        let instance_checks = match &mut dct_check.instances {
+            CheckDctInstances::Equal(instance_checks) => instance_checks,
+            CheckDctInstances::Star => unreachable!(),
+        };
+
+        if let Some(i) = instance_checks
+            .iter()
+            .position(|item| item.nonce.value == nonce.value)
+        {
+            &mut instance_checks[i]
+        } else {
+            instance_checks.push(CheckDctInstance {
+                nonce,
+                ..Default::default()
+            });
+            instance_checks.last_mut().unwrap()
+        }
+    }
+
+    /// Adds a check for a token instance, replacing the previous check for the same nonce, if any.
+    pub fn add_instance_check(&mut self, instance_check: CheckDctInstance) {
+        let nonce = instance_check.nonce.clone();
+        *self.get_or_insert_instance_check(nonce) = instance_check;
+    }
+
     pub fn add_balance_check<N, V>(&mut self, nonce_expr: N, balance_expr: V)
     where
         U64Value: InterpretableFrom<N>,
@@ -71,35 +109,7 @@ impl CheckDct {
         let nonce = U64Value::interpret_from(nonce_expr, &ctx);
         let balance = BigUintValue::interpret_from(balance_expr, &ctx);
 
-        self.convert_to_full();
-
-        if let CheckDct::Full(prev_dct_check) = self {
-            match &mut prev_dct_check.instances {
-                CheckDctInstances::Star => {
-                    let new_instances_check = vec![CheckDctInstance {
-                        nonce,
-                        balance: CheckValue::Equal(balance),
-                        ..Default::default()
-                    }];
-
-                    prev_dct_check.instances = CheckDctInstances::Equal(new_instances_check);
-                },
-                CheckDctInstances::Equal(dct_instance_check) => {
-                    if let Some(i) = dct_instance_check
-                        .iter()
-                        .position(|item| item.nonce.value == nonce.value)
-                    {
-                        dct_instance_check[i].balance = CheckValue::Equal(balance);
-                    } else {
-                        dct_instance_check.push(CheckDctInstance {
-                            nonce,
-                            balance: CheckValue::Equal(balance),
-                            ..Default::default()
-                        });
-                    }
-                },
-            }
-        }
+        self.get_or_insert_instance_check(nonce).balance = CheckValue::Equal(balance);
     }
 
     pub fn add_balance_and_attributes_check<N, V, T>(
@@ -116,38 +126,9 @@ impl CheckDct {
         let balance = BigUintValue::from(balance_expr);
         let attributes = BytesValue::from(attributes_expr);
 
-        self.convert_to_full();
-
-        if let CheckDct::Full(prev_dct_check) = self {
-            match &mut prev_dct_check.instances {
-                CheckDctInstances::Star => {
-                    let new_instances_check = vec![CheckDctInstance {
-                        nonce,
-                        balance: CheckValue::Equal(balance),
-                        attributes: CheckValue::Equal(attributes),
-                        ..Default::default()
-                    }];
-
-                    prev_dct_check.instances = CheckDctInstances::Equal(new_instances_check);
-                },
-                CheckDctInstances::Equal(dct_instance_check) => {
-                    if let Some(i) = dct_instance_check
-                        .iter()
-                        .position(|item| item.nonce.value == nonce.value)
-                    {
-                        dct_instance_check[i].balance = CheckValue::Equal(balance);
-                        dct_instance_check[i].attributes = CheckValue::Equal(attributes);
-                    } else {
-                        dct_instance_check.push(CheckDctInstance {
-                            nonce,
-                            balance: CheckValue::Equal(balance),
-                            attributes: CheckValue::Equal(attributes),
-                            ..Default::default()
-                        });
-                    }
-                },
-            }
-        }
+        let instance_check = self.get_or_insert_instance_check(nonce);
+        instance_check.balance = CheckValue::Equal(balance);
+        instance_check.attributes = CheckValue::Equal(attributes);
     }
 }
 
@@ -10,7 +10,7 @@ pub struct TransferStep {
     pub tx: Box<TxTransfer>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Default, Clone)]
 pub struct ValidatorRewardStep {
     pub id: String,
     pub tx_id: Option<String>,
@@ -80,3 +80,25 @@ impl TransferStep {
         self
     }
 }
+
+impl ValidatorRewardStep {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn to<A>(mut self, address: A) -> Self
+    where
+        AddressValue: From<A>,
+    {
+        self.tx.to = AddressValue::from(address);
+        self
+    }
+
+    pub fn moax_value<A>(mut self, amount: A) -> Self
+    where
+        BigUintValue: From<A>,
+    {
+        self.tx.moax_value = BigUintValue::from(amount);
+        self
+    }
+}
@@ -84,6 +84,24 @@ impl TxExpect {
         self
     }
 
+    /// Only checked if gas metering is enabled in the debugger.
+    pub fn gas<G>(mut self, gas_expr: G) -> Self
+    where
+        U64Value: From<G>,
+    {
+        self.gas = CheckValue::Equal(U64Value::from(gas_expr));
+        self
+    }
+
+    /// Only checked if gas metering is enabled in the debugger.
+    pub fn refund<R>(mut self, refund_expr: R) -> Self
+    where
+        U64Value: From<R>,
+    {
+        self.refund = CheckValue::Equal(U64Value::from(refund_expr));
+        self
+    }
+
     pub fn additional_error_message<A>(mut self, message: A) -> Self
     where
         A: AsRef<str>,
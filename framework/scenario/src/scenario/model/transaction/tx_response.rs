@@ -45,6 +45,8 @@ impl TxResponse {
                 status: tx_result.result_status,
                 message: tx_result.result_message,
             },
+            gas: tx_result.gas_used,
+            refund: tx_result.gas_refund,
             ..Default::default()
         }
     }
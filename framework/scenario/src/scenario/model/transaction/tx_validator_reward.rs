@@ -8,7 +8,7 @@ use crate::{
 
 use super::tx_interpret_util::interpret_moax_value;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Default, Clone)]
 pub struct TxValidatorReward {
     pub to: AddressValue,
     pub moax_value: BigUintValue,
@@ -75,7 +75,12 @@ impl ScenarioTrace {
                         transfer_step.tx.to.clone(),
                     );
                 },
-                Step::ValidatorReward(_) => todo!(),
+                Step::ValidatorReward(validator_reward_step) => {
+                    validator_reward_step.tx.to = addr_value_to_pretty(
+                        &self.addr_to_pretty_string_map,
+                        validator_reward_step.tx.to.clone(),
+                    );
+                },
                 Step::CheckState(check_state_step) => {
                     let acc_map_keys = check_state_step
                         .accounts
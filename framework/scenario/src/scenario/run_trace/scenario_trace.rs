@@ -110,6 +110,7 @@ impl ScenarioRunner for ScenarioTrace {
     }
 
     fn run_validator_reward_step(&mut self, step: &ValidatorRewardStep) {
+        self.process_address_value(&step.tx.to);
         self.scenario_trace
             .steps
             .push(Step::ValidatorReward(step.clone()));
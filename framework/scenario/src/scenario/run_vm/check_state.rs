@@ -3,6 +3,7 @@ use crate::scenario::model::{
     CheckDctInstances, CheckDctMap, CheckStateStep, CheckStorage, CheckValue, Checkable,
 };
 use num_traits::Zero;
+use std::borrow::Cow;
 
 use dharitri_chain_vm::{
     display_util::{bytes_to_string, verbose_hex, verbose_hex_list},
@@ -23,7 +24,13 @@ impl ScenarioVMRunner {
 
 fn execute(state: &BlockchainState, accounts: &CheckAccounts) {
     for (expected_address, expected_account) in accounts.accounts.iter() {
-        if let Some(account) = state.accounts.get(&expected_address.to_vm_address()) {
+        let address = expected_address.to_vm_address();
+        let account = state
+            .accounts
+            .get(&address)
+            .map(Cow::Borrowed)
+            .or_else(|| state.fetch_account(&address).map(Cow::Owned));
+        if let Some(account) = account {
             assert!(
                 expected_account.nonce.check(account.nonce),
                 "bad account nonce. Address: {}. Want: {}. Have: {}",
@@ -211,7 +218,7 @@ pub fn check_dct_data(
 
     if !expected.frozen.check(u64::from(actual.frozen)) {
         errors.push(format!(
-            "bad last nonce. Address: {}. Token Name: {}. Want: {}. Have: {}\n",
+            "bad frozen flag. Address: {}. Token Name: {}. Want: {}. Have: {}\n",
             address, token, expected.frozen, &actual.frozen
         ));
     }
@@ -260,17 +267,6 @@ pub fn check_token_instance(
     actual_value: &DctInstance,
     errors: &mut Vec<String>,
 ) {
-    if !expected_value.balance.check(&actual_value.balance) {
-        errors.push(format!(
-            "bad dct balance. Address: {}. Token {}. Nonce {}. Want: {}. Have: {}",
-            address,
-            token,
-            expected_value.nonce.value,
-            expected_value.balance,
-            &actual_value.balance,
-        ))
-    }
-
     if !expected_value.balance.check(&actual_value.balance) {
         errors.push(format!(
             "bad dct balance. Address: {}. Token {}. Nonce {}. Want: {}. Have: {}",
@@ -0,0 +1,175 @@
+use crate::scenario_format::{
+    reconstruct_trait::ReconstructorContext,
+    serde_raw::ValueSubTree,
+    value_interpreter::{reconstruct, ExprReconstructorHint},
+};
+use dharitri_chain_vm::{
+    tx_mock::{TxCallTrace, TxLog, TxStorageWrite, TxTokenTransfer},
+    types::VMAddress,
+};
+use serde_json::{json, Value};
+use std::fmt::Write;
+
+const TREE_BRANCH: &str = "├─ ";
+const TREE_LAST_BRANCH: &str = "└─ ";
+const TREE_INDENT: &str = "│  ";
+const TREE_LAST_INDENT: &str = "   ";
+
+/// Exports the execution trace as JSON, one object per top-level call, with nested calls inside.
+pub fn execution_trace_json(traces: &[TxCallTrace]) -> String {
+    let calls: Vec<Value> = traces.iter().map(call_trace_json).collect();
+    serde_json::to_string_pretty(&calls).unwrap()
+}
+
+/// Pretty-prints the execution trace as a tree, one for each top-level call.
+pub fn execution_trace_tree(traces: &[TxCallTrace]) -> String {
+    let mut out = String::new();
+    for trace in traces {
+        writeln!(out, "{}", call_trace_line(trace)).unwrap();
+        write_call_trace_children(&mut out, trace, "");
+    }
+    out
+}
+
+fn call_trace_json(trace: &TxCallTrace) -> Value {
+    json!({
+        "kind": trace.call_kind.name(),
+        "from": pretty_address(&trace.from),
+        "to": pretty_address(&trace.to),
+        "endpoint": trace.endpoint.as_str(),
+        "arguments": trace.arguments.iter().map(|arg| pretty_bytes(arg)).collect::<Vec<_>>(),
+        "moaxValue": trace.moax_value.to_string(),
+        "dctTransfers": trace.dct_transfers.iter().map(dct_transfer_json).collect::<Vec<_>>(),
+        "logs": trace.logs.iter().map(log_json).collect::<Vec<_>>(),
+        "storageWrites": trace.storage_writes.iter().map(storage_write_json).collect::<Vec<_>>(),
+        "status": trace.result_status.to_string(),
+        "message": trace.result_message,
+        "nestedCalls": trace.nested_calls.iter().map(call_trace_json).collect::<Vec<_>>(),
+    })
+}
+
+fn dct_transfer_json(transfer: &TxTokenTransfer) -> Value {
+    json!({
+        "tokenIdentifier": pretty_bytes(&transfer.token_identifier),
+        "nonce": transfer.nonce.to_string(),
+        "value": transfer.value.to_string(),
+    })
+}
+
+fn log_json(log: &TxLog) -> Value {
+    json!({
+        "address": pretty_address(&log.address),
+        "endpoint": log.endpoint.as_str(),
+        "topics": log.topics.iter().map(|topic| pretty_bytes(topic)).collect::<Vec<_>>(),
+        "data": pretty_bytes(&log.data),
+    })
+}
+
+fn storage_write_json(storage_write: &TxStorageWrite) -> Value {
+    json!({
+        "address": pretty_address(&storage_write.address),
+        "key": pretty_bytes(&storage_write.key),
+        "old": pretty_bytes(&storage_write.old_value),
+        "new": pretty_bytes(&storage_write.new_value),
+    })
+}
+
+fn call_trace_line(trace: &TxCallTrace) -> String {
+    let arguments: Vec<String> = trace
+        .arguments
+        .iter()
+        .map(|arg| pretty_bytes(arg))
+        .collect();
+    let mut line = format!(
+        "{} {} -> {} {}({})",
+        trace.call_kind.name(),
+        pretty_address(&trace.from),
+        pretty_address(&trace.to),
+        trace.endpoint.as_str(),
+        arguments.join(", "),
+    );
+    if trace.moax_value != 0u32.into() {
+        write!(line, " moax: {}", trace.moax_value).unwrap();
+    }
+    for transfer in &trace.dct_transfers {
+        write!(
+            line,
+            " dct: {}/{}: {}",
+            pretty_bytes(&transfer.token_identifier),
+            transfer.nonce,
+            transfer.value
+        )
+        .unwrap();
+    }
+    if trace.is_success() {
+        line.push_str(" => ok");
+    } else {
+        write!(
+            line,
+            " => FAILED, status {}: {}",
+            trace.result_status, trace.result_message
+        )
+        .unwrap();
+    }
+    line
+}
+
+fn write_call_trace_children(out: &mut String, trace: &TxCallTrace, prefix: &str) {
+    let mut children: Vec<String> = Vec::new();
+    for storage_write in &trace.storage_writes {
+        children.push(format!(
+            "storage {} {}: {} -> {}",
+            pretty_address(&storage_write.address),
+            pretty_bytes(&storage_write.key),
+            pretty_bytes(&storage_write.old_value),
+            pretty_bytes(&storage_write.new_value),
+        ));
+    }
+    for log in &trace.logs {
+        let topics: Vec<String> = log.topics.iter().map(|topic| pretty_bytes(topic)).collect();
+        children.push(format!(
+            "log {} {} [{}] {}",
+            pretty_address(&log.address),
+            log.endpoint.as_str(),
+            topics.join(", "),
+            pretty_bytes(&log.data),
+        ));
+    }
+
+    let num_items = children.len() + trace.nested_calls.len();
+    let mut item_index = 0;
+    for child in children {
+        item_index += 1;
+        let branch = if item_index == num_items {
+            TREE_LAST_BRANCH
+        } else {
+            TREE_BRANCH
+        };
+        writeln!(out, "{prefix}{branch}{child}").unwrap();
+    }
+    for nested_call in &trace.nested_calls {
+        item_index += 1;
+        let (branch, indent) = if item_index == num_items {
+            (TREE_LAST_BRANCH, TREE_LAST_INDENT)
+        } else {
+            (TREE_BRANCH, TREE_INDENT)
+        };
+        writeln!(out, "{prefix}{branch}{}", call_trace_line(nested_call)).unwrap();
+        write_call_trace_children(out, nested_call, &format!("{prefix}{indent}"));
+    }
+}
+
+fn pretty_address(address: &VMAddress) -> String {
+    pretty_value(address.as_bytes(), ExprReconstructorHint::AddressHint)
+}
+
+fn pretty_bytes(bytes: &[u8]) -> String {
+    pretty_value(bytes, ExprReconstructorHint::NoHint)
+}
+
+fn pretty_value(value: &[u8], hint: ExprReconstructorHint) -> String {
+    match reconstruct(value, &hint, &ReconstructorContext::new()) {
+        ValueSubTree::Str(s) => s,
+        _ => unreachable!("values are always reconstructed as strings"),
+    }
+}
@@ -0,0 +1,156 @@
+use crate::{
+    scenario::model::{Scenario, Step},
+    scenario_format::{
+        interpret_trait::{InterpretableFrom, InterpreterContext},
+        serde_raw::ScenarioRaw,
+    },
+    standalone::retrieve_account_as_scenario_set_state,
+};
+use dharitri_chain_vm::{
+    types::VMAddress,
+    world_mock::{AccountData, AccountFetcher},
+};
+use dharitri_sdk::data::address::Address;
+use std::{
+    collections::HashMap,
+    fs,
+    path::{Path, PathBuf},
+    sync::Mutex,
+};
+
+use super::set_state::convert_account;
+
+/// Retrieves accounts from a live network, through a gateway, for running scenarios in fork mode.
+///
+/// Each account is saved in the cache directory, as a scenario file containing a single `setState` step.
+/// Cached accounts are never retrieved again, so forked tests can also run offline.
+pub struct ForkAccountFetcher {
+    gateway_uri: Option<String>,
+    cache_dir: PathBuf,
+    accounts: Mutex<HashMap<VMAddress, Option<AccountData>>>,
+}
+
+impl ForkAccountFetcher {
+    /// Retrieves missing accounts from the gateway and caches them in the given directory.
+    pub fn new<P: AsRef<Path>>(gateway_uri: &str, cache_dir: P) -> Self {
+        ForkAccountFetcher {
+            gateway_uri: Some(gateway_uri.trim_end_matches('/').to_string()),
+            cache_dir: cache_dir.as_ref().to_path_buf(),
+            accounts: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Only works with previously cached accounts, never connects to the network.
+    pub fn offline<P: AsRef<Path>>(cache_dir: P) -> Self {
+        ForkAccountFetcher {
+            gateway_uri: None,
+            cache_dir: cache_dir.as_ref().to_path_buf(),
+            accounts: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub fn cache_dir(&self) -> &Path {
+        &self.cache_dir
+    }
+
+    /// The cache file of an account, named after its bech32 address.
+    pub fn cache_file_path(&self, address: &VMAddress) -> PathBuf {
+        self.cache_dir
+            .join(format!("{}.scen.json", address_bech32(address)))
+    }
+
+    fn load_cached_scenario(&self, address: &VMAddress) -> Option<ScenarioRaw> {
+        let path = self.cache_file_path(address);
+        if !path.is_file() {
+            return None;
+        }
+
+        let contents = fs::read_to_string(&path).unwrap_or_else(|err| {
+            panic!("error reading fork cache file {}: {err}", path.display())
+        });
+        let scenario_raw = serde_json::from_str(&contents)
+            .unwrap_or_else(|err| panic!("invalid fork cache file {}: {err}", path.display()));
+        Some(scenario_raw)
+    }
+
+    fn retrieve_scenario(&self, address: &VMAddress) -> ScenarioRaw {
+        let bech32_address = address_bech32(address);
+        let gateway_uri = self.gateway_uri.clone().unwrap_or_else(|| {
+            panic!("account {bech32_address} not found in the fork cache, and fork is offline")
+        });
+
+        // Running on a separate thread, so the fetcher works both inside and outside an async runtime.
+        let scenario_raw = std::thread::spawn({
+            let gateway_uri = gateway_uri.clone();
+            let bech32_address = bech32_address.clone();
+            move || {
+                tokio::runtime::Builder::new_current_thread()
+                    .enable_all()
+                    .build()
+                    .unwrap()
+                    .block_on(retrieve_account_as_scenario_set_state(
+                        gateway_uri,
+                        bech32_address,
+                        true,
+                    ))
+            }
+        })
+        .join()
+        .unwrap_or_else(|_| {
+            panic!("failed to retrieve account {bech32_address} from gateway {gateway_uri}")
+        });
+
+        self.save_cached_scenario(address, &scenario_raw);
+        scenario_raw
+    }
+
+    fn save_cached_scenario(&self, address: &VMAddress, scenario_raw: &ScenarioRaw) {
+        fs::create_dir_all(&self.cache_dir).unwrap_or_else(|err| {
+            panic!(
+                "error creating fork cache directory {}: {err}",
+                self.cache_dir.display()
+            )
+        });
+        let path = self.cache_file_path(address);
+        fs::write(&path, scenario_raw.to_json_string()).unwrap_or_else(|err| {
+            panic!("error writing fork cache file {}: {err}", path.display())
+        });
+    }
+}
+
+impl AccountFetcher for ForkAccountFetcher {
+    fn fetch_account(&self, address: &VMAddress) -> Option<AccountData> {
+        let mut accounts = self.accounts.lock().unwrap();
+        accounts
+            .entry(address.clone())
+            .or_insert_with(|| {
+                let scenario_raw = self
+                    .load_cached_scenario(address)
+                    .unwrap_or_else(|| self.retrieve_scenario(address));
+                account_from_scenario(address, scenario_raw)
+            })
+            .clone()
+    }
+}
+
+/// Cache files contain a single `setState` step, for the requested account.
+fn account_from_scenario(address: &VMAddress, scenario_raw: ScenarioRaw) -> Option<AccountData> {
+    let scenario = Scenario::interpret_from(scenario_raw, &InterpreterContext::default());
+    scenario.steps.iter().find_map(|step| {
+        if let Step::SetState(set_state_step) = step {
+            set_state_step
+                .accounts
+                .iter()
+                .find(|(address_key, _)| &address_key.to_vm_address() == address)
+                .map(|(address_key, account)| convert_account(address_key, account))
+        } else {
+            None
+        }
+    })
+}
+
+fn address_bech32(address: &VMAddress) -> String {
+    Address::from_bytes(*address.as_array())
+        .to_bech32_string()
+        .unwrap()
+}
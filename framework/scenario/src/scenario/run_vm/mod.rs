@@ -1,4 +1,6 @@
 mod check_state;
+mod execution_trace;
+mod fork_account_fetcher;
 pub mod sc_call;
 pub mod sc_deploy;
 pub mod sc_query;
@@ -8,5 +10,7 @@ mod tx_input_util;
 mod tx_output_check;
 mod vm_runner;
 
+pub use execution_trace::{execution_trace_json, execution_trace_tree};
+pub use fork_account_fetcher::ForkAccountFetcher;
 use tx_output_check::*;
 pub use vm_runner::ScenarioVMRunner;
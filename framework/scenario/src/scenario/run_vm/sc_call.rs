@@ -9,7 +9,7 @@ use dharitri_chain_vm::{
     tx_mock::{TxInput, TxResult, TxTokenTransfer},
 };
 
-use super::{check_tx_output, tx_input_util::generate_tx_hash, ScenarioVMRunner};
+use super::{check_tx_gas, check_tx_output, tx_input_util::generate_tx_hash, ScenarioVMRunner};
 
 impl ScenarioVMRunner {
     /// Adds a SC call step, as specified in the `step` argument, then executes it.
@@ -72,6 +72,9 @@ impl ScenarioVMRunner {
         let tx_result = self.perform_sc_call_lambda(sc_call_step, f);
         if let Some(tx_expect) = &sc_call_step.expect {
             check_tx_output(&sc_call_step.id, tx_expect, &tx_result);
+            if self.blockchain_mock.vm.is_gas_metered() {
+                check_tx_gas(&sc_call_step.id, tx_expect, &tx_result);
+            }
         }
         tx_result
     }
@@ -7,7 +7,7 @@ use dharitri_chain_vm::{
     tx_mock::{TxFunctionName, TxInput, TxResult},
 };
 
-use super::{check_tx_output, tx_input_util::generate_tx_hash, ScenarioVMRunner};
+use super::{check_tx_gas, check_tx_output, tx_input_util::generate_tx_hash, ScenarioVMRunner};
 
 impl ScenarioVMRunner {
     /// Adds a SC deploy step, as specified in the `step` argument, then executes it.
@@ -55,6 +55,9 @@ impl ScenarioVMRunner {
         let (new_address, tx_result) = self.perform_sc_deploy_lambda(sc_deploy_step, f);
         if let Some(tx_expect) = &sc_deploy_step.expect {
             check_tx_output(&sc_deploy_step.id, tx_expect, &tx_result);
+            if self.blockchain_mock.vm.is_gas_metered() {
+                check_tx_gas(&sc_deploy_step.id, tx_expect, &tx_result);
+            }
         }
         (new_address, tx_result)
     }
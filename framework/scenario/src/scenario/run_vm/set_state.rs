@@ -1,4 +1,4 @@
-use crate::scenario::model::SetStateStep;
+use crate::scenario::model::{Account, AddressKey, SetStateStep};
 
 use dharitri_chain_vm::{
     types::VMAddress,
@@ -12,58 +12,28 @@ use super::ScenarioVMRunner;
 
 impl ScenarioVMRunner {
     pub fn perform_set_state(&mut self, set_state_step: &SetStateStep) {
+        let block_nonce_before = self.blockchain_mock.state.current_block_info.block_nonce;
         execute(&mut self.blockchain_mock.state, set_state_step);
+        let block_nonce_after = self.blockchain_mock.state.current_block_info.block_nonce;
+        self.process_cross_shard_calls(block_nonce_after.saturating_sub(block_nonce_before));
+    }
+
+    /// In sharded mode, each new block executes the calls sent to other shards in the previous one.
+    fn process_cross_shard_calls(&mut self, num_blocks: u64) {
+        for _ in 0..num_blocks {
+            if self.blockchain_mock.state.cross_shard_calls.is_empty() {
+                break;
+            }
+            self.blockchain_mock
+                .vm
+                .execute_cross_shard_calls(&mut self.blockchain_mock.state);
+        }
     }
 }
 
 fn execute(state: &mut BlockchainState, set_state_step: &SetStateStep) {
     for (address, account) in set_state_step.accounts.iter() {
-        let storage = account
-            .storage
-            .iter()
-            .map(|(k, v)| (k.value.clone(), v.value.clone()))
-            .collect();
-        let dct = AccountDct::new_from_raw_map(
-            account
-                .dct
-                .iter()
-                .map(|(k, v)| (k.value.clone(), convert_mandos_dct_to_world_mock(v)))
-                .collect(),
-        );
-
-        state.validate_and_add_account(AccountData {
-            address: address.to_vm_address(),
-            nonce: account
-                .nonce
-                .as_ref()
-                .map(|nonce| nonce.value)
-                .unwrap_or_default(),
-            moax_balance: account
-                .balance
-                .as_ref()
-                .map(|balance| balance.value.clone())
-                .unwrap_or_default(),
-            dct,
-            username: account
-                .username
-                .as_ref()
-                .map(|bytes_value| bytes_value.value.clone())
-                .unwrap_or_default(),
-            storage,
-            contract_path: account
-                .code
-                .as_ref()
-                .map(|bytes_value| bytes_value.value.clone()),
-            contract_owner: account
-                .owner
-                .as_ref()
-                .map(|address_value| address_value.to_vm_address()),
-            developer_rewards: account
-                .developer_rewards
-                .as_ref()
-                .map(|rewards| rewards.value.clone())
-                .unwrap_or_default(),
-        });
+        state.validate_and_add_account(convert_account(address, account));
     }
     for new_address in set_state_step.new_addresses.iter() {
         assert!(
@@ -87,6 +57,55 @@ fn execute(state: &mut BlockchainState, set_state_step: &SetStateStep) {
     }
 }
 
+pub(super) fn convert_account(address: &AddressKey, account: &Account) -> AccountData {
+    let storage = account
+        .storage
+        .iter()
+        .map(|(k, v)| (k.value.clone(), v.value.clone()))
+        .collect();
+    let dct = AccountDct::new_from_raw_map(
+        account
+            .dct
+            .iter()
+            .map(|(k, v)| (k.value.clone(), convert_mandos_dct_to_world_mock(v)))
+            .collect(),
+    );
+
+    AccountData {
+        address: address.to_vm_address(),
+        nonce: account
+            .nonce
+            .as_ref()
+            .map(|nonce| nonce.value)
+            .unwrap_or_default(),
+        moax_balance: account
+            .balance
+            .as_ref()
+            .map(|balance| balance.value.clone())
+            .unwrap_or_default(),
+        dct,
+        username: account
+            .username
+            .as_ref()
+            .map(|bytes_value| bytes_value.value.clone())
+            .unwrap_or_default(),
+        storage,
+        contract_path: account
+            .code
+            .as_ref()
+            .map(|bytes_value| bytes_value.value.clone()),
+        contract_owner: account
+            .owner
+            .as_ref()
+            .map(|address_value| address_value.to_vm_address()),
+        developer_rewards: account
+            .developer_rewards
+            .as_ref()
+            .map(|rewards| rewards.value.clone())
+            .unwrap_or_default(),
+    }
+}
+
 fn convert_mandos_dct_to_world_mock(mandos_dct: &crate::scenario::model::Dct) -> DctData {
     match mandos_dct {
         crate::scenario::model::Dct::Short(short_dct) => {
@@ -165,6 +184,7 @@ fn convert_scenario_dct_instance_to_world_mock(
                 .map(|attributes| attributes.value.clone())
                 .unwrap_or_default(),
         },
+        frozen: false,
     }
 }
 
@@ -2,7 +2,7 @@ use super::{sc_call::tx_dct_transfers_from_scenario, ScenarioVMRunner};
 use crate::scenario::model::{TransferStep, TxTransfer, ValidatorRewardStep};
 use dharitri_chain_vm::{
     tx_execution::{execute_current_tx_context_input, BlockchainVMRef},
-    tx_mock::{TxFunctionName, TxInput},
+    tx_mock::{TxCallKind, TxFunctionName, TxInput},
     with_shared::Shareable,
     world_mock::BlockchainState,
 };
@@ -44,6 +44,12 @@ fn execute(vm: BlockchainVMRef, state: &mut Shareable<BlockchainState>, tx_trans
     // nonce gets increased irrespective of whether the tx fails or not
     state.increase_account_nonce(&tx_input.from);
 
+    let from = tx_input.from.clone();
+    let gas_limit = tx_input.gas_limit;
+    let gas_price = tx_input.gas_price;
+    vm.trace(|tracer| tracer.begin_call(TxCallKind::Transaction, &tx_input));
     let tx_result = vm.execute_sc_call_lambda(tx_input, state, execute_current_tx_context_input);
+    vm.trace(|tracer| tracer.end_call(&tx_result));
     tx_result.assert_ok();
+    vm.refund_unused_gas(&tx_result, &from, gas_limit, gas_price, state);
 }
@@ -76,6 +76,25 @@ pub fn check_tx_output(tx_id: &str, tx_expect: &TxExpect, tx_result: &TxResult)
     }
 }
 
+/// Gas is only checked if the VM meters it, otherwise the values in the scenario are ignored.
+pub fn check_tx_gas(tx_id: &str, tx_expect: &TxExpect, tx_result: &TxResult) {
+    assert!(
+        tx_expect.gas.check(tx_result.gas_used),
+        "gas used mismatch. Tx id: '{}'. Want: {}. Have: {}",
+        tx_id,
+        &tx_expect.gas,
+        tx_result.gas_used,
+    );
+
+    assert!(
+        tx_expect.refund.check(tx_result.gas_refund),
+        "gas refund mismatch. Tx id: '{}'. Want: {}. Have: {}",
+        tx_id,
+        &tx_expect.refund,
+        tx_result.gas_refund,
+    );
+}
+
 fn scenario_check(tx_log: &TxLog, check_log: &crate::scenario::model::CheckLog) -> bool {
     check_log.address.check(tx_log.address.as_bytes())
         && check_log.endpoint.check(&tx_log.endpoint)
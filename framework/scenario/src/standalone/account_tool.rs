@@ -57,8 +57,8 @@ pub async fn retrieve_account_as_scenario_set_state(
             storage: convert_storage(account_storage),
             comment: None,
             code: retrieve_code(account.code),
-            owner: None,
-            developer_rewards: None,
+            owner: retrieve_owner(account.owner_address),
+            developer_rewards: account.developer_reward.map(ValueSubTree::Str),
         },
     );
 
@@ -87,11 +87,22 @@ fn retrieve_code(code: String) -> Option<ValueSubTree> {
     }
 }
 
-fn convert_storage(account_storage: HashMap<String, String>) -> BTreeMap<String, ValueSubTree> {
+fn retrieve_owner(owner_address: Option<String>) -> Option<ValueSubTree> {
+    owner_address
+        .filter(|owner| !owner.is_empty())
+        .map(|owner| ValueSubTree::Str(format!("bech32:{owner}")))
+}
+
+fn convert_storage(account_storage: HashMap<Vec<u8>, Vec<u8>>) -> BTreeMap<String, ValueSubTree> {
     account_storage
         .into_iter()
-        .filter(|(k, _)| !k.starts_with("4448415249545249"))
-        .map(|(k, v)| (format!("0x{k}"), ValueSubTree::Str(format!("0x{v}"))))
+        .filter(|(k, _)| !k.starts_with(b"DHARITRI"))
+        .map(|(k, v)| {
+            (
+                format!("0x{}", hex::encode(k)),
+                ValueSubTree::Str(format!("0x{}", hex::encode(v))),
+            )
+        })
         .collect()
 }
 
@@ -109,8 +120,19 @@ fn convert_dct(
             dct_full_raw.instances.push(DctInstanceRaw {
                 nonce: Some(ValueSubTree::Str(nonce.to_string())),
                 balance: Some(ValueSubTree::Str(value.balance)),
-                // TODO: add creator, royalties, etc ...
-                ..Default::default()
+                creator: value
+                    .creator
+                    .filter(|creator| !creator.is_empty())
+                    .map(|creator| ValueSubTree::Str(format!("bech32:{creator}"))),
+                royalties: value.royalties.map(ValueSubTree::Str),
+                hash: value.hash.as_deref().and_then(base64_to_hex_value),
+                uri: value
+                    .uris
+                    .unwrap_or_default()
+                    .iter()
+                    .filter_map(|uri| base64_to_hex_value(uri))
+                    .collect(),
+                attributes: value.attributes.as_deref().and_then(base64_to_hex_value),
             });
         }
     }
@@ -139,3 +161,12 @@ fn split_token_identifer_nonce(full_identifier: String) -> (String, u64) {
         _ => panic!("could not process token identifier: {full_identifier}"),
     }
 }
+
+/// The gateway provides binary NFT fields as base64.
+fn base64_to_hex_value(base64_value: &str) -> Option<ValueSubTree> {
+    if base64_value.is_empty() {
+        return None;
+    }
+    let bytes = base64::decode(base64_value).ok()?;
+    Some(ValueSubTree::Str(format!("0x{}", hex::encode(bytes))))
+}
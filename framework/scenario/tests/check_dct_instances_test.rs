@@ -0,0 +1,162 @@
+use dharitri_sc_scenario::{scenario_model::*, ScenarioWorld};
+
+const OWNER: &str = "address:owner";
+const NFT: &str = "str:NFT-123456";
+const FUNGIBLE: &str = "str:FUNG-123456";
+
+fn world() -> ScenarioWorld {
+    let mut world = ScenarioWorld::new();
+    world.set_state_step(
+        SetStateStep::new().put_account(
+            OWNER,
+            Account::new()
+                .dct_balance(FUNGIBLE, "1000")
+                .dct_nft_all_properties(
+                    NFT,
+                    1,
+                    "1",
+                    Some("str:attributes-1"),
+                    2000,
+                    Some(OWNER),
+                    Some("str:hash-1"),
+                    vec!["str:uri-1", "str:uri-2"],
+                )
+                .dct_nft_balance(NFT, 2, "5", Some("str:attributes-2")),
+        ),
+    );
+    world
+}
+
+fn check_owner(world: &mut ScenarioWorld, check_account: CheckAccount) {
+    world.check_state_step(CheckStateStep::new().put_account(OWNER, check_account));
+}
+
+fn star_instances_check() -> CheckDct {
+    CheckDct::Full(CheckDctData {
+        instances: CheckDctInstances::Star,
+        ..Default::default()
+    })
+}
+
+fn check_account_with(token: &str, check_dct: CheckDct) -> CheckAccount {
+    let mut check_account = CheckAccount::new();
+    let mut contents = CheckDctMapContents {
+        contents: Default::default(),
+        other_dcts_allowed: true,
+    };
+    contents.contents.insert(BytesKey::from(token), check_dct);
+    check_account.dct = CheckDctMap::Equal(contents);
+    check_account
+}
+
+#[test]
+fn check_dct_all_properties_test() {
+    let mut world = world();
+    check_owner(
+        &mut world,
+        CheckAccount::new()
+            .dct_balance(FUNGIBLE, "1000")
+            .dct_nft_all_properties(
+                NFT,
+                1,
+                "1",
+                Some("attributes-1"),
+                2000,
+                Some(OWNER),
+                Some("str:hash-1"),
+                Some(vec!["str:uri-1", "str:uri-2"]),
+            )
+            .dct_nft_all_properties(
+                NFT,
+                2,
+                "5",
+                Some("attributes-2"),
+                0u64,
+                None::<&str>,
+                None::<&str>,
+                Some(Vec::<&str>::new()),
+            ),
+    );
+}
+
+#[test]
+fn check_dct_missing_properties_unchecked_test() {
+    let mut world = world();
+    check_owner(
+        &mut world,
+        CheckAccount::new().dct_nft_all_properties(
+            NFT,
+            1,
+            "1",
+            None::<&str>,
+            2000,
+            None::<&str>,
+            None::<&str>,
+            None::<Vec<&str>>,
+        ),
+    );
+}
+
+#[test]
+#[should_panic(expected = "bad dct uri. Address: address:owner. Token NFT-123456. Nonce 1.")]
+fn check_dct_uris_mismatch_test() {
+    let mut world = world();
+    check_owner(
+        &mut world,
+        CheckAccount::new().dct_nft_all_properties(
+            NFT,
+            1,
+            "1",
+            None::<&str>,
+            2000,
+            None::<&str>,
+            None::<&str>,
+            Some(vec!["str:uri-1"]),
+        ),
+    );
+}
+
+#[test]
+fn check_dct_balance_on_star_instances_test() {
+    let mut world = world();
+
+    // all instances accepted
+    check_owner(&mut world, check_account_with(NFT, star_instances_check()));
+
+    // a balance check can be added on top of "*"
+    check_owner(
+        &mut world,
+        check_account_with(FUNGIBLE, star_instances_check()).dct_balance(FUNGIBLE, "1000"),
+    );
+}
+
+#[test]
+fn check_dct_balance_on_listed_instances_test() {
+    let mut world = world();
+    let mut fungible_check = star_instances_check();
+    fungible_check.add_balance_check("0", "1");
+
+    check_owner(
+        &mut world,
+        check_account_with(FUNGIBLE, fungible_check).dct_balance(FUNGIBLE, "1000"),
+    );
+}
+
+#[test]
+#[should_panic(expected = "bad dct royalties. Address: address:owner. Token NFT-123456. Nonce 1.")]
+fn check_dct_all_properties_mismatch_test() {
+    let mut world = world();
+    check_owner(
+        &mut world,
+        CheckAccount::new().dct_nft_all_properties(
+            NFT,
+            1,
+            "1",
+            Some("attributes-1"),
+            1000,
+            Some(OWNER),
+            Some("str:hash-1"),
+            Some(vec!["str:uri-1", "str:uri-2"]),
+        ),
+    );
+}
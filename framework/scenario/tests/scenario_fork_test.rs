@@ -0,0 +1,193 @@
+use dharitri_sc_scenario::{bech32, scenario_model::*, ScenarioWorld};
+use std::{
+    io::{BufRead, BufReader, Write},
+    net::TcpListener,
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+};
+
+const FORKED_USER: &str = "address:forked-user";
+const RECEIVER: &str = "address:receiver";
+
+/// Minimal stand-in for the gateway, only serving account data.
+///
+/// `forked-user` has some balance, a DCT and a storage entry, all other accounts are empty.
+struct GatewayStandIn {
+    uri: String,
+    request_count: Arc<AtomicUsize>,
+}
+
+impl GatewayStandIn {
+    fn start() -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let uri = format!("http://{}", listener.local_addr().unwrap());
+        let request_count = Arc::new(AtomicUsize::new(0));
+        let forked_user_bech32 = bech32_address(FORKED_USER);
+
+        let thread_request_count = request_count.clone();
+        std::thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                let mut request_line = String::new();
+                let mut reader = BufReader::new(&mut stream);
+                reader.read_line(&mut request_line).unwrap();
+                loop {
+                    let mut header_line = String::new();
+                    reader.read_line(&mut header_line).unwrap();
+                    if header_line.trim().is_empty() {
+                        break;
+                    }
+                }
+
+                thread_request_count.fetch_add(1, Ordering::SeqCst);
+                let path = request_line.split_whitespace().nth(1).unwrap_or_default();
+                let body = gateway_response(path, &forked_user_bech32);
+                write!(
+                    stream,
+                    "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                )
+                .unwrap();
+            }
+        });
+
+        GatewayStandIn { uri, request_count }
+    }
+
+    fn request_count(&self) -> usize {
+        self.request_count.load(Ordering::SeqCst)
+    }
+}
+
+fn gateway_response(path: &str, forked_user_bech32: &str) -> String {
+    let address = path
+        .trim_start_matches("/address/")
+        .split('/')
+        .next()
+        .unwrap();
+    let is_forked_user = address == forked_user_bech32;
+
+    let data = if path.ends_with("/dct") {
+        if is_forked_user {
+            r#"{"dcts":{"FRK-123456":{"tokenIdentifier":"FRK-123456","balance":"300"}}}"#
+                .to_string()
+        } else {
+            r#"{"dcts":{}}"#.to_string()
+        }
+    } else if path.ends_with("/dcts/roles") {
+        r#"{"roles":{}}"#.to_string()
+    } else if path.ends_with("/keys/") {
+        if is_forked_user {
+            format!(
+                r#"{{"pairs":{{"{}":"{}"}}}}"#,
+                hex::encode("key"),
+                hex::encode("value")
+            )
+        } else {
+            r#"{"pairs":{}}"#.to_string()
+        }
+    } else {
+        let (nonce, balance) = if is_forked_user {
+            (5, "1000")
+        } else {
+            (0, "0")
+        };
+        format!(
+            r#"{{"account":{{"address":"{address}","nonce":{nonce},"balance":"{balance}","username":"","code":""}}}}"#
+        )
+    };
+
+    format!(r#"{{"data":{data},"error":"","code":"successful"}}"#)
+}
+
+fn bech32_address(address_expr: &str) -> String {
+    bech32::encode(&AddressValue::from(address_expr).value)
+}
+
+fn fork_cache_dir(test_name: &str) -> PathBuf {
+    let cache_dir = std::env::temp_dir().join(format!(
+        "dharitri-fork-cache-{test_name}-{}",
+        std::process::id()
+    ));
+    let _ = std::fs::remove_dir_all(&cache_dir);
+    cache_dir
+}
+
+fn transfer_from_forked_account(world: &mut ScenarioWorld) {
+    world
+        .transfer_step(
+            TransferStep::new()
+                .from(FORKED_USER)
+                .to(RECEIVER)
+                .moax_value("100"),
+        )
+        .check_state_step(
+            CheckStateStep::new()
+                .put_account(
+                    FORKED_USER,
+                    CheckAccount::new()
+                        .nonce("6")
+                        .balance("900")
+                        .dct_balance("str:FRK-123456", "300")
+                        .check_storage("str:key", "str:value"),
+                )
+                .put_account(RECEIVER, CheckAccount::new().balance("100")),
+        );
+}
+
+fn assert_cached(cache_dir: &Path, address_expr: &str) {
+    let cache_file = cache_dir.join(format!("{}.scen.json", bech32_address(address_expr)));
+    assert!(cache_file.is_file(), "account not cached: {address_expr}");
+}
+
+#[test]
+fn fork_from_gateway_test() {
+    let gateway = GatewayStandIn::start();
+    let cache_dir = fork_cache_dir("gateway");
+
+    let mut world = ScenarioWorld::new();
+    world.fork_from_gateway(&gateway.uri, &cache_dir);
+    transfer_from_forked_account(&mut world);
+
+    // 4 requests per account: account, DCTs, roles, storage
+    assert_eq!(gateway.request_count(), 8);
+    assert_cached(&cache_dir, FORKED_USER);
+    assert_cached(&cache_dir, RECEIVER);
+
+    // a new world, forked from the same gateway, only uses the cache
+    let mut world = ScenarioWorld::new();
+    world.fork_from_gateway(&gateway.uri, &cache_dir);
+    transfer_from_forked_account(&mut world);
+    assert_eq!(gateway.request_count(), 8);
+
+    std::fs::remove_dir_all(&cache_dir).unwrap();
+}
+
+#[test]
+fn fork_from_cache_test() {
+    let gateway = GatewayStandIn::start();
+    let cache_dir = fork_cache_dir("offline");
+
+    let mut world = ScenarioWorld::new();
+    world.fork_from_gateway(&gateway.uri, &cache_dir);
+    transfer_from_forked_account(&mut world);
+
+    let mut offline_world = ScenarioWorld::new();
+    offline_world.fork_from_cache(&cache_dir);
+    transfer_from_forked_account(&mut offline_world);
+
+    std::fs::remove_dir_all(&cache_dir).unwrap();
+}
+
+#[test]
+#[should_panic(expected = "not found in the fork cache")]
+fn fork_from_cache_missing_account_test() {
+    let cache_dir = fork_cache_dir("missing");
+
+    let mut world = ScenarioWorld::new();
+    world.fork_from_cache(&cache_dir);
+    transfer_from_forked_account(&mut world);
+}
@@ -0,0 +1,32 @@
+use dharitri_sc_scenario::{scenario_model::*, ScenarioWorld};
+
+const VALIDATOR: &str = "address:validator";
+
+#[test]
+fn trace_validator_reward_test() {
+    let trace_path = std::env::temp_dir().join(format!(
+        "trace-validator-reward-{}.scen.json",
+        std::process::id()
+    ));
+
+    let mut world = ScenarioWorld::new();
+    world
+        .start_trace()
+        .set_state_step(
+            SetStateStep::new().put_account(VALIDATOR, Account::new().nonce(1).balance("100")),
+        )
+        .validator_reward_step(ValidatorRewardStep::new().to(VALIDATOR).moax_value("1,000"))
+        .check_state_step(
+            CheckStateStep::new().put_account(VALIDATOR, CheckAccount::new().balance("1,100")),
+        )
+        .write_scenario_trace(&trace_path);
+
+    let trace = std::fs::read_to_string(&trace_path).unwrap();
+    assert!(trace.contains(r#""step": "validatorReward""#));
+    assert!(trace.contains(r#""to": "address:validator""#));
+
+    // the trace can be replayed
+    ScenarioWorld::new().run(&trace_path);
+
+    std::fs::remove_file(&trace_path).unwrap();
+}
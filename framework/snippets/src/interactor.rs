@@ -6,11 +6,16 @@ use dharitri_sc_scenario::{
 use dharitri_sdk::{
     blockchain::CommunicationProxy,
     data::{address::Address as ErdrsAddress, network_config::NetworkConfig},
+    signer::Signer,
     wallet::Wallet,
 };
-use std::{collections::HashMap, path::Path, time::Duration};
+use std::{
+    collections::{BTreeSet, HashMap},
+    path::Path,
+    time::Duration,
+};
 
-use crate::Sender;
+use crate::{GasEstimationConfig, Sender, TxCompletionConfig};
 
 pub const INTERACTOR_SCENARIO_TRACE_PATH: &str = "interactor_trace.scen.json";
 
@@ -18,6 +23,9 @@ pub struct Interactor {
     pub proxy: CommunicationProxy,
     pub network_config: NetworkConfig,
     pub sender_map: HashMap<Address, Sender>,
+    pub(crate) relayer: Option<Box<dyn Signer>>,
+    pub(crate) tx_completion: TxCompletionConfig,
+    pub(crate) gas_estimation: Option<GasEstimationConfig>,
 
     pub(crate) waiting_time_ms: u64,
     pub pre_runners: ScenarioRunnerList,
@@ -32,6 +40,9 @@ impl Interactor {
             proxy,
             network_config,
             sender_map: HashMap::new(),
+            relayer: None,
+            tx_completion: TxCompletionConfig::default(),
+            gas_estimation: None,
             waiting_time_ms: 0,
             pre_runners: ScenarioRunnerList::empty(),
             post_runners: ScenarioRunnerList::empty(),
@@ -39,18 +50,89 @@ impl Interactor {
     }
 
     pub fn register_wallet(&mut self, wallet: Wallet) -> Address {
-        let address = erdrs_address_to_h256(wallet.address());
+        self.register_signer(wallet)
+    }
+
+    /// Registers an account whose transactions are signed by the given signer,
+    /// e.g. a remote signing service, so that its private key never needs to be loaded.
+    pub fn register_signer<S>(&mut self, signer: S) -> Address
+    where
+        S: Signer + 'static,
+    {
+        let address = erdrs_address_to_h256(signer.address());
         self.sender_map.insert(
             address.clone(),
             Sender {
                 address: address.clone(),
-                wallet,
+                signer: Box::new(signer),
                 current_nonce: None,
+                in_flight_nonces: BTreeSet::new(),
+                guardian: None,
             },
         );
         address
     }
 
+    pub fn register_guardian_wallet(&mut self, sender_address: &Address, guardian_wallet: Wallet) {
+        self.register_guardian_signer(sender_address, guardian_wallet);
+    }
+
+    /// The guardian co-signs all transactions of the sender from now on.
+    ///
+    /// The sender needs to be registered beforehand.
+    pub fn register_guardian_signer<S>(&mut self, sender_address: &Address, guardian: S)
+    where
+        S: Signer + 'static,
+    {
+        let sender = self
+            .sender_map
+            .get_mut(sender_address)
+            .expect("the guarded wallet is not registered");
+        sender.guardian = Some(Box::new(guardian));
+    }
+
+    pub fn set_relayer_wallet(&mut self, wallet: Wallet) -> Address {
+        self.set_relayer_signer(wallet)
+    }
+
+    /// All transactions sent from now on are relayed (v3), the relayer pays for their gas.
+    pub fn set_relayer_signer<S>(&mut self, relayer: S) -> Address
+    where
+        S: Signer + 'static,
+    {
+        let address = erdrs_address_to_h256(relayer.address());
+        self.relayer = Some(Box::new(relayer));
+        address
+    }
+
+    /// Transactions sent from now on are paid for by their senders again.
+    pub fn clear_relayer_wallet(&mut self) {
+        self.relayer = None;
+    }
+
+    /// Configures how long and how often the interactor polls for the results of the transactions it sends.
+    pub fn with_tx_completion(mut self, tx_completion: TxCompletionConfig) -> Self {
+        self.tx_completion = tx_completion;
+        self
+    }
+
+    pub fn set_tx_completion(&mut self, tx_completion: TxCompletionConfig) {
+        self.tx_completion = tx_completion;
+    }
+
+    /// Enables "auto gas": the gas limits of the transactions are replaced by the simulated ones.
+    ///
+    /// Batches are simulated against the state before the batch, see `send_transactions`.
+    pub fn with_gas_estimation(mut self, gas_estimation: GasEstimationConfig) -> Self {
+        self.gas_estimation = Some(gas_estimation);
+        self
+    }
+
+    /// Pass `None` to go back to the gas limits given in the steps.
+    pub fn set_gas_estimation(&mut self, gas_estimation: Option<GasEstimationConfig>) {
+        self.gas_estimation = gas_estimation;
+    }
+
     pub async fn sleep(&mut self, duration: Duration) {
         self.waiting_time_ms += duration.as_millis() as u64;
         tokio::time::sleep(duration).await;
@@ -0,0 +1,94 @@
+use crate::Interactor;
+use log::info;
+use dharitri_sdk::data::transaction::Transaction;
+
+const DEFAULT_GAS_MULTIPLIER: f64 = 1.1;
+
+/// Configures the "auto gas" mode of the interactor.
+///
+/// Before broadcasting, each transaction is simulated by the network, and its gas limit is replaced
+/// by the simulated gas consumption. Transactions that fail in simulation are never broadcast,
+/// except in batches, where a transaction can depend on an earlier one that was not yet executed
+/// at simulation time; there, the gas limit given by the caller is kept instead, if there is one.
+#[derive(Clone, Debug)]
+pub struct GasEstimationConfig {
+    /// The simulated gas gets multiplied by this, as a safety margin.
+    pub gas_multiplier: f64,
+}
+
+impl Default for GasEstimationConfig {
+    fn default() -> Self {
+        GasEstimationConfig {
+            gas_multiplier: DEFAULT_GAS_MULTIPLIER,
+        }
+    }
+}
+
+impl GasEstimationConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn gas_multiplier(mut self, gas_multiplier: f64) -> Self {
+        assert!(
+            gas_multiplier >= 1.0,
+            "the gas multiplier cannot be less than 1"
+        );
+        self.gas_multiplier = gas_multiplier;
+        self
+    }
+
+    pub(crate) fn apply_gas_multiplier(&self, simulated_gas: u64) -> u64 {
+        (simulated_gas as f64 * self.gas_multiplier).round() as u64
+    }
+}
+
+impl Interactor {
+    /// Replaces the gas limit of the transaction with the simulated one, if auto gas is enabled.
+    ///
+    /// Needs to happen before the nonce is reserved, so that a transaction failing in simulation
+    /// leaves no nonce gap, and before the guardian and relayer are set, since they add to the gas limit.
+    pub(crate) async fn estimate_gas(&self, transaction: &mut Transaction) -> Result<(), String> {
+        let config = if let Some(config) = &self.gas_estimation {
+            config
+        } else {
+            return Ok(());
+        };
+
+        let cost = self
+            .proxy
+            .request_transaction_cost(transaction)
+            .await
+            .map_err(|err| format!("transaction simulation failed: {err}"))?;
+        if !cost.return_message.is_empty() {
+            return Err(format!(
+                "transaction simulation failed: {}",
+                cost.return_message
+            ));
+        }
+
+        transaction.gas_limit = config.apply_gas_multiplier(cost.tx_gas_units);
+        info!(
+            "simulated gas: {}, gas limit: {}",
+            cost.tx_gas_units, transaction.gas_limit
+        );
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apply_gas_multiplier() {
+        let config = GasEstimationConfig::new();
+        assert_eq!(config.apply_gas_multiplier(1_000_000), 1_100_000);
+
+        let config = GasEstimationConfig::new().gas_multiplier(1.0);
+        assert_eq!(config.apply_gas_multiplier(50_000), 50_000);
+
+        let config = GasEstimationConfig::new().gas_multiplier(1.5);
+        assert_eq!(config.apply_gas_multiplier(3), 5);
+    }
+}
@@ -1,7 +1,4 @@
-use crate::{
-    interactor_multi_sc_process::{update_nonces_and_sign_tx, SenderSet, Txs},
-    Interactor, StepBuffer, TransactionSpec,
-};
+use crate::{Interactor, StepBuffer};
 
 use dharitri_sc_scenario::scenario_model::TxResponse;
 use dharitri_sdk::data::transaction::Transaction;
@@ -12,11 +9,8 @@ impl Interactor {
             step.run_step(&mut self.pre_runners);
         }
 
-        let senders = retrieve_senders(buffer.refs.as_slice());
-        self.recall_senders_nonce(senders).await;
-
-        let txs = self.retrieve_txs(&mut buffer);
-        let results = self.process_txs(txs).await;
+        let txs = self.retrieve_txs(&buffer);
+        let results = self.send_transactions(txs).await;
 
         for (i, sc_call_step) in buffer.refs.iter_mut().enumerate() {
             sc_call_step.set_response(TxResponse::from_network_tx(results.get(i).unwrap().clone()));
@@ -27,30 +21,11 @@ impl Interactor {
         }
     }
 
-    fn retrieve_txs(&mut self, buffer: &mut StepBuffer<'_>) -> Vec<Transaction> {
-        let mut txs = Txs::new();
-
-        for sc_call_step in &mut buffer.refs {
-            let mut transaction = sc_call_step.to_transaction(self);
-            let sender_address = &sc_call_step.to_address().value;
-            let sender = self
-                .sender_map
-                .get_mut(sender_address)
-                .expect("sender not registered");
-
-            update_nonces_and_sign_tx(&mut transaction, sender);
-            txs.push(transaction);
-        }
-        txs
-    }
-}
-
-fn retrieve_senders(sc_call_steps: &[&mut dyn TransactionSpec]) -> SenderSet {
-    let mut senders = SenderSet::new();
-
-    for sc_call_step in sc_call_steps {
-        let sender_address = &sc_call_step.to_address().value;
-        senders.insert(sender_address.clone());
+    fn retrieve_txs(&self, buffer: &StepBuffer<'_>) -> Vec<Transaction> {
+        buffer
+            .refs
+            .iter()
+            .map(|sc_call_step| sc_call_step.to_transaction(self))
+            .collect()
     }
-    senders
 }
@@ -1,47 +1,77 @@
-use crate::{dharitri_sc::types::Address, Interactor, Sender};
-use futures::future::join_all;
+use crate::{erdrs_address_to_h256, Interactor};
 use dharitri_sdk::data::transaction::{Transaction, TransactionOnNetwork};
-use std::collections::HashSet;
+use futures::stream::{self, StreamExt};
+use log::warn;
 
-pub(crate) type Txs = Vec<Transaction>;
-pub(crate) type SenderSet = HashSet<Address>;
+/// Maximum number of transactions sent to the gateway in one request.
+const MAX_TXS_PER_BATCH: usize = 100;
+
+/// Maximum number of transactions whose results are polled at the same time.
+const MAX_CONCURRENT_TX_RETRIEVALS: usize = 100;
 
 impl Interactor {
-    pub(crate) async fn recall_senders_nonce(&mut self, senders: HashSet<Address>) {
-        for sender_address in &senders {
-            let nonce = self.recall_nonce(sender_address).await;
-            let sender = self
-                .sender_map
-                .get_mut(sender_address)
-                .expect("sender not registered");
-
-            sender.current_nonce = Some(nonce);
-            println!("sender's recalled nonce: {nonce}");
-        }
+    /// Signs and sends many transactions at once, then waits for all of them to complete.
+    ///
+    /// Nonces are reserved locally, so the transactions of a sender do not wait for one another.
+    /// All senders need to be registered. The results come in the same order as the transactions.
+    ///
+    /// With auto gas, all transactions are simulated against the state before the batch,
+    /// so a transaction depending on an earlier one in the same batch (e.g. a call to a contract
+    /// deployed by it) can fail in simulation. Such a transaction keeps the gas limit it was given,
+    /// and only panics if it was given none.
+    pub async fn send_transactions(&mut self, txs: Vec<Transaction>) -> Vec<TransactionOnNetwork> {
+        let tx_hashes = self.sign_and_send_txs(txs).await;
+
+        stream::iter(
+            tx_hashes
+                .into_iter()
+                .map(|tx_hash| self.retrieve_tx_on_network(tx_hash)),
+        )
+        .buffered(MAX_CONCURRENT_TX_RETRIEVALS)
+        .collect()
+        .await
     }
 
-    pub(crate) async fn process_txs(&mut self, txs: Vec<Transaction>) -> Vec<TransactionOnNetwork> {
-        let mut futures = Vec::new();
+    pub(crate) async fn sign_and_send_txs(&mut self, mut txs: Vec<Transaction>) -> Vec<String> {
+        // all simulated first, so that no nonce gets reserved if any of them fails
+        for tx in txs.iter_mut() {
+            if let Err(err) = self.estimate_gas(tx).await {
+                assert!(tx.gas_limit > 0, "{err}");
+                warn!("{err}, keeping the given gas limit: {}", tx.gas_limit);
+            }
+        }
+        for tx in txs.iter_mut() {
+            let sender_address = erdrs_address_to_h256(tx.sender.clone());
+            self.set_nonce_and_sign_tx(&sender_address, tx).await;
+        }
 
-        for tx in &txs {
-            let tx_hash = self
+        let mut tx_hashes = Vec::with_capacity(txs.len());
+        for batch in txs.chunks(MAX_TXS_PER_BATCH) {
+            let batch = batch.to_vec();
+            let batch_tx_hashes = self
                 .proxy
-                .send_transaction(tx)
+                .send_transactions_indexed(&batch)
                 .await
-                .expect("failed to send transaction");
+                .expect("failed to send transactions");
 
-            println!("process tx hash: {tx_hash} with nonce: {}", tx.nonce);
-            futures.push(self.retrieve_tx_on_network(tx_hash.clone()));
-        }
+            for (tx, tx_hash) in batch.into_iter().zip(batch_tx_hashes) {
+                let nonce = tx.nonce;
+                let sender_address = erdrs_address_to_h256(tx.sender.clone());
+                let tx_hash = match tx_hash {
+                    Some(tx_hash) => {
+                        self.sender_mut(&sender_address)
+                            .in_flight_nonces
+                            .insert(nonce);
+                        tx_hash
+                    },
+                    // sent again on its own, at the same nonce if still unused, to leave no nonce gap
+                    None => self.send_signed_tx(&sender_address, tx).await,
+                };
 
-        join_all(futures).await
+                println!("process tx hash: {tx_hash} with nonce: {nonce}");
+                tx_hashes.push(tx_hash);
+            }
+        }
+        tx_hashes
     }
 }
-
-pub(crate) fn update_nonces_and_sign_tx(transaction: &mut Transaction, sender: &mut Sender) {
-    transaction.nonce = sender.current_nonce.unwrap();
-    sender.current_nonce = Some(sender.current_nonce.unwrap() + 1);
-
-    let signature = sender.wallet.sign_tx(&*transaction);
-    transaction.signature = Some(hex::encode(signature));
-}
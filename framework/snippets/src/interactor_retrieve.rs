@@ -1,64 +1,85 @@
-use crate::Interactor;
+use crate::{is_tx_completed, Interactor};
 use log::info;
 use dharitri_sdk::data::transaction::TransactionOnNetwork;
-use std::time::Duration;
-
-const TX_GET_RESULTS_NUM_RETRIES: usize = 8;
-const EXTRA_WAITING_TIME_MS: Duration = Duration::from_millis(8000);
-const WAITING_TIME_MS: Duration = Duration::from_secs(25);
-const WAIT: u64 = 1000;
+use std::time::Instant;
 
 impl Interactor {
-    /// Retrieves a transaction from the network.
+    /// Retrieves a transaction from the network, once it is completed.
+    ///
+    /// How long and how often to poll is configured in the interactor's `TxCompletionConfig`.
     pub(crate) async fn retrieve_tx_on_network(&self, tx_hash: String) -> TransactionOnNetwork {
-        let mut waiting_time_ms = 0;
-        let mut break_outer = false;
-        sleep(&mut waiting_time_ms, WAITING_TIME_MS).await;
-
-        let tx = 'outer: loop {
-            let mut retries = TX_GET_RESULTS_NUM_RETRIES;
-            let mut wait = WAIT;
-            loop {
-                let tx_info_result = self.proxy.get_transaction_info_with_results(&tx_hash).await;
-                match tx_info_result {
-                    Ok(tx) => {
-                        if break_outer {
-                            break 'outer tx;
-                        }
-
-                        // reset waiting time
-                        waiting_time_ms = WAITING_TIME_MS.as_millis() as u64;
-
-                        tokio::time::sleep(EXTRA_WAITING_TIME_MS).await;
-                        break_outer = true;
+        let start = Instant::now();
+        let mut polling_interval = self.tx_completion.polling_interval;
 
-                        break;
-                    },
-                    Err(err) => {
-                        assert!(
-                            retries > 0,
-                            "still no answer after {TX_GET_RESULTS_NUM_RETRIES} retries"
-                        );
+        let tx = loop {
+            tokio::time::sleep(polling_interval).await;
 
-                        info!(
-                            "tx result fetch error after {} ms: {}",
-                            self.waiting_time_ms, err
-                        );
-                        retries -= 1;
-                        sleep(&mut waiting_time_ms, Duration::from_millis(wait)).await;
-                        wait *= 2;
-                    },
-                }
+            match self.proxy.get_transaction_info_with_results(&tx_hash).await {
+                Ok(tx) => {
+                    if is_tx_completed(&tx) {
+                        break tx;
+                    }
+                    info!("tx {} not yet completed, status: {}", tx_hash, tx.status);
+                },
+                Err(err) => {
+                    info!(
+                        "tx result fetch error after {} ms: {}",
+                        start.elapsed().as_millis(),
+                        err
+                    );
+                },
             }
+
+            self.check_tx_completion_timeout(&tx_hash, start);
+            polling_interval = self.tx_completion.next_polling_interval(polling_interval);
         };
 
+        self.wait_for_confirmations(&tx_hash, &tx, start).await;
+
         info!("tx with results: {:#?}", tx);
         tx
     }
-}
 
-/// Sleeps for the given duration and adds the duration to the waiting time.
-pub async fn sleep(waiting_time_ms: &mut u64, duration: Duration) {
-    *waiting_time_ms += duration.as_millis() as u64;
-    tokio::time::sleep(duration).await;
+    async fn wait_for_confirmations(
+        &self,
+        tx_hash: &str,
+        tx: &TransactionOnNetwork,
+        start: Instant,
+    ) {
+        let confirmations = self.tx_completion.confirmations;
+        if confirmations == 0 {
+            return;
+        }
+
+        let target_nonce = tx.hyperblock_nonce + confirmations;
+        let mut polling_interval = self.tx_completion.polling_interval;
+        loop {
+            match self.proxy.get_latest_hyper_block_nonce(true).await {
+                Ok(nonce) if nonce >= target_nonce => return,
+                Ok(nonce) => {
+                    info!(
+                        "tx {} has {} out of {} confirmations",
+                        tx_hash,
+                        nonce.saturating_sub(tx.hyperblock_nonce),
+                        confirmations
+                    );
+                },
+                Err(err) => {
+                    info!("latest hyperblock nonce fetch error: {}", err);
+                },
+            }
+
+            self.check_tx_completion_timeout(tx_hash, start);
+            tokio::time::sleep(polling_interval).await;
+            polling_interval = self.tx_completion.next_polling_interval(polling_interval);
+        }
+    }
+
+    fn check_tx_completion_timeout(&self, tx_hash: &str, start: Instant) {
+        assert!(
+            start.elapsed() < self.tx_completion.timeout,
+            "tx {tx_hash} still not completed after {} ms",
+            self.tx_completion.timeout.as_millis()
+        );
+    }
 }
@@ -35,10 +35,8 @@ impl Interactor {
         self.pre_runners.run_sc_call_step(sc_call_step);
 
         let sender_address = &sc_call_step.tx.from.value;
-        let mut transaction = self.tx_call_to_blockchain_tx(&sc_call_step.tx);
-        self.set_nonce_and_sign_tx(sender_address, &mut transaction)
-            .await;
-        let tx_hash = self.proxy.send_transaction(&transaction).await.unwrap();
+        let transaction = self.tx_call_to_blockchain_tx(&sc_call_step.tx);
+        let tx_hash = self.sign_and_send_tx(sender_address, transaction).await;
         println!("sc call tx hash: {tx_hash}");
         info!("sc call tx hash: {}", tx_hash);
 
@@ -66,6 +64,10 @@ impl Interactor {
             chain_id: self.network_config.chain_id.clone(),
             version: self.network_config.min_transaction_version,
             options: 0,
+            guardian: None,
+            guardian_signature: None,
+            relayer: None,
+            relayer_signature: None,
         }
     }
 }
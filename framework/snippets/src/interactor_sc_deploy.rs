@@ -23,6 +23,10 @@ impl Interactor {
             chain_id: self.network_config.chain_id.clone(),
             version: self.network_config.min_transaction_version,
             options: 0,
+            guardian: None,
+            guardian_signature: None,
+            relayer: None,
+            relayer_signature: None,
         }
     }
 
@@ -30,14 +34,8 @@ impl Interactor {
         self.pre_runners.run_sc_deploy_step(sc_deploy_step);
 
         let sender_address = &sc_deploy_step.tx.from.value;
-        let mut transaction = self.sc_deploy_to_blockchain_tx(sc_deploy_step);
-        self.set_nonce_and_sign_tx(sender_address, &mut transaction)
-            .await;
-        let tx_hash = self
-            .proxy
-            .send_transaction(&transaction)
-            .await
-            .expect("error sending tx (possible API failure)");
+        let transaction = self.sc_deploy_to_blockchain_tx(sc_deploy_step);
+        let tx_hash = self.sign_and_send_tx(sender_address, transaction).await;
         println!("sc deploy tx hash: {tx_hash}");
         info!("sc deploy tx hash: {}", tx_hash);
 
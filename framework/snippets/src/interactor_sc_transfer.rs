@@ -7,10 +7,8 @@ impl Interactor {
         self.pre_runners.run_transfer_step(&transfer_step);
 
         let sender_address = &transfer_step.tx.from.value;
-        let mut transaction = self.tx_call_to_blockchain_tx(&transfer_step.tx.to_tx_call());
-        self.set_nonce_and_sign_tx(sender_address, &mut transaction)
-            .await;
-        let tx_hash = self.proxy.send_transaction(&transaction).await.unwrap();
+        let transaction = self.tx_call_to_blockchain_tx(&transfer_step.tx.to_tx_call());
+        let tx_hash = self.sign_and_send_tx(sender_address, transaction).await;
         println!("transfer tx hash: {tx_hash}");
         info!("transfer tx hash: {}", tx_hash);
 
@@ -1,14 +1,37 @@
-use log::debug;
 use dharitri_sc_scenario::dharitri_sc::types::Address;
-use dharitri_sdk::{data::transaction::Transaction, wallet::Wallet};
+use dharitri_sdk::{
+    data::transaction::{Transaction, GUARDED_TX_EXTRA_GAS_LIMIT},
+    relayed::RelayedTransactionBuilder,
+    signer::Signer,
+};
+use log::{debug, info};
+use std::{collections::BTreeSet, time::Duration};
 
 use crate::{address_h256_to_erdrs, Interactor};
 
-/// A user account that can sign transactions (a pem is present).
+/// How many times a transaction is sent again, after it was rejected because of its nonce.
+///
+/// Each retry waits for a round first, except when the nonce was already used on the network.
+const NONCE_RESYNC_RETRIES: usize = 3;
+
+/// Rejection reasons reported by the network when the local nonce is out of sync.
+const NONCE_ERRORS: &[&str] = &[
+    "nonce too low",
+    "nonce too high",
+    "lower nonce in transaction",
+    "higher nonce in transaction",
+];
+
+/// A user account that can sign transactions, either with a wallet or through a remote signer.
 pub struct Sender {
     pub address: Address,
-    pub wallet: Wallet,
+    pub signer: Box<dyn Signer>,
+    /// The next nonce to be used, reserved locally. Recalled from the network if missing.
     pub current_nonce: Option<u64>,
+    /// Nonces of the transactions accepted by the network, that were not seen executed yet.
+    pub in_flight_nonces: BTreeSet<u64>,
+    /// Co-signs all transactions, if the account is guarded.
+    pub guardian: Option<Box<dyn Signer>>,
 }
 
 impl Interactor {
@@ -22,35 +45,229 @@ impl Interactor {
         account.nonce
     }
 
+    /// Discards the locally tracked nonce of the sender and recalls it from the network.
+    ///
+    /// Only safe when none of the sender's transactions are still in flight.
+    pub async fn resync_nonce(&mut self, sender_address: &Address) {
+        let nonce = self.recall_nonce(sender_address).await;
+        println!("sender's recalled nonce: {nonce}");
+
+        let sender = self
+            .sender_map
+            .get_mut(sender_address)
+            .expect("the wallet that was supposed to sign is not registered");
+        sender.current_nonce = Some(nonce);
+    }
+
+    /// Reserves the next nonce of the sender, without waiting for the previous transactions to complete.
+    pub(crate) async fn reserve_nonce(&mut self, sender_address: &Address) -> u64 {
+        let current_nonce = self
+            .sender_map
+            .get(sender_address)
+            .expect("the wallet that was supposed to sign is not registered")
+            .current_nonce;
+        if current_nonce.is_none() {
+            self.resync_nonce(sender_address).await;
+        }
+
+        let sender = self.sender_map.get_mut(sender_address).unwrap();
+        let nonce = sender.current_nonce.unwrap();
+        sender.current_nonce = Some(nonce + 1);
+        nonce
+    }
+
     pub(crate) async fn set_nonce_and_sign_tx(
         &mut self,
         sender_address: &Address,
         transaction: &mut Transaction,
     ) {
-        // read
+        transaction.nonce = self.reserve_nonce(sender_address).await;
+        println!("-- tx nonce: {}", transaction.nonce);
+
+        self.sign_tx(sender_address, transaction).await;
+    }
+
+    pub(crate) async fn sign_tx(&self, sender_address: &Address, transaction: &mut Transaction) {
         let sender = self
             .sender_map
             .get(sender_address)
             .expect("the wallet that was supposed to sign is not registered");
 
-        // recall
-        let nonce = self.recall_nonce(&sender.address).await;
-        println!("sender's recalled nonce: {nonce}");
+        // guardian and relayer need to be set before signing, only once if the transaction gets signed again
+        transaction.signature = None;
+        if let Some(guardian) = &sender.guardian {
+            if transaction.guardian.is_none() {
+                transaction.set_guardian(guardian.address());
+                transaction.gas_limit += GUARDED_TX_EXTRA_GAS_LIMIT;
+            }
+        }
+        if let Some(relayer) = &self.relayer {
+            if transaction.relayer.is_none() {
+                RelayedTransactionBuilder::new(&self.network_config)
+                    .relayed_v3(transaction, &relayer.address())
+                    .expect("could not set the transaction relayer");
+            }
+        }
 
-        // set tx nonce
-        transaction.nonce = nonce;
-        println!("-- tx nonce: {}", transaction.nonce);
+        // sign
+        transaction
+            .sign(sender.signer.as_ref())
+            .await
+            .unwrap_or_else(|err| panic!("could not sign the transaction: {err}"));
+        if let Some(guardian) = &sender.guardian {
+            transaction
+                .guardian_sign(guardian.as_ref())
+                .await
+                .unwrap_or_else(|err| {
+                    panic!("could not co-sign the transaction as guardian: {err}")
+                });
+        }
+        if let Some(relayer) = &self.relayer {
+            let relayer_signature = relayer
+                .sign_tx(transaction)
+                .await
+                .unwrap_or_else(|err| panic!("could not sign the transaction as relayer: {err}"));
+            transaction.relayer_signature = Some(hex::encode(relayer_signature));
+        }
+        debug!("transaction {:#?}", transaction);
+    }
 
-        // update
-        let sender = self
-            .sender_map
+    /// Estimates the gas, sets the nonce, signs and sends the transaction.
+    ///
+    /// Panics if the simulation fails, before any nonce is reserved.
+    /// If the network rejects the nonce, the transaction is sent again, see `send_signed_tx`.
+    pub(crate) async fn sign_and_send_tx(
+        &mut self,
+        sender_address: &Address,
+        mut transaction: Transaction,
+    ) -> String {
+        self.estimate_gas(&mut transaction)
+            .await
+            .unwrap_or_else(|err| panic!("{err}"));
+        self.set_nonce_and_sign_tx(sender_address, &mut transaction)
+            .await;
+        self.send_signed_tx(sender_address, transaction).await
+    }
+
+    /// Sends a transaction that already has its nonce reserved.
+    ///
+    /// If the network rejects the nonce because it was already used, the transaction gets a fresh one.
+    /// Otherwise the nonce is ahead of the network: the transaction is sent again after a round,
+    /// giving the transactions with the earlier nonces time to arrive.
+    /// If none of them is in flight, they were abandoned, so the tracker is reset to the network nonce.
+    /// Either way, the nonces reserved for the other transactions in flight stay valid.
+    pub(crate) async fn send_signed_tx(
+        &mut self,
+        sender_address: &Address,
+        mut transaction: Transaction,
+    ) -> String {
+        let mut retries = NONCE_RESYNC_RETRIES;
+        loop {
+            let err = match self.proxy.send_transaction(&transaction).await {
+                Ok(tx_hash) => {
+                    self.sender_mut(sender_address)
+                        .in_flight_nonces
+                        .insert(transaction.nonce);
+                    return tx_hash;
+                },
+                Err(err) => err.to_string(),
+            };
+            assert!(
+                retries > 0 && is_nonce_error(&err),
+                "failed to send transaction: {err}"
+            );
+            retries -= 1;
+
+            let mut network_nonce = self.recall_nonce(sender_address).await;
+            if transaction.nonce >= network_nonce {
+                info!(
+                    "tx with nonce {} rejected, sending it again after a round: {}",
+                    transaction.nonce, err
+                );
+                self.wait_one_round().await;
+                network_nonce = self.recall_nonce(sender_address).await;
+            }
+            if transaction.nonce >= network_nonce {
+                if self.has_earlier_nonce_in_flight(
+                    sender_address,
+                    network_nonce,
+                    transaction.nonce,
+                ) {
+                    continue;
+                }
+
+                transaction.nonce = network_nonce;
+                self.sender_mut(sender_address).current_nonce = Some(network_nonce + 1);
+                info!(
+                    "no earlier nonce in flight, signing again with the network nonce {}",
+                    transaction.nonce
+                );
+                self.sign_tx(sender_address, &mut transaction).await;
+                continue;
+            }
+
+            transaction.nonce = self.reserve_nonce_after(sender_address, network_nonce);
+            info!(
+                "tx nonce already used on the network, signing again with nonce {}: {}",
+                transaction.nonce, err
+            );
+            self.sign_tx(sender_address, &mut transaction).await;
+        }
+    }
+
+    async fn wait_one_round(&self) {
+        let round_duration = Duration::from_millis(self.network_config.round_duration as u64);
+        tokio::time::sleep(round_duration).await;
+    }
+
+    /// Checks whether any transaction with a nonce between the network nonce and the given one is still in flight.
+    ///
+    /// The nonces below the network nonce are no longer in flight, so they are also discarded.
+    fn has_earlier_nonce_in_flight(
+        &mut self,
+        sender_address: &Address,
+        network_nonce: u64,
+        nonce: u64,
+    ) -> bool {
+        let sender = self.sender_mut(sender_address);
+        sender.in_flight_nonces = sender.in_flight_nonces.split_off(&network_nonce);
+        sender.in_flight_nonces.range(..nonce).next().is_some()
+    }
+
+    pub(crate) fn sender_mut(&mut self, sender_address: &Address) -> &mut Sender {
+        self.sender_map
             .get_mut(sender_address)
-            .expect("the wallet that was supposed to sign is not registered");
+            .expect("the wallet that was supposed to sign is not registered")
+    }
+
+    /// Reserves the next nonce of the sender, skipping the nonces already used on the network.
+    ///
+    /// The locally reserved nonces are never reused, since their transactions might still be in flight.
+    fn reserve_nonce_after(&mut self, sender_address: &Address, network_nonce: u64) -> u64 {
+        let sender = self.sender_mut(sender_address);
+        let nonce = sender.current_nonce.unwrap_or_default().max(network_nonce);
         sender.current_nonce = Some(nonce + 1);
+        nonce
+    }
+}
 
-        // sign
-        let signature = sender.wallet.sign_tx(transaction);
-        transaction.signature = Some(hex::encode(signature));
-        debug!("transaction {:#?}", transaction);
+fn is_nonce_error(err: &str) -> bool {
+    let err = err.to_lowercase();
+    NONCE_ERRORS
+        .iter()
+        .any(|nonce_error| err.contains(nonce_error))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_nonce_error() {
+        assert!(is_nonce_error(
+            "transaction generation failed: nonce too low"
+        ));
+        assert!(is_nonce_error("Lower nonce in transaction"));
+        assert!(!is_nonce_error("insufficient funds"));
     }
 }
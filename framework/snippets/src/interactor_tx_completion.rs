@@ -0,0 +1,284 @@
+use dharitri_sdk::data::{transaction::TransactionOnNetwork, vm::CallType};
+use std::time::Duration;
+
+const DEFAULT_POLLING_INTERVAL: Duration = Duration::from_millis(1000);
+const DEFAULT_MAX_POLLING_INTERVAL: Duration = Duration::from_millis(6000);
+const DEFAULT_BACKOFF_MULTIPLIER: u32 = 2;
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(180);
+
+const PENDING_STATUSES: &[&str] = &["pending", "received", "partially-executed"];
+const COMPLETION_EVENTS: &[&str] = &["completedTxEvent", "signalError", "internalVMErrors"];
+
+/// Configures how the interactor waits for the transactions it sent to complete.
+///
+/// The transaction is polled until it is final, together with all its smart contract results,
+/// then optionally until enough hyperblocks were notarized on top of it.
+#[derive(Clone, Debug)]
+pub struct TxCompletionConfig {
+    /// Time until the first poll, and between the first polls.
+    pub polling_interval: Duration,
+
+    /// After each poll, the polling interval gets multiplied by this, until it reaches the maximum.
+    pub backoff_multiplier: u32,
+
+    pub max_polling_interval: Duration,
+
+    /// The interactor panics if the transaction is not completed in this time.
+    pub timeout: Duration,
+
+    /// Number of hyperblocks that need to be notarized after the one containing the transaction.
+    pub confirmations: u64,
+}
+
+impl Default for TxCompletionConfig {
+    fn default() -> Self {
+        TxCompletionConfig {
+            polling_interval: DEFAULT_POLLING_INTERVAL,
+            backoff_multiplier: DEFAULT_BACKOFF_MULTIPLIER,
+            max_polling_interval: DEFAULT_MAX_POLLING_INTERVAL,
+            timeout: DEFAULT_TIMEOUT,
+            confirmations: 0,
+        }
+    }
+}
+
+impl TxCompletionConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn polling_interval(mut self, polling_interval: Duration) -> Self {
+        self.polling_interval = polling_interval;
+        self
+    }
+
+    /// Set the multiplier to 1 to poll at a fixed interval.
+    pub fn backoff(mut self, multiplier: u32, max_polling_interval: Duration) -> Self {
+        self.backoff_multiplier = multiplier;
+        self.max_polling_interval = max_polling_interval;
+        self
+    }
+
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    pub fn confirmations(mut self, confirmations: u64) -> Self {
+        self.confirmations = confirmations;
+        self
+    }
+
+    pub(crate) fn next_polling_interval(&self, polling_interval: Duration) -> Duration {
+        let next = polling_interval * self.backoff_multiplier;
+        if next > self.max_polling_interval {
+            self.max_polling_interval.max(polling_interval)
+        } else {
+            next
+        }
+    }
+}
+
+/// A transaction is complete once it is notarized by the metachain
+/// and no more smart contract results are expected for it.
+pub fn is_tx_completed(tx: &TransactionOnNetwork) -> bool {
+    if PENDING_STATUSES.contains(&tx.status.as_str()) || tx.hyperblock_nonce == 0 {
+        return false;
+    }
+
+    if let Some(logs) = &tx.logs {
+        if logs
+            .events
+            .iter()
+            .any(|event| COMPLETION_EVENTS.contains(&event.identifier.as_str()))
+        {
+            return true;
+        }
+    }
+
+    // every cross-shard async call must have its callback executed
+    match &tx.smart_contract_results {
+        Some(scrs) => {
+            let num_async_calls = scrs
+                .iter()
+                .filter(|scr| matches!(scr.call_type, CallType::AsynchronousCall))
+                .count();
+            let num_callbacks = scrs
+                .iter()
+                .filter(|scr| matches!(scr.call_type, CallType::AsynchronousCallBack))
+                .count();
+            num_callbacks >= num_async_calls
+        },
+        None => true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dharitri_sdk::data::{
+        address::Address,
+        transaction::{ApiLogs, ApiSmartContractResult, Events},
+    };
+
+    fn tx_on_network(status: &str, hyperblock_nonce: u64) -> TransactionOnNetwork {
+        TransactionOnNetwork {
+            kind: "normal".to_string(),
+            hash: Some("tx-hash".to_string()),
+            nonce: 7,
+            round: 0,
+            epoch: 0,
+            value: "0".to_string(),
+            receiver: Address::from_bytes([1u8; 32]),
+            sender: Address::from_bytes([2u8; 32]),
+            gas_price: 1_000_000_000,
+            gas_limit: 5_000_000,
+            signature: String::new(),
+            source_shard: 0,
+            destination_shard: 1,
+            block_nonce: 0,
+            block_hash: String::new(),
+            notarized_at_source_in_meta_nonce: 0,
+            notarized_at_source_in_meta_hash: String::new(),
+            notarized_at_destination_in_meta_nonce: 0,
+            notarized_at_destination_in_meta_hash: String::new(),
+            miniblock_type: String::new(),
+            miniblock_hash: String::new(),
+            timestamp: 0,
+            data: None,
+            status: status.to_string(),
+            hyperblock_nonce,
+            hyperblock_hash: String::new(),
+            smart_contract_results: None,
+            logs: None,
+        }
+    }
+
+    fn with_events(mut tx: TransactionOnNetwork, identifiers: &[&str]) -> TransactionOnNetwork {
+        tx.logs = Some(ApiLogs {
+            address: tx.receiver.clone(),
+            events: identifiers
+                .iter()
+                .map(|identifier| Events {
+                    address: tx.receiver.clone(),
+                    identifier: identifier.to_string(),
+                    topics: None,
+                    data: None,
+                })
+                .collect(),
+        });
+        tx
+    }
+
+    fn with_scrs(mut tx: TransactionOnNetwork, call_types: Vec<CallType>) -> TransactionOnNetwork {
+        tx.smart_contract_results = Some(
+            call_types
+                .into_iter()
+                .map(|call_type| ApiSmartContractResult {
+                    hash: "scr-hash".to_string(),
+                    nonce: 0,
+                    value: 0,
+                    receiver: tx.receiver.clone(),
+                    sender: tx.sender.clone(),
+                    data: String::new(),
+                    prev_tx_hash: "tx-hash".to_string(),
+                    original_tx_hash: "tx-hash".to_string(),
+                    gas_limit: 0,
+                    gas_price: 0,
+                    call_type,
+                    relayer_address: None,
+                    relayed_value: None,
+                    code: None,
+                    code_metadata: None,
+                    return_message: None,
+                    original_sender: None,
+                })
+                .collect(),
+        );
+        tx
+    }
+
+    #[test]
+    fn test_is_tx_completed_pending() {
+        for status in PENDING_STATUSES {
+            assert!(!is_tx_completed(&tx_on_network(status, 10)));
+        }
+        // executed, but not notarized by the metachain yet
+        assert!(!is_tx_completed(&tx_on_network("success", 0)));
+    }
+
+    #[test]
+    fn test_is_tx_completed_success() {
+        assert!(is_tx_completed(&tx_on_network("success", 10)));
+        assert!(is_tx_completed(&with_events(
+            tx_on_network("success", 10),
+            &["writeLog", "completedTxEvent"]
+        )));
+    }
+
+    #[test]
+    fn test_is_tx_completed_failure() {
+        assert!(is_tx_completed(&tx_on_network("fail", 10)));
+        assert!(is_tx_completed(&with_events(
+            tx_on_network("fail", 10),
+            &["signalError"]
+        )));
+        assert!(is_tx_completed(&with_events(
+            tx_on_network("invalid", 10),
+            &["internalVMErrors"]
+        )));
+    }
+
+    #[test]
+    fn test_is_tx_completed_cross_shard_scrs() {
+        // the async call reached the other shard, its callback did not come back yet
+        let tx = with_scrs(
+            tx_on_network("success", 10),
+            vec![CallType::DirectCall, CallType::AsynchronousCall],
+        );
+        assert!(!is_tx_completed(&tx));
+        assert!(!is_tx_completed(&with_events(tx.clone(), &["writeLog"])));
+
+        let tx = with_scrs(
+            tx_on_network("success", 10),
+            vec![
+                CallType::DirectCall,
+                CallType::AsynchronousCall,
+                CallType::AsynchronousCallBack,
+            ],
+        );
+        assert!(is_tx_completed(&tx));
+
+        // the completion event is final, whatever the results received so far
+        let tx = with_scrs(
+            tx_on_network("success", 10),
+            vec![CallType::AsynchronousCall],
+        );
+        assert!(is_tx_completed(&with_events(tx, &["completedTxEvent"])));
+    }
+
+    #[test]
+    fn test_next_polling_interval() {
+        let config = TxCompletionConfig::new()
+            .polling_interval(Duration::from_millis(500))
+            .backoff(3, Duration::from_millis(4000));
+        assert_eq!(
+            config.next_polling_interval(Duration::from_millis(500)),
+            Duration::from_millis(1500)
+        );
+        assert_eq!(
+            config.next_polling_interval(Duration::from_millis(1500)),
+            Duration::from_millis(4000)
+        );
+        assert_eq!(
+            config.next_polling_interval(Duration::from_millis(4000)),
+            Duration::from_millis(4000)
+        );
+
+        let fixed = TxCompletionConfig::new().backoff(1, Duration::from_millis(0));
+        assert_eq!(
+            fixed.next_polling_interval(Duration::from_millis(1000)),
+            Duration::from_millis(1000)
+        );
+    }
+}
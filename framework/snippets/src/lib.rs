@@ -1,5 +1,6 @@
 mod interactor;
 mod interactor_dns;
+mod interactor_gas_estimation;
 mod interactor_multi_sc_exec;
 mod interactor_multi_sc_process;
 mod interactor_retrieve;
@@ -8,6 +9,7 @@ mod interactor_sc_deploy;
 mod interactor_sc_extra;
 mod interactor_sc_transfer;
 mod interactor_sender;
+mod interactor_tx_completion;
 mod interactor_tx_spec;
 mod interactor_vm_query;
 mod step_buffer;
@@ -16,7 +18,9 @@ pub use env_logger;
 pub use hex;
 pub use interactor::*;
 pub use interactor_dns::*;
+pub use interactor_gas_estimation::*;
 pub use interactor_sender::*;
+pub use interactor_tx_completion::*;
 pub use interactor_tx_spec::*;
 pub use log;
 pub use dharitri_sc_scenario::{self, dharitri_sc};
@@ -0,0 +1,12 @@
+use dharitri_sdk::blockchain::{CommunicationProxy, DEVNET_GATEWAY};
+
+#[tokio::main]
+async fn main() {
+    let blockchain = CommunicationProxy::new(DEVNET_GATEWAY.to_string());
+    let token_properties = blockchain
+        .get_token_properties("WMOAX-d7c6bb")
+        .await
+        .unwrap();
+
+    println!("{token_properties:#?}");
+}
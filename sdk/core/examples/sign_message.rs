@@ -0,0 +1,32 @@
+use dharitri_sdk::{
+    blockchain::{CommunicationProxy, DEVNET_GATEWAY},
+    message::verify_message,
+    native_auth::{NativeAuthClient, NativeAuthServer},
+    wallet::Wallet,
+};
+
+#[tokio::main]
+async fn main() {
+    let wl = Wallet::from_private_key(
+        "db6140e6f0854ad8a0b10b3e50e94fb1259b60bb0d7c2665d597eb1970f7a3a9",
+    )
+    .unwrap();
+    let addr = wl.address();
+
+    let signature = wl.sign_message(b"hello");
+    println!("signature {}", hex::encode(signature));
+    println!("valid {}", verify_message(&addr, b"hello", &signature));
+
+    let blockchain = CommunicationProxy::new(DEVNET_GATEWAY.to_string());
+    let token = NativeAuthClient::new("https://localhost:3000")
+        .generate_token(&blockchain, &wl)
+        .await
+        .unwrap();
+    println!("native auth token {token}");
+
+    let validated = NativeAuthServer::new(vec!["https://localhost:3000".to_string()])
+        .validate(&blockchain, &token)
+        .await
+        .unwrap();
+    println!("logged in as {}", validated.address.to_string());
+}
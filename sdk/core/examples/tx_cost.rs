@@ -23,6 +23,10 @@ async fn main() {
         gas_limit: 0,
         gas_price: 0,
         signature: None,
+        guardian: None,
+        guardian_signature: None,
+        relayer: None,
+        relayer_signature: None,
     };
 
     let blockchain = CommunicationProxy::new(DEVNET_GATEWAY.to_string());
@@ -0,0 +1,480 @@
+use anyhow::{anyhow, Result};
+use num_bigint::{BigInt, BigUint};
+use serde_json::Value;
+
+use super::{
+    abi_encode::{is_fieldless_enum, variant_discriminant},
+    abi_type::OPERATION_COMPLETION_STATUSES,
+    AbiType, AbiValue, ContractAbi, StructFieldDescription,
+};
+use crate::data::address::Address;
+
+// same shard and cross shard ("ok") success codes
+const ASYNC_CALL_SUCCESS_CODES: &[u32] = &[0, 0x6f6b];
+
+// DecodedEvent is a contract event, with its topics and data decoded by name
+#[derive(Debug, Clone)]
+pub struct DecodedEvent {
+    pub identifier: String,
+    pub fields: Vec<(String, AbiValue)>,
+}
+
+impl DecodedEvent {
+    pub fn to_json(&self) -> Value {
+        AbiValue::Struct(self.fields.clone()).to_json()
+    }
+}
+
+impl ContractAbi {
+    // decode_endpoint_results decodes what an endpoint returned, one value per ABI output
+    pub fn decode_endpoint_results(
+        &self,
+        endpoint_name: &str,
+        results: &[Vec<u8>],
+    ) -> Result<Vec<AbiValue>> {
+        let endpoint = self.endpoint(endpoint_name)?;
+        let types = endpoint
+            .outputs
+            .iter()
+            .map(|output| AbiType::parse(&output.type_name))
+            .collect::<Result<Vec<_>>>()?;
+        self.decode_multi_values(&types, results)
+            .map_err(|err| anyhow!("endpoint {endpoint_name} results: {err}"))
+    }
+
+    // decode_endpoint_args decodes the arguments of an endpoint call, e.g. from transaction data
+    pub fn decode_endpoint_args(
+        &self,
+        endpoint_name: &str,
+        args: &[Vec<u8>],
+    ) -> Result<Vec<AbiValue>> {
+        let endpoint = self.endpoint(endpoint_name)?;
+        let types = endpoint
+            .inputs
+            .iter()
+            .map(|input| AbiType::parse(&input.type_name))
+            .collect::<Result<Vec<_>>>()?;
+        self.decode_multi_values(&types, args)
+            .map_err(|err| anyhow!("endpoint {endpoint_name} arguments: {err}"))
+    }
+
+    // decode_event decodes a contract event, the first topic being the event identifier.
+    // Indexed inputs are read from the other topics, the remaining input from the event data.
+    pub fn decode_event(&self, topics: &[Vec<u8>], data: &[u8]) -> Result<DecodedEvent> {
+        let identifier = topics
+            .first()
+            .map(|identifier| String::from_utf8_lossy(identifier).to_string())
+            .ok_or_else(|| anyhow!("event has no topics"))?;
+        let event = self.event(&identifier)?;
+
+        let mut topic_args = MultiArgs::new(&topics[1..]);
+        let mut fields = Vec::new();
+        for input in event.inputs.iter().filter(|input| input.indexed) {
+            let value = self
+                .multi_decode(&AbiType::parse(&input.type_name)?, &mut topic_args)
+                .map_err(|err| anyhow!("event {identifier} topic {}: {err}", input.name))?;
+            fields.push((input.name.clone(), value));
+        }
+        topic_args.expect_depleted()?;
+
+        if let Some(input) = event.inputs.iter().find(|input| !input.indexed) {
+            let value = self
+                .top_decode(&AbiType::parse(&input.type_name)?, data)
+                .map_err(|err| anyhow!("event {identifier} data {}: {err}", input.name))?;
+            fields.push((input.name.clone(), value));
+        }
+
+        Ok(DecodedEvent { identifier, fields })
+    }
+
+    // top_decode_value decodes a single value, e.g. a storage value or a single argument
+    pub fn top_decode_value(&self, type_name: &str, bytes: &[u8]) -> Result<AbiValue> {
+        self.top_decode(&AbiType::parse(type_name)?, bytes)
+    }
+
+    // nested_decode_value decodes a value encoded as part of a larger value, the whole input has to be used
+    pub fn nested_decode_value(&self, type_name: &str, bytes: &[u8]) -> Result<AbiValue> {
+        let mut input = bytes;
+        let value = self.nested_decode(&AbiType::parse(type_name)?, &mut input)?;
+        expect_depleted(input)?;
+        Ok(value)
+    }
+
+    fn decode_multi_values(&self, types: &[AbiType], args: &[Vec<u8>]) -> Result<Vec<AbiValue>> {
+        let mut args = MultiArgs::new(args);
+        let values = types
+            .iter()
+            .map(|abi_type| self.multi_decode(abi_type, &mut args))
+            .collect::<Result<Vec<_>>>()?;
+        args.expect_depleted()?;
+        Ok(values)
+    }
+
+    fn multi_decode(&self, abi_type: &AbiType, args: &mut MultiArgs) -> Result<AbiValue> {
+        match abi_type {
+            AbiType::Optional(item_type) => {
+                if args.is_depleted() {
+                    return Ok(AbiValue::Option(None));
+                }
+                Ok(AbiValue::Option(Some(Box::new(
+                    self.multi_decode(item_type, args)?,
+                ))))
+            },
+            AbiType::Variadic(item_type) => {
+                let mut items = Vec::new();
+                while !args.is_depleted() {
+                    items.push(self.multi_decode(item_type, args)?);
+                }
+                Ok(AbiValue::List(items))
+            },
+            AbiType::CountedVariadic(item_type) => {
+                let count = to_usize(&top_decode_unsigned(args.next()?, 4)?)?;
+                // every item takes at least one value, except for the empty ones, which make no sense here
+                if count > args.remaining() {
+                    return Err(anyhow!(
+                        "counted-variadic of {count} items, but only {} values left",
+                        args.remaining()
+                    ));
+                }
+                let mut items = Vec::new();
+                for _ in 0..count {
+                    items.push(self.multi_decode(item_type, args)?);
+                }
+                Ok(AbiValue::List(items))
+            },
+            AbiType::Multi(item_types) => Ok(AbiValue::Tuple(
+                item_types
+                    .iter()
+                    .map(|item_type| self.multi_decode(item_type, args))
+                    .collect::<Result<Vec<_>>>()?,
+            )),
+            AbiType::Ignore => {
+                while !args.is_depleted() {
+                    args.next()?;
+                }
+                Ok(AbiValue::Tuple(Vec::new()))
+            },
+            AbiType::AsyncCallResult(result_type) => {
+                let err_code = u32::try_from(&top_decode_unsigned(args.next()?, 4)?)?;
+                if ASYNC_CALL_SUCCESS_CODES.contains(&err_code) {
+                    return Ok(AbiValue::Enum {
+                        variant: "Ok".to_string(),
+                        fields: vec![("0".to_string(), self.multi_decode(result_type, args)?)],
+                    });
+                }
+                // the error message might be missing, same as in the framework
+                let err_msg = if args.is_depleted() {
+                    Vec::new()
+                } else {
+                    args.next()?.to_vec()
+                };
+                Ok(AbiValue::Enum {
+                    variant: "Err".to_string(),
+                    fields: vec![
+                        ("err_code".to_string(), AbiValue::UInt(err_code.into())),
+                        (
+                            "err_msg".to_string(),
+                            AbiValue::Text(String::from_utf8_lossy(&err_msg).to_string()),
+                        ),
+                    ],
+                })
+            },
+            _ => self.top_decode(abi_type, args.next()?),
+        }
+    }
+
+    fn top_decode(&self, abi_type: &AbiType, bytes: &[u8]) -> Result<AbiValue> {
+        match abi_type {
+            AbiType::Bool => match bytes {
+                [] => Ok(AbiValue::Bool(false)),
+                [1] => Ok(AbiValue::Bool(true)),
+                _ => Err(anyhow!("invalid bool: {}", hex::encode(bytes))),
+            },
+            AbiType::Int { size, signed } => {
+                if *signed {
+                    Ok(AbiValue::Int(top_decode_signed(bytes, *size)?))
+                } else {
+                    Ok(AbiValue::UInt(top_decode_unsigned(bytes, *size)?))
+                }
+            },
+            AbiType::BigUint => Ok(AbiValue::UInt(BigUint::from_bytes_be(bytes))),
+            AbiType::BigInt => Ok(AbiValue::Int(BigInt::from_signed_bytes_be(bytes))),
+            AbiType::Bytes => Ok(AbiValue::Bytes(bytes.to_vec())),
+            AbiType::Utf8String => Ok(AbiValue::Text(decode_utf8(bytes)?)),
+            AbiType::OperationCompletionStatus => {
+                let status = decode_utf8(bytes)?;
+                if !OPERATION_COMPLETION_STATUSES.contains(&status.as_str()) {
+                    return Err(anyhow!("invalid OperationCompletionStatus: {status}"));
+                }
+                Ok(AbiValue::Enum {
+                    variant: status,
+                    fields: Vec::new(),
+                })
+            },
+            AbiType::List(item_type) => {
+                let mut input = bytes;
+                let mut items = Vec::new();
+                while !input.is_empty() {
+                    items.push(self.nested_decode(item_type, &mut input)?);
+                }
+                Ok(AbiValue::List(items))
+            },
+            AbiType::Option(item_type) => match bytes {
+                [] => Ok(AbiValue::Option(None)),
+                [1, rest @ ..] => {
+                    let mut input = rest;
+                    let value = self.nested_decode(item_type, &mut input)?;
+                    expect_depleted(input)?;
+                    Ok(AbiValue::Option(Some(Box::new(value))))
+                },
+                _ => Err(anyhow!("invalid Option: {}", hex::encode(bytes))),
+            },
+            AbiType::Custom(name) => {
+                let type_description = self.type_description(name)?;
+                if type_description.content_type == "enum" {
+                    let first_variant_fieldless = type_description
+                        .variants
+                        .first()
+                        .map(|variant| variant.fields.is_empty())
+                        .unwrap_or(false);
+                    if bytes.is_empty() && first_variant_fieldless {
+                        return self.enum_variant_value(name, 0, &mut &bytes[..]);
+                    }
+                    if is_fieldless_enum(type_description) {
+                        let discriminant = to_usize(&top_decode_unsigned(bytes, 1)?)?;
+                        return self.enum_variant_value(name, discriminant, &mut &bytes[..0]);
+                    }
+                }
+                if type_description.content_type == "explicit-enum" {
+                    return self.explicit_enum_value(name, bytes);
+                }
+                let mut input = bytes;
+                let value = self.nested_decode(abi_type, &mut input)?;
+                expect_depleted(input)?;
+                Ok(value)
+            },
+            abi_type if abi_type.is_multi_value() => Err(anyhow!(
+                "multi-value {abi_type:?} can only be an argument or a result"
+            )),
+            // fixed length values are the same when nested
+            _ => {
+                let mut input = bytes;
+                let value = self.nested_decode(abi_type, &mut input)?;
+                expect_depleted(input)?;
+                Ok(value)
+            },
+        }
+    }
+
+    fn nested_decode(&self, abi_type: &AbiType, input: &mut &[u8]) -> Result<AbiValue> {
+        match abi_type {
+            AbiType::Bool => match take(input, 1)? {
+                [0] => Ok(AbiValue::Bool(false)),
+                [1] => Ok(AbiValue::Bool(true)),
+                bytes => Err(anyhow!("invalid bool: {}", hex::encode(bytes))),
+            },
+            AbiType::Int { size, signed } => {
+                let bytes = take(input, *size)?;
+                if *signed {
+                    Ok(AbiValue::Int(BigInt::from_signed_bytes_be(bytes)))
+                } else {
+                    Ok(AbiValue::UInt(BigUint::from_bytes_be(bytes)))
+                }
+            },
+            AbiType::BigUint => Ok(AbiValue::UInt(BigUint::from_bytes_be(take_buffer(input)?))),
+            AbiType::BigInt => Ok(AbiValue::Int(BigInt::from_signed_bytes_be(take_buffer(
+                input,
+            )?))),
+            AbiType::Bytes => Ok(AbiValue::Bytes(take_buffer(input)?.to_vec())),
+            AbiType::Utf8String => Ok(AbiValue::Text(decode_utf8(take_buffer(input)?)?)),
+            AbiType::Address => {
+                let bytes: [u8; 32] = take(input, 32)?.try_into().unwrap();
+                Ok(AbiValue::Address(Address::from_bytes(bytes)))
+            },
+            AbiType::FixedBytes(length) => Ok(AbiValue::Bytes(take(input, *length)?.to_vec())),
+            AbiType::List(item_type) => {
+                let length = take_length(input)?;
+                let mut items = Vec::new();
+                for _ in 0..length {
+                    items.push(self.nested_decode(item_type, input)?);
+                }
+                Ok(AbiValue::List(items))
+            },
+            AbiType::Array(length, item_type) => Ok(AbiValue::List(
+                (0..*length)
+                    .map(|_| self.nested_decode(item_type, input))
+                    .collect::<Result<Vec<_>>>()?,
+            )),
+            AbiType::Option(item_type) => match take(input, 1)? {
+                [0] => Ok(AbiValue::Option(None)),
+                [1] => Ok(AbiValue::Option(Some(Box::new(
+                    self.nested_decode(item_type, input)?,
+                )))),
+                bytes => Err(anyhow!("invalid Option: {}", hex::encode(bytes))),
+            },
+            AbiType::Tuple(item_types) => Ok(AbiValue::Tuple(
+                item_types
+                    .iter()
+                    .map(|item_type| self.nested_decode(item_type, input))
+                    .collect::<Result<Vec<_>>>()?,
+            )),
+            AbiType::Custom(name) => {
+                let type_description = self.type_description(name)?;
+                match type_description.content_type.as_str() {
+                    "struct" => Ok(AbiValue::Struct(
+                        self.nested_decode_fields(&type_description.fields, input)?,
+                    )),
+                    "enum" => {
+                        let discriminant = take(input, 1)?[0] as usize;
+                        self.enum_variant_value(name, discriminant, input)
+                    },
+                    "explicit-enum" => self.explicit_enum_value(name, take_buffer(input)?),
+                    content_type => Err(anyhow!("type {name} is not decodable: {content_type}")),
+                }
+            },
+            AbiType::OperationCompletionStatus => Err(anyhow!(
+                "OperationCompletionStatus can only be top-level, not nested"
+            )),
+            _ => Err(anyhow!(
+                "multi-value {abi_type:?} can only be an argument or a result"
+            )),
+        }
+    }
+
+    fn nested_decode_fields(
+        &self,
+        fields: &[StructFieldDescription],
+        input: &mut &[u8],
+    ) -> Result<Vec<(String, AbiValue)>> {
+        fields
+            .iter()
+            .map(|field| {
+                let value = self
+                    .nested_decode(&AbiType::parse(&field.field_type)?, input)
+                    .map_err(|err| anyhow!("field {}: {err}", field.name))?;
+                Ok((field.name.clone(), value))
+            })
+            .collect()
+    }
+
+    fn enum_variant_value(
+        &self,
+        name: &str,
+        discriminant: usize,
+        input: &mut &[u8],
+    ) -> Result<AbiValue> {
+        let type_description = self.type_description(name)?;
+        let variant = type_description
+            .variants
+            .iter()
+            .find(|variant| variant_discriminant(type_description, variant) == discriminant)
+            .ok_or_else(|| anyhow!("{name} has no variant with discriminant {discriminant}"))?;
+        Ok(AbiValue::Enum {
+            variant: variant.name.clone(),
+            fields: self.nested_decode_fields(&variant.fields, input)?,
+        })
+    }
+
+    fn explicit_enum_value(&self, name: &str, bytes: &[u8]) -> Result<AbiValue> {
+        let variant_name = decode_utf8(bytes)?;
+        let type_description = self.type_description(name)?;
+        if !type_description
+            .variants
+            .iter()
+            .any(|variant| variant.name == variant_name)
+        {
+            return Err(anyhow!("{name} has no variant {variant_name}"));
+        }
+        Ok(AbiValue::Enum {
+            variant: variant_name,
+            fields: Vec::new(),
+        })
+    }
+}
+
+// MultiArgs reads top encoded values one by one, e.g. endpoint results or event topics
+struct MultiArgs<'a> {
+    args: &'a [Vec<u8>],
+    position: usize,
+}
+
+impl<'a> MultiArgs<'a> {
+    fn new(args: &'a [Vec<u8>]) -> Self {
+        MultiArgs { args, position: 0 }
+    }
+
+    fn is_depleted(&self) -> bool {
+        self.position >= self.args.len()
+    }
+
+    fn remaining(&self) -> usize {
+        self.args.len().saturating_sub(self.position)
+    }
+
+    fn next(&mut self) -> Result<&'a [u8]> {
+        let arg = self
+            .args
+            .get(self.position)
+            .ok_or_else(|| anyhow!("not enough values"))?;
+        self.position += 1;
+        Ok(arg)
+    }
+
+    fn expect_depleted(&self) -> Result<()> {
+        if !self.is_depleted() {
+            return Err(anyhow!(
+                "{} values left over",
+                self.args.len() - self.position
+            ));
+        }
+        Ok(())
+    }
+}
+
+fn take<'a>(input: &mut &'a [u8], length: usize) -> Result<&'a [u8]> {
+    if input.len() < length {
+        return Err(anyhow!("input too short"));
+    }
+    let (taken, rest) = input.split_at(length);
+    *input = rest;
+    Ok(taken)
+}
+
+fn take_length(input: &mut &[u8]) -> Result<usize> {
+    let length_bytes: [u8; 4] = take(input, 4)?.try_into().unwrap();
+    Ok(u32::from_be_bytes(length_bytes) as usize)
+}
+
+fn take_buffer<'a>(input: &mut &'a [u8]) -> Result<&'a [u8]> {
+    let length = take_length(input)?;
+    take(input, length)
+}
+
+fn expect_depleted(input: &[u8]) -> Result<()> {
+    if !input.is_empty() {
+        return Err(anyhow!("input too long"));
+    }
+    Ok(())
+}
+
+fn top_decode_unsigned(bytes: &[u8], size: usize) -> Result<BigUint> {
+    if bytes.len() > size {
+        return Err(anyhow!("input too long for a {size} bytes integer"));
+    }
+    Ok(BigUint::from_bytes_be(bytes))
+}
+
+fn top_decode_signed(bytes: &[u8], size: usize) -> Result<BigInt> {
+    if bytes.len() > size {
+        return Err(anyhow!("input too long for a {size} bytes integer"));
+    }
+    Ok(BigInt::from_signed_bytes_be(bytes))
+}
+
+fn to_usize(value: &BigUint) -> Result<usize> {
+    usize::try_from(value).map_err(|_| anyhow!("{value} does not fit in a usize"))
+}
+
+fn decode_utf8(bytes: &[u8]) -> Result<String> {
+    String::from_utf8(bytes.to_vec()).map_err(|_| anyhow!("invalid utf-8: {}", hex::encode(bytes)))
+}
@@ -0,0 +1,487 @@
+use anyhow::{anyhow, Result};
+use num_bigint::{BigInt, BigUint, Sign};
+use serde_json::Value;
+
+use super::{
+    abi_type::OPERATION_COMPLETION_STATUSES, AbiType, ContractAbi, InputAbi,
+    StructFieldDescription, TypeDescription,
+};
+use crate::data::address::Address;
+
+impl ContractAbi {
+    // encode_endpoint_args encodes the arguments of an endpoint call, one JSON value per ABI input.
+    //
+    // Multi-value inputs take arrays (variadic, multi) or null (optional), and trailing optional
+    // and variadic inputs can be left out.
+    pub fn encode_endpoint_args(
+        &self,
+        endpoint_name: &str,
+        args: &[Value],
+    ) -> Result<Vec<Vec<u8>>> {
+        let endpoint = self.endpoint(endpoint_name)?;
+        self.encode_inputs(&endpoint.inputs, args)
+            .map_err(|err| anyhow!("endpoint {endpoint_name}: {err}"))
+    }
+
+    pub fn encode_constructor_args(&self, args: &[Value]) -> Result<Vec<Vec<u8>>> {
+        let inputs = match &self.constructor {
+            Some(constructor) => constructor.inputs.as_slice(),
+            None => &[],
+        };
+        self.encode_inputs(inputs, args)
+            .map_err(|err| anyhow!("constructor: {err}"))
+    }
+
+    // top_encode_value encodes a single value, as it would be stored or passed as a single argument
+    pub fn top_encode_value(&self, type_name: &str, value: &Value) -> Result<Vec<u8>> {
+        self.top_encode(&AbiType::parse(type_name)?, value)
+    }
+
+    // nested_encode_value encodes a value, as it would be encoded as part of a larger value
+    pub fn nested_encode_value(&self, type_name: &str, value: &Value) -> Result<Vec<u8>> {
+        let mut output = Vec::new();
+        self.nested_encode(&AbiType::parse(type_name)?, value, &mut output)?;
+        Ok(output)
+    }
+
+    fn encode_inputs(&self, inputs: &[InputAbi], args: &[Value]) -> Result<Vec<Vec<u8>>> {
+        if args.len() > inputs.len() {
+            return Err(anyhow!(
+                "expected at most {} arguments, got {}",
+                inputs.len(),
+                args.len()
+            ));
+        }
+
+        let mut output = Vec::new();
+        for (index, input) in inputs.iter().enumerate() {
+            let abi_type = AbiType::parse(&input.type_name)?;
+            self.multi_encode(&abi_type, args.get(index), &mut output)
+                .map_err(|err| anyhow!("argument {}: {err}", input.name))?;
+        }
+        Ok(output)
+    }
+
+    fn multi_encode(
+        &self,
+        abi_type: &AbiType,
+        value: Option<&Value>,
+        output: &mut Vec<Vec<u8>>,
+    ) -> Result<()> {
+        match abi_type {
+            AbiType::Optional(item_type) => match value {
+                None | Some(Value::Null) => Ok(()),
+                Some(value) => self.multi_encode(item_type, Some(value), output),
+            },
+            AbiType::Variadic(item_type) => match value {
+                None => Ok(()),
+                Some(value) => {
+                    for item in expect_array(value)? {
+                        self.multi_encode(item_type, Some(item), output)?;
+                    }
+                    Ok(())
+                },
+            },
+            AbiType::CountedVariadic(item_type) => {
+                let items = match value {
+                    None => &[][..],
+                    Some(value) => expect_array(value)?.as_slice(),
+                };
+                output.push(top_encode_unsigned(&BigUint::from(items.len())));
+                for item in items {
+                    self.multi_encode(item_type, Some(item), output)?;
+                }
+                Ok(())
+            },
+            AbiType::Multi(item_types) => {
+                let items = expect_array(value.ok_or_else(|| anyhow!("missing value"))?)?;
+                if items.len() != item_types.len() {
+                    return Err(anyhow!(
+                        "expected {} values, got {}",
+                        item_types.len(),
+                        items.len()
+                    ));
+                }
+                for (item_type, item) in item_types.iter().zip(items) {
+                    self.multi_encode(item_type, Some(item), output)?;
+                }
+                Ok(())
+            },
+            AbiType::Ignore => Ok(()),
+            AbiType::AsyncCallResult(_) => Err(anyhow!(
+                "AsyncCallResult is only produced by the protocol, it can only be decoded"
+            )),
+            _ => {
+                let value = value.ok_or_else(|| anyhow!("missing value"))?;
+                output.push(self.top_encode(abi_type, value)?);
+                Ok(())
+            },
+        }
+    }
+
+    fn top_encode(&self, abi_type: &AbiType, value: &Value) -> Result<Vec<u8>> {
+        match abi_type {
+            AbiType::Bool => Ok(if expect_bool(value)? {
+                vec![1]
+            } else {
+                Vec::new()
+            }),
+            AbiType::Int { size, signed } => {
+                if *signed {
+                    Ok(top_encode_signed(&expect_signed(value, Some(*size))?))
+                } else {
+                    Ok(top_encode_unsigned(&expect_unsigned(value, Some(*size))?))
+                }
+            },
+            AbiType::BigUint => Ok(top_encode_unsigned(&expect_unsigned(value, None)?)),
+            AbiType::BigInt => Ok(top_encode_signed(&expect_signed(value, None)?)),
+            AbiType::Bytes => expect_bytes(value),
+            AbiType::Utf8String => Ok(expect_str(value)?.as_bytes().to_vec()),
+            AbiType::OperationCompletionStatus => {
+                let status = expect_str(value)?;
+                if !OPERATION_COMPLETION_STATUSES.contains(&status) {
+                    return Err(anyhow!("invalid OperationCompletionStatus: {status}"));
+                }
+                Ok(status.as_bytes().to_vec())
+            },
+            AbiType::List(item_type) => {
+                let mut output = Vec::new();
+                for item in expect_array(value)? {
+                    self.nested_encode(item_type, item, &mut output)?;
+                }
+                Ok(output)
+            },
+            AbiType::Option(item_type) => match value {
+                Value::Null => Ok(Vec::new()),
+                value => {
+                    let mut output = vec![1];
+                    self.nested_encode(item_type, value, &mut output)?;
+                    Ok(output)
+                },
+            },
+            AbiType::Custom(name) => {
+                let type_description = self.type_description(name)?;
+                if type_description.content_type == "enum" {
+                    // fieldless variants are top-encoded as their discriminant alone
+                    let (discriminant, variant_name, _) =
+                        find_variant(name, type_description, value)?;
+                    let variant_fieldless = type_description
+                        .variants
+                        .iter()
+                        .any(|variant| variant.name == variant_name && variant.fields.is_empty());
+                    if variant_fieldless {
+                        return Ok(top_encode_unsigned(&BigUint::from(discriminant)));
+                    }
+                }
+                if type_description.content_type == "explicit-enum" {
+                    let (_, variant_name, _) = find_variant(name, type_description, value)?;
+                    return Ok(variant_name.as_bytes().to_vec());
+                }
+                let mut output = Vec::new();
+                self.nested_encode(abi_type, value, &mut output)?;
+                Ok(output)
+            },
+            abi_type if abi_type.is_multi_value() => Err(anyhow!(
+                "multi-value {abi_type:?} can only be an argument or a result"
+            )),
+            // fixed length values are the same when nested
+            _ => {
+                let mut output = Vec::new();
+                self.nested_encode(abi_type, value, &mut output)?;
+                Ok(output)
+            },
+        }
+    }
+
+    fn nested_encode(&self, abi_type: &AbiType, value: &Value, output: &mut Vec<u8>) -> Result<()> {
+        match abi_type {
+            AbiType::Bool => output.push(expect_bool(value)? as u8),
+            AbiType::Int { size, signed } => {
+                if *signed {
+                    let value = expect_signed(value, Some(*size))?;
+                    let bytes = value.to_signed_bytes_be();
+                    let padding = if value.sign() == Sign::Minus { 0xff } else { 0 };
+                    output.extend(std::iter::repeat(padding).take(size - bytes.len()));
+                    output.extend(bytes);
+                } else {
+                    let bytes = expect_unsigned(value, Some(*size))?.to_bytes_be();
+                    output.extend(std::iter::repeat(0).take(size - bytes.len()));
+                    output.extend(bytes);
+                }
+            },
+            AbiType::BigUint => {
+                nested_encode_buffer(&top_encode_unsigned(&expect_unsigned(value, None)?), output)
+            },
+            AbiType::BigInt => {
+                nested_encode_buffer(&top_encode_signed(&expect_signed(value, None)?), output)
+            },
+            AbiType::Bytes => nested_encode_buffer(&expect_bytes(value)?, output),
+            AbiType::Utf8String => nested_encode_buffer(expect_str(value)?.as_bytes(), output),
+            AbiType::Address => output.extend(expect_address(value)?.to_bytes()),
+            AbiType::FixedBytes(length) => {
+                let bytes = expect_bytes(value)?;
+                if bytes.len() != *length {
+                    return Err(anyhow!("expected {length} bytes, got {}", bytes.len()));
+                }
+                output.extend(bytes);
+            },
+            AbiType::List(item_type) => {
+                let items = expect_array(value)?;
+                output.extend((items.len() as u32).to_be_bytes());
+                for item in items {
+                    self.nested_encode(item_type, item, output)?;
+                }
+            },
+            AbiType::Array(length, item_type) => {
+                let items = expect_array(value)?;
+                if items.len() != *length {
+                    return Err(anyhow!("expected {length} items, got {}", items.len()));
+                }
+                for item in items {
+                    self.nested_encode(item_type, item, output)?;
+                }
+            },
+            AbiType::Option(item_type) => match value {
+                Value::Null => output.push(0),
+                value => {
+                    output.push(1);
+                    self.nested_encode(item_type, value, output)?;
+                },
+            },
+            AbiType::Tuple(item_types) => {
+                let items = expect_array(value)?;
+                if items.len() != item_types.len() {
+                    return Err(anyhow!(
+                        "expected {} tuple items, got {}",
+                        item_types.len(),
+                        items.len()
+                    ));
+                }
+                for (item_type, item) in item_types.iter().zip(items) {
+                    self.nested_encode(item_type, item, output)?;
+                }
+            },
+            AbiType::Custom(name) => self.nested_encode_custom(name, value, output)?,
+            AbiType::OperationCompletionStatus => {
+                return Err(anyhow!(
+                    "OperationCompletionStatus can only be top-level, not nested"
+                ))
+            },
+            _ => {
+                return Err(anyhow!(
+                    "multi-value {abi_type:?} can only be an argument or a result"
+                ))
+            },
+        }
+        Ok(())
+    }
+
+    fn nested_encode_custom(&self, name: &str, value: &Value, output: &mut Vec<u8>) -> Result<()> {
+        let type_description = self.type_description(name)?;
+        match type_description.content_type.as_str() {
+            "struct" => self.nested_encode_fields(&type_description.fields, value, output),
+            "enum" => {
+                let (discriminant, _, fields_value) = find_variant(name, type_description, value)?;
+                let variant = type_description
+                    .variants
+                    .iter()
+                    .find(|variant| variant_discriminant(type_description, variant) == discriminant)
+                    .unwrap();
+                output.push(discriminant as u8);
+                if variant.fields.is_empty() {
+                    return Ok(());
+                }
+                let fields_value = fields_value
+                    .ok_or_else(|| anyhow!("missing fields of {name}::{}", variant.name))?;
+                self.nested_encode_fields(&variant.fields, fields_value, output)
+            },
+            "explicit-enum" => {
+                let (_, variant_name, _) = find_variant(name, type_description, value)?;
+                nested_encode_buffer(variant_name.as_bytes(), output);
+                Ok(())
+            },
+            content_type => Err(anyhow!("type {name} is not encodable: {content_type}")),
+        }
+    }
+
+    fn nested_encode_fields(
+        &self,
+        fields: &[StructFieldDescription],
+        value: &Value,
+        output: &mut Vec<u8>,
+    ) -> Result<()> {
+        let object = value
+            .as_object()
+            .ok_or_else(|| anyhow!("expected an object, got {value}"))?;
+        for field in fields {
+            let field_value = object
+                .get(&field.name)
+                .ok_or_else(|| anyhow!("missing field {}", field.name))?;
+            self.nested_encode(&AbiType::parse(&field.field_type)?, field_value, output)
+                .map_err(|err| anyhow!("field {}: {err}", field.name))?;
+        }
+        Ok(())
+    }
+}
+
+pub(crate) fn is_fieldless_enum(type_description: &TypeDescription) -> bool {
+    type_description.content_type == "enum"
+        && type_description
+            .variants
+            .iter()
+            .all(|variant| variant.fields.is_empty())
+}
+
+pub(crate) fn variant_discriminant(
+    type_description: &TypeDescription,
+    variant: &super::EnumVariantDescription,
+) -> usize {
+    variant.discriminant.unwrap_or_else(|| {
+        type_description
+            .variants
+            .iter()
+            .position(|candidate| candidate.name == variant.name)
+            .unwrap()
+    })
+}
+
+// find_variant reads an enum value, either "Variant" or {"Variant": {fields}}
+fn find_variant<'a>(
+    name: &str,
+    type_description: &'a TypeDescription,
+    value: &'a Value,
+) -> Result<(usize, &'a str, Option<&'a Value>)> {
+    let (variant_name, fields_value) = match value {
+        Value::String(variant_name) => (variant_name.as_str(), None),
+        Value::Object(object) if object.len() == 1 => {
+            let (variant_name, fields_value) = object.iter().next().unwrap();
+            (variant_name.as_str(), Some(fields_value))
+        },
+        _ => return Err(anyhow!("expected a {name} variant, got {value}")),
+    };
+    let variant = type_description
+        .variants
+        .iter()
+        .find(|variant| variant.name == variant_name)
+        .ok_or_else(|| anyhow!("{name} has no variant {variant_name}"))?;
+    Ok((
+        variant_discriminant(type_description, variant),
+        variant.name.as_str(),
+        fields_value,
+    ))
+}
+
+fn nested_encode_buffer(bytes: &[u8], output: &mut Vec<u8>) {
+    output.extend((bytes.len() as u32).to_be_bytes());
+    output.extend(bytes);
+}
+
+fn top_encode_unsigned(value: &BigUint) -> Vec<u8> {
+    if value == &BigUint::default() {
+        return Vec::new();
+    }
+    value.to_bytes_be()
+}
+
+fn top_encode_signed(value: &BigInt) -> Vec<u8> {
+    if value == &BigInt::default() {
+        return Vec::new();
+    }
+    value.to_signed_bytes_be()
+}
+
+fn expect_bool(value: &Value) -> Result<bool> {
+    match value {
+        Value::Bool(value) => Ok(*value),
+        Value::String(value) if value == "true" => Ok(true),
+        Value::String(value) if value == "false" => Ok(false),
+        _ => Err(anyhow!("expected a bool, got {value}")),
+    }
+}
+
+fn expect_str(value: &Value) -> Result<&str> {
+    value
+        .as_str()
+        .ok_or_else(|| anyhow!("expected a string, got {value}"))
+}
+
+fn expect_array(value: &Value) -> Result<&Vec<Value>> {
+    value
+        .as_array()
+        .ok_or_else(|| anyhow!("expected an array, got {value}"))
+}
+
+// expect_unsigned reads an unsigned integer from a JSON number or a decimal string,
+// checking that it fits in the given number of bytes
+fn expect_unsigned(value: &Value, size: Option<usize>) -> Result<BigUint> {
+    let number = match value {
+        Value::Number(number) => number.as_u64().map(BigUint::from),
+        Value::String(number) => number.parse::<BigUint>().ok(),
+        _ => None,
+    }
+    .ok_or_else(|| anyhow!("expected an unsigned integer, got {value}"))?;
+
+    if let Some(size) = size {
+        if number.bits() > (size * 8) as u64 {
+            return Err(anyhow!("{number} does not fit in {size} bytes"));
+        }
+    }
+    Ok(number)
+}
+
+// expect_signed reads a signed integer from a JSON number or a decimal string,
+// checking that it fits in the given number of bytes
+fn expect_signed(value: &Value, size: Option<usize>) -> Result<BigInt> {
+    let number = match value {
+        Value::Number(number) => number
+            .as_i64()
+            .map(BigInt::from)
+            .or_else(|| number.as_u64().map(BigInt::from)),
+        Value::String(number) => number.parse::<BigInt>().ok(),
+        _ => None,
+    }
+    .ok_or_else(|| anyhow!("expected an integer, got {value}"))?;
+
+    if let Some(size) = size {
+        if number.to_signed_bytes_be().len() > size {
+            return Err(anyhow!("{number} does not fit in {size} bytes"));
+        }
+    }
+    Ok(number)
+}
+
+// expect_bytes reads bytes from a hex string, optionally 0x-prefixed, a "str:" prefixed text,
+// or an array of byte values
+fn expect_bytes(value: &Value) -> Result<Vec<u8>> {
+    match value {
+        Value::String(text) => {
+            if let Some(text) = text.strip_prefix("str:") {
+                return Ok(text.as_bytes().to_vec());
+            }
+            hex::decode(text.trim_start_matches("0x"))
+                .map_err(|_| anyhow!("expected hex or str: bytes, got {value}"))
+        },
+        Value::Array(items) => items
+            .iter()
+            .map(|item| {
+                item.as_u64()
+                    .and_then(|item| u8::try_from(item).ok())
+                    .ok_or_else(|| anyhow!("expected a byte, got {item}"))
+            })
+            .collect(),
+        _ => Err(anyhow!("expected bytes, got {value}")),
+    }
+}
+
+// expect_address reads an address from a bech32 string or a 32 bytes hex string
+fn expect_address(value: &Value) -> Result<Address> {
+    let text = expect_str(value)?;
+    if let Ok(address) = Address::from_bech32_string(text) {
+        return Ok(address);
+    }
+    let bytes = hex::decode(text.trim_start_matches("0x"))
+        .ok()
+        .and_then(|bytes| <[u8; 32]>::try_from(bytes).ok())
+        .ok_or_else(|| anyhow!("expected a bech32 or hex address, got {value}"))?;
+    Ok(Address::from_bytes(bytes))
+}
@@ -0,0 +1,167 @@
+use anyhow::{anyhow, Result};
+
+pub(super) const OPERATION_COMPLETION_STATUSES: &[&str] = &["completed", "interrupted"];
+
+// AbiType is a parsed ABI type name, e.g. "List<Option<BigUint>>"
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AbiType {
+    Bool,
+    // fixed size integers, size in bytes
+    Int { size: usize, signed: bool },
+    BigUint,
+    BigInt,
+    // arbitrary length bytes
+    Bytes,
+    // arbitrary length text, also used for token identifiers
+    Utf8String,
+    Address,
+    // fixed length bytes, e.g. H256, CodeMetadata or array32<u8>
+    FixedBytes(usize),
+    List(Box<AbiType>),
+    Array(usize, Box<AbiType>),
+    Option(Box<AbiType>),
+    Tuple(Vec<AbiType>),
+    // the multi-value types below can only appear as endpoint arguments, results or event topics
+    Optional(Box<AbiType>),
+    Variadic(Box<AbiType>),
+    CountedVariadic(Box<AbiType>),
+    Multi(Vec<AbiType>),
+    Ignore,
+    // the result of an async call, as received by its callback: an error code, then the result or the error message
+    AsyncCallResult(Box<AbiType>),
+    // "completed" or "interrupted", returned by endpoints that can run out of gas midway
+    OperationCompletionStatus,
+    // a struct or an enum, described in the types section of the ABI
+    Custom(String),
+}
+
+impl AbiType {
+    pub fn parse(type_name: &str) -> Result<Self> {
+        let type_name = type_name.trim();
+        let (name, args) = match type_name.find('<') {
+            Some(start) => {
+                if !type_name.ends_with('>') {
+                    return Err(anyhow!("invalid ABI type name: {type_name}"));
+                }
+                let args = split_type_args(&type_name[start + 1..type_name.len() - 1])?
+                    .into_iter()
+                    .map(AbiType::parse)
+                    .collect::<Result<Vec<_>>>()?;
+                (&type_name[..start], args)
+            },
+            None => (type_name, Vec::new()),
+        };
+
+        if args.is_empty() {
+            return Ok(Self::parse_simple(name));
+        }
+
+        let single_arg = || -> Result<Box<AbiType>> {
+            if args.len() != 1 {
+                return Err(anyhow!(
+                    "{name} takes a single type argument, in {type_name}"
+                ));
+            }
+            Ok(Box::new(args[0].clone()))
+        };
+        match name {
+            // queues are encoded the same as lists
+            "List" | "Queue" => Ok(AbiType::List(single_arg()?)),
+            "Option" => Ok(AbiType::Option(single_arg()?)),
+            "optional" => Ok(AbiType::Optional(single_arg()?)),
+            "variadic" => Ok(AbiType::Variadic(single_arg()?)),
+            "counted-variadic" => Ok(AbiType::CountedVariadic(single_arg()?)),
+            "tuple" => Ok(AbiType::Tuple(args)),
+            "multi" => Ok(AbiType::Multi(args)),
+            "AsyncCallResult" => Ok(AbiType::AsyncCallResult(single_arg()?)),
+            _ => {
+                if let Some(length) = name.strip_prefix("array") {
+                    let length = length
+                        .parse::<usize>()
+                        .map_err(|_| anyhow!("invalid array length in {type_name}"))?;
+                    let item_type = single_arg()?;
+                    if *item_type == unsigned(1) {
+                        return Ok(AbiType::FixedBytes(length));
+                    }
+                    return Ok(AbiType::Array(length, item_type));
+                }
+                Err(anyhow!("unsupported ABI type: {type_name}"))
+            },
+        }
+    }
+
+    fn parse_simple(name: &str) -> Self {
+        match name {
+            "bool" => AbiType::Bool,
+            "u8" => unsigned(1),
+            "u16" => unsigned(2),
+            "u32" | "NonZeroUsize" => unsigned(4),
+            "u64" => unsigned(8),
+            "i8" => signed(1),
+            "i16" => signed(2),
+            "i32" => signed(4),
+            "i64" => signed(8),
+            "BigUint" => AbiType::BigUint,
+            "BigInt" => AbiType::BigInt,
+            "bytes" => AbiType::Bytes,
+            "utf-8 string" | "TokenIdentifier" | "MoaxOrDctTokenIdentifier" => AbiType::Utf8String,
+            "Address" => AbiType::Address,
+            "H256" => AbiType::FixedBytes(32),
+            "CodeMetadata" => AbiType::FixedBytes(2),
+            "ignore" => AbiType::Ignore,
+            "OperationCompletionStatus" => AbiType::OperationCompletionStatus,
+            "()" => AbiType::Tuple(Vec::new()),
+            _ => AbiType::Custom(name.to_string()),
+        }
+    }
+
+    pub fn is_multi_value(&self) -> bool {
+        matches!(
+            self,
+            AbiType::Optional(_)
+                | AbiType::Variadic(_)
+                | AbiType::CountedVariadic(_)
+                | AbiType::Multi(_)
+                | AbiType::Ignore
+                | AbiType::AsyncCallResult(_)
+        )
+    }
+}
+
+fn unsigned(size: usize) -> AbiType {
+    AbiType::Int {
+        size,
+        signed: false,
+    }
+}
+
+fn signed(size: usize) -> AbiType {
+    AbiType::Int { size, signed: true }
+}
+
+// splits "A,B<C,D>" into ["A", "B<C,D>"]
+fn split_type_args(args: &str) -> Result<Vec<&str>> {
+    let mut result = Vec::new();
+    let mut depth = 0usize;
+    let mut start = 0;
+    for (index, c) in args.char_indices() {
+        match c {
+            '<' => depth += 1,
+            '>' => {
+                depth = depth
+                    .checked_sub(1)
+                    .ok_or_else(|| anyhow!("unbalanced ABI type arguments: {args}"))?
+            },
+            ',' if depth == 0 => {
+                result.push(&args[start..index]);
+                start = index + 1;
+            },
+            _ => {},
+        }
+    }
+    if depth != 0 {
+        return Err(anyhow!("unbalanced ABI type arguments: {args}"));
+    }
+    result.push(&args[start..]);
+    Ok(result)
+}
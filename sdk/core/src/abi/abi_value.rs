@@ -0,0 +1,75 @@
+use num_bigint::{BigInt, BigUint};
+use serde_json::{Map, Value};
+
+use crate::data::address::Address;
+
+// AbiValue is a decoded value, shaped after its ABI type
+#[derive(Debug, Clone)]
+pub enum AbiValue {
+    Bool(bool),
+    UInt(BigUint),
+    Int(BigInt),
+    Bytes(Vec<u8>),
+    Text(String),
+    Address(Address),
+    // lists, arrays and variadic values
+    List(Vec<AbiValue>),
+    // Option and optional values
+    Option(Option<Box<AbiValue>>),
+    // tuples and multi-values
+    Tuple(Vec<AbiValue>),
+    Struct(Vec<(String, AbiValue)>),
+    Enum {
+        variant: String,
+        fields: Vec<(String, AbiValue)>,
+    },
+}
+
+impl AbiValue {
+    // to_json converts the value into human readable JSON, which can be encoded back from:
+    // integers that do not fit in 64 bits become decimal strings, bytes become hex strings,
+    // addresses become bech32 strings, and enums become either the variant name,
+    // or an object with the variant name as the only key, if the variant has fields
+    pub fn to_json(&self) -> Value {
+        match self {
+            AbiValue::Bool(value) => Value::Bool(*value),
+            AbiValue::UInt(value) => match u64::try_from(value) {
+                Ok(value) => Value::from(value),
+                Err(_) => Value::String(value.to_string()),
+            },
+            AbiValue::Int(value) => match i64::try_from(value) {
+                Ok(value) => Value::from(value),
+                Err(_) => Value::String(value.to_string()),
+            },
+            AbiValue::Bytes(bytes) => Value::String(hex::encode(bytes)),
+            AbiValue::Text(text) => Value::String(text.clone()),
+            AbiValue::Address(address) => Value::String(address.to_string()),
+            AbiValue::List(items) | AbiValue::Tuple(items) => {
+                Value::Array(items.iter().map(AbiValue::to_json).collect())
+            },
+            AbiValue::Option(value) => match value {
+                Some(value) => value.to_json(),
+                None => Value::Null,
+            },
+            AbiValue::Struct(fields) => fields_to_json(fields),
+            AbiValue::Enum { variant, fields } => {
+                if fields.is_empty() {
+                    Value::String(variant.clone())
+                } else {
+                    let mut object = Map::new();
+                    object.insert(variant.clone(), fields_to_json(fields));
+                    Value::Object(object)
+                }
+            },
+        }
+    }
+}
+
+fn fields_to_json(fields: &[(String, AbiValue)]) -> Value {
+    Value::Object(
+        fields
+            .iter()
+            .map(|(name, value)| (name.clone(), value.to_json()))
+            .collect(),
+    )
+}
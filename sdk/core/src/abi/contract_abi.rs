@@ -0,0 +1,134 @@
+use anyhow::{anyhow, Result};
+use serde::Deserialize;
+use std::{collections::BTreeMap, path::Path};
+
+// ContractAbi is a contract ABI, as loaded from an *.abi.json file.
+//
+// Only the parts needed to encode and decode contract data are kept,
+// the rest of the file (build info, docs, etc.) is ignored.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContractAbi {
+    pub name: String,
+    #[serde(default)]
+    pub constructor: Option<ConstructorAbi>,
+    #[serde(default)]
+    pub endpoints: Vec<EndpointAbi>,
+    #[serde(default)]
+    pub events: Vec<EventAbi>,
+    #[serde(default)]
+    pub types: BTreeMap<String, TypeDescription>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ConstructorAbi {
+    #[serde(default)]
+    pub inputs: Vec<InputAbi>,
+    #[serde(default)]
+    pub outputs: Vec<OutputAbi>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct EndpointAbi {
+    pub name: String,
+    #[serde(default)]
+    pub inputs: Vec<InputAbi>,
+    #[serde(default)]
+    pub outputs: Vec<OutputAbi>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct InputAbi {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub type_name: String,
+    #[serde(default)]
+    pub multi_arg: bool,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct OutputAbi {
+    #[serde(default)]
+    pub name: String,
+    #[serde(rename = "type")]
+    pub type_name: String,
+    #[serde(default)]
+    pub multi_result: bool,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct EventAbi {
+    pub identifier: String,
+    #[serde(default)]
+    pub inputs: Vec<EventInputAbi>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct EventInputAbi {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub type_name: String,
+    #[serde(default)]
+    pub indexed: bool,
+}
+
+// TypeDescription describes a struct or an enum defined by the contract
+#[derive(Debug, Clone, Deserialize)]
+pub struct TypeDescription {
+    // "struct", "enum" or "explicit-enum"
+    #[serde(rename = "type")]
+    pub content_type: String,
+    #[serde(default)]
+    pub fields: Vec<StructFieldDescription>,
+    #[serde(default)]
+    pub variants: Vec<EnumVariantDescription>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct StructFieldDescription {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub field_type: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct EnumVariantDescription {
+    pub name: String,
+    // missing for explicit enums, which are encoded by variant name
+    #[serde(default)]
+    pub discriminant: Option<usize>,
+    #[serde(default)]
+    pub fields: Vec<StructFieldDescription>,
+}
+
+impl ContractAbi {
+    pub fn from_json_str(json: &str) -> Result<Self> {
+        serde_json::from_str(json).map_err(|err| anyhow!("invalid ABI JSON: {err}"))
+    }
+
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let json = std::fs::read_to_string(path.as_ref())
+            .map_err(|err| anyhow!("could not read ABI file {}: {err}", path.as_ref().display()))?;
+        Self::from_json_str(&json)
+    }
+
+    pub fn endpoint(&self, name: &str) -> Result<&EndpointAbi> {
+        self.endpoints
+            .iter()
+            .find(|endpoint| endpoint.name == name)
+            .ok_or_else(|| anyhow!("endpoint {name} not found in the {} ABI", self.name))
+    }
+
+    pub fn event(&self, identifier: &str) -> Result<&EventAbi> {
+        self.events
+            .iter()
+            .find(|event| event.identifier == identifier)
+            .ok_or_else(|| anyhow!("event {identifier} not found in the {} ABI", self.name))
+    }
+
+    pub fn type_description(&self, name: &str) -> Result<&TypeDescription> {
+        self.types
+            .get(name)
+            .ok_or_else(|| anyhow!("type {name} not found in the {} ABI", self.name))
+    }
+}
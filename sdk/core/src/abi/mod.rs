@@ -0,0 +1,10 @@
+mod abi_decode;
+mod abi_encode;
+mod abi_type;
+mod abi_value;
+mod contract_abi;
+
+pub use abi_decode::DecodedEvent;
+pub use abi_type::AbiType;
+pub use abi_value::AbiValue;
+pub use contract_abi::*;
@@ -1,22 +1,30 @@
 use std::collections::HashMap;
 
-use crate::data::{
-    account::{Account, AccountResponse},
-    account_storage::AccountStorageResponse,
-    address::Address,
-    dct::{DctBalance, DctBalanceResponse, DctRolesResponse},
-    hyperblock::{HyperBlock, HyperBlockResponse},
-    network_config::{NetworkConfig, NetworkConfigResponse},
-    network_economics::{NetworkEconomics, NetworkEconomicsResponse},
-    network_status::NetworkStatusResponse,
-    transaction::{
-        ArgCreateTransaction, ResponseTxCost, SendTransactionResponse, SendTransactionsResponse,
-        Transaction, TransactionInfo, TransactionOnNetwork, TransactionStatus, TxCostResponseData,
+use crate::{
+    data::{
+        account::{Account, AccountResponse},
+        account_storage::{AccountStorageResponse, AccountStorageValueResponse},
+        address::Address,
+        dct::{
+            DctBalance, DctBalanceResponse, DctRolesResponse, NftData, NftDataResponse,
+            TokenProperties,
+        },
+        guardian::{GuardianData, GuardianDataResponse},
+        hyperblock::{HyperBlock, HyperBlockResponse},
+        network_config::{NetworkConfig, NetworkConfigResponse},
+        network_economics::{NetworkEconomics, NetworkEconomicsResponse},
+        network_status::NetworkStatusResponse,
+        transaction::{
+            ApiSmartContractResult, ArgCreateTransaction, NonceGap, NonceGapsResponse,
+            PoolTransaction, ResponseTxCost, SendTransactionResponse, SendTransactionsResponse,
+            Transaction, TransactionInfo, TransactionOnNetwork, TransactionPoolForSenderResponse,
+            TransactionPoolLastNonceResponse, TransactionStatus, TxCostResponseData,
+        },
+        vm::{ResponseVmValue, VmValueRequest, VmValuesResponseData},
     },
-    vm::{ResponseVmValue, VmValueRequest, VmValuesResponseData},
+    gateway_error::GatewayError,
 };
 use anyhow::{anyhow, Result};
-use itertools::Itertools;
 use reqwest::Client;
 
 pub const MAINNET_GATEWAY: &str = "https://gateway.dharitri.com";
@@ -26,6 +34,12 @@ pub const DEVNET_GATEWAY: &str = "https://devnet-gateway.dharitri.com";
 // MetachainShardId will be used to identify a shard ID as metachain
 pub const METACHAIN_SHARD_ID: u32 = 0xFFFFFFFF;
 
+// the system smart contract that keeps the token properties
+pub const DCT_SYSTEM_SC_ADDRESS: [u8; 32] = [
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0xff,
+    0xff,
+];
+
 const NETWORK_CONFIG_ENDPOINT: &str = "network/config";
 const NETWORK_ECONOMICS_ENDPOINT: &str = "network/economics";
 const ACCOUNT_ENDPOINT: &str = "address/";
@@ -39,6 +53,10 @@ const GET_HYPER_BLOCK_BY_HASH_ENDPOINT: &str = "hyperblock/by-hash/";
 const GET_NETWORK_STATUS_ENDPOINT: &str = "network/status";
 const WITH_RESULTS_QUERY_PARAM: &str = "?withResults=true";
 const VM_VALUES_ENDPOINT: &str = "vm-values/query";
+const TRANSACTION_POOL_ENDPOINT: &str = "transaction/pool";
+const POOL_TRANSACTION_FIELDS: &str = "hash,nonce,sender,receiver,value,gaslimit,gasprice,data";
+const GET_TOKEN_PROPERTIES_FUNC_NAME: &str = "getTokenProperties";
+const VM_QUERY_RETURN_CODE_OK: &str = "ok";
 
 #[derive(Clone, Debug)]
 pub struct CommunicationProxy {
@@ -188,74 +206,266 @@ impl CommunicationProxy {
     pub async fn get_account_dct_roles(
         &self,
         address: &Address,
-    ) -> Result<HashMap<String, Vec<String>>> {
-        if !address.is_valid() {
-            return Err(anyhow!("invalid address"));
-        }
-
-        let endpoint = ACCOUNT_ENDPOINT.to_string() + address.to_string().as_str() + "/dcts/roles";
-        let endpoint = self.get_endpoint(endpoint.as_str());
+    ) -> Result<HashMap<String, Vec<String>>, GatewayError> {
+        let endpoint = format!("{}/dcts/roles", account_endpoint(address)?);
         let resp = self
             .client
-            .get(endpoint)
+            .get(self.get_endpoint(endpoint.as_str()))
             .send()
             .await?
             .json::<DctRolesResponse>()
             .await?;
 
         match resp.data {
-            None => Err(anyhow!("{}", resp.error)),
+            None => Err(GatewayError::gateway(resp.code, resp.error)),
             Some(b) => Ok(b.roles),
         }
     }
 
-    // get_account_dct_tokens retrieves an all dct token of an account from the network
+    // get_account_dct_tokens retrieves an all dct token of an account from the network,
+    // the NFT fields are left as the gateway encodes them, get_nft_data decodes them
     pub async fn get_account_dct_tokens(
         &self,
         address: &Address,
-    ) -> Result<HashMap<String, DctBalance>> {
-        if !address.is_valid() {
-            return Err(anyhow!("invalid address"));
-        }
-
-        let endpoint = ACCOUNT_ENDPOINT.to_string() + address.to_string().as_str() + "/dct";
-        let endpoint = self.get_endpoint(endpoint.as_str());
+    ) -> Result<HashMap<String, DctBalance>, GatewayError> {
+        let endpoint = format!("{}/dct", account_endpoint(address)?);
         let resp = self
             .client
-            .get(endpoint)
+            .get(self.get_endpoint(endpoint.as_str()))
             .send()
             .await?
             .json::<DctBalanceResponse>()
             .await?;
 
         match resp.data {
-            None => Err(anyhow!("{}", resp.error)),
+            None => Err(GatewayError::gateway(resp.code, resp.error)),
             Some(b) => Ok(b.dcts),
         }
     }
 
-    // get_account_dct_tokens retrieves an all dct token of an account from the network
+    // get_account_storage_keys retrieves all the storage of an account, with its keys and values decoded
     pub async fn get_account_storage_keys(
         &self,
         address: &Address,
-    ) -> Result<HashMap<String, String>> {
-        if !address.is_valid() {
-            return Err(anyhow!("invalid address"));
+    ) -> Result<HashMap<Vec<u8>, Vec<u8>>, GatewayError> {
+        let endpoint = format!("{}{KEYS_ENDPOINT}", account_endpoint(address)?);
+        let resp = self
+            .client
+            .get(self.get_endpoint(endpoint.as_str()))
+            .send()
+            .await?
+            .json::<AccountStorageResponse>()
+            .await?;
+
+        match resp.data {
+            None => Err(GatewayError::gateway(resp.code, resp.error)),
+            Some(b) => b.decode(),
         }
+    }
 
-        let endpoint = ACCOUNT_ENDPOINT.to_string() + address.to_string().as_str() + KEYS_ENDPOINT;
-        let endpoint = self.get_endpoint(endpoint.as_str());
+    // get_account_storage_value retrieves a single storage value of an account, decoded
+    pub async fn get_account_storage_value(
+        &self,
+        address: &Address,
+        key: &[u8],
+    ) -> Result<Vec<u8>, GatewayError> {
+        let endpoint = format!("{}/key/{}", account_endpoint(address)?, hex::encode(key));
         let resp = self
             .client
-            .get(endpoint)
+            .get(self.get_endpoint(endpoint.as_str()))
             .send()
             .await?
-            .json::<AccountStorageResponse>()
+            .json::<AccountStorageValueResponse>()
             .await?;
 
         match resp.data {
-            None => Err(anyhow!("{}", resp.error)),
-            Some(b) => Ok(b.pairs),
+            None => Err(GatewayError::gateway(resp.code, resp.error)),
+            Some(b) => hex::decode(&b.value)
+                .map_err(|_| GatewayError::decode(format!("storage value: {}", b.value))),
+        }
+    }
+
+    // get_nft_data retrieves an NFT/SFT/MetaDCT instance held by an account
+    pub async fn get_nft_data(
+        &self,
+        address: &Address,
+        token_identifier: &str,
+        nonce: u64,
+    ) -> Result<NftData, GatewayError> {
+        let endpoint = format!(
+            "{}/nft/{token_identifier}/nonce/{nonce}",
+            account_endpoint(address)?
+        );
+        let resp = self
+            .client
+            .get(self.get_endpoint(endpoint.as_str()))
+            .send()
+            .await?
+            .json::<NftDataResponse>()
+            .await?;
+
+        match resp.data {
+            None => Err(GatewayError::gateway(resp.code, resp.error)),
+            Some(b) => NftData::try_from(b.token_data),
+        }
+    }
+
+    // get_token_properties retrieves the properties of a token from the DCT system smart contract
+    pub async fn get_token_properties(
+        &self,
+        token_identifier: &str,
+    ) -> Result<TokenProperties, GatewayError> {
+        let system_sc_address = Address::from_bytes(DCT_SYSTEM_SC_ADDRESS);
+        let vm_request = VmValueRequest {
+            sc_address: system_sc_address.clone(),
+            func_name: GET_TOKEN_PROPERTIES_FUNC_NAME.to_string(),
+            caller: system_sc_address,
+            value: "0".to_string(),
+            args: vec![hex::encode(token_identifier)],
+        };
+        let resp = self
+            .client
+            .post(self.get_endpoint(VM_VALUES_ENDPOINT))
+            .json(&vm_request)
+            .send()
+            .await?
+            .json::<ResponseVmValue>()
+            .await?;
+
+        let vm_output = match resp.data {
+            None => return Err(GatewayError::gateway(resp.code, resp.error)),
+            Some(b) => b.data,
+        };
+        if vm_output.return_code != VM_QUERY_RETURN_CODE_OK {
+            return Err(GatewayError::gateway(
+                vm_output.return_code,
+                vm_output.return_message,
+            ));
+        }
+
+        let results = vm_output
+            .return_data
+            .iter()
+            .map(|result| {
+                base64::decode(result)
+                    .map_err(|_| GatewayError::decode(format!("query result: {result}")))
+            })
+            .collect::<Result<Vec<Vec<u8>>, GatewayError>>()?;
+        TokenProperties::from_query_results(&results)
+    }
+
+    // get_account_guardian_data retrieves the active and pending guardians of an account
+    pub async fn get_account_guardian_data(
+        &self,
+        address: &Address,
+    ) -> Result<GuardianData, GatewayError> {
+        let endpoint = format!("{}/guardian-data", account_endpoint(address)?);
+        let resp = self
+            .client
+            .get(self.get_endpoint(endpoint.as_str()))
+            .send()
+            .await?
+            .json::<GuardianDataResponse>()
+            .await?;
+
+        match resp.data {
+            None => Err(GatewayError::gateway(resp.code, resp.error)),
+            Some(b) => Ok(b.guardian_data),
+        }
+    }
+
+    // get_transaction_pool_for_sender retrieves the transactions of a sender that are still in the pool
+    pub async fn get_transaction_pool_for_sender(
+        &self,
+        address: &Address,
+    ) -> Result<Vec<PoolTransaction>, GatewayError> {
+        let endpoint = format!(
+            "{TRANSACTION_POOL_ENDPOINT}?by-sender={}&fields={POOL_TRANSACTION_FIELDS}",
+            valid_address(address)?
+        );
+        let resp = self
+            .client
+            .get(self.get_endpoint(endpoint.as_str()))
+            .send()
+            .await?
+            .json::<TransactionPoolForSenderResponse>()
+            .await?;
+
+        match resp.data {
+            None => Err(GatewayError::gateway(resp.code, resp.error)),
+            Some(b) => Ok(b
+                .tx_pool
+                .transactions
+                .into_iter()
+                .map(|tx| tx.tx_fields)
+                .collect()),
+        }
+    }
+
+    // get_transaction_pool_last_nonce retrieves the highest nonce of a sender in the transaction pool
+    pub async fn get_transaction_pool_last_nonce(
+        &self,
+        address: &Address,
+    ) -> Result<u64, GatewayError> {
+        let endpoint = format!(
+            "{TRANSACTION_POOL_ENDPOINT}?by-sender={}&last-nonce=true",
+            valid_address(address)?
+        );
+        let resp = self
+            .client
+            .get(self.get_endpoint(endpoint.as_str()))
+            .send()
+            .await?
+            .json::<TransactionPoolLastNonceResponse>()
+            .await?;
+
+        match resp.data {
+            None => Err(GatewayError::gateway(resp.code, resp.error)),
+            Some(b) => Ok(b.nonce),
+        }
+    }
+
+    // get_transaction_pool_nonce_gaps retrieves the nonces missing from the pool, for a sender
+    pub async fn get_transaction_pool_nonce_gaps(
+        &self,
+        address: &Address,
+    ) -> Result<Vec<NonceGap>, GatewayError> {
+        let endpoint = format!(
+            "{TRANSACTION_POOL_ENDPOINT}?by-sender={}&nonce-gaps=true",
+            valid_address(address)?
+        );
+        let resp = self
+            .client
+            .get(self.get_endpoint(endpoint.as_str()))
+            .send()
+            .await?
+            .json::<NonceGapsResponse>()
+            .await?;
+
+        match resp.data {
+            None => Err(GatewayError::gateway(resp.code, resp.error)),
+            Some(b) => Ok(b.nonce_gaps.gaps),
+        }
+    }
+
+    // get_smart_contract_results retrieves the smart contract results generated by a transaction
+    pub async fn get_smart_contract_results(
+        &self,
+        tx_hash: &str,
+    ) -> Result<Vec<ApiSmartContractResult>, GatewayError> {
+        let endpoint =
+            GET_TRANSACTION_INFO_ENDPOINT.to_string() + tx_hash + WITH_RESULTS_QUERY_PARAM;
+        let resp = self
+            .client
+            .get(self.get_endpoint(endpoint.as_str()))
+            .send()
+            .await?
+            .json::<TransactionInfo>()
+            .await?;
+
+        match resp.data {
+            None => Err(GatewayError::gateway(resp.code, resp.error)),
+            Some(b) => Ok(b.transaction.smart_contract_results.unwrap_or_default()),
         }
     }
 
@@ -359,6 +569,16 @@ impl CommunicationProxy {
     }
 
     pub async fn send_transactions(&self, txs: &Vec<Transaction>) -> Result<Vec<String>> {
+        let tx_hashes = self.send_transactions_indexed(txs).await?;
+        Ok(tx_hashes.into_iter().flatten().collect())
+    }
+
+    // send_transactions_indexed broadcasts multiple transactions at once,
+    // the result is aligned with the input, None marking the transactions that were rejected
+    pub async fn send_transactions_indexed(
+        &self,
+        txs: &Vec<Transaction>,
+    ) -> Result<Vec<Option<String>>> {
         let endpoint = self.get_endpoint(SEND_MULTIPLE_TRANSACTIONS_ENDPOINT);
         let resp = self
             .client
@@ -372,12 +592,17 @@ impl CommunicationProxy {
         match resp.data {
             None => Err(anyhow!("{}", resp.error)),
             Some(b) => {
-                let mut tx_hashs: Vec<String> = vec![];
-                for key in b.txs_hashes.keys().sorted() {
-                    tx_hashs.push(b.txs_hashes[key].clone());
+                let mut tx_hashes: Vec<Option<String>> = vec![None; txs.len()];
+                for (index, tx_hash) in b.txs_hashes {
+                    if let Some(slot) = usize::try_from(index)
+                        .ok()
+                        .and_then(|index| tx_hashes.get_mut(index))
+                    {
+                        *slot = Some(tx_hash);
+                    }
                 }
 
-                Ok(tx_hashs)
+                Ok(tx_hashes)
             },
         }
     }
@@ -403,3 +628,17 @@ impl CommunicationProxy {
         }
     }
 }
+
+fn valid_address(address: &Address) -> Result<String, GatewayError> {
+    if !address.is_valid() {
+        return Err(GatewayError::InvalidAddress);
+    }
+
+    address
+        .to_bech32_string()
+        .map_err(|_| GatewayError::InvalidAddress)
+}
+
+fn account_endpoint(address: &Address) -> Result<String, GatewayError> {
+    Ok(ACCOUNT_ENDPOINT.to_string() + valid_address(address)?.as_str())
+}
@@ -1,6 +1,8 @@
 use super::private_key::PrivateKey;
+use crate::data::address::Address;
 use anyhow::Result;
 use bech32::{self, ToBase32, Variant};
+use ed25519_dalek::{Signature, Verifier, VerifyingKey};
 use serde::{
     de::{Deserialize, Deserializer},
     ser::{Serialize, Serializer},
@@ -31,6 +33,18 @@ impl PublicKey {
         bits.copy_from_slice(&bytes[32..]);
         Ok(Self(bits))
     }
+
+    // verify checks an ed25519 signature of the message, made with the matching private key
+    pub fn verify(&self, message: &[u8], signature: &[u8]) -> bool {
+        let signature = match <[u8; 64]>::try_from(signature) {
+            Ok(signature) => Signature::from_bytes(&signature),
+            Err(_) => return false,
+        };
+        match VerifyingKey::from_bytes(&self.0) {
+            Ok(verifying_key) => verifying_key.verify(message, &signature).is_ok(),
+            Err(_) => false,
+        }
+    }
 }
 
 impl<'a> From<&'a PrivateKey> for PublicKey {
@@ -44,6 +58,12 @@ impl<'a> From<&'a PrivateKey> for PublicKey {
     }
 }
 
+impl<'a> From<&'a Address> for PublicKey {
+    fn from(address: &Address) -> PublicKey {
+        PublicKey(address.to_bytes())
+    }
+}
+
 impl ToString for PublicKey {
     fn to_string(&self) -> String {
         hex::encode(self.0)
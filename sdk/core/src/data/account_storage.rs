@@ -1,5 +1,6 @@
 use std::collections::HashMap;
 
+use crate::gateway_error::GatewayError;
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -14,3 +15,40 @@ pub struct AccountStorageResponse {
     pub error: String,
     pub code: String,
 }
+
+impl AccountStorage {
+    // get returns the decoded value stored under a key, if any
+    pub fn get(&self, key: &[u8]) -> Option<Vec<u8>> {
+        self.pairs
+            .get(&hex::encode(key))
+            .and_then(|value| hex::decode(value).ok())
+    }
+
+    // decode returns all the pairs, with their keys and values decoded
+    pub fn decode(&self) -> Result<HashMap<Vec<u8>, Vec<u8>>, GatewayError> {
+        self.pairs
+            .iter()
+            .map(|(key, value)| {
+                let key = hex::decode(key)
+                    .map_err(|_| GatewayError::decode(format!("storage key: {key}")))?;
+                let value = hex::decode(value)
+                    .map_err(|_| GatewayError::decode(format!("storage value: {value}")))?;
+                Ok((key, value))
+            })
+            .collect()
+    }
+}
+
+// AccountStorageValue holds a single storage value of an account, hex encoded
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccountStorageValue {
+    pub value: String,
+}
+
+// AccountStorageValueResponse holds the account storage value endpoint response
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccountStorageValueResponse {
+    pub data: Option<AccountStorageValue>,
+    pub error: String,
+    pub code: String,
+}
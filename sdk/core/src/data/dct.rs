@@ -1,3 +1,5 @@
+use super::address::Address;
+use crate::gateway_error::GatewayError;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -7,6 +9,14 @@ use std::collections::HashMap;
 pub struct DctBalance {
     pub token_identifier: String,
     pub balance: String,
+    // the fields below are only provided for NFT/SFT/MetaDCT instances
+    #[serde(default)]
+    pub nonce: u64,
+    pub attributes: Option<String>,
+    pub creator: Option<String>,
+    pub royalties: Option<String>,
+    pub hash: Option<String>,
+    pub uris: Option<Vec<String>>,
 }
 
 // DctBalanceDataholds the dct balance data
@@ -36,3 +46,194 @@ pub struct DctRolesResponse {
     pub error: String,
     pub code: String,
 }
+
+// ApiNftData holds an NFT/SFT/MetaDCT instance, as returned by the gateway
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApiNftData {
+    pub token_identifier: String,
+    #[serde(default)]
+    pub name: String,
+    pub balance: String,
+    #[serde(default)]
+    pub nonce: u64,
+    #[serde(default)]
+    pub creator: String,
+    #[serde(default)]
+    pub royalties: String,
+    #[serde(default)]
+    pub hash: String,
+    #[serde(default)]
+    pub uris: Vec<String>,
+    #[serde(default)]
+    pub attributes: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NftDataWrapper {
+    pub token_data: ApiNftData,
+}
+
+// NftDataResponse holds the nft data endpoint response
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NftDataResponse {
+    pub data: Option<NftDataWrapper>,
+    pub error: String,
+    pub code: String,
+}
+
+// NftData holds an NFT/SFT/MetaDCT instance, with its fields decoded
+#[derive(Debug, Clone)]
+pub struct NftData {
+    pub token_identifier: String,
+    pub name: String,
+    pub nonce: u64,
+    pub balance: String,
+    pub creator: Option<Address>,
+    pub royalties: u32,
+    pub hash: Vec<u8>,
+    pub uris: Vec<String>,
+    pub attributes: Vec<u8>,
+}
+
+impl TryFrom<ApiNftData> for NftData {
+    type Error = GatewayError;
+
+    fn try_from(api_data: ApiNftData) -> Result<Self, Self::Error> {
+        let creator = if api_data.creator.is_empty() {
+            None
+        } else {
+            Some(decode_address(&api_data.creator)?)
+        };
+        let royalties = if api_data.royalties.is_empty() {
+            0
+        } else {
+            api_data
+                .royalties
+                .parse()
+                .map_err(|_| GatewayError::decode(format!("royalties: {}", api_data.royalties)))?
+        };
+        let uris = api_data
+            .uris
+            .iter()
+            .map(|uri| {
+                decode_base64(uri, "uri").and_then(|uri| {
+                    String::from_utf8(uri).map_err(|_| GatewayError::decode("uri is not utf-8"))
+                })
+            })
+            .collect::<Result<Vec<String>, GatewayError>>()?;
+
+        Ok(NftData {
+            hash: decode_base64(&api_data.hash, "hash")?,
+            attributes: decode_base64(&api_data.attributes, "attributes")?,
+            token_identifier: api_data.token_identifier,
+            name: api_data.name,
+            nonce: api_data.nonce,
+            balance: api_data.balance,
+            creator,
+            royalties,
+            uris,
+        })
+    }
+}
+
+// TokenProperties holds the properties of a token, as registered in the DCT system smart contract
+#[derive(Debug, Clone, Default)]
+pub struct TokenProperties {
+    pub name: String,
+    pub token_type: String,
+    pub owner: Option<Address>,
+    pub total_supply: String,
+    pub burnt_value: String,
+    pub num_decimals: u32,
+    pub is_paused: bool,
+    pub can_upgrade: bool,
+    pub can_mint: bool,
+    pub can_burn: bool,
+    pub can_change_owner: bool,
+    pub can_pause: bool,
+    pub can_freeze: bool,
+    pub can_wipe: bool,
+    pub can_add_special_roles: bool,
+    pub can_transfer_nft_create_role: bool,
+    pub nft_create_stopped: bool,
+    pub num_wiped: u64,
+}
+
+impl TokenProperties {
+    // from_query_results decodes the results of the getTokenProperties system smart contract query,
+    // the first five are positional, the rest are "Name-value" pairs
+    pub fn from_query_results(results: &[Vec<u8>]) -> Result<Self, GatewayError> {
+        if results.len() < 5 {
+            return Err(GatewayError::decode(format!(
+                "expected at least 5 token properties, found {}",
+                results.len()
+            )));
+        }
+
+        let mut properties = TokenProperties {
+            name: utf8_result(&results[0])?,
+            token_type: utf8_result(&results[1])?,
+            owner: if results[2].len() == 32 {
+                let mut owner = [0u8; 32];
+                owner.copy_from_slice(&results[2]);
+                Some(Address::from_bytes(owner))
+            } else {
+                None
+            },
+            total_supply: utf8_result(&results[3])?,
+            burnt_value: utf8_result(&results[4])?,
+            ..Default::default()
+        };
+
+        for result in &results[5..] {
+            let property = utf8_result(result)?;
+            let (name, value) = property
+                .split_once('-')
+                .ok_or_else(|| GatewayError::decode(format!("token property: {property}")))?;
+            match name {
+                "NumDecimals" => properties.num_decimals = parse_property(name, value)?,
+                "IsPaused" => properties.is_paused = parse_property(name, value)?,
+                "CanUpgrade" => properties.can_upgrade = parse_property(name, value)?,
+                "CanMint" => properties.can_mint = parse_property(name, value)?,
+                "CanBurn" => properties.can_burn = parse_property(name, value)?,
+                "CanChangeOwner" => properties.can_change_owner = parse_property(name, value)?,
+                "CanPause" => properties.can_pause = parse_property(name, value)?,
+                "CanFreeze" => properties.can_freeze = parse_property(name, value)?,
+                "CanWipe" => properties.can_wipe = parse_property(name, value)?,
+                "CanAddSpecialRoles" => {
+                    properties.can_add_special_roles = parse_property(name, value)?
+                },
+                "CanTransferNFTCreateRole" => {
+                    properties.can_transfer_nft_create_role = parse_property(name, value)?
+                },
+                "NFTCreateStopped" => properties.nft_create_stopped = parse_property(name, value)?,
+                "NumWiped" => properties.num_wiped = parse_property(name, value)?,
+                _ => {}, // properties added to the protocol later on are ignored
+            }
+        }
+
+        Ok(properties)
+    }
+}
+
+fn decode_address(bech32: &str) -> Result<Address, GatewayError> {
+    Address::from_bech32_string(bech32)
+        .map_err(|_| GatewayError::decode(format!("address: {bech32}")))
+}
+
+fn decode_base64(encoded: &str, field_name: &str) -> Result<Vec<u8>, GatewayError> {
+    base64::decode(encoded).map_err(|_| GatewayError::decode(format!("{field_name}: {encoded}")))
+}
+
+fn utf8_result(result: &[u8]) -> Result<String, GatewayError> {
+    String::from_utf8(result.to_vec())
+        .map_err(|_| GatewayError::decode("token property is not utf-8"))
+}
+
+fn parse_property<T: std::str::FromStr>(name: &str, value: &str) -> Result<T, GatewayError> {
+    value
+        .parse()
+        .map_err(|_| GatewayError::decode(format!("token property {name}: {value}")))
+}
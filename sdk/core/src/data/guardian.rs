@@ -0,0 +1,38 @@
+use super::address::Address;
+use serde::{Deserialize, Serialize};
+
+// Guardian holds an account's active or pending guardian
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Guardian {
+    pub address: Address,
+    pub activation_epoch: u32,
+    #[serde(rename = "serviceUID", default)]
+    pub service_uid: String,
+}
+
+// GuardianData holds the guardian configuration of an account
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GuardianData {
+    #[serde(default)]
+    pub active_guardian: Option<Guardian>,
+    #[serde(default)]
+    pub pending_guardian: Option<Guardian>,
+    #[serde(default)]
+    pub guarded: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GuardianDataWrapper {
+    pub guardian_data: GuardianData,
+}
+
+// GuardianDataResponse holds the guardian data endpoint response
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GuardianDataResponse {
+    pub data: Option<GuardianDataWrapper>,
+    pub error: String,
+    pub code: String,
+}
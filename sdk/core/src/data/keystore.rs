@@ -0,0 +1,47 @@
+use serde::{Deserialize, Serialize};
+
+pub const KEYSTORE_VERSION: u32 = 4;
+pub const KEYSTORE_KIND_SECRET_KEY: &str = "secretKey";
+pub const KEYSTORE_CIPHER: &str = "aes-128-ctr";
+pub const KEYSTORE_KDF: &str = "scrypt";
+
+// Keystore is the password-protected JSON format in which the wallets export secret keys
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Keystore {
+    pub version: u32,
+    #[serde(default = "default_kind")]
+    pub kind: String,
+    pub id: String,
+    pub address: String,
+    pub bech32: String,
+    pub crypto: KeystoreCrypto,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KeystoreCrypto {
+    pub ciphertext: String,
+    pub cipherparams: KeystoreCipherParams,
+    pub cipher: String,
+    pub kdf: String,
+    pub kdfparams: KeystoreKdfParams,
+    pub mac: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KeystoreCipherParams {
+    pub iv: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KeystoreKdfParams {
+    pub dklen: usize,
+    pub salt: String,
+    pub n: u64,
+    pub r: u32,
+    pub p: u32,
+}
+
+// older keystores have no kind, they can only hold secret keys
+fn default_kind() -> String {
+    KEYSTORE_KIND_SECRET_KEY.to_string()
+}
@@ -2,7 +2,9 @@ pub mod account;
 pub mod account_storage;
 pub mod address;
 pub mod dct;
+pub mod guardian;
 pub mod hyperblock;
+pub mod keystore;
 pub mod network_config;
 pub mod network_economics;
 pub mod network_status;
@@ -1,8 +1,18 @@
 use std::collections::HashMap;
 
 use super::{address::Address, vm::CallType};
+use crate::signer::Signer;
+use anyhow::{anyhow, Result};
 use serde::{Deserialize, Serialize};
 
+// transactions need at least this version to use the options field
+pub const TX_VERSION_WITH_OPTIONS: u32 = 2;
+pub const TX_OPTION_HASH_SIGN: u32 = 1;
+pub const TX_OPTION_GUARDED: u32 = 2;
+
+// guarded transactions cost this much more gas than their unguarded counterparts
+pub const GUARDED_TX_EXTRA_GAS_LIMIT: u64 = 50_000;
+
 // Transaction holds the fields of a transaction to be broadcasted to the network
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -20,8 +30,57 @@ pub struct Transaction {
     #[serde(rename = "chainID")]
     pub chain_id: String,
     pub version: u32,
-    #[serde(skip_serializing_if = "is_zero")]
+    #[serde(default, skip_serializing_if = "is_zero")]
     pub options: u32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub guardian: Option<Address>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub guardian_signature: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub relayer: Option<Address>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub relayer_signature: Option<String>,
+}
+
+impl Transaction {
+    pub fn is_guarded(&self) -> bool {
+        self.version >= TX_VERSION_WITH_OPTIONS && self.options & TX_OPTION_GUARDED != 0
+    }
+
+    // sign sets the sender's signature, the signer has to sign for the sender's address
+    pub async fn sign(&mut self, signer: &dyn Signer) -> Result<()> {
+        if signer.address().to_bytes() != self.sender.to_bytes() {
+            return Err(anyhow!("signer is not the sender of the transaction"));
+        }
+
+        let signature = signer.sign_tx(self).await?;
+        self.signature = Some(hex::encode(signature));
+        Ok(())
+    }
+
+    // guardian_sign co-signs a guarded transaction, the signer has to be the guardian set in the transaction
+    pub async fn guardian_sign(&mut self, guardian: &dyn Signer) -> Result<()> {
+        if !self.is_guarded() {
+            return Err(anyhow!("transaction is not guarded"));
+        }
+        match &self.guardian {
+            Some(address) if address.to_bytes() == guardian.address().to_bytes() => {},
+            _ => return Err(anyhow!("signer is not the guardian of the transaction")),
+        }
+
+        let signature = guardian.sign_tx(self).await?;
+        self.guardian_signature = Some(hex::encode(signature));
+        Ok(())
+    }
+
+    // set_guardian marks the transaction as guarded, it needs to happen before the transaction gets signed
+    pub fn set_guardian(&mut self, guardian: Address) {
+        self.guardian = Some(guardian);
+        self.options |= TX_OPTION_GUARDED;
+        if self.version < TX_VERSION_WITH_OPTIONS {
+            self.version = TX_VERSION_WITH_OPTIONS;
+        }
+    }
 }
 
 /// This is only used for serialize
@@ -194,3 +253,86 @@ pub struct SendTransactionsResponse {
     pub code: String,
     pub data: Option<SendTransactionsResponseData>,
 }
+
+// PoolTransaction holds the fields of a transaction that is still in the transaction pool
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PoolTransaction {
+    pub hash: String,
+    pub nonce: u64,
+    pub sender: Address,
+    pub receiver: Address,
+    pub value: String,
+    #[serde(rename = "gaslimit")]
+    pub gas_limit: u64,
+    #[serde(rename = "gasprice")]
+    pub gas_price: u64,
+    #[serde(default)]
+    pub data: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PoolTransactionWrapper {
+    pub tx_fields: PoolTransaction,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TransactionPoolForSender {
+    #[serde(default)]
+    pub transactions: Vec<PoolTransactionWrapper>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionPoolForSenderData {
+    pub tx_pool: TransactionPoolForSender,
+}
+
+// TransactionPoolForSenderResponse holds the transaction pool endpoint response, when filtering by sender
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TransactionPoolForSenderResponse {
+    pub data: Option<TransactionPoolForSenderData>,
+    pub error: String,
+    pub code: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TransactionPoolLastNonceData {
+    pub nonce: u64,
+}
+
+// TransactionPoolLastNonceResponse holds the last nonce of a sender in the transaction pool
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TransactionPoolLastNonceResponse {
+    pub data: Option<TransactionPoolLastNonceData>,
+    pub error: String,
+    pub code: String,
+}
+
+// NonceGap is a range of nonces missing from the transaction pool, blocking the sender's later transactions
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NonceGap {
+    pub from: u64,
+    pub to: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NonceGaps {
+    pub sender: Address,
+    #[serde(default)]
+    pub gaps: Vec<NonceGap>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NonceGapsData {
+    pub nonce_gaps: NonceGaps,
+}
+
+// NonceGapsResponse holds the nonce gaps of a sender in the transaction pool
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NonceGapsResponse {
+    pub data: Option<NonceGapsData>,
+    pub error: String,
+    pub code: String,
+}
@@ -0,0 +1,50 @@
+use std::fmt::{Display, Formatter};
+
+// GatewayError is returned by the typed gateway queries
+#[derive(Debug)]
+pub enum GatewayError {
+    // InvalidAddress means the request was not sent, because of a malformed address
+    InvalidAddress,
+    // Request means the gateway could not be reached, or did not reply with the expected JSON
+    Request(reqwest::Error),
+    // Gateway means the gateway replied, but with an error
+    Gateway { code: String, error: String },
+    // Decode means a field of the reply could not be decoded
+    Decode(String),
+}
+
+impl GatewayError {
+    pub(crate) fn gateway(code: String, error: String) -> Self {
+        GatewayError::Gateway { code, error }
+    }
+
+    pub(crate) fn decode<S: Into<String>>(message: S) -> Self {
+        GatewayError::Decode(message.into())
+    }
+}
+
+impl Display for GatewayError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GatewayError::InvalidAddress => write!(f, "invalid address"),
+            GatewayError::Request(err) => write!(f, "gateway request failed: {err}"),
+            GatewayError::Gateway { code, error } => write!(f, "gateway error ({code}): {error}"),
+            GatewayError::Decode(message) => write!(f, "could not decode gateway reply: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for GatewayError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            GatewayError::Request(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<reqwest::Error> for GatewayError {
+    fn from(err: reqwest::Error) -> Self {
+        GatewayError::Request(err)
+    }
+}
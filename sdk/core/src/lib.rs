@@ -1,4 +1,10 @@
+pub mod abi;
 pub mod blockchain;
 pub mod crypto;
 pub mod data;
+pub mod gateway_error;
+pub mod message;
+pub mod native_auth;
+pub mod relayed;
+pub mod signer;
 pub mod wallet;
@@ -0,0 +1,22 @@
+use sha3::{Digest, Keccak256};
+
+use crate::{crypto::public_key::PublicKey, data::address::Address};
+
+// MESSAGE_PREFIX is prepended to every signed message, so that a signed message can never be a valid transaction.
+// Its first byte is the length of the text that follows it.
+pub const MESSAGE_PREFIX: &str = "\x19Dharitri Signed Message:\n";
+
+// message_signing_bytes returns what actually gets signed for a message:
+// the hash of the prefix, followed by the message length in decimal and the message itself
+pub fn message_signing_bytes(message: &[u8]) -> Vec<u8> {
+    let mut hasher = Keccak256::new();
+    hasher.update(MESSAGE_PREFIX.as_bytes());
+    hasher.update(message.len().to_string().as_bytes());
+    hasher.update(message);
+    hasher.finalize().to_vec()
+}
+
+// verify_message checks that the message was signed by the given address, as done by Signer::sign_message
+pub fn verify_message(address: &Address, message: &[u8], signature: &[u8]) -> bool {
+    PublicKey::from(address).verify(&message_signing_bytes(message), signature)
+}
@@ -0,0 +1,257 @@
+use anyhow::{anyhow, Result};
+use serde_json::Value;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::{
+    blockchain::CommunicationProxy, data::address::Address, message::verify_message, signer::Signer,
+};
+
+// same default as the web wallets: tokens are valid for one day
+pub const DEFAULT_NATIVE_AUTH_TTL: u64 = 86400;
+
+// NativeAuthToken proves that the holder of an address logged in to an origin, without any transaction.
+//
+// Encoded, it reads `base64(address).base64(body).hex(signature)`, where the body is
+// `base64(origin).block_hash.ttl.base64(extra_info)`, all base64 being url-safe and unpadded.
+// The signature is a signed message of the bech32 address followed by the body.
+// The block hash anchors the token in time: it expires ttl seconds after that block.
+//
+// The body is kept exactly as signed, since other clients may serialize the extra info differently.
+#[derive(Debug, Clone)]
+pub struct NativeAuthToken {
+    pub address: Address,
+    pub origin: String,
+    pub block_hash: String,
+    pub ttl: u64,
+    pub extra_info: Value,
+    pub signature: [u8; 64],
+    body: String,
+}
+
+impl NativeAuthToken {
+    // sign creates a token for the signer, anchored in the block with the given hash
+    pub async fn sign(
+        signer: &dyn Signer,
+        origin: &str,
+        block_hash: &str,
+        ttl: u64,
+        extra_info: Value,
+    ) -> Result<Self> {
+        let body = format!(
+            "{}.{}.{}.{}",
+            encode_part(origin.as_bytes()),
+            block_hash,
+            ttl,
+            encode_part(extra_info.to_string().as_bytes())
+        );
+        let mut token = NativeAuthToken {
+            address: signer.address(),
+            origin: origin.to_string(),
+            block_hash: block_hash.to_string(),
+            ttl,
+            extra_info,
+            signature: [0u8; 64],
+            body,
+        };
+        token.signature = signer
+            .sign_message(token.signed_message()?.as_bytes())
+            .await?;
+        Ok(token)
+    }
+
+    // body is the part of the token describing where and until when it is valid, as signed
+    pub fn body(&self) -> &str {
+        &self.body
+    }
+
+    pub fn encode(&self) -> Result<String> {
+        Ok(format!(
+            "{}.{}.{}",
+            encode_part(self.address.to_bech32_string()?.as_bytes()),
+            encode_part(self.body().as_bytes()),
+            hex::encode(self.signature)
+        ))
+    }
+
+    // decode parses a token, without checking its signature
+    pub fn decode(token: &str) -> Result<Self> {
+        let parts: Vec<&str> = token.split('.').collect();
+        if parts.len() != 3 {
+            return Err(anyhow!("malformed native auth token"));
+        }
+
+        let address = Address::from_bech32_string(&decode_part(parts[0])?)?;
+        let body = decode_part(parts[1])?;
+        let signature = hex::decode(parts[2])
+            .ok()
+            .and_then(|signature| <[u8; 64]>::try_from(signature).ok())
+            .ok_or_else(|| anyhow!("malformed native auth token signature"))?;
+
+        let body_parts: Vec<&str> = body.split('.').collect();
+        if body_parts.len() != 4 {
+            return Err(anyhow!("malformed native auth token body"));
+        }
+        let ttl = body_parts[2]
+            .parse::<u64>()
+            .map_err(|_| anyhow!("malformed native auth token ttl"))?;
+        let extra_info = serde_json::from_str(&decode_part(body_parts[3])?)
+            .map_err(|_| anyhow!("malformed native auth token extra info"))?;
+
+        Ok(NativeAuthToken {
+            address,
+            origin: decode_part(body_parts[0])?,
+            block_hash: body_parts[1].to_string(),
+            ttl,
+            extra_info,
+            signature,
+            body,
+        })
+    }
+
+    // verify_signature checks that the token was signed by its address
+    pub fn verify_signature(&self) -> bool {
+        match self.signed_message() {
+            Ok(message) => verify_message(&self.address, message.as_bytes(), &self.signature),
+            Err(_) => false,
+        }
+    }
+
+    fn signed_message(&self) -> Result<String> {
+        Ok(format!("{}{}", self.address.to_bech32_string()?, self.body))
+    }
+}
+
+// NativeAuthClient generates login tokens, for an origin
+#[derive(Debug, Clone)]
+pub struct NativeAuthClient {
+    pub origin: String,
+    pub ttl: u64,
+    pub extra_info: Value,
+}
+
+impl NativeAuthClient {
+    pub fn new(origin: &str) -> Self {
+        NativeAuthClient {
+            origin: origin.to_string(),
+            ttl: DEFAULT_NATIVE_AUTH_TTL,
+            extra_info: Value::Object(Default::default()),
+        }
+    }
+
+    pub fn with_ttl(mut self, ttl: u64) -> Self {
+        self.ttl = ttl;
+        self
+    }
+
+    pub fn with_extra_info(mut self, extra_info: Value) -> Self {
+        self.extra_info = extra_info;
+        self
+    }
+
+    // generate_token creates an encoded token, anchored in the latest metachain block
+    pub async fn generate_token(
+        &self,
+        proxy: &CommunicationProxy,
+        signer: &dyn Signer,
+    ) -> Result<String> {
+        let nonce = proxy.get_latest_hyper_block_nonce(true).await?;
+        let block = proxy.get_hyper_block_by_nonce(nonce).await?;
+        self.generate_token_for_block(signer, &block.hash).await
+    }
+
+    // generate_token_for_block creates an encoded token, anchored in the block with the given hash
+    pub async fn generate_token_for_block(
+        &self,
+        signer: &dyn Signer,
+        block_hash: &str,
+    ) -> Result<String> {
+        NativeAuthToken::sign(
+            signer,
+            &self.origin,
+            block_hash,
+            self.ttl,
+            self.extra_info.clone(),
+        )
+        .await?
+        .encode()
+    }
+}
+
+// NativeAuthServer validates the login tokens sent by clients
+#[derive(Debug, Clone)]
+pub struct NativeAuthServer {
+    // accepted_origins restricts where tokens can come from, no restriction if empty
+    pub accepted_origins: Vec<String>,
+    pub max_ttl: u64,
+}
+
+impl NativeAuthServer {
+    pub fn new(accepted_origins: Vec<String>) -> Self {
+        NativeAuthServer {
+            accepted_origins,
+            max_ttl: DEFAULT_NATIVE_AUTH_TTL,
+        }
+    }
+
+    pub fn with_max_ttl(mut self, max_ttl: u64) -> Self {
+        self.max_ttl = max_ttl;
+        self
+    }
+
+    // validate checks a token, looking up the timestamp of the block it is anchored in
+    pub async fn validate(
+        &self,
+        proxy: &CommunicationProxy,
+        token: &str,
+    ) -> Result<NativeAuthToken> {
+        let decoded = NativeAuthToken::decode(token)?;
+        let block = proxy
+            .get_hyper_block_by_hash(&decoded.block_hash)
+            .await
+            .map_err(|err| anyhow!("native auth token block not found: {err}"))?;
+        let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+        self.validate_at(token, block.timestamp, now)
+    }
+
+    // validate_at checks a token, given the timestamp of the block it is anchored in and the current time
+    pub fn validate_at(
+        &self,
+        token: &str,
+        block_timestamp: u64,
+        now: u64,
+    ) -> Result<NativeAuthToken> {
+        let decoded = NativeAuthToken::decode(token)?;
+
+        if !self.accepted_origins.is_empty() && !self.accepted_origins.contains(&decoded.origin) {
+            return Err(anyhow!(
+                "native auth token origin {} is not accepted",
+                decoded.origin
+            ));
+        }
+        if decoded.ttl > self.max_ttl {
+            return Err(anyhow!(
+                "native auth token ttl {} is over the maximum of {}",
+                decoded.ttl,
+                self.max_ttl
+            ));
+        }
+        if block_timestamp + decoded.ttl < now {
+            return Err(anyhow!("native auth token expired"));
+        }
+        if !decoded.verify_signature() {
+            return Err(anyhow!("invalid native auth token signature"));
+        }
+
+        Ok(decoded)
+    }
+}
+
+fn encode_part(part: &[u8]) -> String {
+    base64::encode_config(part, base64::URL_SAFE_NO_PAD)
+}
+
+fn decode_part(part: &str) -> Result<String> {
+    let bytes = base64::decode_config(part.trim_end_matches('='), base64::URL_SAFE_NO_PAD)
+        .map_err(|_| anyhow!("malformed native auth token"))?;
+    String::from_utf8(bytes).map_err(|_| anyhow!("malformed native auth token"))
+}
@@ -0,0 +1,170 @@
+use anyhow::{anyhow, Result};
+
+use crate::data::{address::Address, network_config::NetworkConfig, transaction::Transaction};
+
+const RELAYED_V1_PREFIX: &str = "relayedTx";
+const RELAYED_V2_PREFIX: &str = "relayedTxV2";
+
+// RelayedTransactionBuilder creates transactions where a relayer pays the gas on behalf of the sender
+pub struct RelayedTransactionBuilder<'a> {
+    network_config: &'a NetworkConfig,
+}
+
+impl<'a> RelayedTransactionBuilder<'a> {
+    pub fn new(network_config: &'a NetworkConfig) -> Self {
+        Self { network_config }
+    }
+
+    // relayed_v1 wraps the signed inner transaction, as JSON, into the data of a transaction sent by the relayer
+    pub fn relayed_v1(
+        &self,
+        inner_tx: &Transaction,
+        relayer: &Address,
+        relayer_nonce: u64,
+    ) -> Result<Transaction> {
+        let data = format!(
+            "{RELAYED_V1_PREFIX}@{}",
+            hex::encode(relayed_v1_inner_tx_json(inner_tx)?)
+        );
+        let gas_limit = self.relayed_gas_limit(&data, inner_tx.gas_limit);
+        Ok(self.relayed_tx(inner_tx, relayer, relayer_nonce, data, gas_limit))
+    }
+
+    // relayed_v2 only works for smart contract calls, the inner transaction must be signed with a gas limit of 0
+    pub fn relayed_v2(
+        &self,
+        inner_tx: &Transaction,
+        inner_tx_gas_limit: u64,
+        relayer: &Address,
+        relayer_nonce: u64,
+    ) -> Result<Transaction> {
+        if inner_tx.gas_limit != 0 {
+            return Err(anyhow!(
+                "the gas limit of the inner transaction must be 0 for relayed v2"
+            ));
+        }
+        let signature = inner_tx_signature(inner_tx)?;
+        let inner_data = decode_tx_data(inner_tx)?;
+
+        let data = format!(
+            "{RELAYED_V2_PREFIX}@{}@{}@{}@{}",
+            hex::encode(inner_tx.receiver.to_bytes()),
+            padded_hex_u64(inner_tx.nonce),
+            hex::encode(inner_data),
+            hex::encode(signature),
+        );
+        let gas_limit = self.relayed_gas_limit(&data, inner_tx_gas_limit);
+        Ok(self.relayed_tx(inner_tx, relayer, relayer_nonce, data, gas_limit))
+    }
+
+    // relayed_v3 prepares the transaction before it gets signed by the sender,
+    // afterwards the relayer signs the same transaction and fills in the relayer signature
+    pub fn relayed_v3(&self, tx: &mut Transaction, relayer: &Address) -> Result<()> {
+        if tx.signature.is_some() {
+            return Err(anyhow!(
+                "the relayer must be set before the transaction is signed"
+            ));
+        }
+        tx.relayer = Some(relayer.clone());
+        tx.gas_limit += self.network_config.min_gas_limit;
+        Ok(())
+    }
+
+    fn relayed_gas_limit(&self, data: &str, inner_tx_gas_limit: u64) -> u64 {
+        self.network_config.min_gas_limit
+            + self.network_config.gas_per_data_byte * data.len() as u64
+            + inner_tx_gas_limit
+    }
+
+    fn relayed_tx(
+        &self,
+        inner_tx: &Transaction,
+        relayer: &Address,
+        relayer_nonce: u64,
+        data: String,
+        gas_limit: u64,
+    ) -> Transaction {
+        Transaction {
+            nonce: relayer_nonce,
+            value: "0".to_string(),
+            receiver: inner_tx.sender.clone(),
+            sender: relayer.clone(),
+            gas_price: inner_tx.gas_price,
+            gas_limit,
+            data: Some(base64::encode(data)),
+            signature: None,
+            chain_id: inner_tx.chain_id.clone(),
+            version: inner_tx.version,
+            options: 0,
+            guardian: None,
+            guardian_signature: None,
+            relayer: None,
+            relayer_signature: None,
+        }
+    }
+}
+
+// the protocol expects the byte fields in base64 and the value as a JSON number, which can exceed u64
+fn relayed_v1_inner_tx_json(inner_tx: &Transaction) -> Result<String> {
+    let signature = inner_tx_signature(inner_tx)?;
+    if inner_tx.value.is_empty() || !inner_tx.value.chars().all(|c| c.is_ascii_digit()) {
+        return Err(anyhow!(
+            "invalid inner transaction value: {}",
+            inner_tx.value
+        ));
+    }
+
+    let mut json = format!(
+        "{{\"nonce\":{},\"sender\":\"{}\",\"receiver\":\"{}\",\"value\":{},\"gasPrice\":{},\"gasLimit\":{},\"data\":\"{}\",\"signature\":\"{}\",\"chainID\":\"{}\",\"version\":{}",
+        inner_tx.nonce,
+        base64::encode(inner_tx.sender.to_bytes()),
+        base64::encode(inner_tx.receiver.to_bytes()),
+        inner_tx.value,
+        inner_tx.gas_price,
+        inner_tx.gas_limit,
+        inner_tx.data.as_deref().unwrap_or_default(),
+        base64::encode(signature),
+        base64::encode(&inner_tx.chain_id),
+        inner_tx.version,
+    );
+    if inner_tx.options != 0 {
+        json.push_str(&format!(",\"options\":{}", inner_tx.options));
+    }
+    if let Some(guardian) = &inner_tx.guardian {
+        json.push_str(&format!(
+            ",\"guardian\":\"{}\"",
+            base64::encode(guardian.to_bytes())
+        ));
+    }
+    if let Some(guardian_signature) = &inner_tx.guardian_signature {
+        json.push_str(&format!(
+            ",\"guardianSignature\":\"{}\"",
+            base64::encode(hex::decode(guardian_signature)?)
+        ));
+    }
+    json.push('}');
+    Ok(json)
+}
+
+fn inner_tx_signature(inner_tx: &Transaction) -> Result<Vec<u8>> {
+    match &inner_tx.signature {
+        Some(signature) => Ok(hex::decode(signature)?),
+        None => Err(anyhow!("the inner transaction must be signed")),
+    }
+}
+
+fn decode_tx_data(tx: &Transaction) -> Result<Vec<u8>> {
+    match &tx.data {
+        Some(data) => Ok(base64::decode(data)?),
+        None => Ok(Vec::new()),
+    }
+}
+
+fn padded_hex_u64(value: u64) -> String {
+    let hex = format!("{value:x}");
+    if hex.len() % 2 == 1 {
+        format!("0{hex}")
+    } else {
+        hex
+    }
+}
@@ -0,0 +1,50 @@
+mod remote_signer;
+mod test_signer;
+
+pub use remote_signer::RemoteSigner;
+pub use test_signer::TestSigner;
+
+use anyhow::Result;
+use serde_json::json;
+use sha3::{Digest, Keccak256};
+use std::{future::Future, pin::Pin};
+
+use crate::data::{
+    address::Address,
+    transaction::{Transaction, TX_OPTION_HASH_SIGN, TX_VERSION_WITH_OPTIONS},
+};
+
+// SignatureFuture is returned by the signers, since signing can involve waiting for a remote service
+pub type SignatureFuture<'a> = Pin<Box<dyn Future<Output = Result<[u8; 64]>> + Send + 'a>>;
+
+// Signer produces the signatures of an account, without necessarily holding its private key
+pub trait Signer: Send + Sync {
+    // address is the account the signatures are produced for
+    fn address(&self) -> Address;
+
+    // sign_tx signs the transaction as it gets serialized for the network, existing signatures are ignored
+    fn sign_tx<'a>(&'a self, tx: &'a Transaction) -> SignatureFuture<'a>;
+
+    // sign_message signs an arbitrary message, prefixed and hashed by message_signing_bytes,
+    // so that the signature can never pass for the one of a transaction
+    fn sign_message<'a>(&'a self, message: &'a [u8]) -> SignatureFuture<'a>;
+}
+
+// tx_signing_bytes returns what actually gets signed for a transaction: its serialized form without signatures,
+// or the hash of it, if the transaction options say so
+pub fn tx_signing_bytes(tx: &Transaction) -> Vec<u8> {
+    let mut unsigned_tx = tx.clone();
+    unsigned_tx.signature = None;
+    unsigned_tx.guardian_signature = None;
+    unsigned_tx.relayer_signature = None;
+
+    let tx_bytes = json!(unsigned_tx).to_string().into_bytes();
+
+    let should_sign_on_tx_hash = unsigned_tx.version >= TX_VERSION_WITH_OPTIONS
+        && unsigned_tx.options & TX_OPTION_HASH_SIGN > 0;
+    if should_sign_on_tx_hash {
+        Keccak256::digest(&tx_bytes).to_vec()
+    } else {
+        tx_bytes
+    }
+}
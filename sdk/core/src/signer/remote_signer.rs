@@ -0,0 +1,226 @@
+use anyhow::{anyhow, Result};
+use reqwest::Client;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::time::Duration;
+
+use super::{SignatureFuture, Signer};
+use crate::data::{address::Address, transaction::Transaction};
+
+const ADDRESS_PATH: &str = "/address";
+const SIGN_TRANSACTION_PATH: &str = "/sign/transaction";
+const SIGN_MESSAGE_PATH: &str = "/sign/message";
+
+// signing may wait for a human to approve it on a device
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(120);
+
+// RemoteSigner delegates signing to a local signing service, so the private key never enters the process.
+//
+// The service speaks JSON over HTTP, on a TCP port or a Unix socket:
+// - GET /address replies with {"address": "<bech32>"}
+// - POST /sign/transaction takes the transaction JSON and replies with {"signature": "<hex>"}
+// - POST /sign/message takes {"message": "<hex>"} and replies with {"signature": "<hex>"},
+//   the service prefixing and hashing the message the same way as message_signing_bytes
+// - non-2xx replies can explain themselves with {"error": "<reason>"}, e.g. when the transaction is refused
+#[derive(Debug, Clone)]
+pub struct RemoteSigner {
+    endpoint: SigningServiceEndpoint,
+    address: Address,
+    timeout: Duration,
+}
+
+#[derive(Debug, Clone)]
+enum SigningServiceEndpoint {
+    Http {
+        url: String,
+        client: Client,
+    },
+    #[cfg(unix)]
+    UnixSocket(std::path::PathBuf),
+}
+
+#[derive(Deserialize)]
+struct AddressReply {
+    address: Address,
+}
+
+#[derive(Serialize)]
+struct SignMessageRequest {
+    message: String,
+}
+
+#[derive(Deserialize)]
+struct SignatureReply {
+    signature: String,
+}
+
+#[derive(Deserialize)]
+struct ErrorReply {
+    error: String,
+}
+
+impl RemoteSigner {
+    // http connects to a signing service on a TCP address, e.g. "127.0.0.1:7000"
+    pub async fn http(address: &str) -> Result<Self> {
+        let address = address.trim_start_matches("http://").trim_end_matches('/');
+        Self::connect(SigningServiceEndpoint::Http {
+            url: format!("http://{address}"),
+            client: Client::new(),
+        })
+        .await
+    }
+
+    // unix_socket connects to a signing service listening on a Unix socket
+    #[cfg(unix)]
+    pub async fn unix_socket<P: AsRef<std::path::Path>>(path: P) -> Result<Self> {
+        Self::connect(SigningServiceEndpoint::UnixSocket(
+            path.as_ref().to_path_buf(),
+        ))
+        .await
+    }
+
+    // with_timeout bounds how long a single request to the service can take
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    async fn connect(endpoint: SigningServiceEndpoint) -> Result<Self> {
+        let mut signer = RemoteSigner {
+            endpoint,
+            address: Address::from_bytes([0u8; 32]),
+            timeout: DEFAULT_TIMEOUT,
+        };
+        let reply: AddressReply = signer.request(ADDRESS_PATH, None).await?;
+        signer.address = reply.address;
+        Ok(signer)
+    }
+
+    // request sends a GET, or a POST if there is a body
+    async fn request<T: DeserializeOwned>(&self, path: &str, body: Option<Vec<u8>>) -> Result<T> {
+        let (status, reply_body) = match &self.endpoint {
+            SigningServiceEndpoint::Http { url, client } => {
+                let request = match body {
+                    Some(body) => client
+                        .post(format!("{url}{path}"))
+                        .header("Content-Type", "application/json")
+                        .body(body),
+                    None => client.get(format!("{url}{path}")),
+                };
+                let response =
+                    request.timeout(self.timeout).send().await.map_err(|err| {
+                        anyhow!("could not reach signing service at {url}: {err}")
+                    })?;
+                let status = response.status().as_u16();
+                (status, response.bytes().await?.to_vec())
+            },
+            #[cfg(unix)]
+            SigningServiceEndpoint::UnixSocket(socket_path) => {
+                tokio::time::timeout(self.timeout, unix_socket_request(socket_path, path, body))
+                    .await
+                    .map_err(|_| anyhow!("signing service did not reply in time"))??
+            },
+        };
+
+        if !(200..300).contains(&status) {
+            let reason = serde_json::from_slice::<ErrorReply>(&reply_body)
+                .map(|reply| reply.error)
+                .unwrap_or_else(|_| String::from_utf8_lossy(&reply_body).to_string());
+            return Err(anyhow!("signing service refused ({status}): {reason}"));
+        }
+        serde_json::from_slice(&reply_body)
+            .map_err(|err| anyhow!("unexpected reply from signing service: {err}"))
+    }
+}
+
+impl Signer for RemoteSigner {
+    fn address(&self) -> Address {
+        self.address.clone()
+    }
+
+    fn sign_tx<'a>(&'a self, tx: &'a Transaction) -> SignatureFuture<'a> {
+        Box::pin(async move {
+            let mut unsigned_tx = tx.clone();
+            unsigned_tx.signature = None;
+            unsigned_tx.guardian_signature = None;
+            unsigned_tx.relayer_signature = None;
+
+            let reply: SignatureReply = self
+                .request(
+                    SIGN_TRANSACTION_PATH,
+                    Some(serde_json::to_vec(&unsigned_tx)?),
+                )
+                .await?;
+            decode_signature(&reply.signature)
+        })
+    }
+
+    fn sign_message<'a>(&'a self, message: &'a [u8]) -> SignatureFuture<'a> {
+        Box::pin(async move {
+            let request = SignMessageRequest {
+                message: hex::encode(message),
+            };
+            let reply: SignatureReply = self
+                .request(SIGN_MESSAGE_PATH, Some(serde_json::to_vec(&request)?))
+                .await?;
+            decode_signature(&reply.signature)
+        })
+    }
+}
+
+fn decode_signature(signature_hex: &str) -> Result<[u8; 64]> {
+    let signature = hex::decode(signature_hex)
+        .map_err(|_| anyhow!("signing service returned a malformed signature"))?;
+    signature
+        .try_into()
+        .map_err(|_| anyhow!("signing service returned a signature of the wrong length"))
+}
+
+// unix_socket_request sends a single HTTP/1.1 request and reads the reply until the service closes the connection
+#[cfg(unix)]
+async fn unix_socket_request(
+    socket_path: &std::path::Path,
+    path: &str,
+    body: Option<Vec<u8>>,
+) -> Result<(u16, Vec<u8>)> {
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    let mut stream = tokio::net::UnixStream::connect(socket_path)
+        .await
+        .map_err(|err| {
+            anyhow!(
+                "could not reach signing service at {}: {err}",
+                socket_path.display()
+            )
+        })?;
+
+    let method = if body.is_some() { "POST" } else { "GET" };
+    let body = body.unwrap_or_default();
+    let head = format!(
+        "{method} {path} HTTP/1.1\r\nHost: localhost\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+        body.len()
+    );
+    stream.write_all(head.as_bytes()).await?;
+    stream.write_all(&body).await?;
+    stream.flush().await?;
+
+    let mut reply = Vec::new();
+    stream.read_to_end(&mut reply).await?;
+
+    let head_end = reply
+        .windows(4)
+        .position(|window| window == b"\r\n\r\n")
+        .ok_or_else(|| anyhow!("malformed reply from signing service"))?;
+    let head = String::from_utf8_lossy(&reply[..head_end]).to_lowercase();
+    if head.contains("transfer-encoding: chunked") {
+        return Err(anyhow!(
+            "signing service replied with a chunked body, which is not supported"
+        ));
+    }
+    let status = head
+        .split_whitespace()
+        .nth(1)
+        .and_then(|status| status.parse::<u16>().ok())
+        .ok_or_else(|| anyhow!("malformed reply from signing service"))?;
+
+    Ok((status, reply[head_end + 4..].to_vec()))
+}
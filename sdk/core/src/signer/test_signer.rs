@@ -0,0 +1,72 @@
+use anyhow::{anyhow, Result};
+use std::sync::{Arc, Mutex};
+
+use super::{SignatureFuture, Signer};
+use crate::{
+    data::{address::Address, transaction::Transaction},
+    wallet::Wallet,
+};
+
+// TestSigner signs with a wallet, and remembers everything it signed, for tests to inspect.
+//
+// It can also be told to refuse signing, to test how a remote signer rejecting a transaction is handled.
+// Clones share the same history.
+#[derive(Debug, Clone)]
+pub struct TestSigner {
+    wallet: Wallet,
+    signed_txs: Arc<Mutex<Vec<Transaction>>>,
+    refusal: Arc<Mutex<Option<String>>>,
+}
+
+impl TestSigner {
+    pub fn new(wallet: Wallet) -> Self {
+        TestSigner {
+            wallet,
+            signed_txs: Arc::new(Mutex::new(Vec::new())),
+            refusal: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    // signed_txs returns the transactions signed so far, in order
+    pub fn signed_txs(&self) -> Vec<Transaction> {
+        self.signed_txs.lock().unwrap().clone()
+    }
+
+    // refuse makes all signing requests from now on fail with the given reason
+    pub fn refuse(&self, reason: &str) {
+        *self.refusal.lock().unwrap() = Some(reason.to_string());
+    }
+
+    // accept undoes refuse
+    pub fn accept(&self) {
+        *self.refusal.lock().unwrap() = None;
+    }
+
+    fn check_refusal(&self) -> Result<()> {
+        match self.refusal.lock().unwrap().as_ref() {
+            Some(reason) => Err(anyhow!("signing refused: {reason}")),
+            None => Ok(()),
+        }
+    }
+}
+
+impl Signer for TestSigner {
+    fn address(&self) -> Address {
+        self.wallet.address()
+    }
+
+    fn sign_tx<'a>(&'a self, tx: &'a Transaction) -> SignatureFuture<'a> {
+        Box::pin(async move {
+            self.check_refusal()?;
+            self.signed_txs.lock().unwrap().push(tx.clone());
+            Ok(self.wallet.sign_tx(tx))
+        })
+    }
+
+    fn sign_message<'a>(&'a self, message: &'a [u8]) -> SignatureFuture<'a> {
+        Box::pin(async move {
+            self.check_refusal()?;
+            Ok(self.wallet.sign_message(message))
+        })
+    }
+}
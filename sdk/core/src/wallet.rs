@@ -1,12 +1,16 @@
 extern crate rand;
 
-use anyhow::Result;
+use aes::{
+    cipher::{KeyIvInit, StreamCipher},
+    Aes128,
+};
+use anyhow::{anyhow, Result};
 use bip39::{Language, Mnemonic};
 use hmac::{Hmac, Mac, NewMac};
 use pbkdf2::pbkdf2;
-use serde_json::json;
-use sha2::{Digest, Sha512};
-use sha3::Keccak256;
+use rand::RngCore;
+use sha2::{Sha256, Sha512};
+use std::future;
 use zeroize::Zeroize;
 
 use crate::{
@@ -14,13 +18,32 @@ use crate::{
         private_key::{PrivateKey, PRIVATE_KEY_LENGTH},
         public_key::PublicKey,
     },
-    data::{address::Address, transaction::Transaction},
+    data::{
+        address::Address,
+        keystore::{
+            Keystore, KeystoreCipherParams, KeystoreCrypto, KeystoreKdfParams, KEYSTORE_CIPHER,
+            KEYSTORE_KDF, KEYSTORE_KIND_SECRET_KEY, KEYSTORE_VERSION,
+        },
+        transaction::Transaction,
+    },
+    message::message_signing_bytes,
+    signer::{tx_signing_bytes, SignatureFuture, Signer},
 };
 
 const MOAX_COIN_TYPE: u32 = 508;
 const HARDENED: u32 = 0x80000000;
 
+// same scrypt parameters as the web wallet
+const KEYSTORE_SCRYPT_LOG_N: u8 = 12;
+const KEYSTORE_SCRYPT_R: u32 = 8;
+const KEYSTORE_SCRYPT_P: u32 = 1;
+const KEYSTORE_DERIVED_KEY_LENGTH: usize = 32;
+const KEYSTORE_SALT_LENGTH: usize = 32;
+const KEYSTORE_IV_LENGTH: usize = 16;
+
 type HmacSha521 = Hmac<Sha512>;
+type HmacSha256 = Hmac<Sha256>;
+type Aes128Ctr = ctr::Ctr128BE<Aes128>;
 
 #[derive(Copy, Clone, Debug)]
 pub struct Wallet {
@@ -109,31 +132,188 @@ impl Wallet {
         let x = pem::parse(contents.clone())?;
         eprintln!("x is s{:?}", x);
         let x = x.contents[..PRIVATE_KEY_LENGTH].to_vec();
-       
+
         let priv_key_str = std::str::from_utf8(x.as_slice())?;
         eprintln!("{:?}", priv_key_str);
         let pri_key = PrivateKey::from_hex_str(priv_key_str)?;
         Ok(Self { priv_key: pri_key })
     }
 
+    pub fn from_keystore_file(file_path: &str, password: &str) -> Result<Self> {
+        let contents = std::fs::read_to_string(file_path)?;
+        Self::from_keystore_json(&contents, password)
+    }
+
+    pub fn from_keystore_json(json: &str, password: &str) -> Result<Self> {
+        let keystore: Keystore =
+            serde_json::from_str(json).map_err(|err| anyhow!("malformed keystore file: {err}"))?;
+        Self::from_keystore(&keystore, password)
+    }
+
+    pub fn from_keystore(keystore: &Keystore, password: &str) -> Result<Self> {
+        if keystore.kind != KEYSTORE_KIND_SECRET_KEY {
+            return Err(anyhow!(
+                "unsupported keystore kind: {}, only secret key keystores can be loaded",
+                keystore.kind
+            ));
+        }
+        let crypto = &keystore.crypto;
+        if crypto.cipher != KEYSTORE_CIPHER {
+            return Err(anyhow!("unsupported keystore cipher: {}", crypto.cipher));
+        }
+        if crypto.kdf != KEYSTORE_KDF {
+            return Err(anyhow!("unsupported keystore kdf: {}", crypto.kdf));
+        }
+
+        let salt = decode_keystore_hex("salt", &crypto.kdfparams.salt)?;
+        let iv = decode_keystore_hex("iv", &crypto.cipherparams.iv)?;
+        let ciphertext = decode_keystore_hex("ciphertext", &crypto.ciphertext)?;
+        let mac = decode_keystore_hex("mac", &crypto.mac)?;
+        if iv.len() != KEYSTORE_IV_LENGTH {
+            return Err(anyhow!("malformed keystore file: bad iv length"));
+        }
+
+        let derived_key = derive_keystore_key(password, &salt, &crypto.kdfparams)?;
+        let mut digest = HmacSha256::new_from_slice(&derived_key[16..32])
+            .expect("HMAC can take key of any size");
+        digest.update(&ciphertext);
+        if digest.verify(&mac).is_err() {
+            return Err(anyhow!("wrong keystore password"));
+        }
+
+        let mut secret_key = ciphertext;
+        let mut cipher = Aes128Ctr::new(derived_key[..16].into(), iv.as_slice().into());
+        cipher.apply_keystream(&mut secret_key);
+        let priv_key = PrivateKey::from_bytes(&secret_key);
+        secret_key.zeroize();
+
+        let wallet = Self {
+            priv_key: priv_key?,
+        };
+        if hex::encode(wallet.address().to_bytes()) != keystore.address {
+            return Err(anyhow!(
+                "keystore address does not match the decrypted secret key"
+            ));
+        }
+        Ok(wallet)
+    }
+
+    /// Exports the secret key as a password-protected JSON keystore, in the same format as the web wallet.
+    pub fn to_keystore(&self, password: &str) -> Result<String> {
+        let mut rng = rand::thread_rng();
+        let mut salt = [0u8; KEYSTORE_SALT_LENGTH];
+        rng.fill_bytes(&mut salt);
+        let mut iv = [0u8; KEYSTORE_IV_LENGTH];
+        rng.fill_bytes(&mut iv);
+        let mut id = [0u8; 16];
+        rng.fill_bytes(&mut id);
+
+        let kdfparams = KeystoreKdfParams {
+            dklen: KEYSTORE_DERIVED_KEY_LENGTH,
+            salt: hex::encode(salt),
+            n: 1 << KEYSTORE_SCRYPT_LOG_N,
+            r: KEYSTORE_SCRYPT_R,
+            p: KEYSTORE_SCRYPT_P,
+        };
+        let derived_key = derive_keystore_key(password, &salt, &kdfparams)?;
+
+        let mut ciphertext = self.priv_key.to_bytes().to_vec();
+        let mut cipher = Aes128Ctr::new(derived_key[..16].into(), (&iv).into());
+        cipher.apply_keystream(&mut ciphertext);
+
+        let mut digest = HmacSha256::new_from_slice(&derived_key[16..32])
+            .expect("HMAC can take key of any size");
+        digest.update(&ciphertext);
+        let mac = digest.finalize().into_bytes();
+
+        let address = self.address();
+        let keystore = Keystore {
+            version: KEYSTORE_VERSION,
+            kind: KEYSTORE_KIND_SECRET_KEY.to_string(),
+            id: uuid_v4_string(id),
+            address: hex::encode(address.to_bytes()),
+            bech32: address.to_bech32_string()?,
+            crypto: KeystoreCrypto {
+                ciphertext: hex::encode(ciphertext),
+                cipherparams: KeystoreCipherParams {
+                    iv: hex::encode(iv),
+                },
+                cipher: KEYSTORE_CIPHER.to_string(),
+                kdf: KEYSTORE_KDF.to_string(),
+                kdfparams,
+                mac: hex::encode(mac),
+            },
+        };
+        Ok(serde_json::to_string_pretty(&keystore)?)
+    }
+
     pub fn address(&self) -> Address {
         let public_key = PublicKey::from(&self.priv_key);
         Address::from(&public_key)
     }
 
     pub fn sign_tx(&self, unsign_tx: &Transaction) -> [u8; 64] {
-        let mut unsign_tx = unsign_tx.clone();
-        unsign_tx.signature = None;
+        self.priv_key.sign(tx_signing_bytes(unsign_tx))
+    }
 
-        let mut tx_bytes = json!(unsign_tx).to_string().as_bytes().to_vec();
+    // sign_message signs an arbitrary message the way wallets do, so it can never pass for a transaction
+    pub fn sign_message(&self, message: &[u8]) -> [u8; 64] {
+        self.priv_key.sign(message_signing_bytes(message))
+    }
+}
 
-        let should_sign_on_tx_hash = unsign_tx.version >= 2 && unsign_tx.options & 1 > 0;
-        if should_sign_on_tx_hash {
-            let mut h = Keccak256::new();
-            h.update(tx_bytes);
-            tx_bytes = h.finalize().as_slice().to_vec();
-        }
+impl Signer for Wallet {
+    fn address(&self) -> Address {
+        Wallet::address(self)
+    }
 
-        self.priv_key.sign(tx_bytes)
+    fn sign_tx<'a>(&'a self, tx: &'a Transaction) -> SignatureFuture<'a> {
+        Box::pin(future::ready(Ok(Wallet::sign_tx(self, tx))))
     }
+
+    fn sign_message<'a>(&'a self, message: &'a [u8]) -> SignatureFuture<'a> {
+        Box::pin(future::ready(Ok(Wallet::sign_message(self, message))))
+    }
+}
+
+fn decode_keystore_hex(field_name: &str, value: &str) -> Result<Vec<u8>> {
+    hex::decode(value)
+        .map_err(|_| anyhow!("malformed keystore file: {field_name} is not valid hex"))
+}
+
+fn derive_keystore_key(
+    password: &str,
+    salt: &[u8],
+    kdfparams: &KeystoreKdfParams,
+) -> Result<Vec<u8>> {
+    if !kdfparams.n.is_power_of_two() || kdfparams.n < 2 {
+        return Err(anyhow!(
+            "malformed keystore file: scrypt n must be a power of 2"
+        ));
+    }
+    if kdfparams.dklen != KEYSTORE_DERIVED_KEY_LENGTH {
+        return Err(anyhow!("malformed keystore file: bad dklen"));
+    }
+    let log_n = kdfparams.n.trailing_zeros() as u8;
+    let params = scrypt::Params::new(log_n, kdfparams.r, kdfparams.p, kdfparams.dklen)
+        .map_err(|err| anyhow!("malformed keystore file: bad scrypt params: {err}"))?;
+
+    let mut derived_key = vec![0u8; kdfparams.dklen];
+    scrypt::scrypt(password.as_bytes(), salt, &params, &mut derived_key)
+        .map_err(|err| anyhow!("scrypt failed: {err}"))?;
+    Ok(derived_key)
+}
+
+fn uuid_v4_string(mut bytes: [u8; 16]) -> String {
+    bytes[6] = (bytes[6] & 0x0f) | 0x40;
+    bytes[8] = (bytes[8] & 0x3f) | 0x80;
+    let hex = hex::encode(bytes);
+    format!(
+        "{}-{}-{}-{}-{}",
+        &hex[..8],
+        &hex[8..12],
+        &hex[12..16],
+        &hex[16..20],
+        &hex[20..]
+    )
 }
@@ -0,0 +1,484 @@
+use dharitri_sc_codec::{
+    self as codec,
+    derive::{NestedDecode, NestedEncode, TopDecode, TopEncode},
+    num_bigint::BigUint,
+    test_util::{dep_encode_to_vec_or_panic, top_encode_to_vec_u8_or_panic},
+};
+use dharitri_sdk::abi::{AbiType, ContractAbi};
+use serde_json::json;
+
+const ADDRESS_HEX: &str = "0139472eff6886771a982f3083da5d421f24c29181e63888228dc81ca60d69e1";
+
+const ABI_JSON: &str = r#"{
+    "name": "Vault",
+    "constructor": {
+        "inputs": [{ "name": "initial_status", "type": "Status" }],
+        "outputs": []
+    },
+    "endpoints": [
+        {
+            "name": "deposit",
+            "mutability": "mutable",
+            "inputs": [
+                { "name": "payment", "type": "Payment" },
+                { "name": "memo", "type": "Option<bytes>" },
+                { "name": "lock_epochs", "type": "optional<u32>", "multi_arg": true }
+            ],
+            "outputs": [{ "type": "List<Payment>" }]
+        },
+        {
+            "name": "multiTransfer",
+            "mutability": "mutable",
+            "inputs": [
+                { "name": "to", "type": "Address" },
+                { "name": "transfers", "type": "variadic<multi<TokenIdentifier,u64,BigUint>>", "multi_arg": true }
+            ],
+            "outputs": []
+        },
+        {
+            "name": "getState",
+            "mutability": "readonly",
+            "inputs": [],
+            "outputs": [
+                { "type": "Status" },
+                { "type": "tuple<u8,i64>" },
+                { "type": "counted-variadic<Action>", "multi_result": true }
+            ]
+        },
+        {
+            "name": "claimCallback",
+            "mutability": "mutable",
+            "inputs": [
+                { "name": "result", "type": "AsyncCallResult<multi<u32,BigUint>>" }
+            ],
+            "outputs": [{ "type": "OperationCompletionStatus" }]
+        }
+    ],
+    "events": [
+        {
+            "identifier": "deposit",
+            "inputs": [
+                { "name": "caller", "type": "Address", "indexed": true },
+                { "name": "epochs", "type": "variadic<u32>", "indexed": true },
+                { "name": "payment", "type": "Payment" }
+            ]
+        }
+    ],
+    "hasCallback": false,
+    "types": {
+        "Action": {
+            "type": "enum",
+            "variants": [
+                { "name": "None", "discriminant": 0 },
+                {
+                    "name": "Transfer",
+                    "discriminant": 1,
+                    "fields": [
+                        { "name": "to", "type": "Address" },
+                        { "name": "amount", "type": "BigUint" }
+                    ]
+                },
+                {
+                    "name": "Burn",
+                    "discriminant": 2,
+                    "fields": [{ "name": "0", "type": "i32" }]
+                }
+            ]
+        },
+        "Payment": {
+            "type": "struct",
+            "fields": [
+                { "name": "token", "type": "TokenIdentifier" },
+                { "name": "nonce", "type": "u64" },
+                { "name": "amount", "type": "BigUint" }
+            ]
+        },
+        "Status": {
+            "type": "enum",
+            "variants": [
+                { "name": "Inactive", "discriminant": 0 },
+                { "name": "Active", "discriminant": 1 },
+                { "name": "Paused", "discriminant": 2 }
+            ]
+        }
+    }
+}"#;
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug)]
+struct Payment {
+    token: Vec<u8>,
+    nonce: u64,
+    amount: BigUint,
+}
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug)]
+enum Status {
+    Inactive,
+    Active,
+    Paused,
+}
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug)]
+enum Action {
+    None,
+    Transfer { to: [u8; 32], amount: BigUint },
+    Burn(i32),
+}
+
+fn abi() -> ContractAbi {
+    ContractAbi::from_json_str(ABI_JSON).unwrap()
+}
+
+fn address() -> [u8; 32] {
+    hex::decode(ADDRESS_HEX).unwrap().try_into().unwrap()
+}
+
+fn payment() -> Payment {
+    Payment {
+        token: b"WMOAX-abcdef".to_vec(),
+        nonce: 5,
+        amount: BigUint::from(10u64).pow(20),
+    }
+}
+
+fn payment_json() -> serde_json::Value {
+    json!({"token": "WMOAX-abcdef", "nonce": 5, "amount": "100000000000000000000"})
+}
+
+#[test]
+fn test_encoding_matches_codec() {
+    let abi = abi();
+
+    let encoded = abi.top_encode_value("Payment", &payment_json()).unwrap();
+    assert_eq!(encoded, top_encode_to_vec_u8_or_panic(&payment()));
+    let encoded = abi.nested_encode_value("Payment", &payment_json()).unwrap();
+    assert_eq!(encoded, dep_encode_to_vec_or_panic(&payment()));
+
+    for (status, name) in [
+        (Status::Inactive, "Inactive"),
+        (Status::Active, "Active"),
+        (Status::Paused, "Paused"),
+    ] {
+        let encoded = abi.top_encode_value("Status", &json!(name)).unwrap();
+        assert_eq!(encoded, top_encode_to_vec_u8_or_panic(&status));
+        let encoded = abi.nested_encode_value("Status", &json!(name)).unwrap();
+        assert_eq!(encoded, dep_encode_to_vec_or_panic(&status));
+    }
+
+    let transfer = Action::Transfer {
+        to: address(),
+        amount: BigUint::from(1000u32),
+    };
+    let transfer_json = json!({"Transfer": {"to": format!("0x{ADDRESS_HEX}"), "amount": 1000}});
+    for (action, action_json) in [
+        (Action::None, json!("None")),
+        (transfer, transfer_json),
+        (Action::Burn(-7), json!({"Burn": {"0": -7}})),
+    ] {
+        let encoded = abi.top_encode_value("Action", &action_json).unwrap();
+        assert_eq!(encoded, top_encode_to_vec_u8_or_panic(&action));
+        let encoded = abi.nested_encode_value("Action", &action_json).unwrap();
+        assert_eq!(encoded, dep_encode_to_vec_or_panic(&action));
+    }
+
+    let list = vec![Some(payment()), None];
+    let list_json = json!([payment_json(), null]);
+    let encoded = abi
+        .top_encode_value("List<Option<Payment>>", &list_json)
+        .unwrap();
+    assert_eq!(encoded, top_encode_to_vec_u8_or_panic(&list));
+    let encoded = abi
+        .nested_encode_value("List<Option<Payment>>", &list_json)
+        .unwrap();
+    assert_eq!(encoded, dep_encode_to_vec_or_panic(&list));
+
+    for (type_name, value, expected) in [
+        ("i64", json!(-1), top_encode_to_vec_u8_or_panic(&-1i64)),
+        ("i16", json!(128), top_encode_to_vec_u8_or_panic(&128i16)),
+        ("u32", json!(0), top_encode_to_vec_u8_or_panic(&0u32)),
+        ("bool", json!(true), top_encode_to_vec_u8_or_panic(&true)),
+        (
+            "tuple<u8,i64>",
+            json!([3, -300]),
+            top_encode_to_vec_u8_or_panic(&(3u8, -300i64)),
+        ),
+    ] {
+        assert_eq!(abi.top_encode_value(type_name, &value).unwrap(), expected);
+    }
+}
+
+#[test]
+fn test_decoding_round_trip() {
+    let abi = abi();
+
+    let decoded = abi
+        .top_decode_value("Payment", &top_encode_to_vec_u8_or_panic(&payment()))
+        .unwrap();
+    assert_eq!(decoded.to_json(), payment_json());
+
+    let action = Action::Transfer {
+        to: address(),
+        amount: BigUint::from(1000u32),
+    };
+    let decoded = abi
+        .top_decode_value("Action", &top_encode_to_vec_u8_or_panic(&action))
+        .unwrap();
+    assert_eq!(
+        decoded.to_json(),
+        json!({"Transfer": {"to": "moa1qyu5wthldzr8wx5c9ucg8kjagg0jfs53s8nr3zpz3hypefsdd8ssfq94h8", "amount": 1000}})
+    );
+    let reencoded = abi.top_encode_value("Action", &decoded.to_json()).unwrap();
+    assert_eq!(reencoded, top_encode_to_vec_u8_or_panic(&action));
+
+    // empty storage reads as the first variant, or as the default value
+    assert_eq!(
+        abi.top_decode_value("Action", &[]).unwrap().to_json(),
+        json!("None")
+    );
+    assert_eq!(
+        abi.top_decode_value("u64", &[]).unwrap().to_json(),
+        json!(0)
+    );
+
+    let decoded = abi
+        .top_decode_value("OperationCompletionStatus", b"interrupted")
+        .unwrap();
+    assert_eq!(decoded.to_json(), json!("interrupted"));
+
+    assert!(abi.top_decode_value("Status", &[3]).is_err());
+    assert!(abi.top_decode_value("u8", &[1, 2]).is_err());
+    assert!(abi
+        .top_decode_value(
+            "Payment",
+            &[top_encode_to_vec_u8_or_panic(&payment()), vec![0]].concat()
+        )
+        .is_err());
+}
+
+#[test]
+fn test_endpoint_args() {
+    let abi = abi();
+
+    let args = abi
+        .encode_endpoint_args("deposit", &[payment_json(), json!("str:hello")])
+        .unwrap();
+    assert_eq!(
+        args,
+        vec![
+            top_encode_to_vec_u8_or_panic(&payment()),
+            top_encode_to_vec_u8_or_panic(&Some(b"hello".to_vec())),
+        ]
+    );
+
+    let args = abi
+        .encode_endpoint_args("deposit", &[payment_json(), json!(null), json!(12)])
+        .unwrap();
+    assert_eq!(
+        args,
+        vec![top_encode_to_vec_u8_or_panic(&payment()), vec![], vec![12]]
+    );
+
+    let args = abi
+        .encode_endpoint_args(
+            "multiTransfer",
+            &[
+                json!(ADDRESS_HEX),
+                json!([["WMOAX-abcdef", 0, 1000], ["NFT-123456", 3, 1]]),
+            ],
+        )
+        .unwrap();
+    assert_eq!(args.len(), 7);
+    assert_eq!(args[1], b"WMOAX-abcdef");
+    assert_eq!(args[2], Vec::<u8>::new());
+    assert_eq!(args[3], vec![0x03, 0xe8]);
+    assert_eq!(args[5], vec![3]);
+
+    let decoded = abi.decode_endpoint_args("multiTransfer", &args).unwrap();
+    assert_eq!(
+        decoded[1].to_json(),
+        json!([["WMOAX-abcdef", 0, 1000], ["NFT-123456", 3, 1]])
+    );
+
+    let args = abi.encode_constructor_args(&[json!("Active")]).unwrap();
+    assert_eq!(args, vec![vec![1]]);
+
+    let err = abi
+        .encode_endpoint_args("deposit", &[json!({"token": "WMOAX-abcdef", "nonce": 5})])
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "endpoint deposit: argument payment: missing field amount"
+    );
+    assert!(abi.encode_endpoint_args("deposit", &[]).is_err());
+    assert!(abi.encode_endpoint_args("missing", &[]).is_err());
+    assert!(abi.encode_endpoint_args("getState", &[json!(1)]).is_err());
+}
+
+#[test]
+fn test_endpoint_results() {
+    let abi = abi();
+
+    let results = vec![
+        top_encode_to_vec_u8_or_panic(&Status::Paused),
+        top_encode_to_vec_u8_or_panic(&(1u8, -2i64)),
+        top_encode_to_vec_u8_or_panic(&2u32),
+        top_encode_to_vec_u8_or_panic(&Action::Burn(5)),
+        top_encode_to_vec_u8_or_panic(&Action::None),
+    ];
+    let decoded = abi.decode_endpoint_results("getState", &results).unwrap();
+    assert_eq!(decoded.len(), 3);
+    assert_eq!(decoded[0].to_json(), json!("Paused"));
+    assert_eq!(decoded[1].to_json(), json!([1, -2]));
+    assert_eq!(decoded[2].to_json(), json!([{"Burn": {"0": 5}}, "None"]));
+
+    assert!(abi
+        .decode_endpoint_results("getState", &results[..4])
+        .is_err());
+    let err = abi
+        .decode_endpoint_results("getState", &[results.clone(), vec![vec![1]]].concat())
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "endpoint getState results: 1 values left over"
+    );
+
+    // the count is checked before reading the items
+    let err = abi
+        .decode_endpoint_results(
+            "getState",
+            &[
+                results[0].clone(),
+                results[1].clone(),
+                top_encode_to_vec_u8_or_panic(&u32::MAX),
+            ],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "endpoint getState results: counted-variadic of 4294967295 items, but only 0 values left"
+    );
+
+    let list = vec![payment(), payment()];
+    let decoded = abi
+        .decode_endpoint_results("deposit", &[top_encode_to_vec_u8_or_panic(&list)])
+        .unwrap();
+    assert_eq!(
+        decoded[0].to_json(),
+        json!([payment_json(), payment_json()])
+    );
+}
+
+#[test]
+fn test_decode_event() {
+    let abi = abi();
+
+    let topics = vec![
+        b"deposit".to_vec(),
+        address().to_vec(),
+        top_encode_to_vec_u8_or_panic(&10u32),
+        top_encode_to_vec_u8_or_panic(&20u32),
+    ];
+    let data = top_encode_to_vec_u8_or_panic(&payment());
+    let event = abi.decode_event(&topics, &data).unwrap();
+    assert_eq!(event.identifier, "deposit");
+    assert_eq!(
+        event.to_json(),
+        json!({
+            "caller": "moa1qyu5wthldzr8wx5c9ucg8kjagg0jfs53s8nr3zpz3hypefsdd8ssfq94h8",
+            "epochs": [10, 20],
+            "payment": payment_json(),
+        })
+    );
+
+    assert!(abi.decode_event(&[b"unknown".to_vec()], &data).is_err());
+    assert!(abi.decode_event(&topics[..1], &data).is_err());
+}
+
+#[test]
+fn test_load_generated_abi() {
+    let abi = ContractAbi::load(
+        "../../contracts/feature-tests/abi-tester/abi_tester_expected_main.abi.json",
+    )
+    .unwrap();
+    assert_eq!(abi.name, "AbiTester");
+
+    for endpoint in &abi.endpoints {
+        for type_name in endpoint
+            .inputs
+            .iter()
+            .map(|input| &input.type_name)
+            .chain(endpoint.outputs.iter().map(|output| &output.type_name))
+        {
+            AbiType::parse(type_name).unwrap();
+        }
+    }
+    assert_eq!(
+        AbiType::parse("variadic<multi<OnlyShowsUpAsNested05,bool,()>>").unwrap(),
+        AbiType::Variadic(Box::new(AbiType::Multi(vec![
+            AbiType::Custom("OnlyShowsUpAsNested05".to_string()),
+            AbiType::Bool,
+            AbiType::Tuple(Vec::new()),
+        ])))
+    );
+}
+
+#[test]
+fn test_framework_types() {
+    let abi = abi();
+
+    assert_eq!(
+        AbiType::parse("Queue<u32>").unwrap(),
+        AbiType::parse("List<u32>").unwrap()
+    );
+
+    // OperationCompletionStatus is known without a type description
+    let decoded = abi
+        .decode_endpoint_results("claimCallback", &[b"completed".to_vec()])
+        .unwrap();
+    assert_eq!(decoded[0].to_json(), json!("completed"));
+    assert_eq!(
+        abi.top_encode_value("OperationCompletionStatus", &json!("interrupted"))
+            .unwrap(),
+        b"interrupted"
+    );
+    assert!(abi
+        .decode_endpoint_results("claimCallback", &[b"done".to_vec()])
+        .is_err());
+
+    // same shard and cross shard success codes
+    for success_code in [0u32, 0x6f6b] {
+        let decoded = abi
+            .decode_endpoint_args(
+                "claimCallback",
+                &[
+                    top_encode_to_vec_u8_or_panic(&success_code),
+                    top_encode_to_vec_u8_or_panic(&5u32),
+                    top_encode_to_vec_u8_or_panic(&BigUint::from(1000u32)),
+                ],
+            )
+            .unwrap();
+        assert_eq!(decoded[0].to_json(), json!({"Ok": {"0": [5, 1000]}}));
+    }
+
+    let decoded = abi
+        .decode_endpoint_args(
+            "claimCallback",
+            &[
+                top_encode_to_vec_u8_or_panic(&4u32),
+                b"insufficient funds".to_vec(),
+            ],
+        )
+        .unwrap();
+    assert_eq!(
+        decoded[0].to_json(),
+        json!({"Err": {"err_code": 4, "err_msg": "insufficient funds"}})
+    );
+
+    let err = abi
+        .encode_endpoint_args("claimCallback", &[json!({"Ok": {"0": [5, 1000]}})])
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "endpoint claimCallback: argument result: AsyncCallResult is only produced by the protocol, it can only be decoded"
+    );
+}
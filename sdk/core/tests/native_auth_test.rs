@@ -0,0 +1,191 @@
+use dharitri_sdk::{
+    message::{message_signing_bytes, verify_message, MESSAGE_PREFIX},
+    native_auth::{NativeAuthClient, NativeAuthServer, NativeAuthToken},
+    signer::TestSigner,
+    wallet::Wallet,
+};
+use serde_json::json;
+
+const BLOCK_HASH: &str = "b3d07565293fd5684c97d2b96eb862d124fd698678f3f95b2515ed07178a27b4";
+const BLOCK_TIMESTAMP: u64 = 1700000000;
+
+fn alice() -> Wallet {
+    Wallet::from_pem_file("tests/alice.pem").unwrap()
+}
+
+fn other_wallet() -> Wallet {
+    Wallet::from_private_key("db6140e6f0854ad8a0b10b3e50e94fb1259b60bb0d7c2665d597eb1970f7a3a9")
+        .unwrap()
+}
+
+#[test]
+fn test_sign_verify_message() {
+    let wallet = alice();
+    let signature = wallet.sign_message(b"hello");
+
+    assert!(verify_message(&wallet.address(), b"hello", &signature));
+    assert!(!verify_message(&wallet.address(), b"hellO", &signature));
+    assert!(!verify_message(
+        &other_wallet().address(),
+        b"hello",
+        &signature
+    ));
+    assert!(!verify_message(
+        &wallet.address(),
+        b"hello",
+        &signature[..63]
+    ));
+}
+
+#[test]
+fn test_sign_message_known_answer() {
+    assert_eq!(
+        MESSAGE_PREFIX.as_bytes()[0] as usize,
+        MESSAGE_PREFIX.len() - 1
+    );
+    assert_eq!(
+        hex::encode(message_signing_bytes(b"hello")),
+        "58ac8720e6ff33c5f4309af9251582f132e9b5c9b0b160b2d1e1fc4bce1c50b7"
+    );
+    assert_eq!(
+        hex::encode(alice().sign_message(b"hello")),
+        "cd1ac1ef18ab2ccf011dcdfd7d8ccaff2bae0d10fd1167e579b3ad868c437712ec7d74df0c7950e55963cbe2bd9e247297191a52ddc9ea4daae6069f2c297202"
+    );
+}
+
+#[test]
+fn test_message_signature_differs_from_raw_signature() {
+    let wallet = alice();
+    let tx_like_message = br#"{"nonce":0}"#;
+    let signature = wallet.sign_message(tx_like_message);
+
+    assert!(verify_message(
+        &wallet.address(),
+        tx_like_message,
+        &signature
+    ));
+    assert_ne!(signature, other_wallet().sign_message(tx_like_message));
+}
+
+#[tokio::test]
+async fn test_native_auth_token_round_trip() {
+    let client = NativeAuthClient::new("https://example.com")
+        .with_ttl(600)
+        .with_extra_info(json!({"timestamp": 1700000001}));
+    let token = client
+        .generate_token_for_block(&alice(), BLOCK_HASH)
+        .await
+        .unwrap();
+
+    let decoded = NativeAuthToken::decode(&token).unwrap();
+    assert_eq!(decoded.address.to_bytes(), alice().address().to_bytes());
+    assert_eq!(decoded.origin, "https://example.com");
+    assert_eq!(decoded.block_hash, BLOCK_HASH);
+    assert_eq!(decoded.ttl, 600);
+    assert_eq!(decoded.extra_info, json!({"timestamp": 1700000001}));
+    assert!(decoded.verify_signature());
+    assert_eq!(decoded.encode().unwrap(), token);
+}
+
+#[tokio::test]
+async fn test_native_auth_server_validation() {
+    let token = NativeAuthClient::new("https://example.com")
+        .with_ttl(600)
+        .generate_token_for_block(&alice(), BLOCK_HASH)
+        .await
+        .unwrap();
+    let server = NativeAuthServer::new(vec!["https://example.com".to_string()]);
+
+    let validated = server
+        .validate_at(&token, BLOCK_TIMESTAMP, BLOCK_TIMESTAMP + 600)
+        .unwrap();
+    assert_eq!(validated.address.to_bytes(), alice().address().to_bytes());
+
+    let err = server
+        .validate_at(&token, BLOCK_TIMESTAMP, BLOCK_TIMESTAMP + 601)
+        .unwrap_err();
+    assert_eq!(err.to_string(), "native auth token expired");
+
+    let err = NativeAuthServer::new(vec!["https://other.com".to_string()])
+        .validate_at(&token, BLOCK_TIMESTAMP, BLOCK_TIMESTAMP)
+        .unwrap_err();
+    assert!(err.to_string().contains("is not accepted"));
+
+    let err = server
+        .clone()
+        .with_max_ttl(300)
+        .validate_at(&token, BLOCK_TIMESTAMP, BLOCK_TIMESTAMP)
+        .unwrap_err();
+    assert!(err.to_string().contains("over the maximum"));
+}
+
+#[tokio::test]
+async fn test_native_auth_token_with_signer() {
+    let signer = TestSigner::new(alice());
+    let token = NativeAuthClient::new("https://example.com")
+        .generate_token_for_block(&signer, BLOCK_HASH)
+        .await
+        .unwrap();
+    assert!(NativeAuthToken::decode(&token).unwrap().verify_signature());
+
+    signer.refuse("policy");
+    let err = NativeAuthClient::new("https://example.com")
+        .generate_token_for_block(&signer, BLOCK_HASH)
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("policy"));
+}
+
+// the extra info is signed the way the client serialized it, which can differ from serde_json
+#[test]
+fn test_native_auth_token_verified_against_raw_body() {
+    let wallet = alice();
+    let bech32_address = wallet.address().to_bech32_string().unwrap();
+    let body = format!(
+        "{}.{BLOCK_HASH}.600.{}",
+        encode_part("https://example.com"),
+        encode_part(r#"{ "timestamp": 1700000001 }"#)
+    );
+    let signature = wallet.sign_message(format!("{bech32_address}{body}").as_bytes());
+    let token = format!(
+        "{}.{}.{}",
+        encode_part(&bech32_address),
+        encode_part(&body),
+        hex::encode(signature)
+    );
+
+    let decoded = NativeAuthToken::decode(&token).unwrap();
+    assert_eq!(decoded.extra_info, json!({"timestamp": 1700000001}));
+    assert_eq!(decoded.body(), body);
+    assert!(decoded.verify_signature());
+    assert_eq!(decoded.encode().unwrap(), token);
+}
+
+#[tokio::test]
+async fn test_native_auth_tampered_token() {
+    let token = NativeAuthClient::new("https://example.com")
+        .generate_token_for_block(&alice(), BLOCK_HASH)
+        .await
+        .unwrap();
+    let parts: Vec<&str> = token.split('.').collect();
+    let decoded = NativeAuthToken::decode(&token).unwrap();
+    let tampered_body = decoded.body().replace(
+        &encode_part("https://example.com"),
+        &encode_part("https://evil.com"),
+    );
+    let encoded = format!("{}.{}.{}", parts[0], encode_part(&tampered_body), parts[2]);
+
+    assert!(!NativeAuthToken::decode(&encoded)
+        .unwrap()
+        .verify_signature());
+    let err = NativeAuthServer::new(vec![])
+        .validate_at(&encoded, BLOCK_TIMESTAMP, BLOCK_TIMESTAMP)
+        .unwrap_err();
+    assert_eq!(err.to_string(), "invalid native auth token signature");
+
+    assert!(NativeAuthToken::decode("not.a-token").is_err());
+}
+
+fn encode_part(part: &str) -> String {
+    base64::encode_config(part, base64::URL_SAFE_NO_PAD)
+}
@@ -0,0 +1,186 @@
+use dharitri_sdk::{
+    data::{address::Address, network_config::NetworkConfig, transaction::Transaction},
+    relayed::RelayedTransactionBuilder,
+    wallet::Wallet,
+};
+
+const RELAYER_PRIVATE_KEY: &str =
+    "db6140e6f0854ad8a0b10b3e50e94fb1259b60bb0d7c2665d597eb1970f7a3a9";
+const RECEIVER: &str = "moa1rc2jekj23w4amdn4p346fzecf9cem9zn4verhzwhtwnmskc77scsjt74t7";
+
+fn network_config() -> NetworkConfig {
+    NetworkConfig {
+        chain_id: "D".to_string(),
+        denomination: 18,
+        gas_per_data_byte: 1500,
+        latest_tag_software_version: String::new(),
+        meta_consensus_group_size: 0,
+        min_gas_limit: 50000,
+        min_gas_price: 1000000000,
+        min_transaction_version: 1,
+        num_metachain_nodes: 0,
+        num_nodes_in_shard: 0,
+        num_shards_without_meta: 3,
+        round_duration: 6000,
+        shard_consensus_group_size: 0,
+        start_time: 0,
+    }
+}
+
+fn sender_wallet() -> Wallet {
+    Wallet::from_pem_file("tests/alice.pem").unwrap()
+}
+
+fn relayer_wallet() -> Wallet {
+    Wallet::from_private_key(RELAYER_PRIVATE_KEY).unwrap()
+}
+
+fn inner_tx(gas_limit: u64) -> Transaction {
+    Transaction {
+        nonce: 15,
+        value: "0".to_string(),
+        receiver: Address::from_bech32_string(RECEIVER).unwrap(),
+        sender: sender_wallet().address(),
+        gas_price: 1000000000,
+        gas_limit,
+        data: Some(base64::encode("getContractConfig")),
+        signature: None,
+        chain_id: "D".to_string(),
+        version: 1,
+        options: 0,
+        guardian: None,
+        guardian_signature: None,
+        relayer: None,
+        relayer_signature: None,
+    }
+}
+
+fn signed_inner_tx(gas_limit: u64) -> Transaction {
+    let mut tx = inner_tx(gas_limit);
+    tx.signature = Some(hex::encode(sender_wallet().sign_tx(&tx)));
+    tx
+}
+
+fn decoded_data(tx: &Transaction) -> String {
+    String::from_utf8(base64::decode(tx.data.as_ref().unwrap()).unwrap()).unwrap()
+}
+
+#[test]
+fn test_relayed_v1() {
+    let network_config = network_config();
+    let inner_tx = signed_inner_tx(60000000);
+    let relayer = relayer_wallet().address();
+
+    let relayed_tx = RelayedTransactionBuilder::new(&network_config)
+        .relayed_v1(&inner_tx, &relayer, 7)
+        .unwrap();
+    assert_eq!(relayed_tx.nonce, 7);
+    assert_eq!(relayed_tx.sender.to_bytes(), relayer.to_bytes());
+    assert_eq!(relayed_tx.receiver.to_bytes(), inner_tx.sender.to_bytes());
+    assert_eq!(relayed_tx.value, "0");
+
+    let data = decoded_data(&relayed_tx);
+    assert_eq!(
+        relayed_tx.gas_limit,
+        50000 + 1500 * data.len() as u64 + 60000000
+    );
+
+    let (prefix, payload) = data.split_once('@').unwrap();
+    assert_eq!(prefix, "relayedTx");
+    let inner_json = String::from_utf8(hex::decode(payload).unwrap()).unwrap();
+    let signature = base64::encode(hex::decode(inner_tx.signature.as_ref().unwrap()).unwrap());
+    assert_eq!(
+        inner_json,
+        format!(
+            "{{\"nonce\":15,\"sender\":\"{}\",\"receiver\":\"{}\",\"value\":0,\"gasPrice\":1000000000,\"gasLimit\":60000000,\"data\":\"Z2V0Q29udHJhY3RDb25maWc=\",\"signature\":\"{signature}\",\"chainID\":\"RA==\",\"version\":1}}",
+            base64::encode(inner_tx.sender.to_bytes()),
+            base64::encode(inner_tx.receiver.to_bytes()),
+        )
+    );
+}
+
+#[test]
+fn test_relayed_v1_unsigned_inner_tx() {
+    let network_config = network_config();
+    let err = RelayedTransactionBuilder::new(&network_config)
+        .relayed_v1(&inner_tx(60000000), &relayer_wallet().address(), 7)
+        .unwrap_err();
+    assert_eq!(err.to_string(), "the inner transaction must be signed");
+}
+
+#[test]
+fn test_relayed_v2() {
+    let network_config = network_config();
+    let inner_tx = signed_inner_tx(0);
+    let relayer = relayer_wallet().address();
+
+    let relayed_tx = RelayedTransactionBuilder::new(&network_config)
+        .relayed_v2(&inner_tx, 60000000, &relayer, 7)
+        .unwrap();
+
+    let data = decoded_data(&relayed_tx);
+    assert_eq!(
+        data,
+        format!(
+            "relayedTxV2@{}@0f@676574436f6e7472616374436f6e666967@{}",
+            hex::encode(inner_tx.receiver.to_bytes()),
+            inner_tx.signature.as_ref().unwrap(),
+        )
+    );
+    assert_eq!(
+        relayed_tx.gas_limit,
+        50000 + 1500 * data.len() as u64 + 60000000
+    );
+
+    let err = RelayedTransactionBuilder::new(&network_config)
+        .relayed_v2(&signed_inner_tx(60000000), 60000000, &relayer, 7)
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "the gas limit of the inner transaction must be 0 for relayed v2"
+    );
+}
+
+#[test]
+fn test_relayed_v3() {
+    let network_config = network_config();
+    let builder = RelayedTransactionBuilder::new(&network_config);
+    let relayer_wallet = relayer_wallet();
+
+    let mut tx = inner_tx(60000000);
+    builder
+        .relayed_v3(&mut tx, &relayer_wallet.address())
+        .unwrap();
+    assert_eq!(tx.gas_limit, 60050000);
+
+    let signature = sender_wallet().sign_tx(&tx);
+    tx.signature = Some(hex::encode(signature));
+    tx.relayer_signature = Some(hex::encode(relayer_wallet.sign_tx(&tx)));
+
+    // neither of the signatures covers the other one
+    assert_eq!(sender_wallet().sign_tx(&tx), signature);
+    assert_eq!(
+        tx.relayer_signature,
+        Some(hex::encode(
+            relayer_wallet.sign_tx(&inner_tx_with_relayer())
+        ))
+    );
+
+    let json = serde_json::to_value(&tx).unwrap();
+    assert_eq!(
+        json["relayer"],
+        relayer_wallet.address().to_bech32_string().unwrap()
+    );
+    assert_eq!(json["relayerSignature"], tx.relayer_signature.unwrap());
+
+    let mut signed_tx = signed_inner_tx(60000000);
+    assert!(builder
+        .relayed_v3(&mut signed_tx, &relayer_wallet.address())
+        .is_err());
+}
+
+fn inner_tx_with_relayer() -> Transaction {
+    let mut tx = inner_tx(60050000);
+    tx.relayer = Some(relayer_wallet().address());
+    tx
+}
@@ -0,0 +1,191 @@
+use dharitri_sdk::{
+    data::transaction::Transaction,
+    signer::{RemoteSigner, Signer, TestSigner},
+    wallet::Wallet,
+};
+use std::{
+    io::{BufRead, BufReader, Read, Write},
+    net::TcpListener,
+    thread,
+};
+
+const REFUSED_VALUE: &str = "666";
+
+fn sender_wallet() -> Wallet {
+    Wallet::from_pem_file("tests/alice.pem").unwrap()
+}
+
+fn other_wallet() -> Wallet {
+    Wallet::from_private_key("db6140e6f0854ad8a0b10b3e50e94fb1259b60bb0d7c2665d597eb1970f7a3a9")
+        .unwrap()
+}
+
+fn tx(value: &str) -> Transaction {
+    Transaction {
+        nonce: 3,
+        value: value.to_string(),
+        receiver: other_wallet().address(),
+        sender: sender_wallet().address(),
+        gas_price: 1000000000,
+        gas_limit: 50000,
+        data: None,
+        signature: None,
+        chain_id: "D".to_string(),
+        version: 1,
+        options: 0,
+        guardian: None,
+        guardian_signature: None,
+        relayer: None,
+        relayer_signature: None,
+    }
+}
+
+// serves a wallet the way a signing service would, refusing to sign transactions with a certain value
+fn handle_signing_request<S: Read + Write>(stream: S, wallet: &Wallet) {
+    let mut reader = BufReader::new(stream);
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line).unwrap();
+    let mut content_length = 0;
+    loop {
+        let mut header = String::new();
+        reader.read_line(&mut header).unwrap();
+        if header == "\r\n" {
+            break;
+        }
+        if let Some(length) = header.to_lowercase().strip_prefix("content-length:") {
+            content_length = length.trim().parse().unwrap();
+        }
+    }
+    let mut body = vec![0u8; content_length];
+    reader.read_exact(&mut body).unwrap();
+
+    let (status, reply) = if request_line.starts_with("GET /address ") {
+        (
+            "200 OK",
+            format!(r#"{{"address":"{}"}}"#, wallet.address().to_string()),
+        )
+    } else if request_line.starts_with("POST /sign/transaction ") {
+        let tx: Transaction = serde_json::from_slice(&body).unwrap();
+        if tx.value == REFUSED_VALUE {
+            (
+                "403 Forbidden",
+                r#"{"error":"value not allowed"}"#.to_string(),
+            )
+        } else {
+            let signature = hex::encode(wallet.sign_tx(&tx));
+            ("200 OK", format!(r#"{{"signature":"{signature}"}}"#))
+        }
+    } else if request_line.starts_with("POST /sign/message ") {
+        let request: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        let message = hex::decode(request["message"].as_str().unwrap()).unwrap();
+        let signature = hex::encode(wallet.sign_message(&message));
+        ("200 OK", format!(r#"{{"signature":"{signature}"}}"#))
+    } else {
+        ("404 Not Found", String::new())
+    };
+
+    let stream = reader.get_mut();
+    write!(
+        stream,
+        "HTTP/1.1 {status}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{reply}",
+        reply.len()
+    )
+    .unwrap();
+}
+
+#[tokio::test]
+async fn test_wallet_signer() {
+    let wallet = sender_wallet();
+    let mut tx = tx("1000");
+
+    tx.sign(&wallet).await.unwrap();
+    assert_eq!(
+        tx.signature.clone().unwrap(),
+        hex::encode(wallet.sign_tx(&tx))
+    );
+
+    assert!(tx.sign(&other_wallet()).await.is_err());
+}
+
+#[tokio::test]
+async fn test_test_signer() {
+    let signer = TestSigner::new(sender_wallet());
+    let mut tx = tx("1000");
+
+    tx.sign(&signer).await.unwrap();
+    assert_eq!(signer.signed_txs().len(), 1);
+    assert_eq!(signer.signed_txs()[0].value, "1000");
+
+    signer.refuse("policy");
+    let err = tx.sign(&signer).await.unwrap_err();
+    assert!(err.to_string().contains("policy"));
+    assert_eq!(signer.signed_txs().len(), 1);
+
+    signer.accept();
+    tx.sign(&signer).await.unwrap();
+    assert_eq!(signer.signed_txs().len(), 2);
+}
+
+#[tokio::test]
+async fn test_remote_signer_http() {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let service_address = listener.local_addr().unwrap();
+    let service = thread::spawn(move || {
+        let wallet = sender_wallet();
+        for stream in listener.incoming().take(4) {
+            handle_signing_request(stream.unwrap(), &wallet);
+        }
+    });
+
+    let signer = RemoteSigner::http(&format!("http://{service_address}"))
+        .await
+        .unwrap();
+    assert_eq!(
+        signer.address().to_bytes(),
+        sender_wallet().address().to_bytes()
+    );
+
+    let mut tx = tx("1000");
+    tx.sign(&signer).await.unwrap();
+    assert_eq!(
+        tx.signature.clone().unwrap(),
+        hex::encode(sender_wallet().sign_tx(&tx))
+    );
+
+    let err = signer.sign_tx(&self::tx(REFUSED_VALUE)).await.unwrap_err();
+    assert!(err.to_string().contains("value not allowed"));
+
+    assert_eq!(
+        signer.sign_message(b"hello").await.unwrap(),
+        sender_wallet().sign_message(b"hello")
+    );
+
+    service.join().unwrap();
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn test_remote_signer_unix_socket() {
+    use std::os::unix::net::UnixListener;
+
+    let socket_path = std::env::temp_dir().join(format!("signer-test-{}.sock", std::process::id()));
+    let _ = std::fs::remove_file(&socket_path);
+    let listener = UnixListener::bind(&socket_path).unwrap();
+    let service = thread::spawn(move || {
+        let wallet = sender_wallet();
+        for stream in listener.incoming().take(2) {
+            handle_signing_request(stream.unwrap(), &wallet);
+        }
+    });
+
+    let signer = RemoteSigner::unix_socket(&socket_path).await.unwrap();
+    let mut tx = tx("1000");
+    tx.sign(&signer).await.unwrap();
+    assert_eq!(
+        tx.signature.clone().unwrap(),
+        hex::encode(sender_wallet().sign_tx(&tx))
+    );
+
+    service.join().unwrap();
+    std::fs::remove_file(&socket_path).unwrap();
+}
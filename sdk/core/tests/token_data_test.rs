@@ -0,0 +1,91 @@
+use dharitri_sdk::{
+    data::{
+        address::Address,
+        dct::{NftData, NftDataResponse, TokenProperties},
+    },
+    gateway_error::GatewayError,
+};
+
+const CREATOR: &str = "moa1rc2jekj23w4amdn4p346fzecf9cem9zn4verhzwhtwnmskc77scsjt74t7";
+
+#[test]
+fn test_nft_data_decode() {
+    let json = format!(
+        r#"{{
+            "data": {{
+                "tokenData": {{
+                    "attributes": "{}",
+                    "balance": "1",
+                    "creator": "{CREATOR}",
+                    "hash": "{}",
+                    "name": "Test NFT",
+                    "nonce": 5,
+                    "royalties": "2500",
+                    "tokenIdentifier": "NFT-123456",
+                    "uris": ["{}"]
+                }}
+            }},
+            "error": "",
+            "code": "successful"
+        }}"#,
+        base64::encode("metadata:abc"),
+        base64::encode([1u8, 2, 3]),
+        base64::encode("https://example.com/5.png"),
+    );
+    let resp: NftDataResponse = serde_json::from_str(&json).unwrap();
+    let nft_data = NftData::try_from(resp.data.unwrap().token_data).unwrap();
+
+    assert_eq!(nft_data.token_identifier, "NFT-123456");
+    assert_eq!(nft_data.nonce, 5);
+    assert_eq!(nft_data.royalties, 2500);
+    assert_eq!(nft_data.attributes, b"metadata:abc".to_vec());
+    assert_eq!(nft_data.hash, vec![1u8, 2, 3]);
+    assert_eq!(nft_data.uris, vec!["https://example.com/5.png".to_string()]);
+    assert_eq!(nft_data.creator.unwrap().to_string(), CREATOR);
+}
+
+#[test]
+fn test_nft_data_decode_error() {
+    let json = r#"{
+        "data": { "tokenData": { "balance": "1", "tokenIdentifier": "NFT-123456", "attributes": "not base64!" } },
+        "error": "",
+        "code": "successful"
+    }"#;
+    let resp: NftDataResponse = serde_json::from_str(json).unwrap();
+    let err = NftData::try_from(resp.data.unwrap().token_data).unwrap_err();
+    assert!(matches!(err, GatewayError::Decode(_)));
+}
+
+#[test]
+fn test_token_properties_decode() {
+    let owner = Address::from_bech32_string(CREATOR).unwrap();
+    let results: Vec<Vec<u8>> = vec![
+        b"WrappedMOAX".to_vec(),
+        b"FungibleDCT".to_vec(),
+        owner.to_bytes().to_vec(),
+        b"1000000".to_vec(),
+        b"0".to_vec(),
+        b"NumDecimals-18".to_vec(),
+        b"IsPaused-false".to_vec(),
+        b"CanUpgrade-true".to_vec(),
+        b"CanMint-true".to_vec(),
+        b"CanBurn-false".to_vec(),
+        b"NumWiped-3".to_vec(),
+        b"SomeFutureProperty-xyz".to_vec(),
+    ];
+    let properties = TokenProperties::from_query_results(&results).unwrap();
+
+    assert_eq!(properties.name, "WrappedMOAX");
+    assert_eq!(properties.token_type, "FungibleDCT");
+    assert_eq!(properties.owner.unwrap().to_string(), CREATOR);
+    assert_eq!(properties.total_supply, "1000000");
+    assert_eq!(properties.num_decimals, 18);
+    assert!(!properties.is_paused);
+    assert!(properties.can_upgrade);
+    assert!(properties.can_mint);
+    assert!(!properties.can_burn);
+    assert_eq!(properties.num_wiped, 3);
+
+    let err = TokenProperties::from_query_results(&results[..3]).unwrap_err();
+    assert!(matches!(err, GatewayError::Decode(_)));
+}
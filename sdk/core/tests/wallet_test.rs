@@ -1,6 +1,13 @@
 use bip39::Mnemonic;
 
-use dharitri_sdk::{crypto::public_key::PublicKey, data::address::Address, wallet::Wallet};
+use dharitri_sdk::{
+    crypto::public_key::PublicKey,
+    data::{
+        address::Address,
+        transaction::{Transaction, TX_OPTION_GUARDED, TX_VERSION_WITH_OPTIONS},
+    },
+    wallet::Wallet,
+};
 
 #[test]
 fn test_private_key_from_mnemonic() {
@@ -49,3 +56,124 @@ fn test_load_from_pem() {
         "moa1qyu5wthldzr8wx5c9ucg8kjagg0jfs53s8nr3zpz3hypefsdd8ssfq94h8"
     );
 }
+
+#[test]
+fn test_load_from_keystore() {
+    let wallet = Wallet::from_keystore_file("tests/alice.json", "password").unwrap();
+    let pem_wallet = Wallet::from_pem_file("tests/alice.pem").unwrap();
+    assert_eq!(
+        wallet.address().to_bech32_string().unwrap(),
+        "moa1qyu5wthldzr8wx5c9ucg8kjagg0jfs53s8nr3zpz3hypefsdd8ssfq94h8"
+    );
+    assert_eq!(format!("{wallet:?}"), format!("{pem_wallet:?}"));
+}
+
+#[test]
+fn test_load_from_keystore_without_kind() {
+    // older keystore format, with different scrypt parameters
+    let wallet =
+        Wallet::from_keystore_file("tests/mnemonic_account.json", "another password").unwrap();
+    assert_eq!(
+        wallet.address().to_bech32_string().unwrap(),
+        "moa1rc2jekj23w4amdn4p346fzecf9cem9zn4verhzwhtwnmskc77scsjt74t7"
+    );
+}
+
+#[test]
+fn test_load_from_keystore_wrong_password() {
+    let err = Wallet::from_keystore_file("tests/alice.json", "wrong password").unwrap_err();
+    assert_eq!(err.to_string(), "wrong keystore password");
+}
+
+#[test]
+fn test_load_from_keystore_malformed() {
+    let err = Wallet::from_keystore_json("{\"version\": 4}", "password").unwrap_err();
+    assert!(err.to_string().starts_with("malformed keystore file"));
+
+    let contents = std::fs::read_to_string("tests/alice.json").unwrap();
+    let err =
+        Wallet::from_keystore_json(&contents.replace("\"n\": 4096", "\"n\": 4000"), "password")
+            .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "malformed keystore file: scrypt n must be a power of 2"
+    );
+}
+
+#[test]
+fn test_keystore_round_trip() {
+    let wallet = Wallet::from_pem_file("tests/alice.pem").unwrap();
+    let keystore_json = wallet.to_keystore("round trip password").unwrap();
+
+    let loaded = Wallet::from_keystore_json(&keystore_json, "round trip password").unwrap();
+    assert_eq!(format!("{loaded:?}"), format!("{wallet:?}"));
+    assert!(Wallet::from_keystore_json(&keystore_json, "password").is_err());
+
+    let keystore: serde_json::Value = serde_json::from_str(&keystore_json).unwrap();
+    assert_eq!(keystore["version"], 4);
+    assert_eq!(keystore["kind"], "secretKey");
+    assert_eq!(
+        keystore["bech32"],
+        "moa1qyu5wthldzr8wx5c9ucg8kjagg0jfs53s8nr3zpz3hypefsdd8ssfq94h8"
+    );
+    assert_eq!(keystore["crypto"]["cipher"], "aes-128-ctr");
+    assert_eq!(keystore["crypto"]["kdfparams"]["n"], 4096);
+}
+
+#[tokio::test]
+async fn test_guardian_sign_tx() {
+    let sender = Wallet::from_pem_file("tests/alice.pem").unwrap();
+    let guardian = Wallet::from_private_key(
+        "db6140e6f0854ad8a0b10b3e50e94fb1259b60bb0d7c2665d597eb1970f7a3a9",
+    )
+    .unwrap();
+
+    let mut tx = Transaction {
+        nonce: 3,
+        value: "1000".to_string(),
+        receiver: guardian.address(),
+        sender: sender.address(),
+        gas_price: 1000000000,
+        gas_limit: 100000,
+        data: None,
+        signature: None,
+        chain_id: "D".to_string(),
+        version: 1,
+        options: 0,
+        guardian: None,
+        guardian_signature: None,
+        relayer: None,
+        relayer_signature: None,
+    };
+    assert!(!tx.is_guarded());
+    assert!(tx.guardian_sign(&guardian).await.is_err());
+
+    tx.set_guardian(guardian.address());
+    assert!(tx.is_guarded());
+    assert_eq!(tx.version, TX_VERSION_WITH_OPTIONS);
+    assert_eq!(tx.options, TX_OPTION_GUARDED);
+
+    tx.signature = Some(hex::encode(sender.sign_tx(&tx)));
+    let err = tx.guardian_sign(&sender).await.unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "signer is not the guardian of the transaction"
+    );
+
+    tx.guardian_sign(&guardian).await.unwrap();
+    assert_eq!(
+        tx.guardian_signature,
+        Some(hex::encode(guardian.sign_tx(&tx)))
+    );
+    // the guardian signature is not covered by the sender signature
+    assert_eq!(tx.signature, Some(hex::encode(sender.sign_tx(&tx))));
+
+    let json = serde_json::to_value(&tx).unwrap();
+    assert_eq!(json["version"], 2);
+    assert_eq!(json["options"], 2);
+    assert_eq!(
+        json["guardian"],
+        guardian.address().to_bech32_string().unwrap()
+    );
+    assert_eq!(json["guardianSignature"], tx.guardian_signature.unwrap());
+}
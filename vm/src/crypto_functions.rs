@@ -1,9 +1,28 @@
+mod bls;
+
+pub use bls::verify_bls;
+
 use ed25519_dalek::*;
+use k256::{
+    ecdsa::signature::hazmat::PrehashVerifier,
+    elliptic_curve::{ops::Reduce, scalar::IsHigh},
+    FieldBytes, Scalar, U256,
+};
+use ripemd::Ripemd160;
 use sha2::Sha256;
 use sha3::{Digest, Keccak256};
 
 pub const SHA256_RESULT_LEN: usize = 32;
 pub const KECCAK256_RESULT_LEN: usize = 32;
+pub const RIPEMD160_RESULT_LEN: usize = 20;
+
+// Hashing applied to the message, before checking a secp256k1 signature.
+// Same codes as in `MessageHashType`. Unknown codes fall back to the plain message.
+pub const SECP256K1_PLAIN_MSG: u8 = 0;
+pub const SECP256K1_SHA256: u8 = 1;
+pub const SECP256K1_DOUBLE_SHA256: u8 = 2;
+pub const SECP256K1_KECCAK256: u8 = 3;
+pub const SECP256K1_RIPEMD160: u8 = 4;
 
 pub fn sha256(data: &[u8]) -> [u8; SHA256_RESULT_LEN] {
     let mut hasher = Sha256::new();
@@ -17,6 +36,12 @@ pub fn keccak256(data: &[u8]) -> [u8; KECCAK256_RESULT_LEN] {
     hasher.finalize().into()
 }
 
+pub fn ripemd160(data: &[u8]) -> [u8; RIPEMD160_RESULT_LEN] {
+    let mut hasher = Ripemd160::new();
+    hasher.update(data);
+    hasher.finalize().into()
+}
+
 pub fn verify_ed25519(key: &[u8], message: &[u8], signature: &[u8]) -> bool {
     let key_32: [u8; 32] = if let Ok(key_32) = key.try_into() {
         key_32
@@ -41,3 +66,257 @@ pub fn verify_ed25519(key: &[u8], message: &[u8], signature: &[u8]) -> bool {
     let result = verifying_key.verify(message, &sig);
     result.is_ok()
 }
+
+fn secp256k1_message_hash(message: &[u8], hash_type: u8) -> Vec<u8> {
+    match hash_type {
+        SECP256K1_SHA256 => sha256(message).to_vec(),
+        SECP256K1_DOUBLE_SHA256 => sha256(&sha256(message)).to_vec(),
+        SECP256K1_KECCAK256 => keccak256(message).to_vec(),
+        SECP256K1_RIPEMD160 => ripemd160(message).to_vec(),
+        _ => message.to_vec(),
+    }
+}
+
+/// Shorter hashes are interpreted as big endian integers, so they get left-padded.
+fn secp256k1_prehash(message: &[u8], hash_type: u8) -> Vec<u8> {
+    let message_hash = secp256k1_message_hash(message, hash_type);
+    if message_hash.len() >= 32 {
+        return message_hash;
+    }
+    let mut padded = vec![0u8; 32 - message_hash.len()];
+    padded.extend(message_hash);
+    padded
+}
+
+/// ECDSA signature over secp256k1.
+///
+/// The key is a SEC1 encoded public key (compressed or uncompressed), the signature is DER encoded.
+/// High S values are accepted, just like on the node.
+pub fn verify_secp256k1(key: &[u8], message: &[u8], signature: &[u8], hash_type: u8) -> bool {
+    let verifying_key = if let Ok(verifying_key) = k256::ecdsa::VerifyingKey::from_sec1_bytes(key) {
+        verifying_key
+    } else {
+        return false;
+    };
+    let sig = if let Ok(sig) = k256::ecdsa::Signature::from_der(signature) {
+        sig
+    } else {
+        return false;
+    };
+    let sig = sig.normalize_s().unwrap_or(sig);
+
+    let prehash = secp256k1_prehash(message, hash_type);
+    verifying_key.verify_prehash(&prehash, &sig).is_ok()
+}
+
+/// Builds a DER encoded secp256k1 signature from its R and S components.
+///
+/// Both components are reduced modulo the curve order, and S is normalized to the lower half of the order.
+pub fn encode_secp256k1_der_signature(r: &[u8], s: &[u8]) -> Vec<u8> {
+    let r = secp256k1_scalar(r);
+    let mut s = secp256k1_scalar(s);
+    if bool::from(s.is_high()) {
+        s = -s;
+    }
+
+    let mut sequence = der_integer(&r.to_bytes());
+    sequence.extend(der_integer(&s.to_bytes()));
+
+    let mut result = vec![0x30, sequence.len() as u8];
+    result.extend(sequence);
+    result
+}
+
+fn secp256k1_scalar(bytes: &[u8]) -> Scalar {
+    let bytes = &bytes[..bytes.len().min(32)];
+    let mut field_bytes = FieldBytes::default();
+    field_bytes[32 - bytes.len()..].copy_from_slice(bytes);
+    <Scalar as Reduce<U256>>::reduce_bytes(&field_bytes)
+}
+
+/// Minimal big endian encoding, with an extra leading zero if the first bit is set.
+fn der_integer(value: &[u8]) -> Vec<u8> {
+    let first_non_zero = value
+        .iter()
+        .position(|byte| *byte != 0)
+        .unwrap_or(value.len() - 1);
+    let value = &value[first_non_zero..];
+
+    let mut result = vec![0x02];
+    if value[0] & 0x80 != 0 {
+        result.push(value.len() as u8 + 1);
+        result.push(0);
+    } else {
+        result.push(value.len() as u8);
+    }
+    result.extend_from_slice(value);
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hex_literal::hex;
+    use k256::ecdsa::{signature::hazmat::PrehashSigner, SigningKey};
+
+    const SECP256K1_SECRET_KEY: [u8; 32] =
+        hex!("c9afa9d845ba75166b5c215767b1d6934e50c3db36e89b127b8a622b120f6721");
+
+    fn secp256k1_sign(message: &[u8], hash_type: u8) -> (Vec<u8>, k256::ecdsa::Signature) {
+        let signing_key = SigningKey::from_bytes(&SECP256K1_SECRET_KEY.into()).unwrap();
+        let public_key = signing_key
+            .verifying_key()
+            .to_encoded_point(true)
+            .as_bytes()
+            .to_vec();
+        let signature: k256::ecdsa::Signature = signing_key
+            .sign_prehash(&secp256k1_prehash(message, hash_type))
+            .unwrap();
+        (public_key, signature)
+    }
+
+    #[test]
+    fn test_ripemd160() {
+        assert_eq!(
+            ripemd160(b""),
+            hex!("9c1185a5c5e9fc54612808977ee8f548b2258d31")
+        );
+        assert_eq!(
+            ripemd160(b"abc"),
+            hex!("8eb208f7e05d987a9b044a8e98c6b087f15a0bfc")
+        );
+        assert_eq!(
+            ripemd160(b"message digest"),
+            hex!("5d0689ef49d2fae572b881b123a85ffa21595f36")
+        );
+    }
+
+    #[test]
+    fn test_verify_secp256k1() {
+        let message = b"bridge transfer batch 42";
+        for hash_type in [
+            SECP256K1_PLAIN_MSG,
+            SECP256K1_SHA256,
+            SECP256K1_DOUBLE_SHA256,
+            SECP256K1_KECCAK256,
+            SECP256K1_RIPEMD160,
+        ] {
+            let (public_key, signature) = secp256k1_sign(message, hash_type);
+            let der_signature = signature.to_der();
+            assert!(verify_secp256k1(
+                &public_key,
+                message,
+                der_signature.as_bytes(),
+                hash_type
+            ));
+            assert!(!verify_secp256k1(
+                &public_key,
+                b"bridge transfer batch 43",
+                der_signature.as_bytes(),
+                hash_type
+            ));
+        }
+
+        let (public_key, signature) = secp256k1_sign(message, SECP256K1_KECCAK256);
+        assert!(!verify_secp256k1(
+            &public_key,
+            message,
+            signature.to_der().as_bytes(),
+            SECP256K1_SHA256
+        ));
+        assert!(!verify_secp256k1(
+            &public_key,
+            message,
+            &signature.to_bytes(),
+            SECP256K1_KECCAK256
+        ));
+        assert!(!verify_secp256k1(
+            &public_key[1..],
+            message,
+            signature.to_der().as_bytes(),
+            SECP256K1_KECCAK256
+        ));
+    }
+
+    #[test]
+    fn test_verify_secp256k1_chain_vectors() {
+        let message = b"message to sign";
+        let signature = hex!("3045022100fab29dbcb623e7e96f44665f312b89ffdb9e4d2e2f53353236fbbcf57b2f49bf02203d6f9c63df8be43121042970a549a2d3baeca093fd37b726ddcc0924638409f5");
+        let compressed_key =
+            hex!("02a673638cb9587cb68ea08dbef685c6f2d2a751a8b3c6f2a7e9a4999e6e4bfaf5");
+        let uncompressed_key = hex!("04a673638cb9587cb68ea08dbef685c6f2d2a751a8b3c6f2a7e9a4999e6e4bfaf5ca1d22fe57c6103dbaac10cf15d15c0791cab8bb9a04f800e4d215276cb3e008");
+        for key in [&compressed_key[..], &uncompressed_key[..]] {
+            assert!(verify_secp256k1(
+                key,
+                message,
+                &signature,
+                SECP256K1_DOUBLE_SHA256
+            ));
+            assert!(!verify_secp256k1(
+                key,
+                message,
+                &signature,
+                SECP256K1_PLAIN_MSG
+            ));
+        }
+
+        assert!(verify_secp256k1(
+            &hex!("04e32df42865e97135acfb65f3bae71bdc86f4d49150ad6a440b6f15878109880a0a2b2667f7e725ceea70c673093bf67663e0312623c8e091b13cf2c0f11ef652"),
+            &hex!("ce0677bb30baa8cf067c88db9811f4333d131bf8bcf12fe7065d211dce971008"),
+            &hex!("304502210090f27b8b488db00b00606796d2987f6a5f59ae62ea05effe84fef5b8b0e5499802204a691139ad57a3f0b906637673aa2f63d1f55cb1a69199d4009eea23ceaddc93"),
+            SECP256K1_PLAIN_MSG
+        ));
+    }
+
+    #[test]
+    fn test_verify_secp256k1_high_s() {
+        let message = b"high s";
+        let (public_key, signature) = secp256k1_sign(message, SECP256K1_SHA256);
+        let (r, s) = signature.split_scalars();
+        let high_s_signature = k256::ecdsa::Signature::from_scalars(r, -*s).unwrap();
+        assert!(verify_secp256k1(
+            &public_key,
+            message,
+            high_s_signature.to_der().as_bytes(),
+            SECP256K1_SHA256
+        ));
+    }
+
+    #[test]
+    fn test_encode_secp256k1_der_signature() {
+        assert_eq!(
+            encode_secp256k1_der_signature(
+                &hex!("90f27b8b488db00b00606796d2987f6a5f59ae62ea05effe84fef5b8b0e54998"),
+                &hex!("4a691139ad57a3f0b906637673aa2f63d1f55cb1a69199d4009eea23ceaddc93")
+            ),
+            hex!("304502210090f27b8b488db00b00606796d2987f6a5f59ae62ea05effe84fef5b8b0e5499802204a691139ad57a3f0b906637673aa2f63d1f55cb1a69199d4009eea23ceaddc93")
+        );
+        assert_eq!(
+            encode_secp256k1_der_signature(&[1], &[2]),
+            hex!("3006020101020102")
+        );
+        assert_eq!(
+            encode_secp256k1_der_signature(&[0x80], &[0x7f]),
+            hex!("30070202008002017f")
+        );
+
+        // s = n - 1 is normalized to 1
+        let n_minus_1 = hex!("fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364140");
+        assert_eq!(
+            encode_secp256k1_der_signature(&[1], &n_minus_1),
+            hex!("3006020101020101")
+        );
+
+        let message = b"encode";
+        let (public_key, signature) = secp256k1_sign(message, SECP256K1_KECCAK256);
+        let der_signature =
+            encode_secp256k1_der_signature(&signature.r().to_bytes(), &signature.s().to_bytes());
+        assert_eq!(der_signature, signature.to_der().as_bytes());
+        assert!(verify_secp256k1(
+            &public_key,
+            message,
+            &der_signature,
+            SECP256K1_KECCAK256
+        ));
+    }
+}
@@ -0,0 +1,263 @@
+//! BLS signature verification, compatible with the node.
+//!
+//! The node uses the herumi/mcl BLS12-381 implementation, with public keys in G2 and signatures in G1,
+//! in the mcl serialization format, and with the original mcl hash-to-curve.
+
+use bls12_381::{pairing, G1Affine, G1Projective, G2Affine, Scalar};
+use hex_literal::hex;
+use num_bigint::BigUint;
+use num_traits::{One, Zero};
+use sha2::{Digest, Sha512};
+
+pub const BLS_PUBLIC_KEY_LEN: usize = 96;
+pub const BLS_SIGNATURE_LEN: usize = 48;
+
+const FP_LEN: usize = 48;
+
+/// The mcl serialization flag, set in the last byte when the y coordinate is odd.
+const MCL_Y_ODD_FLAG: u8 = 0x80;
+
+/// Flags in the first byte of the zkcrypto compressed format.
+const ZKCRYPTO_COMPRESSED_FLAG: u8 = 0x80;
+const ZKCRYPTO_FLAGS_MASK: u8 = 0xe0;
+
+/// Big endian.
+const FIELD_MODULUS: [u8; FP_LEN] = hex!("1a0111ea397fe69a4b1ba7b6434bacd764774b84f38512bf6730d2a0f6b0f6241eabfffeb153ffffb9feffffffffaaab");
+
+/// The generator of the public keys, in the zkcrypto compressed format.
+///
+/// It is not the standard G2 generator: mcl derives it as `mapToG2(1)`.
+const PUBLIC_KEY_GENERATOR: [u8; BLS_PUBLIC_KEY_LEN] = hex!("b71df7a5080f908a16c2658ea90164e28c924c3f0e6655f6d82adca6bfbdfb5f9efca82c1609676fa15cd30396f1a4b30f3d011af81acf00140aab3c122c61bbdf0628db81c37664bdfc828163ce074ee33a1a5ce5488556603bc5d8d9f21ecc");
+
+/// The full G1 cofactor, `(z - 1)^2 / 3`, as little endian limbs.
+const G1_COFACTOR: [u64; 4] = [0x8c00aaab0000aaab, 0x396c8c005555e156, 0, 0];
+
+/// Checks that `e(signature, G) == e(H(message), key)`.
+///
+/// Zero keys and signatures are rejected.
+pub fn verify_bls(key: &[u8], message: &[u8], signature: &[u8]) -> bool {
+    let key = if let Some(key) = deserialize_g2(key) {
+        key
+    } else {
+        return false;
+    };
+    let signature = if let Some(signature) = deserialize_g1(signature) {
+        signature
+    } else {
+        return false;
+    };
+    let message_point = if let Some(message_point) = hash_to_g1(message) {
+        message_point
+    } else {
+        return false;
+    };
+
+    let generator = G2Affine::from_compressed(&PUBLIC_KEY_GENERATOR).unwrap();
+    pairing(&signature, &generator) == pairing(&G1Affine::from(message_point), &key)
+}
+
+/// Converts the little endian mcl encoding of a field element to the big endian zkcrypto one.
+///
+/// Returns `None` if any of the 3 spare high bits is set, since the element would not be reduced.
+fn mcl_fp_to_zkcrypto(mcl_bytes: &[u8]) -> Option<[u8; FP_LEN]> {
+    let mut bytes: [u8; FP_LEN] = mcl_bytes.try_into().ok()?;
+    bytes.reverse();
+    if bytes[0] & ZKCRYPTO_FLAGS_MASK != 0 {
+        return None;
+    }
+    Some(bytes)
+}
+
+fn deserialize_g1(bytes: &[u8]) -> Option<G1Affine> {
+    if bytes.len() != BLS_SIGNATURE_LEN {
+        return None;
+    }
+    let y_odd = bytes[FP_LEN - 1] & MCL_Y_ODD_FLAG != 0;
+    let mut x = bytes.to_vec();
+    x[FP_LEN - 1] &= !MCL_Y_ODD_FLAG;
+
+    let mut compressed = mcl_fp_to_zkcrypto(&x)?;
+    if compressed.iter().all(|byte| *byte == 0) {
+        return None;
+    }
+    compressed[0] |= ZKCRYPTO_COMPRESSED_FLAG;
+    let point = Option::<G1Affine>::from(G1Affine::from_compressed(&compressed))?;
+
+    let point_y_odd = point.to_uncompressed()[2 * FP_LEN - 1] & 1 == 1;
+    if point_y_odd == y_odd {
+        Some(point)
+    } else {
+        Some(-point)
+    }
+}
+
+/// G2 coordinates are `a + b * u`, serialized as `a` followed by `b`.
+/// The parity flag refers to `a`.
+fn deserialize_g2(bytes: &[u8]) -> Option<G2Affine> {
+    if bytes.len() != BLS_PUBLIC_KEY_LEN {
+        return None;
+    }
+    let y_odd = bytes[2 * FP_LEN - 1] & MCL_Y_ODD_FLAG != 0;
+    let mut x = bytes.to_vec();
+    x[2 * FP_LEN - 1] &= !MCL_Y_ODD_FLAG;
+
+    let x_a = mcl_fp_to_zkcrypto(&x[..FP_LEN])?;
+    let x_b = mcl_fp_to_zkcrypto(&x[FP_LEN..])?;
+    let mut compressed = [0u8; BLS_PUBLIC_KEY_LEN];
+    compressed[..FP_LEN].copy_from_slice(&x_b);
+    compressed[FP_LEN..].copy_from_slice(&x_a);
+    if compressed.iter().all(|byte| *byte == 0) {
+        return None;
+    }
+    compressed[0] |= ZKCRYPTO_COMPRESSED_FLAG;
+    let point = Option::<G2Affine>::from(G2Affine::from_compressed(&compressed))?;
+
+    let point_y_a_odd = point.to_uncompressed()[4 * FP_LEN - 1] & 1 == 1;
+    if point_y_a_odd == y_odd {
+        Some(point)
+    } else {
+        Some(-point)
+    }
+}
+
+/// The original mcl hash-to-curve: the message is hashed to a field element,
+/// mapped to the curve as in Fouque-Tibouchi, "Indifferentiable hashing to Barreto-Naehrig curves",
+/// then multiplied by the cofactor.
+fn hash_to_g1(message: &[u8]) -> Option<G1Projective> {
+    let field = Field::new();
+
+    // first 48 bytes of the SHA-512 digest, little endian, masked to 380 bits
+    let digest = Sha512::digest(message);
+    let mask = (BigUint::one() << (8 * FP_LEN - 4)) - 1u32;
+    let t = BigUint::from_bytes_le(&digest[..FP_LEN]) & mask;
+
+    let (x, y) = field.map_to_curve(&t)?;
+    let mut uncompressed = [0u8; 2 * FP_LEN];
+    uncompressed[..FP_LEN].copy_from_slice(&field.to_bytes_be(&x));
+    uncompressed[FP_LEN..].copy_from_slice(&field.to_bytes_be(&y));
+    let point = Option::<G1Affine>::from(G1Affine::from_uncompressed_unchecked(&uncompressed))?;
+
+    Some(G1Projective::from(point) * Scalar::from_raw(G1_COFACTOR))
+}
+
+/// Just enough of the base field for the mcl hash-to-curve, it is not performance critical.
+struct Field {
+    modulus: BigUint,
+}
+
+impl Field {
+    fn new() -> Self {
+        Field {
+            modulus: BigUint::from_bytes_be(&FIELD_MODULUS),
+        }
+    }
+
+    fn add(&self, a: &BigUint, b: &BigUint) -> BigUint {
+        (a + b) % &self.modulus
+    }
+
+    fn sub(&self, a: &BigUint, b: &BigUint) -> BigUint {
+        (a + &self.modulus - b % &self.modulus) % &self.modulus
+    }
+
+    fn mul(&self, a: &BigUint, b: &BigUint) -> BigUint {
+        (a * b) % &self.modulus
+    }
+
+    fn neg(&self, a: &BigUint) -> BigUint {
+        self.sub(&BigUint::zero(), a)
+    }
+
+    fn inv(&self, a: &BigUint) -> BigUint {
+        a.modpow(&(&self.modulus - 2u32), &self.modulus)
+    }
+
+    fn is_square(&self, a: &BigUint) -> bool {
+        let exponent = (&self.modulus - 1u32) >> 1;
+        a.is_zero() || a.modpow(&exponent, &self.modulus).is_one()
+    }
+
+    /// The modulus is 3 mod 4, so the root is `a^((p + 1) / 4)`, same as in mcl.
+    fn sqrt(&self, a: &BigUint) -> Option<BigUint> {
+        let root = a.modpow(&((&self.modulus + 1u32) >> 2), &self.modulus);
+        if self.mul(&root, &root) == a % &self.modulus {
+            Some(root)
+        } else {
+            None
+        }
+    }
+
+    /// `x^3 + 4`
+    fn curve_rhs(&self, x: &BigUint) -> BigUint {
+        self.add(&self.mul(&self.mul(x, x), x), &BigUint::from(4u32))
+    }
+
+    fn map_to_curve(&self, t: &BigUint) -> Option<(BigUint, BigUint)> {
+        if t.is_zero() {
+            return None;
+        }
+        let one = BigUint::one();
+        let c1 = self.sqrt(&self.neg(&BigUint::from(3u32)))?;
+        let c2 = self.mul(&self.sub(&c1, &one), &self.inv(&BigUint::from(2u32)));
+
+        let w_denominator = self.add(&self.add(&self.mul(t, t), &BigUint::from(4u32)), &one);
+        if w_denominator.is_zero() {
+            return None;
+        }
+        let w = self.mul(&self.mul(&c1, t), &self.inv(&w_denominator));
+
+        let x1 = self.sub(&c2, &self.mul(t, &w));
+        let x2 = self.sub(&self.neg(&x1), &one);
+        let x3 = self.add(&self.inv(&self.mul(&w, &w)), &one);
+        for x in [x1, x2, x3] {
+            if let Some(y) = self.sqrt(&self.curve_rhs(&x)) {
+                let y = if self.is_square(t) { y } else { self.neg(&y) };
+                return Some((x, y));
+            }
+        }
+        None
+    }
+
+    fn to_bytes_be(&self, a: &BigUint) -> [u8; FP_LEN] {
+        let bytes = a.to_bytes_be();
+        let mut result = [0u8; FP_LEN];
+        result[FP_LEN - bytes.len()..].copy_from_slice(&bytes);
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // signed by a node key, also used in the crypto_verify_bls scenario
+    const KEY: [u8; BLS_PUBLIC_KEY_LEN] = hex!("b5823f6e564251cc03ce7bad3da83e72576e92795d3500bba1acb30ec9a94dce87bb8aa794d67b2d61d15c33f28f6c0c23ba1dfcbf21e8f8b46286ff871afabac925303ddcaddce6254fcff6d3155797db40b3d3b5865e8fc0bd770b3d79b381");
+    const MESSAGE: &[u8] = b"message to be signed";
+    const SIGNATURE: [u8; BLS_SIGNATURE_LEN] = hex!("af32a2ddf341c08d1eb7232f05dc34e4454155e676b58c40fddf9a036562ac2c01533d2d557cb49d73aa9d7a89744696");
+
+    #[test]
+    fn test_verify_bls() {
+        assert!(verify_bls(&KEY, MESSAGE, &SIGNATURE));
+    }
+
+    #[test]
+    fn test_verify_bls_invalid() {
+        assert!(!verify_bls(&KEY, b"another message", &SIGNATURE));
+
+        let mut signature = SIGNATURE;
+        signature[BLS_SIGNATURE_LEN - 1] ^= MCL_Y_ODD_FLAG;
+        assert!(!verify_bls(&KEY, MESSAGE, &signature));
+
+        let mut key = KEY;
+        key[0] ^= 1;
+        assert!(!verify_bls(&key, MESSAGE, &SIGNATURE));
+
+        assert!(!verify_bls(&KEY[1..], MESSAGE, &SIGNATURE));
+        assert!(!verify_bls(&KEY, MESSAGE, &SIGNATURE[1..]));
+        assert!(!verify_bls(
+            &[0u8; BLS_PUBLIC_KEY_LEN],
+            MESSAGE,
+            &[0u8; BLS_SIGNATURE_LEN]
+        ));
+    }
+}
@@ -1,5 +1,12 @@
+use bech32::{ToBase32, Variant};
+
 use crate::types::VMAddress;
 
+pub fn address_bech32(address: &VMAddress) -> String {
+    bech32::encode("moa", address.as_bytes().to_base32(), Variant::Bech32)
+        .expect("bech32 encode error")
+}
+
 pub fn address_hex(address: &VMAddress) -> String {
     alloc::format!("0x{}", hex::encode(address.as_bytes()))
 }
@@ -0,0 +1,5 @@
+mod gas_schedule;
+mod gas_schedule_sections;
+
+pub use gas_schedule::GasSchedule;
+pub use gas_schedule_sections::*;
@@ -0,0 +1,157 @@
+use std::{fs, path::Path};
+
+use serde::Deserialize;
+
+use crate::tx_execution::builtin_function_names::*;
+
+use super::*;
+
+/// The gas schedule that ships with the Rust VM, in the same format as the node gas schedule files.
+const DEFAULT_GAS_SCHEDULE_TOML: &str = include_str!("gas_schedule_default.toml");
+
+/// Gas costs, as configured in the node gas schedule TOML files (e.g. `gasScheduleV7.toml`).
+///
+/// Only the sections and entries relevant to the Rust VM are loaded, everything else is ignored.
+/// Missing entries default to 0.
+///
+/// Contract code is executed natively in the Rust VM, so there are no WASM opcode costs.
+/// Only VM hooks, storage, builtin functions, system SC calls and async calls are metered.
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct GasSchedule {
+    #[serde(rename = "BuiltInCost")]
+    pub built_in_cost: BuiltInCost,
+    #[serde(rename = "MetaChainSystemSCsCost")]
+    pub meta_chain_system_scs_cost: MetaChainSystemSCsCost,
+    #[serde(rename = "BaseOperationCost")]
+    pub base_operation_cost: BaseOperationCost,
+    #[serde(rename = "BaseOpsAPICost")]
+    pub base_ops_api_cost: BaseOpsAPICost,
+    #[serde(rename = "BigIntAPICost")]
+    pub big_int_api_cost: BigIntAPICost,
+    #[serde(rename = "BigFloatAPICost")]
+    pub big_float_api_cost: BigFloatAPICost,
+    #[serde(rename = "CryptoAPICost")]
+    pub crypto_api_cost: CryptoAPICost,
+    #[serde(rename = "ManagedBufferAPICost")]
+    pub managed_buffer_api_cost: ManagedBufferAPICost,
+    #[serde(rename = "ManagedMapAPICost")]
+    pub managed_map_api_cost: ManagedMapAPICost,
+}
+
+impl GasSchedule {
+    /// The gas schedule bundled with the VM.
+    pub fn default_schedule() -> Self {
+        Self::from_toml_str(DEFAULT_GAS_SCHEDULE_TOML).expect("invalid default gas schedule")
+    }
+
+    /// Parses a gas schedule in the node TOML format.
+    pub fn from_toml_str(toml_str: &str) -> Result<Self, String> {
+        toml::from_str(toml_str).map_err(|err| format!("invalid gas schedule: {err}"))
+    }
+
+    /// Loads a gas schedule file in the node TOML format.
+    pub fn from_toml_file<P: AsRef<Path>>(path: P) -> Result<Self, String> {
+        let toml_str = fs::read_to_string(path.as_ref()).map_err(|err| {
+            format!(
+                "error reading gas schedule file {}: {err}",
+                path.as_ref().display()
+            )
+        })?;
+        Self::from_toml_str(&toml_str)
+    }
+
+    /// The cost of a builtin function call, 0 if the builtin function has no explicit cost.
+    pub fn builtin_function_cost(&self, func_name: &str) -> u64 {
+        let cost = &self.built_in_cost;
+        match func_name {
+            DCT_LOCAL_MINT_FUNC_NAME => cost.dct_local_mint,
+            DCT_LOCAL_BURN_FUNC_NAME => cost.dct_local_burn,
+            DCT_MULTI_TRANSFER_FUNC_NAME => cost.multi_dct_nft_transfer,
+            DCT_NFT_TRANSFER_FUNC_NAME => cost.dct_nft_transfer,
+            DCT_NFT_CREATE_FUNC_NAME => cost.dct_nft_create,
+            DCT_NFT_ADD_QUANTITY_FUNC_NAME => cost.dct_nft_add_quantity,
+            DCT_NFT_ADD_URI_FUNC_NAME => cost.dct_nft_add_uri,
+            DCT_NFT_UPDATE_ATTRIBUTES_FUNC_NAME => cost.dct_nft_update_attributes,
+            DCT_NFT_BURN_FUNC_NAME => cost.dct_nft_burn,
+            DCT_TRANSFER_FUNC_NAME => cost.dct_transfer,
+            CHANGE_OWNER_BUILTIN_FUNC_NAME => cost.change_owner_address,
+            CLAIM_DEVELOPER_REWARDS_FUNC_NAME => cost.claim_developer_rewards,
+            SET_USERNAME_FUNC_NAME | DELETE_USERNAME_FUNC_NAME => cost.save_user_name,
+            _ => 0,
+        }
+    }
+
+    /// The cost of calling the DCT system SC. Issuing is more expensive than all other operations.
+    pub fn system_sc_cost(&self, func_name: &str) -> u64 {
+        let cost = &self.meta_chain_system_scs_cost;
+        match func_name {
+            "issue"
+            | "issueSemiFungible"
+            | "issueNonFungible"
+            | "registerMetaDCT"
+            | "registerAndSetAllRoles" => cost.dct_issue,
+            _ => cost.dct_operations,
+        }
+    }
+
+    /// Storage writes pay per byte written, on top of the cost of the VM hook.
+    ///
+    /// Bytes added on top of the previous value are charged extra, since they increase the state size.
+    pub fn storage_store_bytes_cost(&self, old_value_len: usize, new_value_len: usize) -> u64 {
+        let added_bytes = new_value_len.saturating_sub(old_value_len) as u64;
+        self.base_operation_cost.persist_per_byte * new_value_len as u64
+            + self.base_operation_cost.store_per_byte * added_bytes
+    }
+
+    /// Gas refunded when a storage write reduces the size of the stored value.
+    pub fn storage_release_refund(&self, old_value_len: usize, new_value_len: usize) -> u64 {
+        let released_bytes = old_value_len.saturating_sub(new_value_len) as u64;
+        self.base_operation_cost.release_per_byte * released_bytes
+    }
+
+    /// Cost of copying data to the output, e.g. in logs.
+    pub fn data_copy_cost(&self, data_len: usize) -> u64 {
+        self.base_operation_cost.data_copy_per_byte * data_len as u64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_schedule() {
+        let gas_schedule = GasSchedule::default_schedule();
+        assert!(gas_schedule.base_ops_api_cost.storage_store > 0);
+        assert!(gas_schedule.managed_buffer_api_cost.m_buffer_new > 0);
+        assert_eq!(
+            gas_schedule.builtin_function_cost(DCT_TRANSFER_FUNC_NAME),
+            gas_schedule.built_in_cost.dct_transfer
+        );
+    }
+
+    #[test]
+    fn test_partial_schedule() {
+        let gas_schedule = GasSchedule::from_toml_str(
+            r#"
+            [BaseOpsAPICost]
+                StorageStore = 100
+                GetSCAddress = 5
+
+            [WASMOpcodeCost]
+                Unreachable = 5
+            "#,
+        )
+        .unwrap();
+        assert_eq!(gas_schedule.base_ops_api_cost.storage_store, 100);
+        assert_eq!(gas_schedule.base_ops_api_cost.get_sc_address, 5);
+        assert_eq!(gas_schedule.base_ops_api_cost.storage_load, 0);
+        assert_eq!(gas_schedule.built_in_cost.dct_transfer, 0);
+    }
+
+    #[test]
+    fn test_invalid_schedule() {
+        assert!(GasSchedule::from_toml_str("[BaseOpsAPICost]\nStorageStore = \"abc\"").is_err());
+    }
+}
@@ -0,0 +1,269 @@
+use serde::Deserialize;
+
+/// Costs of the builtin functions, in the `[BuiltInCost]` section.
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct BuiltInCost {
+    pub change_owner_address: u64,
+    pub claim_developer_rewards: u64,
+    pub save_user_name: u64,
+    pub save_key_value: u64,
+    #[serde(rename = "DCTTransfer")]
+    pub dct_transfer: u64,
+    #[serde(rename = "DCTBurn")]
+    pub dct_burn: u64,
+    #[serde(rename = "DCTLocalMint")]
+    pub dct_local_mint: u64,
+    #[serde(rename = "DCTLocalBurn")]
+    pub dct_local_burn: u64,
+    #[serde(rename = "DCTNFTCreate")]
+    pub dct_nft_create: u64,
+    #[serde(rename = "DCTNFTAddQuantity")]
+    pub dct_nft_add_quantity: u64,
+    #[serde(rename = "DCTNFTBurn")]
+    pub dct_nft_burn: u64,
+    #[serde(rename = "DCTNFTTransfer")]
+    pub dct_nft_transfer: u64,
+    #[serde(rename = "DCTNFTAddURI")]
+    pub dct_nft_add_uri: u64,
+    #[serde(rename = "DCTNFTUpdateAttributes")]
+    pub dct_nft_update_attributes: u64,
+    #[serde(rename = "MultiDCTNFTTransfer")]
+    pub multi_dct_nft_transfer: u64,
+}
+
+/// Costs of the system smart contracts, in the `[MetaChainSystemSCsCost]` section.
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct MetaChainSystemSCsCost {
+    #[serde(rename = "DCTIssue")]
+    pub dct_issue: u64,
+    #[serde(rename = "DCTOperations")]
+    pub dct_operations: u64,
+}
+
+/// Costs that are proportional to the size of the data, in the `[BaseOperationCost]` section.
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct BaseOperationCost {
+    pub store_per_byte: u64,
+    pub release_per_byte: u64,
+    pub data_copy_per_byte: u64,
+    pub persist_per_byte: u64,
+    pub compile_per_byte: u64,
+    pub get_code: u64,
+}
+
+/// Costs of the basic VM hooks, in the `[BaseOpsAPICost]` section.
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct BaseOpsAPICost {
+    #[serde(rename = "GetSCAddress")]
+    pub get_sc_address: u64,
+    pub get_owner_address: u64,
+    pub is_smart_contract: u64,
+    pub get_shard_of_address: u64,
+    pub get_external_balance: u64,
+    pub get_block_hash: u64,
+    pub transfer_value: u64,
+    pub get_argument: u64,
+    pub get_function: u64,
+    pub get_num_arguments: u64,
+    pub storage_store: u64,
+    pub storage_load: u64,
+    pub cached_storage_load: u64,
+    pub get_caller: u64,
+    pub get_call_value: u64,
+    pub log: u64,
+    pub finish: u64,
+    pub signal_error: u64,
+    pub get_block_time_stamp: u64,
+    pub get_gas_left: u64,
+    pub int64_get_argument: u64,
+    pub int64_storage_store: u64,
+    pub int64_storage_load: u64,
+    pub int64_finish: u64,
+    pub get_state_root_hash: u64,
+    pub get_block_nonce: u64,
+    pub get_block_epoch: u64,
+    pub get_block_round: u64,
+    pub get_block_random_seed: u64,
+    pub execute_on_same_context: u64,
+    pub execute_on_dest_context: u64,
+    pub delegate_execution: u64,
+    pub async_call_step: u64,
+    pub async_callback_gas_lock: u64,
+    pub execute_read_only: u64,
+    pub create_contract: u64,
+    pub get_return_data: u64,
+    pub get_num_return_data: u64,
+    pub get_return_data_size: u64,
+    pub get_original_tx_hash: u64,
+    pub clean_return_data: u64,
+    pub delete_from_return_data: u64,
+}
+
+/// Costs of the big integer VM hooks, in the `[BigIntAPICost]` section.
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct BigIntAPICost {
+    pub big_int_new: u64,
+    pub big_int_unsigned_byte_length: u64,
+    pub big_int_signed_byte_length: u64,
+    pub big_int_get_unsigned_bytes: u64,
+    pub big_int_get_signed_bytes: u64,
+    pub big_int_set_unsigned_bytes: u64,
+    pub big_int_set_signed_bytes: u64,
+    pub big_int_is_int64: u64,
+    pub big_int_get_int64: u64,
+    pub big_int_set_int64: u64,
+    pub big_int_add: u64,
+    pub big_int_sub: u64,
+    pub big_int_mul: u64,
+    pub big_int_sqrt: u64,
+    pub big_int_pow: u64,
+    pub big_int_log: u64,
+    #[serde(rename = "BigIntTDiv")]
+    pub big_int_t_div: u64,
+    #[serde(rename = "BigIntTMod")]
+    pub big_int_t_mod: u64,
+    #[serde(rename = "BigIntEDiv")]
+    pub big_int_e_div: u64,
+    #[serde(rename = "BigIntEMod")]
+    pub big_int_e_mod: u64,
+    pub big_int_abs: u64,
+    pub big_int_neg: u64,
+    pub big_int_sign: u64,
+    pub big_int_cmp: u64,
+    pub big_int_not: u64,
+    pub big_int_and: u64,
+    pub big_int_or: u64,
+    pub big_int_xor: u64,
+    pub big_int_shr: u64,
+    pub big_int_shl: u64,
+    pub big_int_finish_unsigned: u64,
+    pub big_int_finish_signed: u64,
+    pub big_int_storage_load_unsigned: u64,
+    pub big_int_storage_store_unsigned: u64,
+    pub big_int_get_unsigned_argument: u64,
+    pub big_int_get_signed_argument: u64,
+    pub big_int_get_call_value: u64,
+    pub big_int_get_external_balance: u64,
+}
+
+/// Costs of the big float VM hooks, in the `[BigFloatAPICost]` section.
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct BigFloatAPICost {
+    pub big_float_new_from_parts: u64,
+    pub big_float_add: u64,
+    pub big_float_sub: u64,
+    pub big_float_mul: u64,
+    pub big_float_div: u64,
+    pub big_float_truncate: u64,
+    pub big_float_neg: u64,
+    pub big_float_clone: u64,
+    pub big_float_cmp: u64,
+    pub big_float_abs: u64,
+    pub big_float_sqrt: u64,
+    pub big_float_pow: u64,
+    pub big_float_floor: u64,
+    pub big_float_ceil: u64,
+    pub big_float_is_int: u64,
+    pub big_float_set_big_int: u64,
+    pub big_float_set_int64: u64,
+    pub big_float_get_const: u64,
+}
+
+/// Costs of the cryptographic VM hooks, in the `[CryptoAPICost]` section.
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct CryptoAPICost {
+    #[serde(rename = "SHA256")]
+    pub sha256: u64,
+    #[serde(rename = "Keccak256")]
+    pub keccak256: u64,
+    #[serde(rename = "Ripemd160")]
+    pub ripemd160: u64,
+    #[serde(rename = "VerifyBLS")]
+    pub verify_bls: u64,
+    #[serde(rename = "VerifyEd25519")]
+    pub verify_ed25519: u64,
+    #[serde(rename = "VerifySecp256k1")]
+    pub verify_secp256k1: u64,
+    #[serde(rename = "EllipticCurveNew")]
+    pub elliptic_curve_new: u64,
+    #[serde(rename = "AddECC")]
+    pub add_ecc: u64,
+    #[serde(rename = "DoubleECC")]
+    pub double_ecc: u64,
+    #[serde(rename = "IsOnCurveECC")]
+    pub is_on_curve_ecc: u64,
+    #[serde(rename = "ScalarMultECC")]
+    pub scalar_mult_ecc: u64,
+    #[serde(rename = "MarshalECC")]
+    pub marshal_ecc: u64,
+    #[serde(rename = "MarshalCompressedECC")]
+    pub marshal_compressed_ecc: u64,
+    #[serde(rename = "UnmarshalECC")]
+    pub unmarshal_ecc: u64,
+    #[serde(rename = "UnmarshalCompressedECC")]
+    pub unmarshal_compressed_ecc: u64,
+    #[serde(rename = "GenerateKeyECC")]
+    pub generate_key_ecc: u64,
+    #[serde(rename = "EncodeDERSig")]
+    pub encode_der_sig: u64,
+}
+
+/// Costs of the managed buffer VM hooks, in the `[ManagedBufferAPICost]` section.
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct ManagedBufferAPICost {
+    #[serde(rename = "MBufferNew")]
+    pub m_buffer_new: u64,
+    #[serde(rename = "MBufferNewFromBytes")]
+    pub m_buffer_new_from_bytes: u64,
+    #[serde(rename = "MBufferGetLength")]
+    pub m_buffer_get_length: u64,
+    #[serde(rename = "MBufferGetBytes")]
+    pub m_buffer_get_bytes: u64,
+    #[serde(rename = "MBufferGetByteSlice")]
+    pub m_buffer_get_byte_slice: u64,
+    #[serde(rename = "MBufferCopyByteSlice")]
+    pub m_buffer_copy_byte_slice: u64,
+    #[serde(rename = "MBufferSetBytes")]
+    pub m_buffer_set_bytes: u64,
+    #[serde(rename = "MBufferAppend")]
+    pub m_buffer_append: u64,
+    #[serde(rename = "MBufferAppendBytes")]
+    pub m_buffer_append_bytes: u64,
+    #[serde(rename = "MBufferToBigIntUnsigned")]
+    pub m_buffer_to_big_int_unsigned: u64,
+    #[serde(rename = "MBufferToBigIntSigned")]
+    pub m_buffer_to_big_int_signed: u64,
+    #[serde(rename = "MBufferFromBigIntUnsigned")]
+    pub m_buffer_from_big_int_unsigned: u64,
+    #[serde(rename = "MBufferFromBigIntSigned")]
+    pub m_buffer_from_big_int_signed: u64,
+    #[serde(rename = "MBufferStorageStore")]
+    pub m_buffer_storage_store: u64,
+    #[serde(rename = "MBufferStorageLoad")]
+    pub m_buffer_storage_load: u64,
+    #[serde(rename = "MBufferGetArgument")]
+    pub m_buffer_get_argument: u64,
+    #[serde(rename = "MBufferFinish")]
+    pub m_buffer_finish: u64,
+    #[serde(rename = "MBufferSetRandom")]
+    pub m_buffer_set_random: u64,
+}
+
+/// Costs of the managed map VM hooks, in the `[ManagedMapAPICost]` section.
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct ManagedMapAPICost {
+    pub managed_map_new: u64,
+    pub managed_map_put: u64,
+    pub managed_map_get: u64,
+    pub managed_map_remove: u64,
+    pub managed_map_contains: u64,
+}
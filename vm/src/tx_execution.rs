@@ -3,6 +3,7 @@ mod builtin_function_mocks;
 mod exec_call;
 mod exec_contract_endpoint;
 mod exec_create;
+mod exec_cross_shard;
 mod exec_general_tx;
 mod system_sc;
 
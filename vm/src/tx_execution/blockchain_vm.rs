@@ -1,12 +1,30 @@
-use std::{ops::Deref, sync::Arc};
+use std::{
+    ops::Deref,
+    sync::{Arc, Mutex},
+};
 
 use dharitri_chain_vm_executor::Executor;
 
+use crate::{
+    schedule::GasSchedule,
+    tx_mock::{TxCallTrace, TxTracer},
+    world_mock::ShardCoordinator,
+};
+
 use super::BuiltinFunctionContainer;
 
 pub struct BlockchainVM {
     pub builtin_functions: BuiltinFunctionContainer,
     pub executor: Box<dyn Executor + Send + Sync>,
+
+    /// Gas is only metered if a gas schedule is configured.
+    pub gas_schedule: Option<GasSchedule>,
+
+    /// Sharded execution mode is only enabled if a shard coordinator is configured.
+    pub shard_coordinator: Option<ShardCoordinator>,
+
+    /// Calls are only traced if a tracer is configured.
+    pub tracer: Option<Mutex<TxTracer>>,
 }
 
 #[derive(Clone)]
@@ -17,14 +35,83 @@ impl BlockchainVM {
         BlockchainVM {
             builtin_functions: BuiltinFunctionContainer,
             executor,
+            gas_schedule: None,
+            shard_coordinator: None,
+            tracer: None,
         }
     }
+
+    pub fn is_gas_metered(&self) -> bool {
+        self.gas_schedule.is_some()
+    }
+
+    /// Looks up a cost in the gas schedule. Everything is free if gas is not metered.
+    pub fn gas_cost<F>(&self, cost_fn: F) -> u64
+    where
+        F: FnOnce(&GasSchedule) -> u64,
+    {
+        self.gas_schedule.as_ref().map(cost_fn).unwrap_or_default()
+    }
+
+    pub fn is_tracing(&self) -> bool {
+        self.tracer.is_some()
+    }
+
+    /// Records something in the execution trace. Does nothing if tracing is not enabled.
+    pub fn trace<F>(&self, f: F)
+    where
+        F: FnOnce(&mut TxTracer),
+    {
+        if let Some(tracer) = &self.tracer {
+            f(&mut tracer.lock().unwrap());
+        }
+    }
+
+    /// The call trees of all calls traced so far.
+    pub fn execution_trace(&self) -> Vec<TxCallTrace> {
+        self.tracer
+            .as_ref()
+            .map(|tracer| tracer.lock().unwrap().traces().to_vec())
+            .unwrap_or_default()
+    }
+
+    /// Callbacks of legacy async calls get this much gas reserved, on top of the gas left by the async call.
+    pub fn async_callback_gas_lock(&self) -> u64 {
+        self.gas_cost(|gas_schedule| gas_schedule.base_ops_api_cost.async_callback_gas_lock)
+    }
 }
 
 impl BlockchainVMRef {
     pub fn new(executor: Box<dyn Executor + Send + Sync>) -> Self {
         BlockchainVMRef(Arc::new(BlockchainVM::new(executor)))
     }
+
+    /// Enables gas metering, or disables it, if `None`.
+    ///
+    /// Can only be called while the VM is not executing anything.
+    pub fn set_gas_schedule(&mut self, gas_schedule: Option<GasSchedule>) {
+        Arc::get_mut(&mut self.0)
+            .expect("cannot change the gas schedule while the VM is in use")
+            .gas_schedule = gas_schedule;
+    }
+
+    /// Enables sharded execution, or disables it, if `None`.
+    ///
+    /// Can only be called while the VM is not executing anything.
+    pub fn set_shard_coordinator(&mut self, shard_coordinator: Option<ShardCoordinator>) {
+        Arc::get_mut(&mut self.0)
+            .expect("cannot change the shard coordinator while the VM is in use")
+            .shard_coordinator = shard_coordinator;
+    }
+
+    /// Enables execution tracing, or disables it, if `None`.
+    ///
+    /// Can only be called while the VM is not executing anything.
+    pub fn set_tracer(&mut self, tracer: Option<TxTracer>) {
+        Arc::get_mut(&mut self.0)
+            .expect("cannot change the tracer while the VM is in use")
+            .tracer = tracer.map(Mutex::new);
+    }
 }
 
 impl Deref for BlockchainVMRef {
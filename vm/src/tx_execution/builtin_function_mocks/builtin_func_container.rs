@@ -11,7 +11,7 @@ use super::{
 };
 use crate::{
     tx_execution::BlockchainVMRef,
-    tx_mock::{BlockchainUpdate, TxCache, TxInput, TxResult},
+    tx_mock::{BlockchainUpdate, TxCache, TxInput, TxPanic, TxResult},
     types::DctLocalRole,
 };
 
@@ -119,12 +119,28 @@ impl<'a> BuiltinFunctionCall<'a> {
         }
     }
 
-    fn execute_bf<B, F>(self, builtin_func: B, f: F) -> (TxResult, BlockchainUpdate)
+    fn execute_bf<B, F>(mut self, builtin_func: B, f: F) -> (TxResult, BlockchainUpdate)
     where
         B: BuiltinFunction,
         F: FnOnce(),
     {
-        builtin_func.execute(self.tx_input, self.tx_cache, self.vm, f)
+        let func_name = self.tx_input.func_name.as_str();
+        let gas_cost = self
+            .vm
+            .gas_cost(|gas_schedule| gas_schedule.builtin_function_cost(func_name));
+        if gas_cost > self.tx_input.gas_limit {
+            return (
+                TxResult::from_panic_obj(&TxPanic::out_of_gas()),
+                BlockchainUpdate::empty(),
+            );
+        }
+
+        // the rest of the gas is available to the SC call that might follow the builtin function
+        self.tx_input.gas_limit -= gas_cost;
+        let (mut tx_result, blockchain_updates) =
+            builtin_func.execute(self.tx_input, self.tx_cache, self.vm, f);
+        tx_result.gas_used += gas_cost;
+        (tx_result, blockchain_updates)
     }
 
     fn check_role_and_execute<B, F>(
@@ -60,6 +60,7 @@ impl BuiltinFunction for DCTNftCreate {
                     uri: uris,
                     attributes,
                 },
+                frozen: false,
             });
 
             dct_data.last_nonce
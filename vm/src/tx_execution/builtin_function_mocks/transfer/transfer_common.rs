@@ -1,7 +1,6 @@
 use crate::{
     tx_execution::{
-        builtin_function_mocks::builtin_func_trait::BuiltinFunctionDctTransferInfo,
-        BlockchainVMRef,
+        builtin_function_mocks::builtin_func_trait::BuiltinFunctionDctTransferInfo, BlockchainVMRef,
     },
     tx_mock::{
         BlockchainUpdate, TxCache, TxFunctionName, TxInput, TxLog, TxResult, TxTokenTransfer,
@@ -87,6 +86,7 @@ where
         ..Default::default()
     };
 
+    vm.trace(|tracer| tracer.resolve_builtin_transfer(&exec_input));
     let (mut tx_result, blockchain_updates) = vm.default_execution(exec_input, tx_cache, f);
 
     // prepends dct log
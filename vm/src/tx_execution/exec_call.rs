@@ -1,8 +1,8 @@
 use crate::{
     tx_mock::{
         async_call_tx_input, async_callback_tx_input, async_promise_tx_input, merge_results,
-        AsyncCallTxData, BlockchainUpdate, Promise, TxCache, TxContext, TxContextStack, TxInput,
-        TxPanic, TxResult, TxResultCalls,
+        AsyncCallTxData, BlockchainUpdate, CrossShardCallType, Promise, TxCache, TxCallKind,
+        TxContext, TxContextStack, TxInput, TxPanic, TxResult, TxResultCalls,
     },
     types::VMAddress,
     with_shared::Shareable,
@@ -36,7 +36,9 @@ impl BlockchainVMRef {
     where
         F: FnOnce(),
     {
+        self.trace(|tracer| tracer.begin_call(TxCallKind::Query, &tx_input));
         let (tx_result, _) = self.execute_in_debugger(tx_input, state, f);
+        self.trace(|tracer| tracer.end_call(&tx_result));
         tx_result
     }
 
@@ -87,39 +89,57 @@ impl BlockchainVMRef {
     {
         state.subtract_tx_gas(&tx_input.from, tx_input.gas_limit, tx_input.gas_price);
 
-        let (tx_result, blockchain_updates) = state.with_shared(|state_arc| {
+        let gas_limit = tx_input.gas_limit;
+        let (mut tx_result, blockchain_updates) = state.with_shared(|state_arc| {
             let tx_cache = TxCache::new(state_arc);
             self.execute_builtin_function_or_default(tx_input, tx_cache, f)
         });
 
         if tx_result.result_status == 0 {
             blockchain_updates.apply(state);
+        } else if self.is_gas_metered() {
+            tx_result.consume_all_gas_if_failed(gas_limit);
         }
 
         tx_result
     }
 
+    /// Gives back to the sender the value of the gas that was not used, if gas is metered.
+    pub fn refund_unused_gas(
+        &self,
+        tx_result: &TxResult,
+        from: &VMAddress,
+        gas_limit: u64,
+        gas_price: u64,
+        state: &mut Shareable<BlockchainState>,
+    ) {
+        if self.is_gas_metered() && gas_price > 0 {
+            state.refund_tx_gas(from, tx_result.gas_to_refund(gas_limit), gas_price);
+        }
+    }
+
     pub fn execute_async_call_and_callback(
         &self,
         async_data: AsyncCallTxData,
         state: &mut Shareable<BlockchainState>,
     ) -> (TxResult, TxResult) {
-        if state.accounts.contains_key(&async_data.to) {
+        if state.account_exists(&async_data.to) {
             let async_input = async_call_tx_input(&async_data);
 
-            let async_result = self.sc_call_with_async_and_callback(
+            let async_result = self.traced_sc_call_with_async_and_callback(
+                TxCallKind::AsyncCall,
                 async_input,
                 state,
                 execute_current_tx_context_input,
             );
 
-            let callback_input =
-                async_callback_tx_input(&async_data, &async_result, &self.builtin_functions);
-            let callback_result = self.execute_sc_call_lambda(
-                callback_input,
-                state,
-                execute_current_tx_context_input,
+            let callback_input = async_callback_tx_input(
+                &async_data,
+                &async_result,
+                self.async_callback_gas_lock(),
+                &self.builtin_functions,
             );
+            let callback_result = self.execute_callback(callback_input, state);
             assert!(
                 callback_result.pending_calls.async_call.is_none(),
                 "successive asyncs currently not supported"
@@ -137,40 +157,105 @@ impl BlockchainVMRef {
         }
     }
 
-    // TODO: refactor
     pub fn sc_call_with_async_and_callback<F>(
         &self,
         tx_input: TxInput,
         state: &mut Shareable<BlockchainState>,
         f: F,
     ) -> TxResult
+    where
+        F: FnOnce(),
+    {
+        self.traced_sc_call_with_async_and_callback(TxCallKind::Transaction, tx_input, state, f)
+    }
+
+    /// Same as `sc_call_with_async_and_callback`, but the call shows up in the execution trace as the given kind.
+    pub(super) fn traced_sc_call_with_async_and_callback<F>(
+        &self,
+        call_kind: TxCallKind,
+        tx_input: TxInput,
+        state: &mut Shareable<BlockchainState>,
+        f: F,
+    ) -> TxResult
+    where
+        F: FnOnce(),
+    {
+        self.trace(|tracer| tracer.begin_call(call_kind, &tx_input));
+        let tx_result = self.execute_with_async_and_callback(tx_input, state, f);
+        self.trace(|tracer| tracer.end_call(&tx_result));
+        tx_result
+    }
+
+    // TODO: refactor
+    fn execute_with_async_and_callback<F>(
+        &self,
+        tx_input: TxInput,
+        state: &mut Shareable<BlockchainState>,
+        f: F,
+    ) -> TxResult
     where
         F: FnOnce(),
     {
         // main call
         let contract_address = tx_input.to.clone();
+        let from = tx_input.from.clone();
+        let gas_limit = tx_input.gas_limit;
+        let gas_price = tx_input.gas_price;
         let mut tx_result = self.execute_sc_call_lambda(tx_input, state, f);
 
         // take & clear pending calls
         let pending_calls = std::mem::replace(&mut tx_result.pending_calls, TxResultCalls::empty());
 
+        // transfers to other shards only get executed in the next block
+        if tx_result.result_status == 0 {
+            state
+                .cross_shard_calls
+                .extend(pending_calls.cross_shard_calls);
+        }
+
         // legacy async call
         // the async call also gets reset
         if tx_result.result_status == 0 {
             if let Some(async_data) = pending_calls.async_call {
+                if self.is_cross_shard_call(&async_call_tx_input(&async_data)) {
+                    tx_result = self.send_cross_shard_async_call(tx_result, async_data, state);
+                    self.refund_unused_gas(&tx_result, &from, gas_limit, gas_price, state);
+                    return tx_result;
+                }
+
                 let (async_result, callback_result) =
                     self.execute_async_call_and_callback(async_data, state);
 
                 tx_result = merge_results(tx_result, async_result);
                 tx_result = merge_results(tx_result, callback_result);
 
+                self.refund_unused_gas(&tx_result, &from, gas_limit, gas_price, state);
                 return tx_result;
             }
         }
 
+        let (cross_shard_promises, promises): (Vec<Promise>, Vec<Promise>) = pending_calls
+            .promises
+            .into_iter()
+            .partition(|promise| self.is_cross_shard_call(&async_call_tx_input(&promise.call)));
+
+        // the gas reserved for the promises is replaced by the gas they actually use
+        if self.is_gas_metered() {
+            let reserved_gas: u64 = promises
+                .iter()
+                .map(|promise| {
+                    promise
+                        .call
+                        .gas_limit
+                        .saturating_add(promise.extra_gas_for_callback)
+                })
+                .sum();
+            tx_result.gas_used = tx_result.gas_used.saturating_sub(reserved_gas);
+        }
+
         // calling all promises
         // the promises are also reset
-        for promise in pending_calls.promises {
+        for promise in promises {
             let (async_result, callback_result) =
                 self.execute_promise_call_and_callback(&contract_address, &promise, state);
 
@@ -178,6 +263,17 @@ impl BlockchainVMRef {
             tx_result = merge_results(tx_result, callback_result.clone());
         }
 
+        // promises to other shards keep the gas reserved for them
+        for promise in cross_shard_promises {
+            let call = promise.call.clone();
+            if let Err(err) =
+                self.send_cross_shard_call(CrossShardCallType::Promise(promise), &call, state)
+            {
+                tx_result = merge_results(tx_result, TxResult::from_panic_obj(&err));
+            }
+        }
+
+        self.refund_unused_gas(&tx_result, &from, gas_limit, gas_price, state);
         tx_result
     }
 
@@ -187,20 +283,17 @@ impl BlockchainVMRef {
         promise: &Promise,
         state: &mut Shareable<BlockchainState>,
     ) -> (TxResult, TxResult) {
-        if state.accounts.contains_key(&promise.call.to) {
+        if state.account_exists(&promise.call.to) {
             let async_input = async_call_tx_input(&promise.call);
-            let async_result = self.sc_call_with_async_and_callback(
+            let async_result = self.traced_sc_call_with_async_and_callback(
+                TxCallKind::Promise,
                 async_input,
                 state,
                 execute_current_tx_context_input,
             );
 
             let callback_input = async_promise_tx_input(address, promise, &async_result);
-            let callback_result = self.execute_sc_call_lambda(
-                callback_input,
-                state,
-                execute_current_tx_context_input,
-            );
+            let callback_result = self.execute_callback(callback_input, state);
             assert!(
                 callback_result.pending_calls.promises.is_empty(),
                 "successive promises currently not supported"
@@ -218,8 +311,20 @@ impl BlockchainVMRef {
         }
     }
 
+    pub(super) fn execute_callback(
+        &self,
+        callback_input: TxInput,
+        state: &mut Shareable<BlockchainState>,
+    ) -> TxResult {
+        self.trace(|tracer| tracer.begin_call(TxCallKind::Callback, &callback_input));
+        let callback_result =
+            self.execute_sc_call_lambda(callback_input, state, execute_current_tx_context_input);
+        self.trace(|tracer| tracer.end_call(&callback_result));
+        callback_result
+    }
+
     /// When calling a contract that is unknown to the state, we insert a ghost account.
-    fn insert_ghost_account(
+    pub(super) fn insert_ghost_account(
         &self,
         async_data: &AsyncCallTxData,
         state: &mut Shareable<BlockchainState>,
@@ -242,3 +347,102 @@ impl BlockchainVMRef {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        schedule::GasSchedule,
+        tx_execution::builtin_function_names::DCT_TRANSFER_FUNC_NAME,
+        world_mock::{DctInstanceMetadata, FailingExecutor},
+    };
+
+    const SENDER: VMAddress = VMAddress::new([1u8; 32]);
+    const RECEIVER: VMAddress = VMAddress::new([2u8; 32]);
+    const TOKEN: &[u8] = b"TOKEN-123456";
+
+    fn metered_vm() -> BlockchainVMRef {
+        let gas_schedule = GasSchedule::from_toml_str("[BuiltInCost]\nDCTTransfer = 100").unwrap();
+        let mut vm = BlockchainVMRef::new(Box::new(FailingExecutor));
+        vm.set_gas_schedule(Some(gas_schedule));
+        vm
+    }
+
+    fn new_state() -> Shareable<BlockchainState> {
+        let mut state = BlockchainState::default();
+        let mut sender = AccountData::new_empty(SENDER);
+        sender.moax_balance = BigUint::from(10_000u32);
+        sender.dct.set_dct_balance(
+            TOKEN.to_vec(),
+            0,
+            &BigUint::from(1000u32),
+            DctInstanceMetadata::default(),
+        );
+        state.add_account(sender);
+        state.add_account(AccountData::new_empty(RECEIVER));
+        Shareable::new(state)
+    }
+
+    fn dct_transfer(
+        vm: &BlockchainVMRef,
+        state: &mut Shareable<BlockchainState>,
+        gas_limit: u64,
+    ) -> TxResult {
+        let tx_input = TxInput {
+            from: SENDER,
+            to: RECEIVER,
+            func_name: DCT_TRANSFER_FUNC_NAME.into(),
+            args: vec![TOKEN.to_vec(), BigUint::from(10u32).to_bytes_be()],
+            gas_limit,
+            gas_price: 1,
+            ..Default::default()
+        };
+        vm.sc_call_with_async_and_callback(tx_input, state, execute_current_tx_context_input)
+    }
+
+    #[test]
+    fn test_builtin_gas_refund() {
+        let vm = metered_vm();
+        let mut state = new_state();
+
+        let tx_result = dct_transfer(&vm, &mut state, 1000);
+        assert_eq!(tx_result.result_status, 0);
+        assert_eq!(tx_result.gas_used, 100);
+        assert_eq!(
+            state.accounts[&SENDER].moax_balance,
+            BigUint::from(9_900u32)
+        );
+    }
+
+    #[test]
+    fn test_builtin_out_of_gas() {
+        let vm = metered_vm();
+        let mut state = new_state();
+
+        let tx_result = dct_transfer(&vm, &mut state, 50);
+        assert_eq!(tx_result.result_status, 5);
+        assert_eq!(tx_result.gas_used, 50);
+        assert_eq!(
+            state.accounts[&SENDER].moax_balance,
+            BigUint::from(9_950u32)
+        );
+        assert_eq!(
+            state.accounts[&RECEIVER].dct.get_dct_balance(TOKEN, 0),
+            BigUint::zero()
+        );
+    }
+
+    #[test]
+    fn test_not_metered() {
+        let vm = BlockchainVMRef::new(Box::new(FailingExecutor));
+        let mut state = new_state();
+
+        let tx_result = dct_transfer(&vm, &mut state, 1000);
+        assert_eq!(tx_result.result_status, 0);
+        assert_eq!(tx_result.gas_used, 0);
+        assert_eq!(
+            state.accounts[&SENDER].moax_balance,
+            BigUint::from(9_000u32)
+        );
+    }
+}
@@ -23,7 +23,10 @@ impl BlockchainVMRef {
         state.increase_account_nonce(&tx_input.from);
         state.subtract_tx_gas(&tx_input.from, tx_input.gas_limit, tx_input.gas_price);
 
-        let (tx_result, new_address, blockchain_updates) = state.with_shared(|state_arc| {
+        let from = tx_input.from.clone();
+        let gas_limit = tx_input.gas_limit;
+        let gas_price = tx_input.gas_price;
+        let (mut tx_result, new_address, blockchain_updates) = state.with_shared(|state_arc| {
             let tx_cache = TxCache::new(state_arc);
 
             self.deploy_contract(tx_input, contract_path.to_vec(), tx_cache, f)
@@ -31,6 +34,11 @@ impl BlockchainVMRef {
 
         blockchain_updates.apply(state);
 
+        if self.is_gas_metered() {
+            tx_result.consume_all_gas_if_failed(gas_limit);
+        }
+        self.refund_unused_gas(&tx_result, &from, gas_limit, gas_price, state);
+
         (new_address, tx_result)
     }
 }
@@ -0,0 +1,177 @@
+use crate::{
+    tx_mock::{
+        async_call_tx_input, async_callback_tx_input, async_promise_tx_input, merge_results,
+        AsyncCallTxData, CrossShardCall, CrossShardCallType, TxCache, TxCallKind, TxInput, TxPanic,
+        TxResult,
+    },
+    with_shared::Shareable,
+    world_mock::BlockchainState,
+};
+
+use super::{execute_current_tx_context_input, BlockchainVMRef};
+
+impl BlockchainVMRef {
+    /// In sharded mode, checks whether a call goes to another shard.
+    ///
+    /// Token transfers are routed to their real recipient.
+    pub fn is_cross_shard_call(&self, tx_input: &TxInput) -> bool {
+        if let Some(shard_coordinator) = &self.shard_coordinator {
+            let recipient = self
+                .builtin_functions
+                .extract_token_transfers(tx_input)
+                .real_recipient;
+            !shard_coordinator.same_shard(&tx_input.from, &recipient)
+        } else {
+            false
+        }
+    }
+
+    /// Sends a call to another shard, to be executed in the next block.
+    ///
+    /// The value transferred leaves the sender immediately.
+    pub(super) fn send_cross_shard_call(
+        &self,
+        call_type: CrossShardCallType,
+        async_data: &AsyncCallTxData,
+        state: &mut Shareable<BlockchainState>,
+    ) -> Result<(), TxPanic> {
+        let tx_input = async_call_tx_input(async_data);
+        let token_transfers = self.builtin_functions.extract_token_transfers(&tx_input);
+        let (in_flight, blockchain_updates) = state.with_shared(|state_arc| {
+            let tx_cache = TxCache::new(state_arc);
+            tx_cache
+                .send_in_flight_value(
+                    &tx_input.from,
+                    &tx_input.moax_value,
+                    &token_transfers.transfers,
+                )
+                .map(|in_flight| (in_flight, tx_cache.into_blockchain_updates()))
+        })?;
+        blockchain_updates.apply(state);
+
+        state.cross_shard_calls.push(CrossShardCall {
+            call_type,
+            in_flight,
+        });
+        Ok(())
+    }
+
+    /// Sends a legacy async call to another shard.
+    ///
+    /// The gas forwarded to the async call and its callback counts as used by the original transaction.
+    pub(super) fn send_cross_shard_async_call(
+        &self,
+        mut tx_result: TxResult,
+        async_data: AsyncCallTxData,
+        state: &mut Shareable<BlockchainState>,
+    ) -> TxResult {
+        let forwarded_gas = async_data.gas_limit + self.async_callback_gas_lock();
+        match self.send_cross_shard_call(
+            CrossShardCallType::AsyncCall(async_data.clone()),
+            &async_data,
+            state,
+        ) {
+            Ok(()) => {
+                if self.is_gas_metered() {
+                    tx_result.gas_used += forwarded_gas;
+                }
+                tx_result
+            },
+            Err(err) => merge_results(tx_result, TxResult::from_panic_obj(&err)),
+        }
+    }
+
+    /// Executes all cross-shard calls sent in the previous block.
+    ///
+    /// The calls that they produce, e.g. the callbacks, are only executed in the next block.
+    /// Their outcome reaches the caller through the callbacks, as on-chain.
+    pub fn execute_cross_shard_calls(&self, state: &mut Shareable<BlockchainState>) {
+        let cross_shard_calls = std::mem::take(&mut state.cross_shard_calls);
+        for cross_shard_call in cross_shard_calls {
+            self.execute_cross_shard_call(cross_shard_call, state);
+        }
+    }
+
+    fn execute_cross_shard_call(
+        &self,
+        cross_shard_call: CrossShardCall,
+        state: &mut Shareable<BlockchainState>,
+    ) {
+        let sender = cross_shard_call.sender().clone();
+        let blockchain_updates = state.with_shared(|state_arc| {
+            let tx_cache = TxCache::new(state_arc);
+            tx_cache.receive_in_flight_value(&sender, cross_shard_call.in_flight);
+            tx_cache.into_blockchain_updates()
+        });
+        blockchain_updates.apply(state);
+
+        match cross_shard_call.call_type {
+            CrossShardCallType::AsyncCall(async_data) => {
+                let async_result =
+                    self.execute_cross_shard_destination(TxCallKind::AsyncCall, &async_data, state);
+                let callback_input = async_callback_tx_input(
+                    &async_data,
+                    &async_result,
+                    self.async_callback_gas_lock(),
+                    &self.builtin_functions,
+                );
+                self.send_callback(callback_input, state);
+            },
+            CrossShardCallType::Promise(promise) => {
+                let async_result =
+                    self.execute_cross_shard_destination(TxCallKind::Promise, &promise.call, state);
+                let callback_input =
+                    async_promise_tx_input(&promise.call.from, &promise, &async_result);
+                self.send_callback(callback_input, state);
+            },
+            // the outcome of these only shows in the state, there is no callback to report it to
+            CrossShardCallType::TransferExecute(async_data) => {
+                let _ = self.execute_cross_shard_destination(
+                    TxCallKind::TransferExecute,
+                    &async_data,
+                    state,
+                );
+            },
+            CrossShardCallType::Callback(callback_input) => {
+                let _ = self.traced_sc_call_with_async_and_callback(
+                    TxCallKind::Callback,
+                    callback_input,
+                    state,
+                    execute_current_tx_context_input,
+                );
+            },
+        }
+    }
+
+    fn execute_cross_shard_destination(
+        &self,
+        call_kind: TxCallKind,
+        async_data: &AsyncCallTxData,
+        state: &mut Shareable<BlockchainState>,
+    ) -> TxResult {
+        if state.account_exists(&async_data.to) {
+            self.traced_sc_call_with_async_and_callback(
+                call_kind,
+                async_call_tx_input(async_data),
+                state,
+                execute_current_tx_context_input,
+            )
+        } else {
+            match self.insert_ghost_account(async_data, state) {
+                Ok(blockchain_updates) => {
+                    state.commit_updates(blockchain_updates);
+                    TxResult::empty()
+                },
+                Err(err) => TxResult::from_panic_obj(&err),
+            }
+        }
+    }
+
+    fn send_callback(&self, callback_input: TxInput, state: &mut Shareable<BlockchainState>) {
+        state
+            .cross_shard_calls
+            .push(CrossShardCall::new(CrossShardCallType::Callback(
+                callback_input,
+            )));
+    }
+}
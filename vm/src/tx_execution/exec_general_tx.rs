@@ -3,8 +3,8 @@ use num_traits::Zero;
 use crate::{
     tx_execution::execute_system_sc,
     tx_mock::{
-        BlockchainUpdate, TxCache, TxContext, TxContextStack, TxFunctionName, TxInput, TxLog,
-        TxResult,
+        BlockchainUpdate, TxCache, TxCallKind, TxContext, TxContextStack, TxFunctionName, TxInput,
+        TxLog, TxPanic, TxResult,
     },
     types::VMAddress,
     with_shared::Shareable,
@@ -78,7 +78,18 @@ impl BlockchainVMRef {
         }
 
         let (mut tx_result, blockchain_updates) = if is_system_sc_address(&tx_input.to) {
-            execute_system_sc(tx_input, tx_cache)
+            let gas_cost = self
+                .gas_cost(|gas_schedule| gas_schedule.system_sc_cost(tx_input.func_name.as_str()));
+            if gas_cost > tx_input.gas_limit {
+                return (
+                    TxResult::from_panic_obj(&TxPanic::out_of_gas()),
+                    BlockchainUpdate::empty(),
+                );
+            }
+
+            let (mut tx_result, blockchain_updates) = execute_system_sc(tx_input, tx_cache);
+            tx_result.gas_used = gas_cost;
+            (tx_result, blockchain_updates)
         } else if should_execute_sc_call(&tx_input) {
             let tx_context = TxContext::new(self.clone(), tx_input, tx_cache);
             let mut tx_context_sh = Shareable::new(tx_context);
@@ -111,6 +122,7 @@ impl BlockchainVMRef {
         let new_address = tx_cache.get_new_address(&tx_input.from);
         tx_input.to = new_address.clone();
         tx_input.func_name = TxFunctionName::INIT;
+        self.trace(|tracer| tracer.begin_call(TxCallKind::Deploy, &tx_input));
         let tx_context = TxContext::new(self.clone(), tx_input, tx_cache);
         let mut tx_context_sh = Shareable::new(tx_context);
         let tx_input_ref = tx_context_sh.input_ref();
@@ -119,11 +131,9 @@ impl BlockchainVMRef {
             .tx_cache
             .subtract_moax_balance(&tx_input_ref.from, &tx_input_ref.moax_value)
         {
-            return (
-                TxResult::from_panic_obj(&err),
-                VMAddress::zero(),
-                BlockchainUpdate::empty(),
-            );
+            let tx_result = TxResult::from_panic_obj(&err);
+            self.trace(|tracer| tracer.end_call(&tx_result));
+            return (tx_result, VMAddress::zero(), BlockchainUpdate::empty());
         }
         tx_context_sh.create_new_contract(&new_address, contract_path, tx_input_ref.from.clone());
         tx_context_sh
@@ -133,6 +143,7 @@ impl BlockchainVMRef {
         TxContextStack::execute_on_vm_stack(&mut tx_context_sh, f);
 
         let (tx_result, blockchain_updates) = tx_context_sh.into_inner().into_results();
+        self.trace(|tracer| tracer.end_call(&tx_result));
         (tx_result, new_address, blockchain_updates)
     }
 }
@@ -1,15 +1,22 @@
+mod system_sc_common;
+mod system_sc_config;
+mod system_sc_freeze;
 mod system_sc_issue;
+mod system_sc_mint_burn;
 mod system_sc_special_roles;
-mod system_sc_unimplemented;
+mod system_sc_token_management;
 
 use crate::{
     tx_mock::{BlockchainUpdate, TxCache, TxInput, TxResult},
     types::VMAddress,
 };
 use hex_literal::hex;
+use system_sc_config::*;
+use system_sc_freeze::*;
 use system_sc_issue::*;
+use system_sc_mint_burn::*;
 use system_sc_special_roles::*;
-use system_sc_unimplemented::*;
+use system_sc_token_management::*;
 
 /// Address of the system smart contract that manages DCT.
 /// Bech32: erd1qqqqqqqqqqqqqqqpqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqzllls8a5w6u
@@ -58,3 +65,261 @@ pub fn execute_system_sc(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, Blo
         invalid_func_name => panic!("invalid system SC function: {invalid_func_name}"),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use num_bigint::BigUint;
+
+    use super::*;
+    use crate::world_mock::{AccountData, BlockchainState, DctInstanceMetadata};
+
+    const OWNER: VMAddress = VMAddress::new([1u8; 32]);
+    const USER: VMAddress = VMAddress::new([2u8; 32]);
+
+    fn new_state() -> BlockchainState {
+        let mut state = BlockchainState::default();
+        state.add_account(AccountData::new_empty(OWNER));
+        state.add_account(AccountData::new_empty(USER));
+        state
+    }
+
+    fn call(
+        state: &mut BlockchainState,
+        from: &VMAddress,
+        func_name: &str,
+        args: Vec<Vec<u8>>,
+    ) -> TxResult {
+        let tx_input = TxInput {
+            from: from.clone(),
+            to: VMAddress::new(DCT_SYSTEM_SC_ADDRESS_ARRAY),
+            func_name: func_name.into(),
+            args,
+            ..Default::default()
+        };
+        let tx_cache = TxCache::new(Arc::new(state.clone()));
+        let (tx_result, blockchain_updates) = execute_system_sc(tx_input, tx_cache);
+        if tx_result.result_status == 0 {
+            blockchain_updates.apply(state);
+        }
+        tx_result
+    }
+
+    fn issue_fungible(state: &mut BlockchainState, properties: &[&str]) -> Vec<u8> {
+        let mut args = vec![
+            b"TestToken".to_vec(),
+            b"TEST".to_vec(),
+            BigUint::from(1000u32).to_bytes_be(),
+            vec![18],
+        ];
+        for property in properties {
+            args.push(property.as_bytes().to_vec());
+            args.push(b"true".to_vec());
+        }
+        let tx_result = call(state, &OWNER, "issue", args);
+        assert_eq!(tx_result.result_status, 0);
+        tx_result.result_values[0].clone()
+    }
+
+    fn give_user_tokens(state: &mut BlockchainState, token_identifier: &[u8], amount: u32) {
+        state.accounts.get_mut(&USER).unwrap().dct.set_dct_balance(
+            token_identifier.to_vec(),
+            0,
+            &BigUint::from(amount),
+            DctInstanceMetadata::default(),
+        );
+    }
+
+    fn user_balance(state: &BlockchainState, token_identifier: &[u8]) -> BigUint {
+        state.accounts[&USER]
+            .dct
+            .get_dct_balance(token_identifier, 0)
+    }
+
+    #[test]
+    fn test_issue_credits_total_supply() {
+        let mut state = new_state();
+        let token = issue_fungible(&mut state, &[]);
+
+        assert_eq!(
+            state.accounts[&OWNER].dct.get_dct_balance(&token, 0),
+            BigUint::from(1000u32)
+        );
+        assert_eq!(user_balance(&state, &token), BigUint::from(0u32));
+    }
+
+    #[test]
+    fn test_freeze_wipe() {
+        let mut state = new_state();
+        let token = issue_fungible(&mut state, &["canFreeze", "canWipe"]);
+        give_user_tokens(&mut state, &token, 100);
+
+        let wipe_args = vec![token.clone(), USER.to_vec()];
+        let tx_result = call(&mut state, &OWNER, "wipe", wipe_args.clone());
+        assert_eq!(
+            tx_result.result_message,
+            "cannot wipe because the account is not frozen for this dct token"
+        );
+
+        let tx_result = call(&mut state, &USER, "freeze", wipe_args.clone());
+        assert_eq!(tx_result.result_message, "can be called by owner only");
+
+        let tx_result = call(&mut state, &OWNER, "freeze", wipe_args.clone());
+        assert_eq!(tx_result.result_status, 0);
+        assert!(
+            state.accounts[&USER]
+                .dct
+                .get_by_identifier(&token)
+                .unwrap()
+                .frozen
+        );
+
+        let tx_result = call(&mut state, &OWNER, "wipe", wipe_args.clone());
+        assert_eq!(tx_result.result_status, 0);
+        assert_eq!(user_balance(&state, &token), BigUint::from(0u32));
+
+        let tx_result = call(&mut state, &OWNER, "unFreeze", wipe_args);
+        assert_eq!(tx_result.result_status, 0);
+        assert!(
+            !state.accounts[&USER]
+                .dct
+                .get_by_identifier(&token)
+                .unwrap()
+                .frozen
+        );
+    }
+
+    #[test]
+    fn test_freeze_not_allowed() {
+        let mut state = new_state();
+        let token = issue_fungible(&mut state, &[]);
+
+        let tx_result = call(&mut state, &OWNER, "freeze", vec![token, USER.to_vec()]);
+        assert_eq!(tx_result.result_message, "cannot freeze");
+
+        let tx_result = call(
+            &mut state,
+            &OWNER,
+            "freeze",
+            vec![b"UNKNOWN-123456".to_vec(), USER.to_vec()],
+        );
+        assert_eq!(tx_result.result_message, "no ticker with given name");
+    }
+
+    #[test]
+    fn test_invalid_address_argument() {
+        let mut state = new_state();
+        let token = issue_fungible(&mut state, &["canFreeze", "canMint"]);
+
+        let tx_result = call(
+            &mut state,
+            &OWNER,
+            "freeze",
+            vec![token.clone(), vec![1, 2, 3]],
+        );
+        assert_eq!(tx_result.result_message, "invalid address");
+
+        let mint_args = vec![token, BigUint::from(5u32).to_bytes_be(), vec![1; 33]];
+        let tx_result = call(&mut state, &OWNER, "mint", mint_args);
+        assert_eq!(tx_result.result_message, "invalid address");
+    }
+
+    #[test]
+    fn test_pause_unpause() {
+        let mut state = new_state();
+        let token = issue_fungible(&mut state, &["canPause"]);
+
+        let tx_result = call(&mut state, &OWNER, "unPause", vec![token.clone()]);
+        assert_eq!(
+            tx_result.result_message,
+            "cannot unPause an already un-paused token"
+        );
+
+        let tx_result = call(&mut state, &OWNER, "pause", vec![token.clone()]);
+        assert_eq!(tx_result.result_status, 0);
+        assert!(state.dct_system_tokens[&token].paused);
+
+        let tx_result = call(
+            &mut state,
+            &OWNER,
+            "getTokenProperties",
+            vec![token.clone()],
+        );
+        assert!(tx_result.result_values.contains(&b"IsPaused-true".to_vec()));
+
+        let tx_result = call(&mut state, &OWNER, "unPause", vec![token.clone()]);
+        assert_eq!(tx_result.result_status, 0);
+        assert!(!state.dct_system_tokens[&token].paused);
+    }
+
+    #[test]
+    fn test_mint_burn() {
+        let mut state = new_state();
+        let token = issue_fungible(&mut state, &["canMint", "canBurn"]);
+
+        let amount = BigUint::from(500u32).to_bytes_be();
+        let tx_result = call(
+            &mut state,
+            &OWNER,
+            "mint",
+            vec![token.clone(), amount, USER.to_vec()],
+        );
+        assert_eq!(tx_result.result_status, 0);
+        assert_eq!(user_balance(&state, &token), BigUint::from(500u32));
+
+        let burn_args = vec![token.clone(), BigUint::from(200u32).to_bytes_be()];
+        let tx_result = call(&mut state, &USER, "DCTBurn", burn_args);
+        assert_eq!(tx_result.result_status, 0);
+        assert_eq!(user_balance(&state, &token), BigUint::from(300u32));
+
+        let burn_args = vec![token.clone(), BigUint::from(1000u32).to_bytes_be()];
+        let tx_result = call(&mut state, &USER, "DCTBurn", burn_args);
+        assert_eq!(tx_result.result_message, "insufficient funds");
+        assert_eq!(user_balance(&state, &token), BigUint::from(300u32));
+    }
+
+    #[test]
+    fn test_transfer_ownership_and_roles() {
+        let mut state = new_state();
+        let token = issue_fungible(&mut state, &["canChangeOwner"]);
+
+        let tx_result = call(
+            &mut state,
+            &OWNER,
+            "setSpecialRole",
+            vec![token.clone(), USER.to_vec(), b"DCTRoleLocalMint".to_vec()],
+        );
+        assert_eq!(tx_result.result_status, 0);
+        assert_eq!(
+            state.accounts[&USER].dct.get_roles(&token),
+            vec![b"DCTRoleLocalMint".to_vec()]
+        );
+
+        let tx_result = call(
+            &mut state,
+            &OWNER,
+            "transferOwnership",
+            vec![token.clone(), USER.to_vec()],
+        );
+        assert_eq!(tx_result.result_status, 0);
+        assert_eq!(state.dct_system_tokens[&token].owner, USER);
+
+        let tx_result = call(
+            &mut state,
+            &OWNER,
+            "unSetSpecialRole",
+            vec![token.clone(), USER.to_vec(), b"DCTRoleLocalMint".to_vec()],
+        );
+        assert_eq!(tx_result.result_message, "can be called by owner only");
+
+        let tx_result = call(
+            &mut state,
+            &USER,
+            "unSetSpecialRole",
+            vec![token.clone(), USER.to_vec(), b"DCTRoleLocalMint".to_vec()],
+        );
+        assert_eq!(tx_result.result_status, 0);
+        assert!(state.accounts[&USER].dct.get_roles(&token).is_empty());
+    }
+}
@@ -0,0 +1,77 @@
+use crate::{
+    tx_mock::{BlockchainUpdate, TxCache, TxInput, TxPanic, TxResult},
+    types::VMAddress,
+    world_mock::DctSystemTokenData,
+};
+
+const ADDRESS_LEN: usize = 32;
+
+/// Converts the outcome of a system SC function into the usual execution result.
+///
+/// Changes are only kept if the function succeeded.
+pub(super) fn system_sc_result(
+    result: Result<TxResult, TxPanic>,
+    tx_cache: TxCache,
+) -> (TxResult, BlockchainUpdate) {
+    match result {
+        Ok(tx_result) => (tx_result, tx_cache.into_blockchain_updates()),
+        Err(tx_panic) => (
+            TxResult::from_panic_obj(&tx_panic),
+            BlockchainUpdate::empty(),
+        ),
+    }
+}
+
+/// Loads the token-level data from the system SC, as long as the token has been issued.
+pub(super) fn load_token(
+    tx_cache: &TxCache,
+    token_identifier: &[u8],
+) -> Result<DctSystemTokenData, TxPanic> {
+    tx_cache
+        .get_dct_system_token(token_identifier)
+        .ok_or_else(|| TxPanic::vm_error("no ticker with given name"))
+}
+
+/// Loads the token-level data and also makes sure the caller is the token owner.
+///
+/// Most system SC token management functions can only be called by the owner.
+pub(super) fn load_owned_token(
+    tx_input: &TxInput,
+    tx_cache: &TxCache,
+    token_identifier: &[u8],
+) -> Result<DctSystemTokenData, TxPanic> {
+    let token_data = load_token(tx_cache, token_identifier)?;
+    if token_data.owner != tx_input.from {
+        return Err(TxPanic::vm_error("can be called by owner only"));
+    }
+    Ok(token_data)
+}
+
+pub(super) fn check_num_args(tx_input: &TxInput, min_num_args: usize) -> Result<(), TxPanic> {
+    if tx_input.args.len() < min_num_args {
+        return Err(TxPanic::vm_error("not enough arguments"));
+    }
+    Ok(())
+}
+
+pub(super) fn check_property(allowed: bool, err_msg: &str) -> Result<(), TxPanic> {
+    if !allowed {
+        return Err(TxPanic::vm_error(err_msg));
+    }
+    Ok(())
+}
+
+/// Addresses given as arguments need to be exactly 32 bytes long.
+pub(super) fn address_arg(arg: &[u8]) -> Result<VMAddress, TxPanic> {
+    if arg.len() != ADDRESS_LEN {
+        return Err(TxPanic::vm_error("invalid address"));
+    }
+    Ok(VMAddress::from_slice(arg))
+}
+
+pub(super) fn check_account_exists(tx_cache: &TxCache, address: &VMAddress) -> Result<(), TxPanic> {
+    if !tx_cache.account_exists(address) {
+        return Err(TxPanic::vm_error("destination address does not exist"));
+    }
+    Ok(())
+}
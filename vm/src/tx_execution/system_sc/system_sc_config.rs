@@ -0,0 +1,41 @@
+use num_bigint::BigUint;
+
+use crate::{
+    tx_mock::{BlockchainUpdate, TxCache, TxInput, TxResult},
+    types::top_encode_u64,
+};
+
+/// Default system SC configuration, same as on mainnet.
+const BASE_ISSUING_COST: u64 = 50_000_000_000_000_000;
+const MIN_TOKEN_NAME_LENGTH: u64 = 3;
+const MAX_TOKEN_NAME_LENGTH: u64 = 20;
+
+/// The system SC has no owner in the Rust VM, so nobody can claim its funds.
+pub fn claim(_tx_input: TxInput, _tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    (
+        TxResult::from_vm_error("claim can be called only by the system SC owner"),
+        BlockchainUpdate::empty(),
+    )
+}
+
+/// The system SC has no owner in the Rust VM, so its configuration cannot be changed.
+pub fn config_change(_tx_input: TxInput, _tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    (
+        TxResult::from_vm_error("configChange can be called only by the system SC owner"),
+        BlockchainUpdate::empty(),
+    )
+}
+
+/// View function, returns: owner, base issuing cost, min token name length, max token name length.
+pub fn get_contract_config(_tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    let tx_result = TxResult {
+        result_values: vec![
+            vec![0u8; 32],
+            BigUint::from(BASE_ISSUING_COST).to_bytes_be(),
+            top_encode_u64(MIN_TOKEN_NAME_LENGTH),
+            top_encode_u64(MAX_TOKEN_NAME_LENGTH),
+        ],
+        ..Default::default()
+    };
+    (tx_result, tx_cache.into_blockchain_updates())
+}
@@ -0,0 +1,176 @@
+use crate::{
+    tx_mock::{BlockchainUpdate, TxCache, TxInput, TxPanic, TxResult},
+    types::{top_decode_u64, VMTokenType},
+};
+
+use super::system_sc_common::*;
+
+/// Freezes the balance of a fungible, semi-fungible or meta token for an account.
+pub fn freeze(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    let result = toggle_freeze(&tx_input, &tx_cache, true);
+    system_sc_result(result, tx_cache)
+}
+
+/// The reverse operation of `freeze`.
+pub fn unfreeze(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    let result = toggle_freeze(&tx_input, &tx_cache, false);
+    system_sc_result(result, tx_cache)
+}
+
+/// Wipes out the balance of a frozen account.
+pub fn wipe(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    let result = wipe_account(&tx_input, &tx_cache);
+    system_sc_result(result, tx_cache)
+}
+
+/// Freezes a single NFT instance held by an account.
+pub fn freeze_single_nft(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    let result = toggle_freeze_single_nft(&tx_input, &tx_cache, true);
+    system_sc_result(result, tx_cache)
+}
+
+/// The reverse operation of `freezeSingleNFT`.
+pub fn unfreeze_single_nft(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    let result = toggle_freeze_single_nft(&tx_input, &tx_cache, false);
+    system_sc_result(result, tx_cache)
+}
+
+/// Wipes out a single frozen NFT instance held by an account.
+pub fn wipe_single_nft(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    let result = wipe_account_single_nft(&tx_input, &tx_cache);
+    system_sc_result(result, tx_cache)
+}
+
+fn toggle_freeze(
+    tx_input: &TxInput,
+    tx_cache: &TxCache,
+    frozen: bool,
+) -> Result<TxResult, TxPanic> {
+    check_num_args(tx_input, 2)?;
+    let token_identifier = tx_input.args[0].as_slice();
+    let address = address_arg(tx_input.args[1].as_slice())?;
+
+    let token_data = load_owned_token(tx_input, tx_cache, token_identifier)?;
+    check_property(token_data.properties.can_freeze, "cannot freeze")?;
+    if token_data.token_type == VMTokenType::NonFungible {
+        return Err(TxPanic::vm_error(
+            "only fungible tokens can be frozen per account, use freezeSingleNFT",
+        ));
+    }
+    check_account_exists(tx_cache, &address)?;
+
+    tx_cache.with_account_mut(&address, |account| {
+        account.dct.set_frozen(token_identifier, frozen);
+    });
+
+    Ok(TxResult::empty())
+}
+
+fn wipe_account(tx_input: &TxInput, tx_cache: &TxCache) -> Result<TxResult, TxPanic> {
+    check_num_args(tx_input, 2)?;
+    let token_identifier = tx_input.args[0].as_slice();
+    let address = address_arg(tx_input.args[1].as_slice())?;
+
+    let mut token_data = load_owned_token(tx_input, tx_cache, token_identifier)?;
+    check_property(token_data.properties.can_wipe, "cannot wipe")?;
+    if token_data.token_type == VMTokenType::NonFungible {
+        return Err(TxPanic::vm_error(
+            "only fungible tokens can be wiped per account, use wipeSingleNFT",
+        ));
+    }
+    check_account_exists(tx_cache, &address)?;
+
+    let frozen = tx_cache.with_account(&address, |account| {
+        account
+            .dct
+            .get_by_identifier(token_identifier)
+            .map(|dct_data| dct_data.frozen)
+            .unwrap_or_default()
+    });
+    if !frozen {
+        return Err(TxPanic::vm_error(
+            "cannot wipe because the account is not frozen for this dct token",
+        ));
+    }
+
+    tx_cache.with_account_mut(&address, |account| {
+        account.dct.wipe(token_identifier);
+    });
+    token_data.num_wiped += 1;
+    tx_cache.set_dct_system_token(token_identifier, token_data);
+
+    Ok(TxResult::empty())
+}
+
+fn toggle_freeze_single_nft(
+    tx_input: &TxInput,
+    tx_cache: &TxCache,
+    frozen: bool,
+) -> Result<TxResult, TxPanic> {
+    check_num_args(tx_input, 3)?;
+    let token_identifier = tx_input.args[0].as_slice();
+    let nonce = top_decode_u64(tx_input.args[1].as_slice());
+    let address = address_arg(tx_input.args[2].as_slice())?;
+
+    let token_data = load_owned_token(tx_input, tx_cache, token_identifier)?;
+    check_property(token_data.properties.can_freeze, "cannot freeze")?;
+    check_not_fungible(token_data.token_type)?;
+    check_account_exists(tx_cache, &address)?;
+
+    let found = tx_cache.with_account_mut(&address, |account| {
+        account
+            .dct
+            .set_instance_frozen(token_identifier, nonce, frozen)
+    });
+    if !found {
+        return Err(TxPanic::vm_error("no nft with given nonce"));
+    }
+
+    Ok(TxResult::empty())
+}
+
+fn wipe_account_single_nft(tx_input: &TxInput, tx_cache: &TxCache) -> Result<TxResult, TxPanic> {
+    check_num_args(tx_input, 3)?;
+    let token_identifier = tx_input.args[0].as_slice();
+    let nonce = top_decode_u64(tx_input.args[1].as_slice());
+    let address = address_arg(tx_input.args[2].as_slice())?;
+
+    let mut token_data = load_owned_token(tx_input, tx_cache, token_identifier)?;
+    check_property(token_data.properties.can_wipe, "cannot wipe")?;
+    check_not_fungible(token_data.token_type)?;
+    check_account_exists(tx_cache, &address)?;
+
+    let instance_frozen = tx_cache.with_account(&address, |account| {
+        account
+            .dct
+            .get_by_identifier(token_identifier)
+            .and_then(|dct_data| dct_data.instances.get_by_nonce(nonce))
+            .map(|instance| instance.frozen)
+    });
+    match instance_frozen {
+        None => return Err(TxPanic::vm_error("no nft with given nonce")),
+        Some(false) => {
+            return Err(TxPanic::vm_error(
+                "cannot wipe because the nft is not frozen",
+            ))
+        },
+        Some(true) => {},
+    }
+
+    tx_cache.with_account_mut(&address, |account| {
+        account.dct.wipe_instance(token_identifier, nonce);
+    });
+    token_data.num_wiped += 1;
+    tx_cache.set_dct_system_token(token_identifier, token_data);
+
+    Ok(TxResult::empty())
+}
+
+fn check_not_fungible(token_type: VMTokenType) -> Result<(), TxPanic> {
+    if token_type == VMTokenType::Fungible {
+        return Err(TxPanic::vm_error(
+            "only non fungible tokens can be frozen or wiped per nonce",
+        ));
+    }
+    Ok(())
+}
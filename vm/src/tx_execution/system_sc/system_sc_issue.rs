@@ -1,13 +1,14 @@
 use num_bigint::BigUint;
+use num_traits::Zero;
 
 use crate::{
     crypto_functions::keccak256,
     tx_mock::{BlockchainUpdate, TxCache, TxInput, TxResult},
     types::{top_decode_u64, VMTokenType},
+    world_mock::{DctInstanceMetadata, DctSystemTokenData},
 };
 
-/// Issues a new fungible token.
-#[allow(unused_variables)]
+/// Issues a new fungible token, crediting the total supply to the issuer.
 pub fn issue(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
     if tx_input.args.len() < 4 {
         let tx_result = TxResult::from_vm_error("not enough arguments");
@@ -17,12 +18,25 @@ pub fn issue(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdat
     let ticker = tx_input.args[1].clone();
     let total_supply = BigUint::from_bytes_be(tx_input.args[2].clone().as_ref());
     let decimals = top_decode_u64(tx_input.args[3].clone().as_ref()) as u32;
+    let property_args = tx_input.args[4..].to_vec();
 
-    register_and_set_roles(tx_input, tx_cache, ticker, VMTokenType::Fungible)
+    let token_data = DctSystemTokenData::new(
+        tx_input.from.clone(),
+        name,
+        ticker,
+        VMTokenType::Fungible,
+        decimals,
+    );
+    register_and_set_roles(
+        tx_input,
+        tx_cache,
+        token_data,
+        &total_supply,
+        &property_args,
+    )
 }
 
 /// Issues a new semi-fungible token.
-#[allow(unused_variables)]
 pub fn issue_semi_fungible(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
     if tx_input.args.len() < 2 {
         let tx_result = TxResult::from_vm_error("not enough arguments");
@@ -30,12 +44,25 @@ pub fn issue_semi_fungible(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, B
     }
     let name = tx_input.args[0].clone();
     let ticker = tx_input.args[1].clone();
+    let property_args = tx_input.args[2..].to_vec();
 
-    register_and_set_roles(tx_input, tx_cache, ticker, VMTokenType::SemiFungible)
+    let token_data = DctSystemTokenData::new(
+        tx_input.from.clone(),
+        name,
+        ticker,
+        VMTokenType::SemiFungible,
+        0,
+    );
+    register_and_set_roles(
+        tx_input,
+        tx_cache,
+        token_data,
+        &BigUint::zero(),
+        &property_args,
+    )
 }
 
 /// Issues a new non-fungible token.
-#[allow(unused_variables)]
 pub fn issue_non_fungible(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
     if tx_input.args.len() < 2 {
         let tx_result = TxResult::from_vm_error("not enough arguments");
@@ -43,12 +70,52 @@ pub fn issue_non_fungible(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, Bl
     }
     let name = tx_input.args[0].clone();
     let ticker = tx_input.args[1].clone();
+    let property_args = tx_input.args[2..].to_vec();
 
-    register_and_set_roles(tx_input, tx_cache, ticker, VMTokenType::NonFungible)
+    let token_data = DctSystemTokenData::new(
+        tx_input.from.clone(),
+        name,
+        ticker,
+        VMTokenType::NonFungible,
+        0,
+    );
+    register_and_set_roles(
+        tx_input,
+        tx_cache,
+        token_data,
+        &BigUint::zero(),
+        &property_args,
+    )
+}
+
+/// Registers a new meta-DCT token.
+pub fn register_meta_dct(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    if tx_input.args.len() < 3 {
+        let tx_result = TxResult::from_vm_error("not enough arguments");
+        return (tx_result, BlockchainUpdate::empty());
+    }
+    let name = tx_input.args[0].clone();
+    let ticker = tx_input.args[1].clone();
+    let decimals = top_decode_u64(tx_input.args[2].clone().as_ref()) as u32;
+    let property_args = tx_input.args[3..].to_vec();
+
+    let token_data = DctSystemTokenData::new(
+        tx_input.from.clone(),
+        name,
+        ticker,
+        VMTokenType::Meta,
+        decimals,
+    );
+    register_and_set_roles(
+        tx_input,
+        tx_cache,
+        token_data,
+        &BigUint::zero(),
+        &property_args,
+    )
 }
 
 // Issues a new token and sets all roles for its type.
-#[allow(unused_variables)]
 pub fn register_and_set_all_roles(
     tx_input: TxInput,
     tx_cache: TxCache,
@@ -63,15 +130,24 @@ pub fn register_and_set_all_roles(
     let token_type = VMTokenType::from_system_sc_arg(&tx_input.args[2]);
     let decimals = top_decode_u64(tx_input.args[3].clone().as_ref()) as u32;
 
-    register_and_set_roles(tx_input, tx_cache, ticker, token_type)
+    let token_data =
+        DctSystemTokenData::new(tx_input.from.clone(), name, ticker, token_type, decimals);
+    register_and_set_roles(tx_input, tx_cache, token_data, &BigUint::zero(), &[])
 }
 
 fn register_and_set_roles(
     tx_input: TxInput,
     tx_cache: TxCache,
-    ticker: Vec<u8>,
-    token_type: VMTokenType,
+    mut token_data: DctSystemTokenData,
+    initial_supply: &BigUint,
+    property_args: &[Vec<u8>],
 ) -> (TxResult, BlockchainUpdate) {
+    if let Err(err_msg) = token_data.properties.apply_raw_args(property_args) {
+        return (TxResult::from_vm_error(err_msg), BlockchainUpdate::empty());
+    }
+
+    let ticker = token_data.ticker.clone();
+    let token_type = token_data.token_type;
     let mut new_token_identifiers = tx_cache.get_new_token_identifiers();
 
     let token_identifier = if let Some((i, ti)) =
@@ -89,6 +165,16 @@ fn register_and_set_roles(
             .register_and_set_roles(&token_identifier, token_type);
     });
     tx_cache.set_new_token_identifiers(new_token_identifiers);
+    tx_cache.set_dct_system_token(&token_identifier, token_data);
+    if !initial_supply.is_zero() {
+        tx_cache.increase_dct_balance(
+            &tx_input.from,
+            &token_identifier,
+            0,
+            initial_supply,
+            DctInstanceMetadata::default(),
+        );
+    }
 
     let tx_result = TxResult {
         result_values: vec![token_identifier],
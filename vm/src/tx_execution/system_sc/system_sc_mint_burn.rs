@@ -0,0 +1,92 @@
+use num_bigint::BigUint;
+use num_traits::Zero;
+
+use crate::{
+    tx_mock::{BlockchainUpdate, TxCache, TxInput, TxPanic, TxResult, TxTokenTransfer},
+    types::VMTokenType,
+    world_mock::DctInstanceMetadata,
+};
+
+use super::system_sc_common::*;
+
+/// Mints more fungible tokens, either to the owner, or to a given destination.
+pub fn mint(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    let result = mint_tokens(&tx_input, &tx_cache);
+    system_sc_result(result, tx_cache)
+}
+
+/// Burns fungible tokens held by the caller.
+///
+/// The tokens can either be sent to the system SC (`DCTTransfer@...@DCTBurn`),
+/// or given as token identifier and amount arguments.
+pub fn dct_burn(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    let result = burn_tokens(&tx_input, &tx_cache);
+    system_sc_result(result, tx_cache)
+}
+
+fn mint_tokens(tx_input: &TxInput, tx_cache: &TxCache) -> Result<TxResult, TxPanic> {
+    check_num_args(tx_input, 2)?;
+    let token_identifier = tx_input.args[0].as_slice();
+    let amount = BigUint::from_bytes_be(tx_input.args[1].as_slice());
+    let destination = if let Some(raw_destination) = tx_input.args.get(2) {
+        address_arg(raw_destination.as_slice())?
+    } else {
+        tx_input.from.clone()
+    };
+
+    let token_data = load_owned_token(tx_input, tx_cache, token_identifier)?;
+    if token_data.token_type != VMTokenType::Fungible {
+        return Err(TxPanic::vm_error("mint possible only for fungible tokens"));
+    }
+    check_property(token_data.properties.can_mint, "token is not mintable")?;
+    if amount.is_zero() {
+        return Err(TxPanic::vm_error("negative or zero mint value"));
+    }
+    check_account_exists(tx_cache, &destination)?;
+
+    tx_cache.increase_dct_balance(
+        &destination,
+        token_identifier,
+        0,
+        &amount,
+        DctInstanceMetadata::default(),
+    );
+
+    Ok(TxResult::empty())
+}
+
+fn burn_tokens(tx_input: &TxInput, tx_cache: &TxCache) -> Result<TxResult, TxPanic> {
+    let transfers = if tx_input.dct_values.is_empty() {
+        check_num_args(tx_input, 2)?;
+        vec![TxTokenTransfer {
+            token_identifier: tx_input.args[0].clone(),
+            nonce: 0,
+            value: BigUint::from_bytes_be(tx_input.args[1].as_slice()),
+        }]
+    } else {
+        tx_input.dct_values.clone()
+    };
+
+    for transfer in &transfers {
+        let token_data = load_token(tx_cache, &transfer.token_identifier)?;
+        if token_data.token_type != VMTokenType::Fungible {
+            return Err(TxPanic::vm_error("burn possible only for fungible tokens"));
+        }
+        check_property(
+            token_data.properties.can_burn || token_data.burn_role_globally,
+            "token is not burnable",
+        )?;
+        if transfer.value.is_zero() {
+            return Err(TxPanic::vm_error("negative or zero burn value"));
+        }
+
+        tx_cache.subtract_dct_balance(
+            &tx_input.from,
+            &transfer.token_identifier,
+            0,
+            &transfer.value,
+        )?;
+    }
+
+    Ok(TxResult::empty())
+}
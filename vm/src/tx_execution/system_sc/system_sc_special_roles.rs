@@ -1,8 +1,13 @@
 use crate::{
-    tx_mock::{BlockchainUpdate, TxCache, TxInput, TxResult},
-    types::VMAddress,
+    display_util::address_bech32,
+    tx_mock::{BlockchainUpdate, TxCache, TxInput, TxPanic, TxResult},
+    types::{VMAddress, VMTokenType},
 };
 
+use super::system_sc_common::*;
+
+const DCT_ROLE_NFT_CREATE: &[u8] = b"DCTRoleNFTCreate";
+
 pub fn set_special_role(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
     if tx_input.args.len() < 3 {
         return (
@@ -12,12 +17,239 @@ pub fn set_special_role(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, Bloc
     }
 
     let token_identifier = tx_input.args[0].clone();
-    let address = VMAddress::from_slice(tx_input.args[1].as_slice());
-    let role = tx_input.args[2].clone();
+    let address = match address_arg(tx_input.args[1].as_slice()) {
+        Ok(address) => address,
+        Err(tx_panic) => {
+            return (
+                TxResult::from_panic_obj(&tx_panic),
+                BlockchainUpdate::empty(),
+            )
+        },
+    };
+    if let Err(tx_panic) = check_special_roles_allowed(&tx_input, &tx_cache, &token_identifier) {
+        return (
+            TxResult::from_panic_obj(&tx_panic),
+            BlockchainUpdate::empty(),
+        );
+    }
 
     tx_cache.with_account_mut(&address, |account| {
-        account.dct.set_special_role(&token_identifier, &role);
+        for role in &tx_input.args[2..] {
+            account.dct.set_special_role(&token_identifier, role);
+        }
     });
 
     (TxResult::empty(), tx_cache.into_blockchain_updates())
 }
+
+pub fn unset_special_role(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    if tx_input.args.len() < 3 {
+        return (
+            TxResult::from_vm_error("unSetSpecialRole too few arguments"),
+            BlockchainUpdate::empty(),
+        );
+    }
+
+    let token_identifier = tx_input.args[0].clone();
+    let address = match address_arg(tx_input.args[1].as_slice()) {
+        Ok(address) => address,
+        Err(tx_panic) => {
+            return (
+                TxResult::from_panic_obj(&tx_panic),
+                BlockchainUpdate::empty(),
+            )
+        },
+    };
+    if let Err(tx_panic) = check_special_roles_allowed(&tx_input, &tx_cache, &token_identifier) {
+        return (
+            TxResult::from_panic_obj(&tx_panic),
+            BlockchainUpdate::empty(),
+        );
+    }
+
+    tx_cache.with_account_mut(&address, |account| {
+        for role in &tx_input.args[2..] {
+            account.dct.unset_special_role(&token_identifier, role);
+        }
+    });
+
+    (TxResult::empty(), tx_cache.into_blockchain_updates())
+}
+
+/// Moves the NFT create role from one address to another.
+pub fn transfer_nft_create_role(
+    tx_input: TxInput,
+    tx_cache: TxCache,
+) -> (TxResult, BlockchainUpdate) {
+    let result = move_nft_create_role(&tx_input, &tx_cache);
+    system_sc_result(result, tx_cache)
+}
+
+/// Removes the NFT create role from everyone. No NFT can be created afterwards.
+pub fn stop_nft_create(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    let result = stop_nft_create_for_all(&tx_input, &tx_cache);
+    system_sc_result(result, tx_cache)
+}
+
+/// View function, lists all addresses that have roles for the token, as `bech32:role1,role2`.
+pub fn get_special_roles(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    let result = check_num_args(&tx_input, 1)
+        .and_then(|_| load_token(&tx_cache, &tx_input.args[0]))
+        .map(|_| {
+            let result_values = addresses_with_roles(&tx_cache, &tx_input.args[0])
+                .into_iter()
+                .map(|(address, roles)| {
+                    let role_names: Vec<String> = roles
+                        .iter()
+                        .map(|role| String::from_utf8_lossy(role).into_owned())
+                        .collect();
+                    format!("{}:{}", address_bech32(&address), role_names.join(",")).into_bytes()
+                })
+                .collect();
+            TxResult {
+                result_values,
+                ..Default::default()
+            }
+        });
+    system_sc_result(result, tx_cache)
+}
+
+/// View function, lists all addresses that have roles for the token,
+/// each address followed by its roles.
+pub fn get_all_addresses_and_roles(
+    tx_input: TxInput,
+    tx_cache: TxCache,
+) -> (TxResult, BlockchainUpdate) {
+    let result = check_num_args(&tx_input, 1)
+        .and_then(|_| load_token(&tx_cache, &tx_input.args[0]))
+        .map(|_| {
+            let mut result_values = Vec::new();
+            for (address, roles) in addresses_with_roles(&tx_cache, &tx_input.args[0]) {
+                result_values.push(address.to_vec());
+                result_values.extend(roles);
+            }
+            TxResult {
+                result_values,
+                ..Default::default()
+            }
+        });
+    system_sc_result(result, tx_cache)
+}
+
+/// Broadcasts the addresses with transfer role to all shards.
+///
+/// There is only one shard in the Rust VM, so it only checks ownership.
+pub fn send_all_transfer_role_addresses(
+    tx_input: TxInput,
+    tx_cache: TxCache,
+) -> (TxResult, BlockchainUpdate) {
+    let result = check_num_args(&tx_input, 1)
+        .and_then(|_| load_owned_token(&tx_input, &tx_cache, &tx_input.args[0]))
+        .map(|_| TxResult::empty());
+    system_sc_result(result, tx_cache)
+}
+
+/// Tokens that were set up directly in the state, rather than issued, are unknown to the system SC.
+/// For these, roles are set without any checks, as before.
+fn check_special_roles_allowed(
+    tx_input: &TxInput,
+    tx_cache: &TxCache,
+    token_identifier: &[u8],
+) -> Result<(), TxPanic> {
+    if tx_cache.get_dct_system_token(token_identifier).is_none() {
+        return Ok(());
+    }
+    let token_data = load_owned_token(tx_input, tx_cache, token_identifier)?;
+    check_property(
+        token_data.properties.can_add_special_roles,
+        "cannot add special roles",
+    )
+}
+
+fn move_nft_create_role(tx_input: &TxInput, tx_cache: &TxCache) -> Result<TxResult, TxPanic> {
+    check_num_args(tx_input, 3)?;
+    let token_identifier = tx_input.args[0].as_slice();
+    let old_creator = address_arg(tx_input.args[1].as_slice())?;
+    let new_creator = address_arg(tx_input.args[2].as_slice())?;
+
+    let token_data = load_owned_token(tx_input, tx_cache, token_identifier)?;
+    if token_data.token_type == VMTokenType::Fungible {
+        return Err(TxPanic::vm_error(
+            "NFT create role is not available for fungible tokens",
+        ));
+    }
+    check_property(
+        token_data.properties.can_transfer_nft_create_role,
+        "NFT create role transfer is not allowed",
+    )?;
+    check_account_exists(tx_cache, &old_creator)?;
+    check_account_exists(tx_cache, &new_creator)?;
+
+    let has_role = tx_cache.with_account(&old_creator, |account| {
+        account
+            .dct
+            .get_by_identifier(token_identifier)
+            .map(|dct_data| dct_data.roles.contains(DCT_ROLE_NFT_CREATE))
+            .unwrap_or_default()
+    });
+    if !has_role {
+        return Err(TxPanic::vm_error(
+            "old address does not have the NFT create role",
+        ));
+    }
+
+    tx_cache.with_account_mut(&old_creator, |account| {
+        account
+            .dct
+            .unset_special_role(token_identifier, DCT_ROLE_NFT_CREATE);
+    });
+    tx_cache.with_account_mut(&new_creator, |account| {
+        account
+            .dct
+            .set_special_role(token_identifier, DCT_ROLE_NFT_CREATE);
+    });
+
+    Ok(TxResult::empty())
+}
+
+fn stop_nft_create_for_all(tx_input: &TxInput, tx_cache: &TxCache) -> Result<TxResult, TxPanic> {
+    check_num_args(tx_input, 1)?;
+    let token_identifier = tx_input.args[0].as_slice();
+
+    let mut token_data = load_owned_token(tx_input, tx_cache, token_identifier)?;
+    if token_data.nft_create_stopped {
+        return Err(TxPanic::vm_error("NFT create was already stopped"));
+    }
+
+    for (address, _) in addresses_with_roles(tx_cache, token_identifier) {
+        tx_cache.with_account_mut(&address, |account| {
+            account
+                .dct
+                .unset_special_role(token_identifier, DCT_ROLE_NFT_CREATE);
+        });
+    }
+    token_data.nft_create_stopped = true;
+    tx_cache.set_dct_system_token(token_identifier, token_data);
+
+    Ok(TxResult::empty())
+}
+
+/// All known addresses that have at least one role for the given token, sorted by address.
+fn addresses_with_roles(
+    tx_cache: &TxCache,
+    token_identifier: &[u8],
+) -> Vec<(VMAddress, Vec<Vec<u8>>)> {
+    tx_cache
+        .all_known_addresses()
+        .into_iter()
+        .filter_map(|address| {
+            let roles =
+                tx_cache.with_account(&address, |account| account.dct.get_roles(token_identifier));
+            if roles.is_empty() {
+                None
+            } else {
+                Some((address, roles))
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,209 @@
+use crate::{
+    tx_mock::{BlockchainUpdate, TxCache, TxInput, TxPanic, TxResult},
+    types::{top_decode_u64, VMTokenType},
+    world_mock::DctSystemTokenData,
+};
+
+use super::system_sc_common::*;
+
+/// Suspends all transfers of a token.
+pub fn pause(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    let result = toggle_pause(&tx_input, &tx_cache, true);
+    system_sc_result(result, tx_cache)
+}
+
+/// The reverse operation of `pause`.
+pub fn unpause(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    let result = toggle_pause(&tx_input, &tx_cache, false);
+    system_sc_result(result, tx_cache)
+}
+
+/// Changes token properties, only allowed for upgradable tokens.
+pub fn control_changes(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    let result = change_properties(&tx_input, &tx_cache);
+    system_sc_result(result, tx_cache)
+}
+
+/// Hands the token management over to another address.
+pub fn transfer_ownership(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    let result = change_owner(&tx_input, &tx_cache);
+    system_sc_result(result, tx_cache)
+}
+
+/// Converts a semi-fungible token to a meta-DCT, by giving it a number of decimals.
+pub fn change_sft_to_meta_dct(
+    tx_input: TxInput,
+    tx_cache: TxCache,
+) -> (TxResult, BlockchainUpdate) {
+    let result = change_to_meta(&tx_input, &tx_cache);
+    system_sc_result(result, tx_cache)
+}
+
+/// Allows the NFT create role to be set on addresses in multiple shards.
+pub fn change_to_multi_shard_create(
+    tx_input: TxInput,
+    tx_cache: TxCache,
+) -> (TxResult, BlockchainUpdate) {
+    let result = update_owned_token(&tx_input, &tx_cache, |token_data| {
+        if token_data.properties.can_create_multi_shard {
+            return Err(TxPanic::vm_error("it is already multi shard create"));
+        }
+        check_property(
+            token_data.properties.can_add_special_roles,
+            "cannot add special roles",
+        )?;
+        token_data.properties.can_create_multi_shard = true;
+        Ok(())
+    });
+    system_sc_result(result, tx_cache)
+}
+
+/// Allows anyone holding the token to burn it.
+pub fn set_burn_role_globally(
+    tx_input: TxInput,
+    tx_cache: TxCache,
+) -> (TxResult, BlockchainUpdate) {
+    let result = update_owned_token(&tx_input, &tx_cache, |token_data| {
+        if token_data.burn_role_globally {
+            return Err(TxPanic::vm_error("burn role is already set globally"));
+        }
+        token_data.burn_role_globally = true;
+        Ok(())
+    });
+    system_sc_result(result, tx_cache)
+}
+
+/// The reverse operation of `setBurnRoleGlobally`.
+pub fn unset_burn_role_globally(
+    tx_input: TxInput,
+    tx_cache: TxCache,
+) -> (TxResult, BlockchainUpdate) {
+    let result = update_owned_token(&tx_input, &tx_cache, |token_data| {
+        if !token_data.burn_role_globally {
+            return Err(TxPanic::vm_error("burn role is not set globally"));
+        }
+        token_data.burn_role_globally = false;
+        Ok(())
+    });
+    system_sc_result(result, tx_cache)
+}
+
+/// View function, returns the token properties in the same format as the system SC.
+pub fn get_token_properties(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    let result = check_num_args(&tx_input, 1)
+        .and_then(|_| load_token(&tx_cache, tx_input.args[0].as_slice()))
+        .map(|token_data| TxResult {
+            result_values: token_properties_result_values(&token_data),
+            ..Default::default()
+        });
+    system_sc_result(result, tx_cache)
+}
+
+fn toggle_pause(tx_input: &TxInput, tx_cache: &TxCache, paused: bool) -> Result<TxResult, TxPanic> {
+    update_owned_token(tx_input, tx_cache, |token_data| {
+        check_property(token_data.properties.can_pause, "cannot pause/un-pause")?;
+        if token_data.paused == paused {
+            let err_msg = if paused {
+                "cannot pause an already paused token"
+            } else {
+                "cannot unPause an already un-paused token"
+            };
+            return Err(TxPanic::vm_error(err_msg));
+        }
+        token_data.paused = paused;
+        Ok(())
+    })
+}
+
+fn change_properties(tx_input: &TxInput, tx_cache: &TxCache) -> Result<TxResult, TxPanic> {
+    let property_args = tx_input.args.get(1..).unwrap_or_default().to_vec();
+    update_owned_token(tx_input, tx_cache, |token_data| {
+        check_property(token_data.properties.can_upgrade, "token is not upgradable")?;
+        token_data
+            .properties
+            .apply_raw_args(&property_args)
+            .map_err(TxPanic::vm_error)
+    })
+}
+
+fn change_owner(tx_input: &TxInput, tx_cache: &TxCache) -> Result<TxResult, TxPanic> {
+    check_num_args(tx_input, 2)?;
+    let new_owner = address_arg(tx_input.args[1].as_slice())?;
+    update_owned_token(tx_input, tx_cache, |token_data| {
+        check_property(
+            token_data.properties.can_change_owner,
+            "cannot change owner of the token",
+        )?;
+        token_data.owner = new_owner;
+        Ok(())
+    })
+}
+
+fn change_to_meta(tx_input: &TxInput, tx_cache: &TxCache) -> Result<TxResult, TxPanic> {
+    check_num_args(tx_input, 2)?;
+    let num_decimals = top_decode_u64(tx_input.args[1].as_slice()) as u32;
+    update_owned_token(tx_input, tx_cache, |token_data| {
+        if token_data.token_type != VMTokenType::SemiFungible {
+            return Err(TxPanic::vm_error(
+                "change can happen to semi fungible tokens only",
+            ));
+        }
+        token_data.token_type = VMTokenType::Meta;
+        token_data.num_decimals = num_decimals;
+        Ok(())
+    })
+}
+
+/// Loads a token owned by the caller (first argument), applies the change and saves it.
+fn update_owned_token<F>(tx_input: &TxInput, tx_cache: &TxCache, f: F) -> Result<TxResult, TxPanic>
+where
+    F: FnOnce(&mut DctSystemTokenData) -> Result<(), TxPanic>,
+{
+    check_num_args(tx_input, 1)?;
+    let token_identifier = tx_input.args[0].as_slice();
+    let mut token_data = load_owned_token(tx_input, tx_cache, token_identifier)?;
+    f(&mut token_data)?;
+    tx_cache.set_dct_system_token(token_identifier, token_data);
+    Ok(TxResult::empty())
+}
+
+fn token_properties_result_values(token_data: &DctSystemTokenData) -> Vec<Vec<u8>> {
+    let properties = &token_data.properties;
+    let flags = [
+        ("IsPaused", token_data.paused),
+        ("CanUpgrade", properties.can_upgrade),
+        ("CanMint", properties.can_mint),
+        ("CanBurn", properties.can_burn),
+        ("CanChangeOwner", properties.can_change_owner),
+        ("CanPause", properties.can_pause),
+        ("CanFreeze", properties.can_freeze),
+        ("CanWipe", properties.can_wipe),
+        ("CanAddSpecialRoles", properties.can_add_special_roles),
+        (
+            "CanTransferNFTCreateRole",
+            properties.can_transfer_nft_create_role,
+        ),
+        ("NFTCreateStopped", token_data.nft_create_stopped),
+    ];
+
+    let mut result = vec![
+        token_data.name.clone(),
+        token_type_name(token_data.token_type).as_bytes().to_vec(),
+        token_data.owner.to_vec(),
+        format!("NumDecimals-{}", token_data.num_decimals).into_bytes(),
+    ];
+    for (name, value) in flags {
+        result.push(format!("{name}-{value}").into_bytes());
+    }
+    result.push(format!("NumWiped-{}", token_data.num_wiped).into_bytes());
+    result
+}
+
+fn token_type_name(token_type: VMTokenType) -> &'static str {
+    match token_type {
+        VMTokenType::Fungible => "FungibleDCT",
+        VMTokenType::SemiFungible => "SemiFungibleDCT",
+        VMTokenType::Meta => "MetaDCT",
+        VMTokenType::NonFungible => "NonFungibleDCT",
+    }
+}
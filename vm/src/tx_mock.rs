@@ -10,6 +10,7 @@ mod tx_cache_source;
 mod tx_context;
 mod tx_context_ref;
 mod tx_context_stack;
+mod tx_cross_shard_call;
 mod tx_input;
 mod tx_input_function;
 mod tx_log;
@@ -17,6 +18,7 @@ mod tx_managed_types;
 mod tx_panic;
 mod tx_result;
 mod tx_result_calls;
+mod tx_trace;
 
 pub use blockchain_rng::*;
 pub use blockchain_update::BlockchainUpdate;
@@ -27,6 +29,7 @@ pub use tx_cache_source::*;
 pub use tx_context::*;
 pub use tx_context_ref::*;
 pub use tx_context_stack::*;
+pub use tx_cross_shard_call::*;
 pub use tx_input::*;
 pub use tx_input_function::*;
 pub use tx_log::*;
@@ -34,3 +37,4 @@ pub use tx_managed_types::*;
 pub use tx_panic::*;
 pub use tx_result::*;
 pub use tx_result_calls::*;
+pub use tx_trace::*;
@@ -2,13 +2,14 @@ use std::collections::HashMap;
 
 use crate::{
     types::VMAddress,
-    world_mock::{AccountData, BlockchainState},
+    world_mock::{AccountData, BlockchainState, DctSystemTokenData},
 };
 
 #[derive(Default)]
 pub struct BlockchainUpdate {
     pub accounts: HashMap<VMAddress, AccountData>,
     pub new_token_identifiers: Option<Vec<String>>,
+    pub dct_system_tokens: HashMap<Vec<u8>, DctSystemTokenData>,
 }
 
 impl BlockchainUpdate {
@@ -18,6 +19,7 @@ impl BlockchainUpdate {
 
     pub fn apply(self, blockchain: &mut BlockchainState) {
         blockchain.update_accounts(self.accounts);
+        blockchain.update_dct_system_tokens(self.dct_system_tokens);
 
         if let Some(token_identifiers) = self.new_token_identifiers {
             blockchain.update_new_token_identifiers(token_identifiers);
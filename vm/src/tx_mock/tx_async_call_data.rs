@@ -15,6 +15,7 @@ pub struct AsyncCallTxData {
     pub call_value: BigUint,
     pub endpoint_name: TxFunctionName,
     pub arguments: Vec<Vec<u8>>,
+    pub gas_limit: u64,
     pub tx_hash: H256,
 }
 
@@ -26,7 +27,7 @@ pub fn async_call_tx_input(async_call: &AsyncCallTxData) -> TxInput {
         dct_values: Vec::new(),
         func_name: async_call.endpoint_name.clone(),
         args: async_call.arguments.clone(),
-        gas_limit: 1000,
+        gas_limit: async_call.gas_limit,
         gas_price: 0,
         tx_hash: async_call.tx_hash.clone(),
         ..Default::default()
@@ -41,9 +42,11 @@ fn result_status_bytes(result_status: u64) -> Vec<u8> {
     }
 }
 
+/// The callback gets the gas reserved for it, plus whatever gas the async call did not use.
 pub fn async_callback_tx_input(
     async_data: &AsyncCallTxData,
     async_result: &TxResult,
+    callback_gas_lock: u64,
     builtin_functions: &BuiltinFunctionContainer,
 ) -> TxInput {
     let mut args: Vec<Vec<u8>> = vec![result_status_bytes(async_result.result_status)];
@@ -61,7 +64,7 @@ pub fn async_callback_tx_input(
         dct_values: Vec::new(),
         func_name: TxFunctionName::CALLBACK,
         args,
-        gas_limit: 1000,
+        gas_limit: callback_gas_lock + async_data.gas_limit.saturating_sub(async_result.gas_used),
         gas_price: 0,
         tx_hash: async_data.tx_hash.clone(),
         callback_payments,
@@ -113,7 +116,9 @@ pub fn async_promise_tx_input(
         dct_values: Vec::new(),
         func_name: callback_name,
         args,
-        gas_limit: 1000,
+        gas_limit: promise
+            .extra_gas_for_callback
+            .saturating_add(promise.call.gas_limit.saturating_sub(async_result.gas_used)),
         gas_price: 0,
         tx_hash: promise.call.tx_hash.clone(),
         promise_callback_closure_data: promise.callback_closure_data.clone(),
@@ -126,8 +131,12 @@ pub fn merge_results(mut original: TxResult, mut new: TxResult) -> TxResult {
         original.result_values.append(&mut new.result_values);
         original.result_logs.append(&mut new.result_logs);
         original.result_message = new.result_message;
+        original.gas_used += new.gas_used;
+        original.gas_refund += new.gas_refund;
         original
     } else {
+        new.gas_used += original.gas_used;
+        new.gas_refund += original.gas_refund;
         new
     }
 }
@@ -6,4 +6,5 @@ pub struct Promise {
     pub success_callback: TxFunctionName,
     pub error_callback: TxFunctionName,
     pub callback_closure_data: Vec<u8>,
+    pub extra_gas_for_callback: u64,
 }
@@ -7,7 +7,7 @@ use std::{
 use crate::{
     display_util::address_hex,
     types::VMAddress,
-    world_mock::{AccountData, BlockchainState},
+    world_mock::{AccountData, BlockchainState, DctSystemTokenData},
 };
 
 use super::{BlockchainUpdate, TxCacheSource};
@@ -16,6 +16,7 @@ pub struct TxCache {
     source_ref: Arc<dyn TxCacheSource>,
     pub(super) accounts: Mutex<HashMap<VMAddress, AccountData>>,
     pub(super) new_token_identifiers: Mutex<Option<Vec<String>>>,
+    pub(super) dct_system_tokens: Mutex<HashMap<Vec<u8>, Option<DctSystemTokenData>>>,
 }
 
 impl fmt::Debug for TxCache {
@@ -32,6 +33,7 @@ impl TxCache {
             source_ref,
             accounts: Mutex::new(HashMap::new()),
             new_token_identifiers: Mutex::new(None),
+            dct_system_tokens: Mutex::new(HashMap::new()),
         }
     }
 
@@ -48,6 +50,11 @@ impl TxCache {
         }
     }
 
+    pub fn account_exists(&self, address: &VMAddress) -> bool {
+        self.load_account_if_necessary(address);
+        self.accounts.lock().unwrap().contains_key(address)
+    }
+
     pub fn with_account<R, F>(&self, address: &VMAddress, f: F) -> R
     where
         F: FnOnce(&AccountData) -> R,
@@ -103,14 +110,60 @@ impl TxCache {
         *self.new_token_identifiers.lock().unwrap() = Some(token_identifiers);
     }
 
+    /// Token-level data, as registered in the DCT system SC.
+    ///
+    /// Loaded from the source on first access. Provides a clone.
+    pub fn get_dct_system_token(&self, token_identifier: &[u8]) -> Option<DctSystemTokenData> {
+        let mut tokens = self.dct_system_tokens.lock().unwrap();
+        tokens
+            .entry(token_identifier.to_vec())
+            .or_insert_with(|| self.source_ref.load_dct_system_token(token_identifier))
+            .clone()
+    }
+
+    pub fn set_dct_system_token(&self, token_identifier: &[u8], token_data: DctSystemTokenData) {
+        self.dct_system_tokens
+            .lock()
+            .unwrap()
+            .insert(token_identifier.to_vec(), Some(token_data));
+    }
+
+    /// All accounts currently known, either in the cache or in the underlying blockchain state.
+    pub fn all_known_addresses(&self) -> Vec<VMAddress> {
+        let mut addresses: Vec<VMAddress> =
+            self.blockchain_ref().accounts.keys().cloned().collect();
+        for address in self.accounts.lock().unwrap().keys() {
+            if !addresses.contains(address) {
+                addresses.push(address.clone());
+            }
+        }
+        addresses.sort_by(|a, b| a.as_bytes().cmp(b.as_bytes()));
+        addresses
+    }
+
     pub fn into_blockchain_updates(self) -> BlockchainUpdate {
         BlockchainUpdate {
             accounts: self.accounts.into_inner().unwrap(),
             new_token_identifiers: self.new_token_identifiers.into_inner().unwrap(),
+            dct_system_tokens: self
+                .dct_system_tokens
+                .into_inner()
+                .unwrap()
+                .into_iter()
+                .filter_map(|(token_identifier, token_data)| {
+                    token_data.map(|token_data| (token_identifier, token_data))
+                })
+                .collect(),
         }
     }
 
     pub fn commit_updates(&self, updates: BlockchainUpdate) {
         self.accounts.lock().unwrap().extend(updates.accounts);
+        self.dct_system_tokens.lock().unwrap().extend(
+            updates
+                .dct_system_tokens
+                .into_iter()
+                .map(|(token_identifier, token_data)| (token_identifier, Some(token_data))),
+        );
     }
 }
@@ -1,7 +1,9 @@
 use num_bigint::BigUint;
 
 use crate::{
-    tx_execution::is_system_sc_address, tx_mock::TxPanic, types::VMAddress,
+    tx_execution::is_system_sc_address,
+    tx_mock::{InFlightValue, TxPanic, TxTokenTransfer},
+    types::VMAddress,
     world_mock::DctInstanceMetadata,
 };
 
@@ -110,11 +112,85 @@ impl TxCache {
         value: &BigUint,
     ) -> Result<(), TxPanic> {
         if !is_system_sc_address(from) && !is_system_sc_address(to) {
+            self.check_dct_transfer_allowed(from, to, dct_token_identifier, nonce)?;
             let metadata = self.subtract_dct_balance(from, dct_token_identifier, nonce, value)?;
             self.increase_dct_balance(to, dct_token_identifier, nonce, value, metadata);
         }
         Ok(())
     }
+
+    /// Takes the value of a call leaving the shard out of the sender account.
+    pub fn send_in_flight_value(
+        &self,
+        from: &VMAddress,
+        moax_value: &BigUint,
+        dct_transfers: &[TxTokenTransfer],
+    ) -> Result<InFlightValue, TxPanic> {
+        let mut in_flight = InFlightValue::default();
+        if is_system_sc_address(from) {
+            return Ok(in_flight);
+        }
+
+        self.subtract_moax_balance(from, moax_value)?;
+        in_flight.moax_value = moax_value.clone();
+        for transfer in dct_transfers {
+            let metadata = self.subtract_dct_balance(
+                from,
+                &transfer.token_identifier,
+                transfer.nonce,
+                &transfer.value,
+            )?;
+            in_flight.dct_values.push((transfer.clone(), metadata));
+        }
+        Ok(in_flight)
+    }
+
+    /// Gives the value of a cross-shard call back to the sender, so the call can transfer it as usual.
+    pub fn receive_in_flight_value(&self, from: &VMAddress, in_flight: InFlightValue) {
+        if !is_system_sc_address(from) {
+            self.increase_moax_balance(from, &in_flight.moax_value);
+        }
+        for (transfer, metadata) in in_flight.dct_values {
+            self.increase_dct_balance(
+                from,
+                &transfer.token_identifier,
+                transfer.nonce,
+                &transfer.value,
+                metadata,
+            );
+        }
+    }
+
+    /// Paused tokens cannot be transferred at all,
+    /// frozen tokens cannot be transferred to or from the frozen account.
+    fn check_dct_transfer_allowed(
+        &self,
+        from: &VMAddress,
+        to: &VMAddress,
+        dct_token_identifier: &[u8],
+        nonce: u64,
+    ) -> Result<(), TxPanic> {
+        if let Some(token_data) = self.get_dct_system_token(dct_token_identifier) {
+            if token_data.paused {
+                return Err(TxPanic::vm_error("dct token is paused"));
+            }
+        }
+
+        for address in [from, to] {
+            let frozen = self.with_account(address, |account| {
+                account
+                    .dct
+                    .get_by_identifier(dct_token_identifier)
+                    .map(|dct_data| dct_data.is_frozen(nonce))
+                    .unwrap_or_default()
+            });
+            if frozen {
+                return Err(TxPanic::vm_error("account is frozen"));
+            }
+        }
+
+        Ok(())
+    }
 }
 
 fn err_insufficient_funds() -> TxPanic {
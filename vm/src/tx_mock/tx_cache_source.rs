@@ -1,6 +1,6 @@
 use crate::{
     types::VMAddress,
-    world_mock::{AccountData, BlockchainState},
+    world_mock::{AccountData, BlockchainState, DctSystemTokenData},
 };
 
 use super::TxCache;
@@ -8,6 +8,8 @@ use super::TxCache;
 pub trait TxCacheSource: Send + Sync {
     fn load_account(&self, address: &VMAddress) -> Option<AccountData>;
 
+    fn load_dct_system_token(&self, token_identifier: &[u8]) -> Option<DctSystemTokenData>;
+
     fn blockchain_ref(&self) -> &BlockchainState;
 }
 
@@ -16,6 +18,10 @@ impl TxCacheSource for TxCache {
         Some(self.with_account(address, AccountData::clone))
     }
 
+    fn load_dct_system_token(&self, token_identifier: &[u8]) -> Option<DctSystemTokenData> {
+        self.get_dct_system_token(token_identifier)
+    }
+
     fn blockchain_ref(&self) -> &BlockchainState {
         self.blockchain_ref()
     }
@@ -23,7 +29,14 @@ impl TxCacheSource for TxCache {
 
 impl TxCacheSource for BlockchainState {
     fn load_account(&self, address: &VMAddress) -> Option<AccountData> {
-        self.accounts.get(address).map(AccountData::clone)
+        self.accounts
+            .get(address)
+            .map(AccountData::clone)
+            .or_else(|| self.fetch_account(address))
+    }
+
+    fn load_dct_system_token(&self, token_identifier: &[u8]) -> Option<DctSystemTokenData> {
+        self.dct_system_tokens.get(token_identifier).cloned()
     }
 
     fn blockchain_ref(&self) -> &BlockchainState {
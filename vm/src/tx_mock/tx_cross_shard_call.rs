@@ -0,0 +1,60 @@
+use num_bigint::BigUint;
+
+use crate::{types::VMAddress, world_mock::DctInstanceMetadata};
+
+use super::{AsyncCallTxData, Promise, TxInput, TxTokenTransfer};
+
+/// A call sent to another shard, in sharded execution mode.
+///
+/// It models the smart contract result that the node would produce, which only gets executed in the next block.
+#[derive(Clone, Debug)]
+pub struct CrossShardCall {
+    pub call_type: CrossShardCallType,
+
+    /// Value that already left the sender, but did not yet reach the destination.
+    pub in_flight: InFlightValue,
+}
+
+#[derive(Clone, Debug)]
+pub enum CrossShardCallType {
+    /// Legacy async call. Its callback is sent back to the caller shard.
+    AsyncCall(AsyncCallTxData),
+
+    /// Promise. Its callback is sent back to the caller shard.
+    Promise(Promise),
+
+    /// Transfer & execute, has no callback.
+    TransferExecute(AsyncCallTxData),
+
+    /// The callback of an async call or promise, on its way back to the caller shard.
+    Callback(TxInput),
+}
+
+/// Funds taken from the sender when a call leaves its shard.
+///
+/// They are given back to the sender right before the call is executed in the destination shard,
+/// so the call can pay them as usual. If the call fails, the funds remain with the sender.
+#[derive(Clone, Debug, Default)]
+pub struct InFlightValue {
+    pub moax_value: BigUint,
+    pub dct_values: Vec<(TxTokenTransfer, DctInstanceMetadata)>,
+}
+
+impl CrossShardCall {
+    pub fn new(call_type: CrossShardCallType) -> Self {
+        CrossShardCall {
+            call_type,
+            in_flight: InFlightValue::default(),
+        }
+    }
+
+    /// The address the funds in flight belong to, while the call is in transit.
+    pub fn sender(&self) -> &VMAddress {
+        match &self.call_type {
+            CrossShardCallType::AsyncCall(async_data)
+            | CrossShardCallType::TransferExecute(async_data) => &async_data.from,
+            CrossShardCallType::Promise(promise) => &promise.call.from,
+            CrossShardCallType::Callback(tx_input) => &tx_input.from,
+        }
+    }
+}
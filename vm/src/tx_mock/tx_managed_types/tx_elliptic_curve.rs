@@ -0,0 +1,13 @@
+use crate::types::{RawHandle, VMEllipticCurve};
+
+use super::TxManagedTypes;
+
+impl TxManagedTypes {
+    pub fn ec_new(&mut self, curve: VMEllipticCurve) -> RawHandle {
+        self.elliptic_curve_map.insert_new_handle_raw(curve)
+    }
+
+    pub fn ec_get(&self, handle: RawHandle) -> &VMEllipticCurve {
+        self.elliptic_curve_map.get(handle)
+    }
+}
@@ -1,3 +1,5 @@
+use crate::vm_err_msg;
+
 #[derive(Debug, Clone)]
 pub struct TxPanic {
     pub status: u64,
@@ -19,4 +21,8 @@ impl TxPanic {
     pub fn vm_error(message: &str) -> TxPanic {
         TxPanic::new(10, message)
     }
+
+    pub fn out_of_gas() -> TxPanic {
+        TxPanic::new(5, vm_err_msg::OUT_OF_GAS)
+    }
 }
@@ -19,6 +19,12 @@ pub struct TxResult {
     ///
     /// Is never cleared of its contents.
     pub all_calls: Vec<AsyncCallTxData>,
+
+    /// Gas consumed, only metered if the VM is configured with a gas schedule.
+    pub gas_used: u64,
+
+    /// Gas given back for releasing storage, only metered if the VM is configured with a gas schedule.
+    pub gas_refund: u64,
 }
 
 impl Default for TxResult {
@@ -30,6 +36,8 @@ impl Default for TxResult {
             result_logs: Vec::new(),
             pending_calls: TxResultCalls::empty(),
             all_calls: Vec::new(),
+            gas_used: 0,
+            gas_refund: 0,
         }
     }
 }
@@ -90,6 +98,26 @@ impl TxResult {
             );
             self.pending_calls.async_call = Some(sync_result_async.clone());
         }
+        self.pending_calls
+            .cross_shard_calls
+            .extend_from_slice(sync_call_result.pending_calls.cross_shard_calls.as_slice());
+        self.gas_used += sync_call_result.gas_used;
+        self.gas_refund += sync_call_result.gas_refund;
+    }
+
+    /// Failed transactions consume all the gas they were given.
+    pub fn consume_all_gas_if_failed(&mut self, gas_limit: u64) {
+        if self.result_status != 0 {
+            self.gas_used = gas_limit;
+            self.gas_refund = 0;
+        }
+    }
+
+    /// Gas that should be paid back to the sender: the unused gas, plus the storage refund.
+    ///
+    /// The storage refund cannot exceed the gas actually used.
+    pub fn gas_to_refund(&self, gas_limit: u64) -> u64 {
+        gas_limit.saturating_sub(self.gas_used) + self.gas_refund.min(self.gas_used)
     }
 
     pub fn assert_ok(&self) {
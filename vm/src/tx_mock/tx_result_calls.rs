@@ -1,9 +1,12 @@
-use super::{AsyncCallTxData, Promise};
+use super::{AsyncCallTxData, CrossShardCall, Promise};
 
 #[derive(Clone, Default, Debug)]
 pub struct TxResultCalls {
     pub async_call: Option<AsyncCallTxData>,
     pub promises: Vec<Promise>,
+
+    /// Transfers sent to other shards, in sharded mode.
+    pub cross_shard_calls: Vec<CrossShardCall>,
 }
 
 impl TxResultCalls {
@@ -11,10 +14,11 @@ impl TxResultCalls {
         TxResultCalls {
             async_call: None,
             promises: Vec::new(),
+            cross_shard_calls: Vec::new(),
         }
     }
 
     pub fn no_calls(&self) -> bool {
-        self.async_call.is_none() && self.promises.is_empty()
+        self.async_call.is_none() && self.promises.is_empty() && self.cross_shard_calls.is_empty()
     }
 }
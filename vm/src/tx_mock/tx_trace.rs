@@ -0,0 +1,150 @@
+use num_bigint::BigUint;
+
+use crate::types::VMAddress;
+
+use super::{TxFunctionName, TxInput, TxLog, TxResult, TxTokenTransfer};
+
+/// How a traced call came to be executed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TxCallKind {
+    /// Transaction coming from outside the VM, e.g. from a scenario step.
+    Transaction,
+    Query,
+    Deploy,
+    SyncCall,
+    TransferExecute,
+    AsyncCall,
+    Promise,
+    Callback,
+}
+
+impl TxCallKind {
+    pub fn name(&self) -> &'static str {
+        match self {
+            TxCallKind::Transaction => "transaction",
+            TxCallKind::Query => "query",
+            TxCallKind::Deploy => "deploy",
+            TxCallKind::SyncCall => "sync",
+            TxCallKind::TransferExecute => "transferExecute",
+            TxCallKind::AsyncCall => "async",
+            TxCallKind::Promise => "promise",
+            TxCallKind::Callback => "callback",
+        }
+    }
+}
+
+/// A storage write, as performed by a contract.
+#[derive(Clone, Debug)]
+pub struct TxStorageWrite {
+    pub address: VMAddress,
+    pub key: Vec<u8>,
+    pub old_value: Vec<u8>,
+    pub new_value: Vec<u8>,
+}
+
+/// Everything that was recorded about a call, including the calls it triggered.
+///
+/// Logs and storage writes only belong to the call that produced them, not to its callers.
+/// They are also kept if the call fails and they get reverted.
+#[derive(Clone, Debug)]
+pub struct TxCallTrace {
+    pub call_kind: TxCallKind,
+    pub from: VMAddress,
+    pub to: VMAddress,
+    pub endpoint: TxFunctionName,
+    pub arguments: Vec<Vec<u8>>,
+    pub moax_value: BigUint,
+    pub dct_transfers: Vec<TxTokenTransfer>,
+    pub logs: Vec<TxLog>,
+    pub storage_writes: Vec<TxStorageWrite>,
+    pub result_status: u64,
+    pub result_message: String,
+    pub nested_calls: Vec<TxCallTrace>,
+}
+
+impl TxCallTrace {
+    fn new(call_kind: TxCallKind, tx_input: &TxInput) -> Self {
+        TxCallTrace {
+            call_kind,
+            from: tx_input.from.clone(),
+            to: tx_input.to.clone(),
+            endpoint: tx_input.func_name.clone(),
+            arguments: tx_input.args.clone(),
+            moax_value: tx_input.moax_value.clone(),
+            dct_transfers: tx_input.dct_values.clone(),
+            logs: Vec::new(),
+            storage_writes: Vec::new(),
+            result_status: 0,
+            result_message: String::new(),
+            nested_calls: Vec::new(),
+        }
+    }
+
+    pub fn is_success(&self) -> bool {
+        self.result_status == 0
+    }
+
+    /// The innermost call that failed, searching the nested calls in execution order.
+    pub fn failed_call(&self) -> Option<&TxCallTrace> {
+        self.nested_calls
+            .iter()
+            .find_map(TxCallTrace::failed_call)
+            .or(if self.is_success() { None } else { Some(self) })
+    }
+}
+
+/// Builds the call trees of all the calls executed while tracing is enabled.
+#[derive(Debug, Default)]
+pub struct TxTracer {
+    call_stack: Vec<TxCallTrace>,
+    traces: Vec<TxCallTrace>,
+}
+
+impl TxTracer {
+    /// All calls that are nested get started and ended before their parent is ended.
+    pub fn begin_call(&mut self, call_kind: TxCallKind, tx_input: &TxInput) {
+        self.call_stack.push(TxCallTrace::new(call_kind, tx_input));
+    }
+
+    /// Transfer builtin functions only find out the real destination and endpoint once they start executing.
+    pub fn resolve_builtin_transfer(&mut self, exec_input: &TxInput) {
+        if let Some(call) = self.call_stack.last_mut() {
+            call.to = exec_input.to.clone();
+            call.endpoint = exec_input.func_name.clone();
+            call.arguments = exec_input.args.clone();
+            call.dct_transfers = exec_input.dct_values.clone();
+        }
+    }
+
+    pub fn log(&mut self, tx_log: &TxLog) {
+        if let Some(call) = self.call_stack.last_mut() {
+            call.logs.push(tx_log.clone());
+        }
+    }
+
+    pub fn storage_write(&mut self, storage_write: TxStorageWrite) {
+        if let Some(call) = self.call_stack.last_mut() {
+            call.storage_writes.push(storage_write);
+        }
+    }
+
+    pub fn end_call(&mut self, tx_result: &TxResult) {
+        if let Some(mut call) = self.call_stack.pop() {
+            call.result_status = tx_result.result_status;
+            if tx_result.result_status != 0 {
+                call.result_message = tx_result.result_message.clone();
+            }
+
+            if let Some(parent) = self.call_stack.last_mut() {
+                parent.nested_calls.push(call);
+            } else {
+                self.traces.push(call);
+            }
+        }
+    }
+
+    /// The call trees of all the calls completed so far, in execution order.
+    pub fn traces(&self) -> &[TxCallTrace] {
+        &self.traces
+    }
+}
@@ -2,6 +2,7 @@ mod vm_address;
 mod vm_code_metadata;
 mod vm_dct_local_role;
 mod vm_dct_local_role_flags;
+mod vm_elliptic_curve;
 mod vm_h256;
 mod vm_token_type;
 
@@ -11,6 +12,7 @@ pub use vm_address::VMAddress;
 pub use vm_code_metadata::VMCodeMetadata;
 pub use vm_dct_local_role::DctLocalRole;
 pub use vm_dct_local_role_flags::DctLocalRoleFlags;
+pub use vm_elliptic_curve::VMEllipticCurve;
 pub use vm_h256::H256;
 pub use vm_token_type::VMTokenType;
 
@@ -0,0 +1,427 @@
+use num_bigint::BigUint;
+use num_traits::{One, Zero};
+
+/// Points are represented in affine coordinates, `None` is the point at infinity.
+type Point = Option<(BigUint, BigUint)>;
+
+/// One of the NIST curves supported by the VM, of the form y² = x³ - 3x + b.
+///
+/// Same semantics as the generic curve implementation the node relies on:
+/// the point at infinity is represented as (0, 0) and operations do not validate their inputs.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VMEllipticCurve {
+    pub field_order: BigUint,
+    pub base_point_order: BigUint,
+    pub eq_constant: BigUint,
+    pub x_base_point: BigUint,
+    pub y_base_point: BigUint,
+    pub size_of_field: u32,
+}
+
+fn hex_to_big_uint(hex_str: &str) -> BigUint {
+    BigUint::parse_bytes(hex_str.as_bytes(), 16).unwrap()
+}
+
+impl VMEllipticCurve {
+    fn from_hex_params(p: &str, n: &str, b: &str, gx: &str, gy: &str, size_of_field: u32) -> Self {
+        VMEllipticCurve {
+            field_order: hex_to_big_uint(p),
+            base_point_order: hex_to_big_uint(n),
+            eq_constant: hex_to_big_uint(b),
+            x_base_point: hex_to_big_uint(gx),
+            y_base_point: hex_to_big_uint(gy),
+            size_of_field,
+        }
+    }
+
+    pub fn p224() -> Self {
+        Self::from_hex_params(
+            "ffffffffffffffffffffffffffffffff000000000000000000000001",
+            "ffffffffffffffffffffffffffff16a2e0b8f03e13dd29455c5c2a3d",
+            "b4050a850c04b3abf54132565044b0b7d7bfd8ba270b39432355ffb4",
+            "b70e0cbd6bb4bf7f321390b94a03c1d356c21122343280d6115c1d21",
+            "bd376388b5f723fb4c22dfe6cd4375a05a07476444d5819985007e34",
+            224,
+        )
+    }
+
+    pub fn p256() -> Self {
+        Self::from_hex_params(
+            "ffffffff00000001000000000000000000000000ffffffffffffffffffffffff",
+            "ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551",
+            "5ac635d8aa3a93e7b3ebbd55769886bc651d06b0cc53b0f63bce3c3e27d2604b",
+            "6b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296",
+            "4fe342e2fe1a7f9b8ee7eb4a7c0f9e162bce33576b315ececbb6406837bf51f5",
+            256,
+        )
+    }
+
+    pub fn p384() -> Self {
+        Self::from_hex_params(
+            "fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffeffffffff0000000000000000ffffffff",
+            "ffffffffffffffffffffffffffffffffffffffffffffffffc7634d81f4372ddf581a0db248b0a77aecec196accc52973",
+            "b3312fa7e23ee7e4988e056be3f82d19181d9c6efe8141120314088f5013875ac656398d8a2ed19d2a85c8edd3ec2aef",
+            "aa87ca22be8b05378eb1c71ef320ad746e1d3b628ba79b9859f741e082542a385502f25dbf55296c3a545e3872760ab7",
+            "3617de4a96262c6f5d9e98bf9292dc29f8f41dbd289a147ce9da3113b5f0b8c00a60b1ce1d7e819d7a431d7c90ea0e5f",
+            384,
+        )
+    }
+
+    pub fn p521() -> Self {
+        Self::from_hex_params(
+            "1ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+            "1fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffa51868783bf2f966b7fcc0148f709a5d03bb5c9b8899c47aebb6fb71e91386409",
+            "051953eb9618e1c9a1f929a21a0b68540eea2da725b99b315f3b8b489918ef109e156193951ec7e937b1652c0bd3bb1bf073573df883d2c34f1ef451fd46b503f00",
+            "0c6858e06b70404e9cd9e3ecb662395b4429c648139053fb521f828af606b4d3dbaa14b5e77efe75928fe1dc127a2ffa8de3348b3c1856a429bf97e7e31c2e5bd66",
+            "11839296a789a3bc0045c8a5fb42c7d1bd998f54449579b446817afbd17273e662c97ee72995ef42640c550b9013fad0761353c7086a272c24088be94769fd16650",
+            521,
+        )
+    }
+
+    pub fn from_name(name: &[u8]) -> Option<Self> {
+        match name {
+            b"p224" => Some(Self::p224()),
+            b"p256" => Some(Self::p256()),
+            b"p384" => Some(Self::p384()),
+            b"p521" => Some(Self::p521()),
+            _ => None,
+        }
+    }
+
+    /// Length of a serialized coordinate.
+    pub fn byte_length(&self) -> usize {
+        (self.size_of_field as usize + 7) / 8
+    }
+
+    pub fn private_key_byte_length(&self) -> usize {
+        (self.base_point_order.bits() as usize + 7) / 8
+    }
+
+    pub fn is_on_curve(&self, x: &BigUint, y: &BigUint) -> bool {
+        let p = &self.field_order;
+        if x >= p || y >= p {
+            return false;
+        }
+        (y * y) % p == self.polynomial(x)
+    }
+
+    pub fn add(
+        &self,
+        x1: &BigUint,
+        y1: &BigUint,
+        x2: &BigUint,
+        y2: &BigUint,
+    ) -> (BigUint, BigUint) {
+        let result = self.add_points(&to_point(x1, y1), &to_point(x2, y2));
+        from_point(result)
+    }
+
+    pub fn double(&self, x: &BigUint, y: &BigUint) -> (BigUint, BigUint) {
+        let result = self.double_point(&to_point(x, y));
+        from_point(result)
+    }
+
+    /// The scalar is a big endian number, it is not reduced modulo the base point order.
+    pub fn scalar_mult(&self, x: &BigUint, y: &BigUint, scalar: &[u8]) -> (BigUint, BigUint) {
+        let result = self.scalar_mult_point(&to_point(x, y), scalar);
+        from_point(result)
+    }
+
+    pub fn scalar_base_mult(&self, scalar: &[u8]) -> (BigUint, BigUint) {
+        self.scalar_mult(&self.x_base_point, &self.y_base_point, scalar)
+    }
+
+    /// Uncompressed SEC 1 encoding: 0x04 followed by both coordinates.
+    pub fn marshal(&self, x: &BigUint, y: &BigUint) -> Vec<u8> {
+        let mut result = vec![4u8];
+        result.extend(self.coordinate_bytes(x));
+        result.extend(self.coordinate_bytes(y));
+        result
+    }
+
+    /// Compressed SEC 1 encoding: 0x02 or 0x03, depending on the parity of y, followed by x.
+    pub fn marshal_compressed(&self, x: &BigUint, y: &BigUint) -> Vec<u8> {
+        let mut result = vec![2u8 + y.bit(0) as u8];
+        result.extend(self.coordinate_bytes(x));
+        result
+    }
+
+    /// Returns `None` if the data is not a valid encoding of a point on the curve.
+    pub fn unmarshal(&self, data: &[u8]) -> Option<(BigUint, BigUint)> {
+        let byte_length = self.byte_length();
+        if data.len() != 1 + 2 * byte_length || data[0] != 4 {
+            return None;
+        }
+        let x = BigUint::from_bytes_be(&data[1..1 + byte_length]);
+        let y = BigUint::from_bytes_be(&data[1 + byte_length..]);
+        if !self.is_on_curve(&x, &y) {
+            return None;
+        }
+        Some((x, y))
+    }
+
+    /// Returns `None` if the data is not a valid compressed encoding of a point on the curve.
+    pub fn unmarshal_compressed(&self, data: &[u8]) -> Option<(BigUint, BigUint)> {
+        let byte_length = self.byte_length();
+        if data.len() != 1 + byte_length || (data[0] != 2 && data[0] != 3) {
+            return None;
+        }
+        let x = BigUint::from_bytes_be(&data[1..]);
+        if x >= self.field_order {
+            return None;
+        }
+        let mut y = mod_sqrt(&self.polynomial(&x), &self.field_order)?;
+        if y.bit(0) != (data[0] == 3) {
+            y = (&self.field_order - &y) % &self.field_order;
+        }
+        if !self.is_on_curve(&x, &y) {
+            return None;
+        }
+        Some((x, y))
+    }
+
+    /// Generates a key pair, returns the private key followed by the public key coordinates.
+    ///
+    /// Random bytes are drawn until they produce a valid private key, just like on the node.
+    pub fn generate_key<F>(&self, mut random_bytes: F) -> (Vec<u8>, BigUint, BigUint)
+    where
+        F: FnMut(usize) -> Vec<u8>,
+    {
+        const MASK: [u8; 8] = [0xff, 0x1, 0x3, 0x7, 0xf, 0x1f, 0x3f, 0x7f];
+        let bit_size = self.base_point_order.bits() as usize;
+        loop {
+            let mut private_key = random_bytes(self.private_key_byte_length());
+            private_key[0] &= MASK[bit_size % 8];
+            // avoids all-zero keys when the randomness source is trivial
+            private_key[1] ^= 0x42;
+            if BigUint::from_bytes_be(&private_key) >= self.base_point_order {
+                continue;
+            }
+            let (x, y) = self.scalar_base_mult(&private_key);
+            if !x.is_zero() || !y.is_zero() {
+                return (private_key, x, y);
+            }
+        }
+    }
+
+    /// x³ - 3x + b
+    fn polynomial(&self, x: &BigUint) -> BigUint {
+        let p = &self.field_order;
+        let x3 = x * x * x;
+        let three_x = x * 3u32 % p;
+        (x3 + &self.eq_constant + p - three_x) % p
+    }
+
+    fn coordinate_bytes(&self, value: &BigUint) -> Vec<u8> {
+        let bytes = value.to_bytes_be();
+        let byte_length = self.byte_length();
+        let mut result = vec![0u8; byte_length.saturating_sub(bytes.len())];
+        result.extend(bytes);
+        result
+    }
+
+    fn add_points(&self, first: &Point, second: &Point) -> Point {
+        let p = &self.field_order;
+        let ((x1, y1), (x2, y2)) = match (first, second) {
+            (None, _) => return second.clone(),
+            (_, None) => return first.clone(),
+            (Some(first), Some(second)) => (first, second),
+        };
+        if x1 == x2 {
+            if y1 == y2 {
+                return self.double_point(first);
+            }
+            return None;
+        }
+        let slope = mod_sub(y2, y1, p) * mod_inverse(&mod_sub(x2, x1, p), p) % p;
+        let x3 = mod_sub(&mod_sub(&(&slope * &slope % p), x1, p), x2, p);
+        let y3 = mod_sub(&(slope * mod_sub(x1, &x3, p) % p), y1, p);
+        Some((x3, y3))
+    }
+
+    fn double_point(&self, point: &Point) -> Point {
+        let p = &self.field_order;
+        let (x, y) = point.as_ref()?;
+        if y.is_zero() {
+            return None;
+        }
+        // 3x² + a, with a = -3
+        let numerator = mod_sub(&(x * x * 3u32 % p), &BigUint::from(3u32), p);
+        let slope = numerator * mod_inverse(&(y * 2u32 % p), p) % p;
+        let x3 = mod_sub(&(&slope * &slope % p), &(x * 2u32 % p), p);
+        let y3 = mod_sub(&(slope * mod_sub(x, &x3, p) % p), y, p);
+        Some((x3, y3))
+    }
+
+    fn scalar_mult_point(&self, point: &Point, scalar: &[u8]) -> Point {
+        let mut result = None;
+        for byte in scalar {
+            for bit_index in (0..8).rev() {
+                result = self.double_point(&result);
+                if (byte >> bit_index) & 1 == 1 {
+                    result = self.add_points(&result, point);
+                }
+            }
+        }
+        result
+    }
+}
+
+fn to_point(x: &BigUint, y: &BigUint) -> Point {
+    if x.is_zero() && y.is_zero() {
+        None
+    } else {
+        Some((x.clone(), y.clone()))
+    }
+}
+
+fn from_point(point: Point) -> (BigUint, BigUint) {
+    point.unwrap_or_default()
+}
+
+fn mod_sub(a: &BigUint, b: &BigUint, p: &BigUint) -> BigUint {
+    (a % p + p - b % p) % p
+}
+
+/// The field order is prime, so the inverse is a^(p-2).
+fn mod_inverse(a: &BigUint, p: &BigUint) -> BigUint {
+    a.modpow(&(p - 2u32), p)
+}
+
+/// Tonelli-Shanks, returns `None` if the value is not a quadratic residue.
+fn mod_sqrt(value: &BigUint, p: &BigUint) -> Option<BigUint> {
+    let value = value % p;
+    if value.is_zero() {
+        return Some(value);
+    }
+    let one = BigUint::one();
+    let p_minus_1 = p - 1u32;
+    if value.modpow(&(&p_minus_1 >> 1), p) != one {
+        return None;
+    }
+
+    let mut q = p_minus_1.clone();
+    let mut s = 0u32;
+    while !q.bit(0) {
+        q >>= 1;
+        s += 1;
+    }
+
+    let mut non_residue = BigUint::from(2u32);
+    while non_residue.modpow(&(&p_minus_1 >> 1), p) != p_minus_1 {
+        non_residue += 1u32;
+    }
+
+    let mut m = s;
+    let mut c = non_residue.modpow(&q, p);
+    let mut t = value.modpow(&q, p);
+    let mut r = value.modpow(&((&q + 1u32) >> 1), p);
+    while t != one {
+        let mut i = 0u32;
+        let mut t_pow = t.clone();
+        while t_pow != one {
+            t_pow = &t_pow * &t_pow % p;
+            i += 1;
+        }
+        let b = c.modpow(&(BigUint::one() << (m - i - 1)), p);
+        m = i;
+        c = &b * &b % p;
+        t = t * &c % p;
+        r = r * b % p;
+    }
+    Some(r)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn all_curves() -> Vec<VMEllipticCurve> {
+        vec![
+            VMEllipticCurve::p224(),
+            VMEllipticCurve::p256(),
+            VMEllipticCurve::p384(),
+            VMEllipticCurve::p521(),
+        ]
+    }
+
+    #[test]
+    fn test_curve_params() {
+        for curve in all_curves() {
+            assert!(curve.is_on_curve(&curve.x_base_point, &curve.y_base_point));
+            let order = curve.base_point_order.to_bytes_be();
+            let (x, y) = curve.scalar_base_mult(&order);
+            assert!(x.is_zero() && y.is_zero());
+        }
+        assert_eq!(VMEllipticCurve::p521().byte_length(), 66);
+        assert_eq!(VMEllipticCurve::p521().private_key_byte_length(), 66);
+        assert_eq!(
+            VMEllipticCurve::from_name(b"p256"),
+            Some(VMEllipticCurve::p256())
+        );
+        assert_eq!(VMEllipticCurve::from_name(b"secp256k1"), None);
+    }
+
+    #[test]
+    fn test_add_double_mult() {
+        let curve = VMEllipticCurve::p256();
+        let (gx, gy) = (&curve.x_base_point, &curve.y_base_point);
+        let (x2, y2) = curve.double(gx, gy);
+        assert_eq!(
+            x2,
+            hex_to_big_uint("7cf27b188d034f7e8a52380304b51ac3c08969e277f21b35a60b48fc47669978")
+        );
+        assert_eq!(
+            y2,
+            hex_to_big_uint("7775510db8ed040293d9ac69f7430dbba7dade63ce982299e04b79d227873d1")
+        );
+        assert_eq!(curve.add(gx, gy, gx, gy), (x2.clone(), y2.clone()));
+        assert_eq!(curve.scalar_base_mult(&[2]), (x2.clone(), y2.clone()));
+
+        let (x3, y3) = curve.add(&x2, &y2, gx, gy);
+        assert_eq!(curve.scalar_mult(gx, gy, &[0, 3]), (x3.clone(), y3.clone()));
+        assert!(curve.is_on_curve(&x3, &y3));
+
+        let zero = BigUint::zero();
+        assert_eq!(curve.add(gx, gy, &zero, &zero), (gx.clone(), gy.clone()));
+        let neg_gy = &curve.field_order - gy;
+        assert_eq!(curve.add(gx, gy, gx, &neg_gy), (zero.clone(), zero));
+    }
+
+    #[test]
+    fn test_marshal_unmarshal() {
+        for curve in all_curves() {
+            let (x, y) = curve.scalar_base_mult(&[7, 123]);
+            let marshalled = curve.marshal(&x, &y);
+            assert_eq!(marshalled.len(), 1 + 2 * curve.byte_length());
+            assert_eq!(curve.unmarshal(&marshalled), Some((x.clone(), y.clone())));
+
+            let compressed = curve.marshal_compressed(&x, &y);
+            assert_eq!(compressed.len(), 1 + curve.byte_length());
+            assert_eq!(
+                curve.unmarshal_compressed(&compressed),
+                Some((x.clone(), y.clone()))
+            );
+
+            let mut invalid = marshalled.clone();
+            *invalid.last_mut().unwrap() ^= 1;
+            assert_eq!(curve.unmarshal(&invalid), None);
+            assert_eq!(curve.unmarshal(&marshalled[1..]), None);
+            assert_eq!(curve.unmarshal_compressed(&marshalled), None);
+        }
+    }
+
+    #[test]
+    fn test_generate_key() {
+        for curve in all_curves() {
+            let mut seed = 0u8;
+            let (private_key, x, y) = curve.generate_key(|length| {
+                seed += 1;
+                vec![seed; length]
+            });
+            assert_eq!(private_key.len(), curve.private_key_byte_length());
+            assert!(curve.is_on_curve(&x, &y));
+            assert_eq!(curve.scalar_base_mult(&private_key), (x, y));
+        }
+    }
+}
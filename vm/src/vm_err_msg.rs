@@ -10,4 +10,12 @@ pub const NUMBER_IS_NOT_NORMAL: &str =
     "number is not normal. It is either infinite, NaN or subnormal";
 pub const CANNOT_COMPARE_VALUES: &str = "values are not comparable";
 
+pub const OUT_OF_GAS: &str = "out of gas";
+
 pub const ERROR_SIGNALLED_BY_SMARTCONTRACT: &str = "error signalled by smartcontract";
+
+pub const SYNC_EXECUTION_CROSS_SHARD: &str = "sync execution request is not in the same shard";
+
+pub const EC_INVALID_CURVE_NAME: &str = "invalid elliptic curve name";
+pub const EC_POINT_NOT_ON_CURVE: &str = "point is not on curve";
+pub const EC_BUFFER_LENGTH_INVALID: &str = "length of buffer is not correct";
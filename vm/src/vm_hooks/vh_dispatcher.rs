@@ -2,7 +2,7 @@ use std::ffi::c_void;
 
 use dharitri_chain_vm_executor::{MemLength, MemPtr, VMHooks};
 
-use crate::mem_conv;
+use crate::{mem_conv, schedule::GasSchedule};
 
 use super::VMHooksHandler;
 
@@ -16,6 +16,16 @@ impl VMHooksDispatcher {
     pub fn new(handler: Box<dyn VMHooksHandler>) -> Self {
         VMHooksDispatcher { handler }
     }
+
+    /// Charges the cost of a VM hook, if gas is metered.
+    fn use_gas<F>(&self, cost_fn: F)
+    where
+        F: FnOnce(&GasSchedule) -> u64,
+    {
+        if let Some(gas_schedule) = self.handler.gas_schedule() {
+            self.handler.use_gas(cost_fn(gas_schedule));
+        }
+    }
 }
 
 fn bool_to_i32(b: bool) -> i32 {
@@ -26,6 +36,15 @@ fn bool_to_i32(b: bool) -> i32 {
     }
 }
 
+/// Signature verification hooks return 0 for a valid signature and -1 otherwise.
+fn bool_to_result(valid: bool) -> i32 {
+    if valid {
+        0
+    } else {
+        -1
+    }
+}
+
 #[allow(unused)]
 impl VMHooks for VMHooksDispatcher {
     fn set_vm_hooks_ptr(&mut self, _vm_hooks_ptr: *mut c_void) {}
@@ -33,6 +52,7 @@ impl VMHooks for VMHooksDispatcher {
     fn managed_get_back_transfers(&self, _: i32, _: i32) {}
 
     fn get_gas_left(&self) -> i64 {
+        self.use_gas(|gas_schedule| gas_schedule.base_ops_api_cost.get_gas_left);
         self.handler.get_gas_left() as i64
     }
 
@@ -45,6 +65,7 @@ impl VMHooks for VMHooksDispatcher {
     }
 
     fn get_shard_of_address(&self, address_offset: MemPtr) -> i32 {
+        self.use_gas(|gas_schedule| gas_schedule.base_ops_api_cost.get_shard_of_address);
         unsafe {
             mem_conv::with_bytes(address_offset, 32, |address_bytes| {
                 self.handler.get_shard_of_address(address_bytes)
@@ -53,6 +74,7 @@ impl VMHooks for VMHooksDispatcher {
     }
 
     fn is_smart_contract(&self, address_offset: MemPtr) -> i32 {
+        self.use_gas(|gas_schedule| gas_schedule.base_ops_api_cost.is_smart_contract);
         unsafe {
             bool_to_i32(mem_conv::with_bytes(address_offset, 32, |address_bytes| {
                 self.handler.is_smart_contract(address_bytes)
@@ -61,6 +83,7 @@ impl VMHooks for VMHooksDispatcher {
     }
 
     fn signal_error(&self, message_offset: MemPtr, message_length: MemLength) {
+        self.use_gas(|gas_schedule| gas_schedule.base_ops_api_cost.signal_error);
         unsafe {
             mem_conv::with_bytes(message_offset, message_length, |message| {
                 self.handler.signal_error(message);
@@ -136,6 +159,7 @@ impl VMHooks for VMHooksDispatcher {
     }
 
     fn get_dct_local_roles(&self, token_id_handle: i32) -> i64 {
+        self.use_gas(|gas_schedule| gas_schedule.base_ops_api_cost.storage_load);
         self.handler.get_dct_local_roles_bits(token_id_handle) as i64
     }
 
@@ -306,6 +330,7 @@ impl VMHooks for VMHooksDispatcher {
     }
 
     fn get_num_arguments(&self) -> i32 {
+        self.use_gas(|gas_schedule| gas_schedule.base_ops_api_cost.get_num_arguments);
         self.handler.get_num_arguments()
     }
 
@@ -363,6 +388,7 @@ impl VMHooks for VMHooksDispatcher {
     }
 
     fn check_no_payment(&self) {
+        self.use_gas(|gas_schedule| gas_schedule.base_ops_api_cost.get_call_value);
         self.handler.check_not_payable();
     }
 
@@ -400,6 +426,7 @@ impl VMHooks for VMHooksDispatcher {
         token_id_offset: MemPtr,
         token_id_len: MemLength,
     ) -> i64 {
+        self.use_gas(|gas_schedule| gas_schedule.base_ops_api_cost.storage_load);
         unsafe {
             mem_conv::with_bytes(address_offset, 32, |address_bytes| {
                 mem_conv::with_bytes(token_id_offset, token_id_len, |token_id_bytes| {
@@ -420,6 +447,7 @@ impl VMHooks for VMHooksDispatcher {
     }
 
     fn get_num_dct_transfers(&self) -> i32 {
+        self.use_gas(|gas_schedule| gas_schedule.base_ops_api_cost.get_call_value);
         self.handler.dct_num_transfers() as i32
     }
 
@@ -462,18 +490,22 @@ impl VMHooks for VMHooksDispatcher {
     }
 
     fn get_block_timestamp(&self) -> i64 {
+        self.use_gas(|gas_schedule| gas_schedule.base_ops_api_cost.get_block_time_stamp);
         self.handler.get_block_timestamp() as i64
     }
 
     fn get_block_nonce(&self) -> i64 {
+        self.use_gas(|gas_schedule| gas_schedule.base_ops_api_cost.get_block_nonce);
         self.handler.get_block_nonce() as i64
     }
 
     fn get_block_round(&self) -> i64 {
+        self.use_gas(|gas_schedule| gas_schedule.base_ops_api_cost.get_block_round);
         self.handler.get_block_round() as i64
     }
 
     fn get_block_epoch(&self) -> i64 {
+        self.use_gas(|gas_schedule| gas_schedule.base_ops_api_cost.get_block_epoch);
         self.handler.get_block_epoch() as i64
     }
 
@@ -486,18 +518,22 @@ impl VMHooks for VMHooksDispatcher {
     }
 
     fn get_prev_block_timestamp(&self) -> i64 {
+        self.use_gas(|gas_schedule| gas_schedule.base_ops_api_cost.get_block_time_stamp);
         self.handler.get_prev_block_timestamp() as i64
     }
 
     fn get_prev_block_nonce(&self) -> i64 {
+        self.use_gas(|gas_schedule| gas_schedule.base_ops_api_cost.get_block_nonce);
         self.handler.get_prev_block_nonce() as i64
     }
 
     fn get_prev_block_round(&self) -> i64 {
+        self.use_gas(|gas_schedule| gas_schedule.base_ops_api_cost.get_block_round);
         self.handler.get_prev_block_round() as i64
     }
 
     fn get_prev_block_epoch(&self) -> i64 {
+        self.use_gas(|gas_schedule| gas_schedule.base_ops_api_cost.get_block_epoch);
         self.handler.get_prev_block_epoch() as i64
     }
 
@@ -506,6 +542,7 @@ impl VMHooks for VMHooksDispatcher {
     }
 
     fn finish(&self, pointer: MemPtr, length: MemLength) {
+        self.use_gas(|gas_schedule| gas_schedule.base_ops_api_cost.finish);
         unsafe {
             mem_conv::with_bytes(pointer, length, |bytes| {
                 self.handler.finish_slice_u8(bytes);
@@ -596,10 +633,12 @@ impl VMHooks for VMHooksDispatcher {
     }
 
     fn clean_return_data(&self) {
+        self.use_gas(|gas_schedule| gas_schedule.base_ops_api_cost.clean_return_data);
         self.handler.clean_return_data();
     }
 
     fn delete_from_return_data(&self, result_id: i32) {
+        self.use_gas(|gas_schedule| gas_schedule.base_ops_api_cost.delete_from_return_data);
         self.handler.delete_from_return_data(result_id as usize);
     }
 
@@ -616,26 +655,32 @@ impl VMHooks for VMHooksDispatcher {
     }
 
     fn managed_sc_address(&self, destination_handle: i32) {
+        self.use_gas(|gas_schedule| gas_schedule.base_ops_api_cost.get_sc_address);
         self.handler.managed_sc_address(destination_handle);
     }
 
     fn managed_owner_address(&self, destination_handle: i32) {
+        self.use_gas(|gas_schedule| gas_schedule.base_ops_api_cost.get_owner_address);
         self.handler.managed_owner_address(destination_handle);
     }
 
     fn managed_caller(&self, destination_handle: i32) {
+        self.use_gas(|gas_schedule| gas_schedule.base_ops_api_cost.get_caller);
         self.handler.managed_caller(destination_handle);
     }
 
     fn managed_signal_error(&self, err_handle: i32) {
+        self.use_gas(|gas_schedule| gas_schedule.base_ops_api_cost.signal_error);
         self.handler.signal_error_from_buffer(err_handle);
     }
 
     fn managed_write_log(&self, topics_handle: i32, data_handle: i32) {
+        self.use_gas(|gas_schedule| gas_schedule.base_ops_api_cost.log);
         self.handler.managed_write_log(topics_handle, data_handle);
     }
 
     fn managed_get_original_tx_hash(&self, result_handle: i32) {
+        self.use_gas(|gas_schedule| gas_schedule.base_ops_api_cost.get_original_tx_hash);
         self.handler.get_tx_hash(result_handle);
     }
 
@@ -644,10 +689,12 @@ impl VMHooks for VMHooksDispatcher {
     }
 
     fn managed_get_block_random_seed(&self, result_handle: i32) {
+        self.use_gas(|gas_schedule| gas_schedule.base_ops_api_cost.get_block_random_seed);
         self.handler.get_block_random_seed(result_handle);
     }
 
     fn managed_get_prev_block_random_seed(&self, result_handle: i32) {
+        self.use_gas(|gas_schedule| gas_schedule.base_ops_api_cost.get_block_random_seed);
         self.handler.get_prev_block_random_seed(result_handle);
     }
 
@@ -656,6 +703,7 @@ impl VMHooks for VMHooksDispatcher {
     }
 
     fn managed_get_multi_dct_call_value(&self, multi_call_value_handle: i32) {
+        self.use_gas(|gas_schedule| gas_schedule.base_ops_api_cost.get_call_value);
         self.handler
             .load_all_dct_transfers(multi_call_value_handle)
     }
@@ -684,6 +732,7 @@ impl VMHooks for VMHooksDispatcher {
         royalties_handle: i32,
         uris_handle: i32,
     ) {
+        self.use_gas(|gas_schedule| gas_schedule.base_ops_api_cost.get_external_balance);
         self.handler.managed_get_dct_token_data(
             address_handle,
             token_id_handle,
@@ -706,6 +755,7 @@ impl VMHooks for VMHooksDispatcher {
         function_handle: i32,
         arguments_handle: i32,
     ) {
+        self.use_gas(|gas_schedule| gas_schedule.base_ops_api_cost.async_call_step);
         self.handler
             .async_call_raw(dest_handle, value_handle, function_handle, arguments_handle)
     }
@@ -724,6 +774,7 @@ impl VMHooks for VMHooksDispatcher {
         extra_gas_for_callback: i64,
         callback_closure_handle: i32,
     ) -> i32 {
+        self.use_gas(|gas_schedule| gas_schedule.base_ops_api_cost.async_call_step);
         unsafe {
             mem_conv::with_bytes(success_offset, success_length, |success_callback| {
                 mem_conv::with_bytes(error_offset, error_length, |error_callback| {
@@ -745,6 +796,7 @@ impl VMHooks for VMHooksDispatcher {
     }
 
     fn managed_get_callback_closure(&self, callback_closure_handle: i32) {
+        self.use_gas(|gas_schedule| gas_schedule.base_ops_api_cost.get_argument);
         self.handler
             .load_callback_closure_buffer(callback_closure_handle)
     }
@@ -759,6 +811,7 @@ impl VMHooks for VMHooksDispatcher {
         arguments_handle: i32,
         _result_handle: i32,
     ) {
+        self.use_gas(|gas_schedule| gas_schedule.base_ops_api_cost.create_contract);
         self.handler.upgrade_from_source_contract(
             dest_handle,
             gas as u64,
@@ -779,6 +832,7 @@ impl VMHooks for VMHooksDispatcher {
         arguments_handle: i32,
         _result_handle: i32,
     ) {
+        self.use_gas(|gas_schedule| gas_schedule.base_ops_api_cost.create_contract);
         self.handler.upgrade_contract(
             dest_handle,
             gas as u64,
@@ -803,6 +857,7 @@ impl VMHooks for VMHooksDispatcher {
         result_address_handle: i32,
         result_handle: i32,
     ) -> i32 {
+        self.use_gas(|gas_schedule| gas_schedule.base_ops_api_cost.create_contract);
         self.handler.deploy_from_source_contract(
             gas as u64,
             value_handle,
@@ -825,6 +880,7 @@ impl VMHooks for VMHooksDispatcher {
         result_address_handle: i32,
         result_handle: i32,
     ) -> i32 {
+        self.use_gas(|gas_schedule| gas_schedule.base_ops_api_cost.create_contract);
         self.handler.deploy_contract(
             gas as u64,
             value_handle,
@@ -869,6 +925,7 @@ impl VMHooks for VMHooksDispatcher {
         arguments_handle: i32,
         result_handle: i32,
     ) -> i32 {
+        self.use_gas(|gas_schedule| gas_schedule.base_ops_api_cost.execute_on_dest_context);
         self.handler.execute_on_dest_context_raw(
             gas as u64,
             address_handle,
@@ -888,6 +945,7 @@ impl VMHooks for VMHooksDispatcher {
         function_handle: i32,
         arguments_handle: i32,
     ) -> i32 {
+        self.use_gas(|gas_schedule| gas_schedule.base_ops_api_cost.transfer_value);
         self.handler.multi_transfer_dct_nft_execute(
             dst_handle,
             token_transfers_handle,
@@ -906,6 +964,7 @@ impl VMHooks for VMHooksDispatcher {
         function_handle: i32,
         arguments_handle: i32,
     ) -> i32 {
+        self.use_gas(|gas_schedule| gas_schedule.base_ops_api_cost.transfer_value);
         self.handler.transfer_value_execute(
             dst_handle,
             value_handle,
@@ -917,6 +976,7 @@ impl VMHooks for VMHooksDispatcher {
     }
 
     fn managed_is_dct_frozen(&self, address_handle: i32, token_id_handle: i32, nonce: i64) -> i32 {
+        self.use_gas(|gas_schedule| gas_schedule.base_ops_api_cost.storage_load);
         bool_to_i32(
             self.handler
                 .check_dct_frozen(address_handle, token_id_handle, nonce as u64),
@@ -924,14 +984,17 @@ impl VMHooks for VMHooksDispatcher {
     }
 
     fn managed_is_dct_limited_transfer(&self, _token_id_handle: i32) -> i32 {
+        self.use_gas(|gas_schedule| gas_schedule.base_ops_api_cost.storage_load);
         bool_to_i32(false)
     }
 
-    fn managed_is_dct_paused(&self, _token_id_handle: i32) -> i32 {
-        bool_to_i32(false)
+    fn managed_is_dct_paused(&self, token_id_handle: i32) -> i32 {
+        self.use_gas(|gas_schedule| gas_schedule.base_ops_api_cost.storage_load);
+        bool_to_i32(self.handler.check_dct_paused(token_id_handle))
     }
 
     fn managed_buffer_to_hex(&self, source_handle: i32, dest_handle: i32) {
+        self.use_gas(|gas_schedule| gas_schedule.managed_buffer_api_cost.m_buffer_set_bytes);
         self.handler.mb_to_hex(source_handle, dest_handle);
     }
 
@@ -949,95 +1012,117 @@ impl VMHooks for VMHooksDispatcher {
         fractional_part: i32,
         exponent: i32,
     ) -> i32 {
+        self.use_gas(|gas_schedule| gas_schedule.big_float_api_cost.big_float_new_from_parts);
         self.handler
             .bf_from_parts(integral_part, fractional_part, exponent)
     }
 
     fn big_float_new_from_frac(&self, numerator: i64, denominator: i64) -> i32 {
+        self.use_gas(|gas_schedule| gas_schedule.big_float_api_cost.big_float_new_from_parts);
         self.handler.bf_from_frac(numerator, denominator)
     }
 
     fn big_float_new_from_sci(&self, significand: i64, exponent: i64) -> i32 {
+        self.use_gas(|gas_schedule| gas_schedule.big_float_api_cost.big_float_new_from_parts);
         self.handler.bf_from_sci(significand, exponent)
     }
 
     fn big_float_add(&self, destination_handle: i32, op1_handle: i32, op2_handle: i32) {
+        self.use_gas(|gas_schedule| gas_schedule.big_float_api_cost.big_float_add);
         self.handler
             .bf_add(destination_handle, op1_handle, op2_handle);
     }
 
     fn big_float_sub(&self, destination_handle: i32, op1_handle: i32, op2_handle: i32) {
+        self.use_gas(|gas_schedule| gas_schedule.big_float_api_cost.big_float_sub);
         self.handler
             .bf_sub(destination_handle, op1_handle, op2_handle);
     }
 
     fn big_float_mul(&self, destination_handle: i32, op1_handle: i32, op2_handle: i32) {
+        self.use_gas(|gas_schedule| gas_schedule.big_float_api_cost.big_float_mul);
         self.handler
             .bf_mul(destination_handle, op1_handle, op2_handle);
     }
 
     fn big_float_div(&self, destination_handle: i32, op1_handle: i32, op2_handle: i32) {
+        self.use_gas(|gas_schedule| gas_schedule.big_float_api_cost.big_float_div);
         self.handler
             .bf_div(destination_handle, op1_handle, op2_handle);
     }
 
     fn big_float_neg(&self, destination_handle: i32, op_handle: i32) {
+        self.use_gas(|gas_schedule| gas_schedule.big_float_api_cost.big_float_neg);
         self.handler.bf_neg(destination_handle, op_handle);
     }
 
     fn big_float_clone(&self, destination_handle: i32, op_handle: i32) {
+        self.use_gas(|gas_schedule| gas_schedule.big_float_api_cost.big_float_clone);
         self.handler.bf_clone(destination_handle, op_handle);
     }
 
     fn big_float_cmp(&self, op1_handle: i32, op2_handle: i32) -> i32 {
+        self.use_gas(|gas_schedule| gas_schedule.big_float_api_cost.big_float_cmp);
         self.handler.bf_cmp(op1_handle, op2_handle)
     }
 
     fn big_float_abs(&self, destination_handle: i32, op_handle: i32) {
+        self.use_gas(|gas_schedule| gas_schedule.big_float_api_cost.big_float_abs);
         self.handler.bf_abs(destination_handle, op_handle);
     }
 
     fn big_float_sign(&self, op_handle: i32) -> i32 {
+        self.use_gas(|gas_schedule| gas_schedule.big_float_api_cost.big_float_cmp);
         self.handler.bf_sign(op_handle)
     }
 
     fn big_float_sqrt(&self, destination_handle: i32, op_handle: i32) {
+        self.use_gas(|gas_schedule| gas_schedule.big_float_api_cost.big_float_sqrt);
         self.handler.bf_sqrt(destination_handle, op_handle);
     }
 
     fn big_float_pow(&self, destination_handle: i32, op_handle: i32, exponent: i32) {
+        self.use_gas(|gas_schedule| gas_schedule.big_float_api_cost.big_float_pow);
         self.handler.bf_pow(destination_handle, op_handle, exponent);
     }
 
     fn big_float_floor(&self, dest_big_int_handle: i32, op_handle: i32) {
+        self.use_gas(|gas_schedule| gas_schedule.big_float_api_cost.big_float_floor);
         self.handler.bf_floor(dest_big_int_handle, op_handle);
     }
 
     fn big_float_ceil(&self, dest_big_int_handle: i32, op_handle: i32) {
+        self.use_gas(|gas_schedule| gas_schedule.big_float_api_cost.big_float_ceil);
         self.handler.bf_ceil(dest_big_int_handle, op_handle);
     }
 
     fn big_float_truncate(&self, dest_big_int_handle: i32, op_handle: i32) {
+        self.use_gas(|gas_schedule| gas_schedule.big_float_api_cost.big_float_truncate);
         self.handler.bf_trunc(dest_big_int_handle, op_handle);
     }
 
     fn big_float_set_int64(&self, destination_handle: i32, value: i64) {
+        self.use_gas(|gas_schedule| gas_schedule.big_float_api_cost.big_float_set_int64);
         self.handler.bf_set_i64(destination_handle, value);
     }
 
     fn big_float_is_int(&self, op_handle: i32) -> i32 {
+        self.use_gas(|gas_schedule| gas_schedule.big_float_api_cost.big_float_is_int);
         bool_to_i32(self.handler.bf_is_bi(op_handle))
     }
 
     fn big_float_set_big_int(&self, destination_handle: i32, big_int_handle: i32) {
+        self.use_gas(|gas_schedule| gas_schedule.big_float_api_cost.big_float_set_big_int);
         self.handler.bf_set_bi(destination_handle, big_int_handle);
     }
 
     fn big_float_get_const_pi(&self, destination_handle: i32) {
+        self.use_gas(|gas_schedule| gas_schedule.big_float_api_cost.big_float_get_const);
         self.handler.bf_get_const_pi(destination_handle);
     }
 
     fn big_float_get_const_e(&self, destination_handle: i32) {
+        self.use_gas(|gas_schedule| gas_schedule.big_float_api_cost.big_float_get_const);
         self.handler.bf_get_const_e(destination_handle);
     }
 
@@ -1068,6 +1153,7 @@ impl VMHooks for VMHooksDispatcher {
     }
 
     fn big_int_get_call_value(&self, destination_handle: i32) {
+        self.use_gas(|gas_schedule| gas_schedule.big_int_api_cost.big_int_get_call_value);
         self.handler.load_moax_value(destination_handle);
     }
 
@@ -1080,6 +1166,7 @@ impl VMHooks for VMHooksDispatcher {
     }
 
     fn big_int_get_external_balance(&self, address_offset: MemPtr, result: i32) {
+        self.use_gas(|gas_schedule| gas_schedule.big_int_api_cost.big_int_get_external_balance);
         unsafe {
             mem_conv::with_bytes(address_offset, 32, |address_bytes| {
                 self.handler.load_balance(address_bytes, result);
@@ -1095,6 +1182,7 @@ impl VMHooks for VMHooksDispatcher {
         nonce: i64,
         result_handle: i32,
     ) {
+        self.use_gas(|gas_schedule| gas_schedule.big_int_api_cost.big_int_get_external_balance);
         unsafe {
             mem_conv::with_bytes(address_offset, 32, |address_bytes| {
                 mem_conv::with_bytes(token_id_offset, token_id_len, |token_id_bytes| {
@@ -1110,6 +1198,7 @@ impl VMHooks for VMHooksDispatcher {
     }
 
     fn big_int_new(&self, small_value: i64) -> i32 {
+        self.use_gas(|gas_schedule| gas_schedule.big_int_api_cost.big_int_new);
         self.handler.bi_new(small_value)
     }
 
@@ -1135,6 +1224,7 @@ impl VMHooks for VMHooksDispatcher {
         byte_offset: MemPtr,
         byte_length: MemLength,
     ) {
+        self.use_gas(|gas_schedule| gas_schedule.big_int_api_cost.big_int_set_unsigned_bytes);
         unsafe {
             mem_conv::with_bytes_mut(byte_offset, byte_length, |bytes| {
                 self.handler
@@ -1149,6 +1239,7 @@ impl VMHooks for VMHooksDispatcher {
         byte_offset: MemPtr,
         byte_length: MemLength,
     ) {
+        self.use_gas(|gas_schedule| gas_schedule.big_int_api_cost.big_int_set_signed_bytes);
         unsafe {
             mem_conv::with_bytes_mut(byte_offset, byte_length, |bytes| {
                 self.handler.bi_set_signed_bytes(destination_handle, bytes);
@@ -1157,76 +1248,93 @@ impl VMHooks for VMHooksDispatcher {
     }
 
     fn big_int_is_int64(&self, destination_handle: i32) -> i32 {
+        self.use_gas(|gas_schedule| gas_schedule.big_int_api_cost.big_int_is_int64);
         self.handler.bi_is_int64(destination_handle)
     }
 
     fn big_int_get_int64(&self, destination_handle: i32) -> i64 {
+        self.use_gas(|gas_schedule| gas_schedule.big_int_api_cost.big_int_get_int64);
         self.handler.bi_get_int64(destination_handle)
     }
 
     fn big_int_set_int64(&self, destination_handle: i32, value: i64) {
+        self.use_gas(|gas_schedule| gas_schedule.big_int_api_cost.big_int_set_int64);
         self.handler.bi_set_int64(destination_handle, value);
     }
 
     fn big_int_add(&self, destination_handle: i32, op1_handle: i32, op2_handle: i32) {
+        self.use_gas(|gas_schedule| gas_schedule.big_int_api_cost.big_int_add);
         self.handler
             .bi_add(destination_handle, op1_handle, op2_handle);
     }
 
     fn big_int_sub(&self, destination_handle: i32, op1_handle: i32, op2_handle: i32) {
+        self.use_gas(|gas_schedule| gas_schedule.big_int_api_cost.big_int_sub);
         self.handler
             .bi_sub(destination_handle, op1_handle, op2_handle);
     }
 
     fn big_int_mul(&self, destination_handle: i32, op1_handle: i32, op2_handle: i32) {
+        self.use_gas(|gas_schedule| gas_schedule.big_int_api_cost.big_int_mul);
         self.handler
             .bi_mul(destination_handle, op1_handle, op2_handle);
     }
 
     fn big_int_tdiv(&self, destination_handle: i32, op1_handle: i32, op2_handle: i32) {
+        self.use_gas(|gas_schedule| gas_schedule.big_int_api_cost.big_int_t_div);
         self.handler
             .bi_t_div(destination_handle, op1_handle, op2_handle);
     }
 
     fn big_int_tmod(&self, destination_handle: i32, op1_handle: i32, op2_handle: i32) {
+        self.use_gas(|gas_schedule| gas_schedule.big_int_api_cost.big_int_t_mod);
         self.handler
             .bi_t_mod(destination_handle, op1_handle, op2_handle);
     }
 
     fn big_int_ediv(&self, destination_handle: i32, op1_handle: i32, op2_handle: i32) {
+        self.use_gas(|gas_schedule| gas_schedule.big_int_api_cost.big_int_e_div);
         panic!("Not supported: big_int_ediv");
     }
 
     fn big_int_emod(&self, destination_handle: i32, op1_handle: i32, op2_handle: i32) {
+        self.use_gas(|gas_schedule| gas_schedule.big_int_api_cost.big_int_e_mod);
         panic!("Not supported: big_int_emod");
     }
 
     fn big_int_sqrt(&self, destination_handle: i32, op_handle: i32) {
+        self.use_gas(|gas_schedule| gas_schedule.big_int_api_cost.big_int_sqrt);
         self.handler.bi_sqrt(destination_handle, op_handle);
     }
 
     fn big_int_pow(&self, destination_handle: i32, op1_handle: i32, op2_handle: i32) {
+        self.use_gas(|gas_schedule| gas_schedule.big_int_api_cost.big_int_pow);
         self.handler
             .bi_pow(destination_handle, op1_handle, op2_handle);
     }
 
     fn big_int_log2(&self, op_handle: i32) -> i32 {
+        self.use_gas(|gas_schedule| gas_schedule.big_int_api_cost.big_int_log);
         self.handler.bi_log2(op_handle)
     }
 
     fn big_int_abs(&self, destination_handle: i32, op_handle: i32) {
+        self.use_gas(|gas_schedule| gas_schedule.big_int_api_cost.big_int_abs);
         self.handler.bi_abs(destination_handle, op_handle);
     }
 
     fn big_int_neg(&self, destination_handle: i32, op_handle: i32) {
+        self.use_gas(|gas_schedule| gas_schedule.big_int_api_cost.big_int_neg);
         self.handler.bi_neg(destination_handle, op_handle);
     }
 
     fn big_int_sign(&self, op_handle: i32) -> i32 {
+        self.use_gas(|gas_schedule| gas_schedule.big_int_api_cost.big_int_sign);
         self.handler.bi_sign(op_handle)
     }
 
     fn big_int_cmp(&self, op1_handle: i32, op2_handle: i32) -> i32 {
+        self.use_gas(|gas_schedule| gas_schedule.big_int_api_cost.big_int_cmp);
         self.handler.bi_cmp(op1_handle, op2_handle)
     }
 
@@ -1235,48 +1343,58 @@ impl VMHooks for VMHooksDispatcher {
     }
 
     fn big_int_and(&self, destination_handle: i32, op1_handle: i32, op2_handle: i32) {
+        self.use_gas(|gas_schedule| gas_schedule.big_int_api_cost.big_int_and);
         self.handler
             .bi_and(destination_handle, op1_handle, op2_handle);
     }
 
     fn big_int_or(&self, destination_handle: i32, op1_handle: i32, op2_handle: i32) {
+        self.use_gas(|gas_schedule| gas_schedule.big_int_api_cost.big_int_or);
         self.handler
             .bi_or(destination_handle, op1_handle, op2_handle);
     }
 
     fn big_int_xor(&self, destination_handle: i32, op1_handle: i32, op2_handle: i32) {
+        self.use_gas(|gas_schedule| gas_schedule.big_int_api_cost.big_int_xor);
         self.handler
             .bi_xor(destination_handle, op1_handle, op2_handle);
     }
 
     fn big_int_shr(&self, destination_handle: i32, op_handle: i32, bits: i32) {
+        self.use_gas(|gas_schedule| gas_schedule.big_int_api_cost.big_int_shr);
         self.handler
             .bi_shr(destination_handle, op_handle, bits as usize);
     }
 
     fn big_int_shl(&self, destination_handle: i32, op_handle: i32, bits: i32) {
+        self.use_gas(|gas_schedule| gas_schedule.big_int_api_cost.big_int_shl);
         self.handler
             .bi_shl(destination_handle, op_handle, bits as usize);
     }
 
     fn big_int_finish_unsigned(&self, reference_handle: i32) {
+        self.use_gas(|gas_schedule| gas_schedule.big_int_api_cost.big_int_finish_unsigned);
         self.handler.finish_big_uint_raw(reference_handle);
     }
 
     fn big_int_finish_signed(&self, reference_handle: i32) {
+        self.use_gas(|gas_schedule| gas_schedule.big_int_api_cost.big_int_finish_signed);
         self.handler.finish_big_int_raw(reference_handle);
     }
 
     fn big_int_to_string(&self, big_int_handle: i32, destination_handle: i32) {
+        self.use_gas(|gas_schedule| gas_schedule.big_int_api_cost.big_int_get_signed_bytes);
         self.handler
             .bi_to_string(big_int_handle, destination_handle);
     }
 
     fn mbuffer_new(&self) -> i32 {
+        self.use_gas(|gas_schedule| gas_schedule.managed_buffer_api_cost.m_buffer_new);
         self.handler.mb_new_empty()
     }
 
     fn mbuffer_new_from_bytes(&self, data_offset: MemPtr, data_length: MemLength) -> i32 {
+        self.use_gas(|gas_schedule| gas_schedule.managed_buffer_api_cost.m_buffer_new_from_bytes);
         unsafe {
             mem_conv::with_bytes_mut(data_offset, data_length, |bytes| {
                 self.handler.mb_new_from_bytes(bytes)
@@ -1285,10 +1403,12 @@ impl VMHooks for VMHooksDispatcher {
     }
 
     fn mbuffer_get_length(&self, m_buffer_handle: i32) -> i32 {
+        self.use_gas(|gas_schedule| gas_schedule.managed_buffer_api_cost.m_buffer_get_length);
         self.handler.mb_len(m_buffer_handle) as i32
     }
 
     fn mbuffer_get_bytes(&self, m_buffer_handle: i32, result_offset: MemPtr) -> i32 {
+        self.use_gas(|gas_schedule| gas_schedule.managed_buffer_api_cost.m_buffer_get_bytes);
         unsafe {
             self.handler
                 .mb_copy_bytes(m_buffer_handle, result_offset as *mut u8) as i32
@@ -1302,6 +1422,7 @@ impl VMHooks for VMHooksDispatcher {
         slice_length: i32,
         result_offset: MemPtr,
     ) -> i32 {
+        self.use_gas(|gas_schedule| gas_schedule.managed_buffer_api_cost.m_buffer_get_byte_slice);
         unsafe {
             mem_conv::with_bytes_mut(result_offset, slice_length as isize, |bytes| {
                 self.handler
@@ -1317,6 +1438,11 @@ impl VMHooks for VMHooksDispatcher {
         slice_length: i32,
         destination_handle: i32,
     ) -> i32 {
+        self.use_gas(|gas_schedule| {
+            gas_schedule
+                .managed_buffer_api_cost
+                .m_buffer_copy_byte_slice
+        });
         self.handler.mb_copy_slice(
             source_handle,
             starting_position as usize,
@@ -1326,6 +1452,7 @@ impl VMHooks for VMHooksDispatcher {
     }
 
     fn mbuffer_eq(&self, m_buffer_handle1: i32, m_buffer_handle2: i32) -> i32 {
+        self.use_gas(|gas_schedule| gas_schedule.managed_buffer_api_cost.m_buffer_get_length);
         self.handler.mb_eq(m_buffer_handle1, m_buffer_handle2)
     }
 
@@ -1335,6 +1462,7 @@ impl VMHooks for VMHooksDispatcher {
         data_offset: MemPtr,
         data_length: MemLength,
     ) -> i32 {
+        self.use_gas(|gas_schedule| gas_schedule.managed_buffer_api_cost.m_buffer_set_bytes);
         unsafe {
             mem_conv::with_bytes(data_offset, data_length, |bytes| {
                 self.handler.mb_set(m_buffer_handle, bytes);
@@ -1350,6 +1478,7 @@ impl VMHooks for VMHooksDispatcher {
         data_length: MemLength,
         data_offset: MemPtr,
     ) -> i32 {
+        self.use_gas(|gas_schedule| gas_schedule.managed_buffer_api_cost.m_buffer_set_bytes);
         unsafe {
             mem_conv::with_bytes(data_offset, data_length, |bytes| {
                 self.handler
@@ -1359,6 +1488,7 @@ impl VMHooks for VMHooksDispatcher {
     }
 
     fn mbuffer_append(&self, accumulator_handle: i32, data_handle: i32) -> i32 {
+        self.use_gas(|gas_schedule| gas_schedule.managed_buffer_api_cost.m_buffer_append);
         self.handler.mb_append(accumulator_handle, data_handle);
         0
     }
@@ -1369,6 +1499,7 @@ impl VMHooks for VMHooksDispatcher {
         data_offset: MemPtr,
         data_length: MemLength,
     ) -> i32 {
+        self.use_gas(|gas_schedule| gas_schedule.managed_buffer_api_cost.m_buffer_append_bytes);
         unsafe {
             mem_conv::with_bytes(data_offset, data_length, |bytes| {
                 self.handler.mb_append_bytes(accumulator_handle, bytes);
@@ -1378,24 +1509,44 @@ impl VMHooks for VMHooksDispatcher {
     }
 
     fn mbuffer_to_big_int_unsigned(&self, m_buffer_handle: i32, big_int_handle: i32) -> i32 {
+        self.use_gas(|gas_schedule| {
+            gas_schedule
+                .managed_buffer_api_cost
+                .m_buffer_to_big_int_unsigned
+        });
         self.handler
             .mb_to_big_int_unsigned(m_buffer_handle, big_int_handle);
         0
     }
 
     fn mbuffer_to_big_int_signed(&self, m_buffer_handle: i32, big_int_handle: i32) -> i32 {
+        self.use_gas(|gas_schedule| {
+            gas_schedule
+                .managed_buffer_api_cost
+                .m_buffer_to_big_int_signed
+        });
         self.handler
             .mb_to_big_int_signed(m_buffer_handle, big_int_handle);
         0
     }
 
     fn mbuffer_from_big_int_unsigned(&self, m_buffer_handle: i32, big_int_handle: i32) -> i32 {
+        self.use_gas(|gas_schedule| {
+            gas_schedule
+                .managed_buffer_api_cost
+                .m_buffer_from_big_int_unsigned
+        });
         self.handler
             .mb_from_big_int_unsigned(m_buffer_handle, big_int_handle);
         0
     }
 
     fn mbuffer_from_big_int_signed(&self, m_buffer_handle: i32, big_int_handle: i32) -> i32 {
+        self.use_gas(|gas_schedule| {
+            gas_schedule
+                .managed_buffer_api_cost
+                .m_buffer_from_big_int_signed
+        });
         self.handler
             .mb_from_big_int_signed(m_buffer_handle, big_int_handle);
         0
@@ -1410,12 +1561,14 @@ impl VMHooks for VMHooksDispatcher {
     }
 
     fn mbuffer_storage_store(&self, key_handle: i32, source_handle: i32) -> i32 {
+        self.use_gas(|gas_schedule| gas_schedule.managed_buffer_api_cost.m_buffer_storage_store);
         self.handler
             .storage_store_managed_buffer_raw(key_handle, source_handle);
         0
     }
 
     fn mbuffer_storage_load(&self, key_handle: i32, destination_handle: i32) -> i32 {
+        self.use_gas(|gas_schedule| gas_schedule.managed_buffer_api_cost.m_buffer_storage_load);
         self.handler
             .storage_load_managed_buffer_raw(key_handle, destination_handle);
         0
@@ -1427,65 +1580,78 @@ impl VMHooks for VMHooksDispatcher {
         key_handle: i32,
         destination_handle: i32,
     ) {
+        self.use_gas(|gas_schedule| gas_schedule.managed_buffer_api_cost.m_buffer_storage_load);
         self.handler
             .storage_load_from_address(address_handle, key_handle, destination_handle);
     }
 
     fn mbuffer_get_argument(&self, id: i32, destination_handle: i32) -> i32 {
+        self.use_gas(|gas_schedule| gas_schedule.managed_buffer_api_cost.m_buffer_get_argument);
         self.handler
             .load_argument_managed_buffer(id, destination_handle);
         0
     }
 
     fn mbuffer_finish(&self, source_handle: i32) -> i32 {
+        self.use_gas(|gas_schedule| gas_schedule.managed_buffer_api_cost.m_buffer_finish);
         self.handler.finish_managed_buffer_raw(source_handle);
         0
     }
 
     fn mbuffer_set_random(&self, destination_handle: i32, length: i32) -> i32 {
+        self.use_gas(|gas_schedule| gas_schedule.managed_buffer_api_cost.m_buffer_set_random);
         self.handler
             .mb_set_random(destination_handle, length as usize);
         0
     }
 
     fn managed_map_new(&self) -> i32 {
+        self.use_gas(|gas_schedule| gas_schedule.managed_map_api_cost.managed_map_new);
         self.handler.mm_new()
     }
 
     fn managed_map_put(&self, map_handle: i32, key_handle: i32, value_handle: i32) -> i32 {
+        self.use_gas(|gas_schedule| gas_schedule.managed_map_api_cost.managed_map_put);
         self.handler.mm_put(map_handle, key_handle, value_handle);
         0
     }
 
     fn managed_map_get(&self, map_handle: i32, key_handle: i32, out_value_handle: i32) -> i32 {
+        self.use_gas(|gas_schedule| gas_schedule.managed_map_api_cost.managed_map_get);
         self.handler
             .mm_get(map_handle, key_handle, out_value_handle);
         0
     }
 
     fn managed_map_remove(&self, map_handle: i32, key_handle: i32, out_value_handle: i32) -> i32 {
+        self.use_gas(|gas_schedule| gas_schedule.managed_map_api_cost.managed_map_remove);
         self.handler
             .mm_remove(map_handle, key_handle, out_value_handle);
         0
     }
 
     fn managed_map_contains(&self, map_handle: i32, key_handle: i32) -> i32 {
+        self.use_gas(|gas_schedule| gas_schedule.managed_map_api_cost.managed_map_contains);
         bool_to_i32(self.handler.mm_contains(map_handle, key_handle))
     }
 
     fn small_int_get_unsigned_argument(&self, id: i32) -> i64 {
+        self.use_gas(|gas_schedule| gas_schedule.base_ops_api_cost.int64_get_argument);
         self.handler.get_argument_u64(id) as i64
     }
 
     fn small_int_get_signed_argument(&self, id: i32) -> i64 {
+        self.use_gas(|gas_schedule| gas_schedule.base_ops_api_cost.int64_get_argument);
         self.handler.get_argument_i64(id)
     }
 
     fn small_int_finish_unsigned(&self, value: i64) {
+        self.use_gas(|gas_schedule| gas_schedule.base_ops_api_cost.int64_finish);
         self.handler.finish_u64(value as u64);
     }
 
     fn small_int_finish_signed(&self, value: i64) {
+        self.use_gas(|gas_schedule| gas_schedule.base_ops_api_cost.int64_finish);
         self.handler.finish_i64(value);
     }
 
@@ -1536,6 +1702,7 @@ impl VMHooks for VMHooksDispatcher {
     }
 
     fn managed_sha256(&self, input_handle: i32, output_handle: i32) -> i32 {
+        self.use_gas(|gas_schedule| gas_schedule.crypto_api_cost.sha256);
         self.handler.sha256_managed(output_handle, input_handle);
         0
     }
@@ -1545,6 +1712,7 @@ impl VMHooks for VMHooksDispatcher {
     }
 
     fn managed_keccak256(&self, input_handle: i32, output_handle: i32) -> i32 {
+        self.use_gas(|gas_schedule| gas_schedule.crypto_api_cost.keccak256);
         self.handler.keccak256_managed(output_handle, input_handle);
         0
     }
@@ -1554,7 +1722,9 @@ impl VMHooks for VMHooksDispatcher {
     }
 
     fn managed_ripemd160(&self, input_handle: i32, output_handle: i32) -> i32 {
-        panic!("Unavailable: managed_ripemd160")
+        self.use_gas(|gas_schedule| gas_schedule.crypto_api_cost.ripemd160);
+        self.handler.ripemd160_managed(output_handle, input_handle);
+        0
     }
 
     fn verify_bls(
@@ -1568,7 +1738,11 @@ impl VMHooks for VMHooksDispatcher {
     }
 
     fn managed_verify_bls(&self, key_handle: i32, message_handle: i32, sig_handle: i32) -> i32 {
-        panic!("Unavailable: managed_verify_bls")
+        self.use_gas(|gas_schedule| gas_schedule.crypto_api_cost.verify_bls);
+        bool_to_result(
+            self.handler
+                .verify_bls_managed(key_handle, message_handle, sig_handle),
+        )
     }
 
     fn verify_ed25519(
@@ -1582,6 +1756,7 @@ impl VMHooks for VMHooksDispatcher {
     }
 
     fn managed_verify_ed25519(&self, key_handle: i32, message_handle: i32, sig_handle: i32) -> i32 {
+        self.use_gas(|gas_schedule| gas_schedule.crypto_api_cost.verify_ed25519);
         self.handler
             .verify_ed25519_managed(key_handle, message_handle, sig_handle);
         0
@@ -1606,7 +1781,13 @@ impl VMHooks for VMHooksDispatcher {
         sig_handle: i32,
         hash_type: i32,
     ) -> i32 {
-        panic!("Unavailable: managed_verify_custom_secp256k1")
+        self.use_gas(|gas_schedule| gas_schedule.crypto_api_cost.verify_secp256k1);
+        bool_to_result(self.handler.verify_custom_secp256k1_managed(
+            key_handle,
+            message_handle,
+            sig_handle,
+            hash_type as u8,
+        ))
     }
 
     fn verify_secp256k1(
@@ -1626,7 +1807,12 @@ impl VMHooks for VMHooksDispatcher {
         message_handle: i32,
         sig_handle: i32,
     ) -> i32 {
-        panic!("Unavailable: managed_verify_secp256k1")
+        self.use_gas(|gas_schedule| gas_schedule.crypto_api_cost.verify_secp256k1);
+        bool_to_result(self.handler.verify_secp256k1_managed(
+            key_handle,
+            message_handle,
+            sig_handle,
+        ))
     }
 
     fn encode_secp256k1_der_signature(
@@ -1646,7 +1832,10 @@ impl VMHooks for VMHooksDispatcher {
         s_handle: i32,
         sig_handle: i32,
     ) -> i32 {
-        panic!("Unavailable: managed_encode_secp256k1_der_signature")
+        self.use_gas(|gas_schedule| gas_schedule.crypto_api_cost.encode_der_sig);
+        self.handler
+            .encode_secp256k1_der_signature_managed(r_handle, s_handle, sig_handle);
+        0
     }
 
     fn add_ec(
@@ -1659,7 +1848,16 @@ impl VMHooks for VMHooksDispatcher {
         snd_point_xhandle: i32,
         snd_point_yhandle: i32,
     ) {
-        panic!("Unavailable: add_ec");
+        self.use_gas(|gas_schedule| gas_schedule.crypto_api_cost.add_ecc);
+        self.handler.ec_add(
+            x_result_handle,
+            y_result_handle,
+            ec_handle,
+            fst_point_xhandle,
+            fst_point_yhandle,
+            snd_point_xhandle,
+            snd_point_yhandle,
+        );
     }
 
     fn double_ec(
@@ -1670,11 +1868,22 @@ impl VMHooks for VMHooksDispatcher {
         point_xhandle: i32,
         point_yhandle: i32,
     ) {
-        panic!("Unavailable: double_ec");
+        self.use_gas(|gas_schedule| gas_schedule.crypto_api_cost.double_ecc);
+        self.handler.ec_double(
+            x_result_handle,
+            y_result_handle,
+            ec_handle,
+            point_xhandle,
+            point_yhandle,
+        );
     }
 
     fn is_on_curve_ec(&self, ec_handle: i32, point_xhandle: i32, point_yhandle: i32) -> i32 {
-        panic!("Unavailable: is_on_curve_ec")
+        self.use_gas(|gas_schedule| gas_schedule.crypto_api_cost.is_on_curve_ecc);
+        bool_to_i32(
+            self.handler
+                .ec_is_on_curve(ec_handle, point_xhandle, point_yhandle),
+        )
     }
 
     fn scalar_base_mult_ec(
@@ -1695,7 +1904,10 @@ impl VMHooks for VMHooksDispatcher {
         ec_handle: i32,
         data_handle: i32,
     ) -> i32 {
-        panic!("Unavailable: managed_scalar_base_mult_ec")
+        self.use_gas(|gas_schedule| gas_schedule.crypto_api_cost.scalar_mult_ecc);
+        self.handler
+            .ec_scalar_base_mult(x_result_handle, y_result_handle, ec_handle, data_handle);
+        0
     }
 
     fn scalar_mult_ec(
@@ -1720,7 +1932,16 @@ impl VMHooks for VMHooksDispatcher {
         point_yhandle: i32,
         data_handle: i32,
     ) -> i32 {
-        panic!("Unavailable: managed_scalar_mult_ec")
+        self.use_gas(|gas_schedule| gas_schedule.crypto_api_cost.scalar_mult_ecc);
+        self.handler.ec_scalar_mult(
+            x_result_handle,
+            y_result_handle,
+            ec_handle,
+            point_xhandle,
+            point_yhandle,
+            data_handle,
+        );
+        0
     }
 
     fn marshal_ec(
@@ -1740,7 +1961,10 @@ impl VMHooks for VMHooksDispatcher {
         ec_handle: i32,
         result_handle: i32,
     ) -> i32 {
-        panic!("Unavailable: managed_marshal_ec")
+        self.use_gas(|gas_schedule| gas_schedule.crypto_api_cost.marshal_ecc);
+        self.handler
+            .ec_marshal(ec_handle, x_pair_handle, y_pair_handle, result_handle);
+        0
     }
 
     fn marshal_compressed_ec(
@@ -1760,7 +1984,10 @@ impl VMHooks for VMHooksDispatcher {
         ec_handle: i32,
         result_handle: i32,
     ) -> i32 {
-        panic!("Unavailable: managed_marshal_compressed_ec")
+        self.use_gas(|gas_schedule| gas_schedule.crypto_api_cost.marshal_compressed_ecc);
+        self.handler
+            .ec_marshal_compressed(ec_handle, x_pair_handle, y_pair_handle, result_handle);
+        0
     }
 
     fn unmarshal_ec(
@@ -1781,7 +2008,10 @@ impl VMHooks for VMHooksDispatcher {
         ec_handle: i32,
         data_handle: i32,
     ) -> i32 {
-        panic!("Unavailable: managed_unmarshal_ec")
+        self.use_gas(|gas_schedule| gas_schedule.crypto_api_cost.unmarshal_ecc);
+        self.handler
+            .ec_unmarshal(x_result_handle, y_result_handle, ec_handle, data_handle);
+        0
     }
 
     fn unmarshal_compressed_ec(
@@ -1802,7 +2032,14 @@ impl VMHooks for VMHooksDispatcher {
         ec_handle: i32,
         data_handle: i32,
     ) -> i32 {
-        panic!("Unavailable: managed_unmarshal_compressed_ec")
+        self.use_gas(|gas_schedule| gas_schedule.crypto_api_cost.unmarshal_compressed_ecc);
+        self.handler.ec_unmarshal_compressed(
+            x_result_handle,
+            y_result_handle,
+            ec_handle,
+            data_handle,
+        );
+        0
     }
 
     fn generate_key_ec(
@@ -1822,7 +2059,10 @@ impl VMHooks for VMHooksDispatcher {
         ec_handle: i32,
         result_handle: i32,
     ) -> i32 {
-        panic!("Unavailable: managed_generate_key_ec")
+        self.use_gas(|gas_schedule| gas_schedule.crypto_api_cost.generate_key_ecc);
+        self.handler
+            .ec_generate_key(x_pub_key_handle, y_pub_key_handle, ec_handle, result_handle);
+        0
     }
 
     fn create_ec(&self, data_offset: MemPtr, data_length: MemLength) -> i32 {
@@ -1830,15 +2070,16 @@ impl VMHooks for VMHooksDispatcher {
     }
 
     fn managed_create_ec(&self, data_handle: i32) -> i32 {
-        panic!("Unavailable: managed_create_ec")
+        self.use_gas(|gas_schedule| gas_schedule.crypto_api_cost.elliptic_curve_new);
+        self.handler.ec_create_from_name(data_handle)
     }
 
     fn get_curve_length_ec(&self, ec_handle: i32) -> i32 {
-        panic!("Unavailable: get_curve_length_ec")
+        self.handler.ec_curve_length(ec_handle) as i32
     }
 
     fn get_priv_key_byte_length_ec(&self, ec_handle: i32) -> i32 {
-        panic!("Unavailable: get_priv_key_byte_length_ec")
+        self.handler.ec_private_key_byte_length(ec_handle) as i32
     }
 
     fn elliptic_curve_get_values(
@@ -1850,6 +2091,14 @@ impl VMHooks for VMHooksDispatcher {
         x_base_point_handle: i32,
         y_base_point_handle: i32,
     ) -> i32 {
-        panic!("Unavailable: elliptic_curve_get_values")
+        self.handler.ec_get_values(
+            ec_handle,
+            field_order_handle,
+            base_point_order_handle,
+            eq_constant_handle,
+            x_base_point_handle,
+            y_base_point_handle,
+        );
+        0
     }
 }
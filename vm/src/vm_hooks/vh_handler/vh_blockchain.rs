@@ -58,7 +58,7 @@ pub trait VMHooksBlockchain: VMHooksHandlerSource {
     }
 
     fn get_gas_left(&self) -> u64 {
-        self.input_ref().gas_limit
+        self.gas_left()
     }
 
     fn get_block_timestamp(&self) -> u64 {
@@ -203,18 +203,25 @@ pub trait VMHooksBlockchain: VMHooksHandlerSource {
         &self,
         address_handle: RawHandle,
         token_id_handle: RawHandle,
-        _nonce: u64,
+        nonce: u64,
     ) -> bool {
         let address = VMAddress::from_slice(self.m_types_lock().mb_get(address_handle));
         let token_id_bytes = self.m_types_lock().mb_get(token_id_handle).to_vec();
         let account = self.account_data(&address);
         if let Some(dct_data) = account.dct.get_by_identifier(token_id_bytes.as_slice()) {
-            return dct_data.frozen;
+            return dct_data.is_frozen(nonce);
         }
 
         false
     }
 
+    fn check_dct_paused(&self, token_id_handle: RawHandle) -> bool {
+        let token_id_bytes = self.m_types_lock().mb_get(token_id_handle).to_vec();
+        self.dct_system_token_data(token_id_bytes.as_slice())
+            .map(|token_data| token_data.paused)
+            .unwrap_or_default()
+    }
+
     fn get_dct_local_roles_bits(&self, token_id_handle: RawHandle) -> u64 {
         let token_id_bytes = self.m_types_lock().mb_get(token_id_handle).to_vec();
         let account = self.current_account_data();
@@ -243,7 +250,7 @@ pub trait VMHooksBlockchain: VMHooksHandlerSource {
     ) {
         let mut m_types = self.m_types_lock();
         m_types.bi_overwrite(value_handle, instance.balance.clone().into());
-        if dct_data.frozen {
+        if dct_data.frozen || instance.frozen {
             m_types.mb_set(properties_handle, vec![1, 0]);
         } else {
             m_types.mb_set(properties_handle, vec![0, 0]);
@@ -30,4 +30,56 @@ pub trait VMHooksCrypto: VMHooksHandlerSource {
             self.vm_error("invalid signature");
         }
     }
+
+    fn verify_bls_managed(&self, key: RawHandle, message: RawHandle, signature: RawHandle) -> bool {
+        let types = self.m_types_lock();
+        let key = types.mb_get(key);
+        let message = types.mb_get(message);
+        let signature = types.mb_get(signature);
+        crypto_functions::verify_bls(key, message, signature)
+    }
+
+    fn ripemd160_managed(&self, dest: RawHandle, data_handle: RawHandle) {
+        let mut types = self.m_types_lock();
+        let data = types.mb_get(data_handle);
+        let result_bytes = crypto_functions::ripemd160(data);
+        types.mb_set(dest, result_bytes[..].to_vec());
+    }
+
+    /// The node hashes the message twice with SHA-256, Bitcoin style.
+    fn verify_secp256k1_managed(
+        &self,
+        key: RawHandle,
+        message: RawHandle,
+        signature: RawHandle,
+    ) -> bool {
+        self.verify_custom_secp256k1_managed(
+            key,
+            message,
+            signature,
+            crypto_functions::SECP256K1_DOUBLE_SHA256,
+        )
+    }
+
+    fn verify_custom_secp256k1_managed(
+        &self,
+        key: RawHandle,
+        message: RawHandle,
+        signature: RawHandle,
+        hash_type: u8,
+    ) -> bool {
+        let types = self.m_types_lock();
+        let key = types.mb_get(key);
+        let message = types.mb_get(message);
+        let signature = types.mb_get(signature);
+        crypto_functions::verify_secp256k1(key, message, signature, hash_type)
+    }
+
+    fn encode_secp256k1_der_signature_managed(&self, r: RawHandle, s: RawHandle, dest: RawHandle) {
+        let mut types = self.m_types_lock();
+        let r = types.mb_get(r);
+        let s = types.mb_get(s);
+        let der_signature = crypto_functions::encode_secp256k1_der_signature(r, s);
+        types.mb_set(dest, der_signature);
+    }
 }
@@ -4,6 +4,9 @@ pub trait VMHooksLog: VMHooksHandlerSource {
     fn managed_write_log(&self, topics_handle: RawHandle, data_handle: RawHandle) {
         let topics = self.m_types_lock().mb_get_vec_of_bytes(topics_handle);
         let data = self.m_types_lock().mb_get(data_handle).to_vec();
+        if let Some(gas_schedule) = self.gas_schedule() {
+            self.use_gas(gas_schedule.data_copy_cost(data.len()));
+        }
         self.push_tx_log(TxLog {
             address: self.current_address().clone(),
             endpoint: self.input_ref().func_name.clone(),
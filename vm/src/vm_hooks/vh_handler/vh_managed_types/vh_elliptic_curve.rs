@@ -0,0 +1,250 @@
+use num_bigint::{BigInt, BigUint};
+
+use crate::{
+    tx_mock::TxManagedTypes,
+    types::{RawHandle, VMEllipticCurve},
+    vm_err_msg,
+    vm_hooks::VMHooksHandlerSource,
+};
+
+/// Loads a point from two big int handles, `None` if it is not on the curve.
+fn point_on_curve(
+    types: &TxManagedTypes,
+    curve: &VMEllipticCurve,
+    x_handle: RawHandle,
+    y_handle: RawHandle,
+) -> Option<(BigUint, BigUint)> {
+    let x = types.bi_get(x_handle).to_biguint()?;
+    let y = types.bi_get(y_handle).to_biguint()?;
+    if curve.is_on_curve(&x, &y) {
+        Some((x, y))
+    } else {
+        None
+    }
+}
+
+fn set_point(
+    types: &mut TxManagedTypes,
+    x_result_handle: RawHandle,
+    y_result_handle: RawHandle,
+    (x, y): (BigUint, BigUint),
+) {
+    types.bi_overwrite(x_result_handle, BigInt::from(x));
+    types.bi_overwrite(y_result_handle, BigInt::from(y));
+}
+
+/// Elliptic curves are kept in the managed types, just like on the VM, and only referenced by handle in contracts.
+///
+/// Operations fail if given points that are not on the curve.
+pub trait VMHooksEllipticCurve: VMHooksHandlerSource {
+    fn ec_create_from_name(&self, name_handle: RawHandle) -> RawHandle {
+        let mut types = self.m_types_lock();
+        let curve = VMEllipticCurve::from_name(types.mb_get(name_handle))
+            .unwrap_or_else(|| self.vm_error(vm_err_msg::EC_INVALID_CURVE_NAME));
+        types.ec_new(curve)
+    }
+
+    fn ec_get_values(
+        &self,
+        ec_handle: RawHandle,
+        field_order_handle: RawHandle,
+        base_point_order_handle: RawHandle,
+        eq_constant_handle: RawHandle,
+        x_base_point_handle: RawHandle,
+        y_base_point_handle: RawHandle,
+    ) {
+        let mut types = self.m_types_lock();
+        let curve = types.ec_get(ec_handle).clone();
+        types.bi_overwrite(field_order_handle, curve.field_order.into());
+        types.bi_overwrite(base_point_order_handle, curve.base_point_order.into());
+        types.bi_overwrite(eq_constant_handle, curve.eq_constant.into());
+        types.bi_overwrite(x_base_point_handle, curve.x_base_point.into());
+        types.bi_overwrite(y_base_point_handle, curve.y_base_point.into());
+    }
+
+    fn ec_curve_length(&self, ec_handle: RawHandle) -> u32 {
+        self.m_types_lock().ec_get(ec_handle).size_of_field
+    }
+
+    fn ec_private_key_byte_length(&self, ec_handle: RawHandle) -> u32 {
+        self.m_types_lock()
+            .ec_get(ec_handle)
+            .private_key_byte_length() as u32
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn ec_add(
+        &self,
+        x_result_handle: RawHandle,
+        y_result_handle: RawHandle,
+        ec_handle: RawHandle,
+        x_first_point_handle: RawHandle,
+        y_first_point_handle: RawHandle,
+        x_second_point_handle: RawHandle,
+        y_second_point_handle: RawHandle,
+    ) {
+        let mut types = self.m_types_lock();
+        let curve = types.ec_get(ec_handle).clone();
+        let (x1, y1) = point_on_curve(&types, &curve, x_first_point_handle, y_first_point_handle)
+            .unwrap_or_else(|| self.vm_error(vm_err_msg::EC_POINT_NOT_ON_CURVE));
+        let (x2, y2) = point_on_curve(&types, &curve, x_second_point_handle, y_second_point_handle)
+            .unwrap_or_else(|| self.vm_error(vm_err_msg::EC_POINT_NOT_ON_CURVE));
+        let result = curve.add(&x1, &y1, &x2, &y2);
+        set_point(&mut types, x_result_handle, y_result_handle, result);
+    }
+
+    fn ec_double(
+        &self,
+        x_result_handle: RawHandle,
+        y_result_handle: RawHandle,
+        ec_handle: RawHandle,
+        x_point_handle: RawHandle,
+        y_point_handle: RawHandle,
+    ) {
+        let mut types = self.m_types_lock();
+        let curve = types.ec_get(ec_handle).clone();
+        let (x, y) = point_on_curve(&types, &curve, x_point_handle, y_point_handle)
+            .unwrap_or_else(|| self.vm_error(vm_err_msg::EC_POINT_NOT_ON_CURVE));
+        let result = curve.double(&x, &y);
+        set_point(&mut types, x_result_handle, y_result_handle, result);
+    }
+
+    fn ec_is_on_curve(
+        &self,
+        ec_handle: RawHandle,
+        x_point_handle: RawHandle,
+        y_point_handle: RawHandle,
+    ) -> bool {
+        let types = self.m_types_lock();
+        let curve = types.ec_get(ec_handle);
+        point_on_curve(&types, curve, x_point_handle, y_point_handle).is_some()
+    }
+
+    /// The result is also checked, multiplying by a multiple of the base point order is an error.
+    fn ec_scalar_mult(
+        &self,
+        x_result_handle: RawHandle,
+        y_result_handle: RawHandle,
+        ec_handle: RawHandle,
+        x_point_handle: RawHandle,
+        y_point_handle: RawHandle,
+        data_handle: RawHandle,
+    ) {
+        let mut types = self.m_types_lock();
+        let curve = types.ec_get(ec_handle).clone();
+        let (x, y) = point_on_curve(&types, &curve, x_point_handle, y_point_handle)
+            .unwrap_or_else(|| self.vm_error(vm_err_msg::EC_POINT_NOT_ON_CURVE));
+        let (x_result, y_result) = curve.scalar_mult(&x, &y, types.mb_get(data_handle));
+        if !curve.is_on_curve(&x_result, &y_result) {
+            self.vm_error(vm_err_msg::EC_POINT_NOT_ON_CURVE);
+        }
+        set_point(
+            &mut types,
+            x_result_handle,
+            y_result_handle,
+            (x_result, y_result),
+        );
+    }
+
+    fn ec_scalar_base_mult(
+        &self,
+        x_result_handle: RawHandle,
+        y_result_handle: RawHandle,
+        ec_handle: RawHandle,
+        data_handle: RawHandle,
+    ) {
+        let mut types = self.m_types_lock();
+        let curve = types.ec_get(ec_handle).clone();
+        let (x_result, y_result) = curve.scalar_base_mult(types.mb_get(data_handle));
+        if !curve.is_on_curve(&x_result, &y_result) {
+            self.vm_error(vm_err_msg::EC_POINT_NOT_ON_CURVE);
+        }
+        set_point(
+            &mut types,
+            x_result_handle,
+            y_result_handle,
+            (x_result, y_result),
+        );
+    }
+
+    fn ec_marshal(
+        &self,
+        ec_handle: RawHandle,
+        x_pair_handle: RawHandle,
+        y_pair_handle: RawHandle,
+        result_handle: RawHandle,
+    ) {
+        let mut types = self.m_types_lock();
+        let curve = types.ec_get(ec_handle).clone();
+        let (x, y) = point_on_curve(&types, &curve, x_pair_handle, y_pair_handle)
+            .unwrap_or_else(|| self.vm_error(vm_err_msg::EC_POINT_NOT_ON_CURVE));
+        types.mb_set(result_handle, curve.marshal(&x, &y));
+    }
+
+    fn ec_marshal_compressed(
+        &self,
+        ec_handle: RawHandle,
+        x_pair_handle: RawHandle,
+        y_pair_handle: RawHandle,
+        result_handle: RawHandle,
+    ) {
+        let mut types = self.m_types_lock();
+        let curve = types.ec_get(ec_handle).clone();
+        let (x, y) = point_on_curve(&types, &curve, x_pair_handle, y_pair_handle)
+            .unwrap_or_else(|| self.vm_error(vm_err_msg::EC_POINT_NOT_ON_CURVE));
+        types.mb_set(result_handle, curve.marshal_compressed(&x, &y));
+    }
+
+    fn ec_unmarshal(
+        &self,
+        x_result_handle: RawHandle,
+        y_result_handle: RawHandle,
+        ec_handle: RawHandle,
+        data_handle: RawHandle,
+    ) {
+        let mut types = self.m_types_lock();
+        let curve = types.ec_get(ec_handle).clone();
+        let data = types.mb_get(data_handle);
+        if data.len() != 2 * curve.byte_length() + 1 {
+            self.vm_error(vm_err_msg::EC_BUFFER_LENGTH_INVALID);
+        }
+        let point = curve
+            .unmarshal(data)
+            .unwrap_or_else(|| self.vm_error(vm_err_msg::EC_POINT_NOT_ON_CURVE));
+        set_point(&mut types, x_result_handle, y_result_handle, point);
+    }
+
+    fn ec_unmarshal_compressed(
+        &self,
+        x_result_handle: RawHandle,
+        y_result_handle: RawHandle,
+        ec_handle: RawHandle,
+        data_handle: RawHandle,
+    ) {
+        let mut types = self.m_types_lock();
+        let curve = types.ec_get(ec_handle).clone();
+        let data = types.mb_get(data_handle);
+        if data.len() != curve.byte_length() + 1 {
+            self.vm_error(vm_err_msg::EC_BUFFER_LENGTH_INVALID);
+        }
+        let point = curve
+            .unmarshal_compressed(data)
+            .unwrap_or_else(|| self.vm_error(vm_err_msg::EC_POINT_NOT_ON_CURVE));
+        set_point(&mut types, x_result_handle, y_result_handle, point);
+    }
+
+    /// Randomness comes from the block random seeds, so keys are deterministic in tests.
+    fn ec_generate_key(
+        &self,
+        x_pub_key_handle: RawHandle,
+        y_pub_key_handle: RawHandle,
+        ec_handle: RawHandle,
+        result_handle: RawHandle,
+    ) {
+        let curve = self.m_types_lock().ec_get(ec_handle).clone();
+        let (private_key, x, y) = curve.generate_key(|length| self.random_next_bytes(length));
+        let mut types = self.m_types_lock();
+        set_point(&mut types, x_pub_key_handle, y_pub_key_handle, (x, y));
+        types.mb_set(result_handle, private_key);
+    }
+}
@@ -205,10 +205,13 @@ pub trait VMHooksSend: VMHooksHandlerSource {
         arg_buffer_handle: RawHandle,
         success_callback: &[u8],
         error_callback: &[u8],
-        _gas: u64,
-        _extra_gas_for_callback: u64,
+        gas: u64,
+        extra_gas_for_callback: u64,
         callback_closure_handle: RawHandle,
     ) {
+        // the gas of the call and of the callback is reserved upfront
+        self.use_gas(gas.saturating_add(extra_gas_for_callback));
+
         let contract_address = self.current_address().clone();
         let to = self.m_types_lock().mb_to_address(to_handle);
         let moax_value = self.m_types_lock().bu_get(moax_value_handle);
@@ -225,6 +228,7 @@ pub trait VMHooksSend: VMHooksHandlerSource {
             call_value: moax_value,
             endpoint_name,
             arguments: arg_buffer,
+            gas_limit: gas,
             tx_hash,
         };
 
@@ -233,6 +237,7 @@ pub trait VMHooksSend: VMHooksHandlerSource {
             success_callback: success_callback.into(),
             error_callback: error_callback.into(),
             callback_closure_data,
+            extra_gas_for_callback,
         };
 
         let mut tx_result = self.result_lock();
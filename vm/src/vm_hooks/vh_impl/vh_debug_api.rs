@@ -3,20 +3,23 @@ use std::sync::{Arc, MutexGuard};
 use dharitri_chain_vm_executor::BreakpointValue;
 
 use crate::{
+    schedule::GasSchedule,
     tx_execution::execute_current_tx_context_input,
     tx_mock::{
-        async_call_tx_input, AsyncCallTxData, BlockchainUpdate, TxCache, TxContext, TxFunctionName,
-        TxInput, TxManagedTypes, TxPanic, TxResult,
+        async_call_tx_input, AsyncCallTxData, BlockchainUpdate, CrossShardCall, CrossShardCallType,
+        TxCache, TxCallKind, TxContext, TxFunctionName, TxInput, TxLog, TxManagedTypes, TxPanic,
+        TxResult, TxStorageWrite,
     },
     types::{VMAddress, VMCodeMetadata},
     vm_err_msg,
     vm_hooks::{
         VMHooksBigFloat, VMHooksBigInt, VMHooksBlockchain, VMHooksCallValue, VMHooksCrypto,
-        VMHooksEndpointArgument, VMHooksEndpointFinish, VMHooksError, VMHooksErrorManaged,
-        VMHooksHandler, VMHooksHandlerSource, VMHooksLog, VMHooksManagedBuffer, VMHooksManagedMap,
-        VMHooksManagedTypes, VMHooksSend, VMHooksStorageRead, VMHooksStorageWrite,
+        VMHooksEllipticCurve, VMHooksEndpointArgument, VMHooksEndpointFinish, VMHooksError,
+        VMHooksErrorManaged, VMHooksHandler, VMHooksHandlerSource, VMHooksLog,
+        VMHooksManagedBuffer, VMHooksManagedMap, VMHooksManagedTypes, VMHooksSend,
+        VMHooksStorageRead, VMHooksStorageWrite,
     },
-    world_mock::{reserved::STORAGE_RESERVED_PREFIX, AccountData, BlockInfo},
+    world_mock::{reserved::STORAGE_RESERVED_PREFIX, AccountData, BlockInfo, DctSystemTokenData},
 };
 
 /// A simple wrapper around a managed type container RefCell.
@@ -57,6 +60,15 @@ impl VMHooksHandlerSource for DebugApiVMHooksHandler {
         self.0.result_lock()
     }
 
+    fn gas_schedule(&self) -> Option<&GasSchedule> {
+        self.0.vm_ref.gas_schedule.as_ref()
+    }
+
+    fn push_tx_log(&self, tx_log: TxLog) {
+        self.0.vm_ref.trace(|tracer| tracer.log(&tx_log));
+        self.result_lock().result_logs.push(tx_log);
+    }
+
     fn storage_read_any_address(&self, address: &VMAddress, key: &[u8]) -> Vec<u8> {
         self.0.with_account_mut(address, |account| {
             account.storage.get(key).cloned().unwrap_or_default()
@@ -66,6 +78,25 @@ impl VMHooksHandlerSource for DebugApiVMHooksHandler {
     fn storage_write(&self, key: &[u8], value: &[u8]) {
         self.check_reserved_key(key);
 
+        if let Some(gas_schedule) = self.gas_schedule() {
+            let old_value_len = self.storage_read(key).len();
+            self.use_gas(gas_schedule.storage_store_bytes_cost(old_value_len, value.len()));
+            self.result_lock().gas_refund +=
+                gas_schedule.storage_release_refund(old_value_len, value.len());
+        }
+
+        if self.0.vm_ref.is_tracing() {
+            let storage_write = TxStorageWrite {
+                address: self.current_address().clone(),
+                key: key.to_vec(),
+                old_value: self.storage_read(key),
+                new_value: value.to_vec(),
+            };
+            self.0
+                .vm_ref
+                .trace(|tracer| tracer.storage_write(storage_write));
+        }
+
         self.0.with_contract_account_mut(|account| {
             account.storage.insert(key.to_vec(), value.to_vec());
         });
@@ -90,6 +121,12 @@ impl VMHooksHandlerSource for DebugApiVMHooksHandler {
             .unwrap_or_else(|| panic!("Account is not a smart contract, it has no code"))
     }
 
+    fn dct_system_token_data(&self, token_identifier: &[u8]) -> Option<DctSystemTokenData> {
+        self.0
+            .blockchain_cache()
+            .get_dct_system_token(token_identifier)
+    }
+
     fn perform_async_call(
         &self,
         to: VMAddress,
@@ -97,7 +134,14 @@ impl VMHooksHandlerSource for DebugApiVMHooksHandler {
         func_name: TxFunctionName,
         arguments: Vec<Vec<u8>>,
     ) -> ! {
-        let async_call_data = self.create_async_call_data(to, moax_value, func_name, arguments);
+        let mut async_call_data = self.create_async_call_data(to, moax_value, func_name, arguments);
+        // the callback gas is reserved from the gas left, the async call gets the rest
+        let callback_gas_lock = self.0.vm_ref.async_callback_gas_lock();
+        if async_call_data.gas_limit < callback_gas_lock {
+            let out_of_gas = TxPanic::out_of_gas();
+            self.halt_with_error(out_of_gas.status, &out_of_gas.message);
+        }
+        async_call_data.gas_limit -= callback_gas_lock;
         // the cell is no longer needed, since we end in a panic
         let mut tx_result = self.result_lock();
         tx_result.all_calls.push(async_call_data.clone());
@@ -115,12 +159,20 @@ impl VMHooksHandlerSource for DebugApiVMHooksHandler {
     ) -> Vec<Vec<u8>> {
         let async_call_data = self.create_async_call_data(to, moax_value, func_name, arguments);
         let tx_input = async_call_tx_input(&async_call_data);
+        if self.0.vm_ref.is_cross_shard_call(&tx_input) {
+            self.vm_error(vm_err_msg::SYNC_EXECUTION_CROSS_SHARD);
+        }
+
         let tx_cache = TxCache::new(self.0.blockchain_cache_arc());
+        self.0
+            .vm_ref
+            .trace(|tracer| tracer.begin_call(TxCallKind::SyncCall, &tx_input));
         let (tx_result, blockchain_updates) = self.0.vm_ref.execute_builtin_function_or_default(
             tx_input,
             tx_cache,
             execute_current_tx_context_input,
         );
+        self.0.vm_ref.trace(|tracer| tracer.end_call(&tx_result));
 
         if tx_result.result_status == 0 {
             self.sync_call_post_processing(tx_result, blockchain_updates)
@@ -146,7 +198,7 @@ impl VMHooksHandlerSource for DebugApiVMHooksHandler {
             dct_values: Vec::new(),
             func_name: TxFunctionName::EMPTY,
             args,
-            gas_limit: 1000,
+            gas_limit: self.gas_left(),
             gas_price: 0,
             tx_hash,
             ..Default::default()
@@ -180,12 +232,21 @@ impl VMHooksHandlerSource for DebugApiVMHooksHandler {
     ) {
         let async_call_data = self.create_async_call_data(to, moax_value, func_name, arguments);
         let tx_input = async_call_tx_input(&async_call_data);
+        if self.0.vm_ref.is_cross_shard_call(&tx_input) {
+            self.send_cross_shard_transfer_execute(async_call_data, &tx_input);
+            return;
+        }
+
         let tx_cache = TxCache::new(self.0.blockchain_cache_arc());
+        self.0
+            .vm_ref
+            .trace(|tracer| tracer.begin_call(TxCallKind::TransferExecute, &tx_input));
         let (tx_result, blockchain_updates) = self.0.vm_ref.execute_builtin_function_or_default(
             tx_input,
             tx_cache,
             execute_current_tx_context_input,
         );
+        self.0.vm_ref.trace(|tracer| tracer.end_call(&tx_result));
 
         match tx_result.result_status {
             0 => {
@@ -215,10 +276,43 @@ impl DebugApiVMHooksHandler {
             call_value: moax_value,
             endpoint_name: func_name,
             arguments,
+            gas_limit: self.gas_left(),
             tx_hash,
         }
     }
 
+    /// The value leaves the current shard right away, the destination only gets executed in the next block.
+    fn send_cross_shard_transfer_execute(
+        &self,
+        async_call_data: AsyncCallTxData,
+        tx_input: &TxInput,
+    ) {
+        let token_transfers = self
+            .0
+            .vm_ref
+            .builtin_functions
+            .extract_token_transfers(tx_input);
+        let in_flight = self
+            .0
+            .blockchain_cache()
+            .send_in_flight_value(
+                &tx_input.from,
+                &tx_input.moax_value,
+                &token_transfers.transfers,
+            )
+            .unwrap_or_else(|err| self.vm_error(&err.message));
+
+        let mut tx_result = self.0.result_lock();
+        tx_result.all_calls.push(async_call_data.clone());
+        tx_result
+            .pending_calls
+            .cross_shard_calls
+            .push(CrossShardCall {
+                call_type: CrossShardCallType::TransferExecute(async_call_data),
+                in_flight,
+            });
+    }
+
     fn sync_call_post_processing(
         &self,
         tx_result: TxResult,
@@ -241,6 +335,7 @@ impl DebugApiVMHooksHandler {
 impl VMHooksBigInt for DebugApiVMHooksHandler {}
 impl VMHooksManagedBuffer for DebugApiVMHooksHandler {}
 impl VMHooksManagedMap for DebugApiVMHooksHandler {}
+impl VMHooksEllipticCurve for DebugApiVMHooksHandler {}
 impl VMHooksBigFloat for DebugApiVMHooksHandler {}
 impl VMHooksManagedTypes for DebugApiVMHooksHandler {}
 
@@ -8,9 +8,10 @@ use crate::{
     types::{VMAddress, VMCodeMetadata},
     vm_hooks::{
         VMHooksBigFloat, VMHooksBigInt, VMHooksBlockchain, VMHooksCallValue, VMHooksCrypto,
-        VMHooksEndpointArgument, VMHooksEndpointFinish, VMHooksError, VMHooksErrorManaged,
-        VMHooksHandler, VMHooksHandlerSource, VMHooksLog, VMHooksManagedBuffer, VMHooksManagedMap,
-        VMHooksManagedTypes, VMHooksSend, VMHooksStorageRead, VMHooksStorageWrite,
+        VMHooksEllipticCurve, VMHooksEndpointArgument, VMHooksEndpointFinish, VMHooksError,
+        VMHooksErrorManaged, VMHooksHandler, VMHooksHandlerSource, VMHooksLog,
+        VMHooksManagedBuffer, VMHooksManagedMap, VMHooksManagedTypes, VMHooksSend,
+        VMHooksStorageRead, VMHooksStorageWrite,
     },
     world_mock::{AccountData, BlockInfo},
 };
@@ -145,6 +146,7 @@ impl VMHooksHandlerSource for SingleTxApiVMHooksHandler {
 impl VMHooksBigInt for SingleTxApiVMHooksHandler {}
 impl VMHooksManagedBuffer for SingleTxApiVMHooksHandler {}
 impl VMHooksManagedMap for SingleTxApiVMHooksHandler {}
+impl VMHooksEllipticCurve for SingleTxApiVMHooksHandler {}
 impl VMHooksBigFloat for SingleTxApiVMHooksHandler {}
 impl VMHooksManagedTypes for SingleTxApiVMHooksHandler {}
 
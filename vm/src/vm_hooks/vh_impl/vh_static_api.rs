@@ -5,9 +5,10 @@ use crate::{
     types::{VMAddress, VMCodeMetadata},
     vm_hooks::{
         VMHooksBigFloat, VMHooksBigInt, VMHooksBlockchain, VMHooksCallValue, VMHooksCrypto,
-        VMHooksEndpointArgument, VMHooksEndpointFinish, VMHooksError, VMHooksErrorManaged,
-        VMHooksHandler, VMHooksHandlerSource, VMHooksLog, VMHooksManagedBuffer, VMHooksManagedMap,
-        VMHooksManagedTypes, VMHooksSend, VMHooksStorageRead, VMHooksStorageWrite,
+        VMHooksEllipticCurve, VMHooksEndpointArgument, VMHooksEndpointFinish, VMHooksError,
+        VMHooksErrorManaged, VMHooksHandler, VMHooksHandlerSource, VMHooksLog,
+        VMHooksManagedBuffer, VMHooksManagedMap, VMHooksManagedTypes, VMHooksSend,
+        VMHooksStorageRead, VMHooksStorageWrite,
     },
     world_mock::{AccountData, BlockInfo},
 };
@@ -120,6 +121,7 @@ impl VMHooksHandlerSource for StaticApiVMHooksHandler {
 impl VMHooksBigInt for StaticApiVMHooksHandler {}
 impl VMHooksManagedBuffer for StaticApiVMHooksHandler {}
 impl VMHooksManagedMap for StaticApiVMHooksHandler {}
+impl VMHooksEllipticCurve for StaticApiVMHooksHandler {}
 impl VMHooksBigFloat for StaticApiVMHooksHandler {}
 impl VMHooksManagedTypes for StaticApiVMHooksHandler {}
 
@@ -1,9 +1,10 @@
 use std::{fmt::Debug, sync::MutexGuard};
 
 use crate::{
-    tx_mock::{TxFunctionName, TxInput, TxLog, TxManagedTypes, TxResult},
+    schedule::GasSchedule,
+    tx_mock::{TxFunctionName, TxInput, TxLog, TxManagedTypes, TxPanic, TxResult},
     types::{VMAddress, VMCodeMetadata, H256},
-    world_mock::{AccountData, BlockInfo},
+    world_mock::{AccountData, BlockInfo, DctSystemTokenData},
 };
 
 /// Abstracts away the borrowing of a managed types structure.
@@ -31,6 +32,35 @@ pub trait VMHooksHandlerSource: Debug {
 
     fn result_lock(&self) -> MutexGuard<TxResult>;
 
+    /// Only available if gas is metered.
+    fn gas_schedule(&self) -> Option<&GasSchedule> {
+        None
+    }
+
+    /// Consumes gas, stops execution if there is not enough left.
+    ///
+    /// Does nothing if gas is not metered.
+    fn use_gas(&self, gas: u64) {
+        if self.gas_schedule().is_none() {
+            return;
+        }
+
+        let mut tx_result = self.result_lock();
+        let gas_used = tx_result.gas_used.saturating_add(gas);
+        if gas_used > self.input_ref().gas_limit {
+            drop(tx_result); // halting also needs the lock
+            let out_of_gas = TxPanic::out_of_gas();
+            self.halt_with_error(out_of_gas.status, &out_of_gas.message);
+        }
+        tx_result.gas_used = gas_used;
+    }
+
+    fn gas_left(&self) -> u64 {
+        self.input_ref()
+            .gas_limit
+            .saturating_sub(self.result_lock().gas_used)
+    }
+
     fn push_tx_log(&self, tx_log: TxLog) {
         self.result_lock().result_logs.push(tx_log);
     }
@@ -61,6 +91,13 @@ pub trait VMHooksHandlerSource: Debug {
 
     fn account_code(&self, address: &VMAddress) -> Vec<u8>;
 
+    /// Token-level data, as registered in the DCT system SC.
+    ///
+    /// Not all APIs have access to it, by default no token is registered.
+    fn dct_system_token_data(&self, _token_identifier: &[u8]) -> Option<DctSystemTokenData> {
+        None
+    }
+
     fn perform_async_call(
         &self,
         to: VMAddress,
@@ -1,4 +1,5 @@
 mod account_data;
+mod account_fetcher;
 mod block_info;
 mod blockchain_mock;
 mod blockchain_mock_account_util;
@@ -8,10 +9,13 @@ mod dct_instance;
 mod dct_instance_metadata;
 mod dct_instances;
 mod dct_roles;
+mod dct_system_token_data;
 mod failing_executor;
 pub mod reserved;
+mod shard_coordinator;
 
 pub use account_data::*;
+pub use account_fetcher::AccountFetcher;
 pub use block_info::*;
 pub use blockchain_mock::*;
 pub use blockchain_state::BlockchainState;
@@ -20,4 +24,6 @@ pub use dct_instance::*;
 pub use dct_instance_metadata::*;
 pub use dct_instances::*;
 pub use dct_roles::*;
+pub use dct_system_token_data::*;
 pub use failing_executor::FailingExecutor;
+pub use shard_coordinator::*;
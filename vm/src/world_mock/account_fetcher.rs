@@ -0,0 +1,14 @@
+use crate::types::VMAddress;
+
+use super::AccountData;
+
+/// Provides accounts that are missing from the local blockchain state.
+///
+/// Used in fork mode, where the state is initialized lazily, from a live network snapshot,
+/// the first time each account is accessed.
+pub trait AccountFetcher: Send + Sync {
+    /// Retrieves the account, as it is on the forked network.
+    ///
+    /// Returns `None` if the account cannot be found.
+    fn fetch_account(&self, address: &VMAddress) -> Option<AccountData>;
+}
@@ -1,11 +1,16 @@
-use std::{collections::HashMap, fmt::Debug};
+use std::{collections::HashMap, fmt::Debug, sync::Arc};
 
 use num_bigint::BigUint;
 use num_traits::Zero;
 
-use crate::{tx_mock::BlockchainUpdate, types::VMAddress};
+use crate::{
+    tx_mock::{BlockchainUpdate, CrossShardCall},
+    types::VMAddress,
+};
 
-use super::{reserved::STORAGE_REWARD_KEY, AccountData, BlockInfo};
+use super::{
+    reserved::STORAGE_REWARD_KEY, AccountData, AccountFetcher, BlockInfo, DctSystemTokenData,
+};
 
 #[derive(Default, Clone)]
 pub struct BlockchainState {
@@ -14,6 +19,13 @@ pub struct BlockchainState {
     pub previous_block_info: BlockInfo,
     pub current_block_info: BlockInfo,
     pub new_token_identifiers: Vec<String>,
+    pub dct_system_tokens: HashMap<Vec<u8>, DctSystemTokenData>,
+
+    /// Only set in fork mode. Provides the accounts that are not yet in the local state.
+    pub account_fetcher: Option<Arc<dyn AccountFetcher>>,
+
+    /// Only used in sharded mode. Calls sent to other shards, to be executed in the next block.
+    pub cross_shard_calls: Vec<CrossShardCall>,
 }
 
 impl BlockchainState {
@@ -22,26 +34,40 @@ impl BlockchainState {
     }
 
     pub fn account_exists(&self, address: &VMAddress) -> bool {
-        self.accounts.contains_key(address)
+        self.accounts.contains_key(address) || self.fetch_account(address).is_some()
     }
 
-    pub fn increase_account_nonce(&mut self, address: &VMAddress) {
-        let account = self.accounts.get_mut(address).unwrap_or_else(|| {
+    /// Retrieves an account that is not in the local state, in fork mode.
+    ///
+    /// Does not add it to the local state, this only happens once the account gets modified.
+    pub fn fetch_account(&self, address: &VMAddress) -> Option<AccountData> {
+        self.account_fetcher
+            .as_ref()
+            .and_then(|fetcher| fetcher.fetch_account(address))
+    }
+
+    fn get_account_mut(&mut self, address: &VMAddress) -> &mut AccountData {
+        if !self.accounts.contains_key(address) {
+            if let Some(account) = self.fetch_account(address) {
+                self.accounts.insert(address.clone(), account);
+            }
+        }
+
+        self.accounts.get_mut(address).unwrap_or_else(|| {
             panic!(
                 "Account not found: {}",
                 &std::str::from_utf8(address.as_ref()).unwrap()
             )
-        });
+        })
+    }
+
+    pub fn increase_account_nonce(&mut self, address: &VMAddress) {
+        let account = self.get_account_mut(address);
         account.nonce += 1;
     }
 
     pub fn subtract_tx_gas(&mut self, address: &VMAddress, gas_limit: u64, gas_price: u64) {
-        let account = self.accounts.get_mut(address).unwrap_or_else(|| {
-            panic!(
-                "Account not found: {}",
-                &std::str::from_utf8(address.as_ref()).unwrap()
-            )
-        });
+        let account = self.get_account_mut(address);
         let gas_cost = BigUint::from(gas_limit) * BigUint::from(gas_price);
         assert!(
             account.moax_balance >= gas_cost,
@@ -50,13 +76,14 @@ impl BlockchainState {
         account.moax_balance -= &gas_cost;
     }
 
+    /// Gives back the payment for the gas that was not used.
+    pub fn refund_tx_gas(&mut self, address: &VMAddress, gas_refund: u64, gas_price: u64) {
+        let account = self.get_account_mut(address);
+        account.moax_balance += BigUint::from(gas_refund) * BigUint::from(gas_price);
+    }
+
     pub fn increase_validator_reward(&mut self, address: &VMAddress, amount: &BigUint) {
-        let account = self.accounts.get_mut(address).unwrap_or_else(|| {
-            panic!(
-                "Account not found: {}",
-                &std::str::from_utf8(address.as_ref()).unwrap()
-            )
-        });
+        let account = self.get_account_mut(address);
         account.moax_balance += amount;
         let mut storage_v_rew =
             if let Some(old_storage_value) = account.storage.get(STORAGE_REWARD_KEY) {
@@ -81,6 +108,13 @@ impl BlockchainState {
     pub fn update_new_token_identifiers(&mut self, token_identifiers: Vec<String>) {
         self.new_token_identifiers = token_identifiers;
     }
+
+    pub fn update_dct_system_tokens(
+        &mut self,
+        dct_system_tokens: HashMap<Vec<u8>, DctSystemTokenData>,
+    ) {
+        self.dct_system_tokens.extend(dct_system_tokens);
+    }
 }
 
 impl Debug for BlockchainState {
@@ -89,6 +123,8 @@ impl Debug for BlockchainState {
             .field("accounts", &self.accounts)
             .field("new_addresses", &self.new_addresses)
             .field("current_block_info", &self.current_block_info)
+            .field("fork_mode", &self.account_fetcher.is_some())
+            .field("cross_shard_calls", &self.cross_shard_calls)
             .finish()
     }
 }
@@ -28,6 +28,16 @@ impl DctData {
     pub fn get_roles(&self) -> Vec<Vec<u8>> {
         self.roles.get()
     }
+
+    /// Either the whole token is frozen for the account, or just the given NFT instance.
+    pub fn is_frozen(&self, nonce: u64) -> bool {
+        self.frozen
+            || self
+                .instances
+                .get_by_nonce(nonce)
+                .map(|instance| instance.frozen)
+                .unwrap_or_default()
+    }
 }
 
 #[derive(Clone, Default, Debug)]
@@ -151,13 +161,62 @@ impl AccountDct {
     }
 
     pub fn set_special_role(&mut self, token_identifier: &[u8], role: &[u8]) {
+        let dct_data = self.0.entry(token_identifier.to_vec()).or_default();
+        let roles = dct_data.roles.get();
+        if !roles.contains(role.to_vec().as_ref()) {
+            let mut new_roles = roles;
+            new_roles.push(role.to_vec());
+            dct_data.roles = DctRoles::new(new_roles);
+        }
+    }
+
+    pub fn unset_special_role(&mut self, token_identifier: &[u8], role: &[u8]) {
         if let Some(dct_data) = self.get_mut_by_identifier(token_identifier) {
-            let roles = dct_data.roles.get();
-            if !roles.contains(role.to_vec().as_ref()) {
-                let mut new_roles = roles;
-                new_roles.push(role.to_vec());
-                dct_data.roles = DctRoles::new(new_roles);
-            }
+            dct_data.roles.remove(role);
+        }
+    }
+
+    /// Freezes or unfreezes the whole token balance of the account.
+    pub fn set_frozen(&mut self, token_identifier: &[u8], frozen: bool) {
+        self.0.entry(token_identifier.to_vec()).or_default().frozen = frozen;
+    }
+
+    /// Freezes or unfreezes a single NFT instance.
+    ///
+    /// Returns false if the account does not hold the instance.
+    pub fn set_instance_frozen(
+        &mut self,
+        token_identifier: &[u8],
+        nonce: u64,
+        frozen: bool,
+    ) -> bool {
+        if let Some(instance) = self
+            .0
+            .get_mut(token_identifier)
+            .and_then(|dct_data| dct_data.instances.get_mut_by_nonce(nonce))
+        {
+            instance.frozen = frozen;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Clears the balances of all instances of a token.
+    pub fn wipe(&mut self, token_identifier: &[u8]) {
+        if let Some(dct_data) = self.0.get_mut(token_identifier) {
+            dct_data.instances = DctInstances::new();
+        }
+    }
+
+    /// Clears the balance of a single instance of a token.
+    pub fn wipe_instance(&mut self, token_identifier: &[u8], nonce: u64) {
+        if let Some(instance) = self
+            .0
+            .get_mut(token_identifier)
+            .and_then(|dct_data| dct_data.instances.get_mut_by_nonce(nonce))
+        {
+            instance.balance = BigUint::zero();
         }
     }
 
@@ -9,6 +9,8 @@ pub struct DctInstance {
     pub nonce: u64,
     pub balance: BigUint,
     pub metadata: DctInstanceMetadata,
+    /// Set via `freezeSingleNFT`, only relevant for non-fungible tokens.
+    pub frozen: bool,
 }
 
 impl DctInstance {
@@ -17,6 +19,7 @@ impl DctInstance {
             nonce,
             balance: BigUint::zero(),
             metadata: DctInstanceMetadata::default(),
+            frozen: false,
         }
     }
 
@@ -25,6 +28,7 @@ impl DctInstance {
             nonce: 0,
             balance,
             metadata: DctInstanceMetadata::default(),
+            frozen: false,
         }
     }
 
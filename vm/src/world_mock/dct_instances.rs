@@ -44,6 +44,7 @@ impl DctInstances {
             nonce,
             balance: BigUint::zero(),
             metadata: metadata.clone(),
+            frozen: false,
         });
         if instance.balance.is_zero() {
             instance.metadata = metadata;
@@ -65,6 +66,7 @@ impl DctInstances {
                 nonce,
                 balance: value.clone(),
                 metadata,
+                frozen: false,
             });
     }
 
@@ -15,6 +15,14 @@ impl DctRoles {
     pub fn get(&self) -> Vec<Vec<u8>> {
         self.0.clone()
     }
+
+    pub fn contains(&self, role: &[u8]) -> bool {
+        self.0.iter().any(|r| r.as_slice() == role)
+    }
+
+    pub fn remove(&mut self, role: &[u8]) {
+        self.0.retain(|r| r.as_slice() != role);
+    }
 }
 
 impl fmt::Display for DctRoles {
@@ -0,0 +1,154 @@
+use crate::types::{VMAddress, VMTokenType};
+
+const PROPERTY_CAN_FREEZE: &[u8] = b"canFreeze";
+const PROPERTY_CAN_WIPE: &[u8] = b"canWipe";
+const PROPERTY_CAN_PAUSE: &[u8] = b"canPause";
+const PROPERTY_CAN_MINT: &[u8] = b"canMint";
+const PROPERTY_CAN_BURN: &[u8] = b"canBurn";
+const PROPERTY_CAN_CHANGE_OWNER: &[u8] = b"canChangeOwner";
+const PROPERTY_CAN_UPGRADE: &[u8] = b"canUpgrade";
+const PROPERTY_CAN_ADD_SPECIAL_ROLES: &[u8] = b"canAddSpecialRoles";
+const PROPERTY_CAN_TRANSFER_NFT_CREATE_ROLE: &[u8] = b"canTransferNFTCreateRole";
+const PROPERTY_CAN_CREATE_MULTI_SHARD: &[u8] = b"canCreateMultiShard";
+
+/// The token properties that can be configured at issue and later changed via `controlChanges`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DctTokenProperties {
+    pub can_freeze: bool,
+    pub can_wipe: bool,
+    pub can_pause: bool,
+    pub can_mint: bool,
+    pub can_burn: bool,
+    pub can_change_owner: bool,
+    pub can_upgrade: bool,
+    pub can_add_special_roles: bool,
+    pub can_transfer_nft_create_role: bool,
+    pub can_create_multi_shard: bool,
+}
+
+impl Default for DctTokenProperties {
+    /// Same defaults as the system SC: only upgrading and adding special roles are allowed.
+    fn default() -> Self {
+        DctTokenProperties {
+            can_freeze: false,
+            can_wipe: false,
+            can_pause: false,
+            can_mint: false,
+            can_burn: false,
+            can_change_owner: false,
+            can_upgrade: true,
+            can_add_special_roles: true,
+            can_transfer_nft_create_role: false,
+            can_create_multi_shard: false,
+        }
+    }
+}
+
+impl DctTokenProperties {
+    /// Sets a single property, by its system SC name.
+    pub fn set_by_name(&mut self, name: &[u8], value: bool) -> Result<(), &'static str> {
+        let field = match name {
+            PROPERTY_CAN_FREEZE => &mut self.can_freeze,
+            PROPERTY_CAN_WIPE => &mut self.can_wipe,
+            PROPERTY_CAN_PAUSE => &mut self.can_pause,
+            PROPERTY_CAN_MINT => &mut self.can_mint,
+            PROPERTY_CAN_BURN => &mut self.can_burn,
+            PROPERTY_CAN_CHANGE_OWNER => &mut self.can_change_owner,
+            PROPERTY_CAN_UPGRADE => &mut self.can_upgrade,
+            PROPERTY_CAN_ADD_SPECIAL_ROLES => &mut self.can_add_special_roles,
+            PROPERTY_CAN_TRANSFER_NFT_CREATE_ROLE => &mut self.can_transfer_nft_create_role,
+            PROPERTY_CAN_CREATE_MULTI_SHARD => &mut self.can_create_multi_shard,
+            _ => return Err("invalid argument"),
+        };
+        *field = value;
+        Ok(())
+    }
+
+    /// Parses property arguments, given as (name, "true"/"false") pairs.
+    pub fn apply_raw_args(&mut self, args: &[Vec<u8>]) -> Result<(), &'static str> {
+        if args.len() % 2 != 0 {
+            return Err("invalid number of arguments");
+        }
+        for pair in args.chunks(2) {
+            let value = match pair[1].as_slice() {
+                b"true" => true,
+                b"false" => false,
+                _ => return Err("invalid argument"),
+            };
+            self.set_by_name(&pair[0], value)?;
+        }
+        Ok(())
+    }
+}
+
+/// Token-level data, as kept by the DCT system smart contract.
+///
+/// Account-level data (balances, roles, frozen flags) is kept in each account's `AccountDct`.
+#[derive(Clone, Debug)]
+pub struct DctSystemTokenData {
+    pub owner: VMAddress,
+    pub name: Vec<u8>,
+    pub ticker: Vec<u8>,
+    pub token_type: VMTokenType,
+    pub num_decimals: u32,
+    pub paused: bool,
+    pub nft_create_stopped: bool,
+    pub burn_role_globally: bool,
+    pub num_wiped: u64,
+    pub properties: DctTokenProperties,
+}
+
+impl DctSystemTokenData {
+    pub fn new(
+        owner: VMAddress,
+        name: Vec<u8>,
+        ticker: Vec<u8>,
+        token_type: VMTokenType,
+        num_decimals: u32,
+    ) -> Self {
+        DctSystemTokenData {
+            owner,
+            name,
+            ticker,
+            token_type,
+            num_decimals,
+            paused: false,
+            nft_create_stopped: false,
+            burn_role_globally: false,
+            num_wiped: 0,
+            properties: DctTokenProperties::default(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apply_raw_args() {
+        let mut properties = DctTokenProperties::default();
+        let args = vec![
+            b"canFreeze".to_vec(),
+            b"true".to_vec(),
+            b"canUpgrade".to_vec(),
+            b"false".to_vec(),
+        ];
+        properties.apply_raw_args(&args).unwrap();
+        assert!(properties.can_freeze);
+        assert!(!properties.can_upgrade);
+        assert!(properties.can_add_special_roles);
+    }
+
+    #[test]
+    fn test_apply_raw_args_invalid() {
+        let mut properties = DctTokenProperties::default();
+        assert!(properties.apply_raw_args(&[b"canFreeze".to_vec()]).is_err());
+        assert!(properties
+            .apply_raw_args(&[b"canFly".to_vec(), b"true".to_vec()])
+            .is_err());
+        assert!(properties
+            .apply_raw_args(&[b"canFreeze".to_vec(), b"yes".to_vec()])
+            .is_err());
+    }
+}
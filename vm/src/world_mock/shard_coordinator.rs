@@ -0,0 +1,133 @@
+use crate::types::VMAddress;
+
+/// Shard id of the metachain, where the system smart contracts live.
+pub const METACHAIN_SHARD_ID: u32 = u32::MAX;
+
+/// SC addresses with this many zero bytes after the reserved prefix are on the metachain.
+const NUM_ZERO_BYTES_METACHAIN_SC: usize = 5;
+
+/// Reserved SC address prefix: 8 zero bytes + 2 bytes for the VM type.
+const SC_ADDRESS_RESERVED_PREFIX_LENGTH: usize = 10;
+
+/// Assigns accounts to shards, based on their address, the same way the node does.
+///
+/// The shard is given by the last byte of the address, for less than 256 shards.
+#[derive(Clone, Debug)]
+pub struct ShardCoordinator {
+    num_shards: u32,
+    mask_high: u32,
+    mask_low: u32,
+}
+
+impl ShardCoordinator {
+    pub fn new(num_shards: u32) -> Self {
+        assert!(num_shards > 0, "at least one shard required");
+        let n = u32::BITS - (num_shards - 1).leading_zeros();
+        ShardCoordinator {
+            num_shards,
+            mask_high: (1u32 << n) - 1,
+            mask_low: (1u32 << n >> 1).saturating_sub(1),
+        }
+    }
+
+    pub fn num_shards(&self) -> u32 {
+        self.num_shards
+    }
+
+    pub fn compute_shard(&self, address: &VMAddress) -> u32 {
+        if is_smart_contract_on_metachain(address) {
+            return METACHAIN_SHARD_ID;
+        }
+
+        let bytes_needed = if self.num_shards <= 256 { 1 } else { 2 };
+        let address_bytes = address.as_bytes();
+        let suffix = &address_bytes[address_bytes.len() - bytes_needed..];
+        let addr = suffix
+            .iter()
+            .fold(0u32, |acc, byte| (acc << 8) + *byte as u32);
+
+        let shard = addr & self.mask_high;
+        if shard > self.num_shards - 1 {
+            addr & self.mask_low
+        } else {
+            shard
+        }
+    }
+
+    pub fn same_shard(&self, address1: &VMAddress, address2: &VMAddress) -> bool {
+        self.compute_shard(address1) == self.compute_shard(address2)
+    }
+}
+
+fn is_smart_contract_on_metachain(address: &VMAddress) -> bool {
+    address.is_smart_contract_address()
+        && address.as_bytes()[SC_ADDRESS_RESERVED_PREFIX_LENGTH
+            ..SC_ADDRESS_RESERVED_PREFIX_LENGTH + NUM_ZERO_BYTES_METACHAIN_SC]
+            .iter()
+            .all(|byte| *byte == 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn address_with_last_byte(last_byte: u8) -> VMAddress {
+        let mut bytes = [b'_'; 32];
+        bytes[31] = last_byte;
+        VMAddress::new(bytes)
+    }
+
+    #[test]
+    fn test_compute_shard() {
+        let shard_coordinator = ShardCoordinator::new(3);
+        assert_eq!(
+            shard_coordinator.compute_shard(&address_with_last_byte(0)),
+            0
+        );
+        assert_eq!(
+            shard_coordinator.compute_shard(&address_with_last_byte(1)),
+            1
+        );
+        assert_eq!(
+            shard_coordinator.compute_shard(&address_with_last_byte(2)),
+            2
+        );
+        // 3 & 0b11 is out of range, so 3 & 0b01 is used instead
+        assert_eq!(
+            shard_coordinator.compute_shard(&address_with_last_byte(3)),
+            1
+        );
+        assert_eq!(
+            shard_coordinator.compute_shard(&address_with_last_byte(b'_')),
+            1
+        );
+        assert_eq!(
+            shard_coordinator.compute_shard(&address_with_last_byte(4)),
+            0
+        );
+    }
+
+    #[test]
+    fn test_single_shard() {
+        let shard_coordinator = ShardCoordinator::new(1);
+        for last_byte in 0..=255u8 {
+            assert_eq!(
+                shard_coordinator.compute_shard(&address_with_last_byte(last_byte)),
+                0
+            );
+        }
+    }
+
+    #[test]
+    fn test_metachain() {
+        let shard_coordinator = ShardCoordinator::new(3);
+        let mut system_sc_address = [0u8; 32];
+        system_sc_address[9] = 1;
+        system_sc_address[30] = 0xff;
+        system_sc_address[31] = 0xff;
+        assert_eq!(
+            shard_coordinator.compute_shard(&VMAddress::new(system_sc_address)),
+            METACHAIN_SHARD_ID
+        );
+    }
+}